@@ -0,0 +1,257 @@
+//! Command-line arguments
+//!
+//! Parsed by hand: four subcommands with a couple of options don't need an
+//! argument parser dependency.
+
+use std::fmt;
+
+use csfd_core::EpisodeSort;
+
+/// Help text printed for `--help` and after usage errors
+pub const USAGE: &str = "\
+Usage: csfd <command> [options]
+
+Commands:
+  search <query>              Search for series (e.g. csfd search dr house)
+  series <id>                 Show a series with its seasons
+  episodes <id>               List all episodes of a series
+  season <id> <number>        List the episodes of one season
+
+Options:
+  -f, --format <table|json>   Output format (default: table)
+      --json                  Same as --format json
+      --page <n>              Page of search results (default: 1)
+      --sort <aired|rating>   Order of episodes (default: aired)
+  -h, --help                  Show this help";
+
+/// How results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Aligned columns for reading in a terminal
+    #[default]
+    Table,
+    /// Pretty-printed JSON, shaped like the library's data types
+    Json,
+}
+
+/// What to fetch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Search for series by name
+    Search {
+        /// Search query
+        query: String,
+        /// Page number (1-based)
+        page: u32,
+    },
+    /// Show a series with its seasons
+    Series {
+        /// ČSFD ID of the series
+        csfd_id: u32,
+    },
+    /// List all episodes of a series
+    Episodes {
+        /// ČSFD ID of the series
+        csfd_id: u32,
+        /// Order of the listing
+        sort: EpisodeSort,
+    },
+    /// List the episodes of one season
+    Season {
+        /// ČSFD ID of the series
+        csfd_id: u32,
+        /// Season number, or position for seasons labeled by year
+        number: u8,
+        /// Order of the listing
+        sort: EpisodeSort,
+    },
+    /// Print the help text
+    Help,
+}
+
+/// Parsed command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    /// What to fetch
+    pub command: Command,
+    /// How to print it
+    pub format: Format,
+}
+
+/// Invalid command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgsError(String);
+
+impl fmt::Display for ArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ArgsError {}
+
+impl Args {
+    /// Parse arguments, without the program name.
+    ///
+    /// Options may appear anywhere, before or after the subcommand.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, ArgsError> {
+        let mut format = Format::default();
+        let mut page = None;
+        let mut sort = None;
+        let mut positional = Vec::new();
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => {
+                    (name.to_string(), Some(value.to_string()))
+                }
+                _ => (arg.clone(), None),
+            };
+            let mut value = |name: &str| {
+                inline
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| ArgsError(format!("{} needs a value", name)))
+            };
+            match name.as_str() {
+                "-h" | "--help" => {
+                    return Ok(Self {
+                        command: Command::Help,
+                        format,
+                    })
+                }
+                "--json" => format = Format::Json,
+                "-f" | "--format" => format = parse_format(&value(&name)?)?,
+                "--page" => page = Some(parse_number(&name, &value(&name)?)?),
+                "--sort" => sort = Some(parse_sort(&value(&name)?)?),
+                _ if name.starts_with('-') && name.len() > 1 => {
+                    return Err(ArgsError(format!("unknown option {}", name)))
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let mut positional = positional.into_iter();
+        let sort = sort.unwrap_or_default();
+        let command = match positional.next().as_deref() {
+            None | Some("help") => Command::Help,
+            Some("search") => {
+                let query = positional.by_ref().collect::<Vec<_>>().join(" ");
+                if query.trim().is_empty() {
+                    return Err(ArgsError("search needs a query".to_string()));
+                }
+                Command::Search {
+                    query,
+                    page: page.unwrap_or(1).max(1),
+                }
+            }
+            Some("series") => Command::Series {
+                csfd_id: required(&mut positional, "series ID")?,
+            },
+            Some("episodes") => Command::Episodes {
+                csfd_id: required(&mut positional, "series ID")?,
+                sort,
+            },
+            Some("season") => Command::Season {
+                csfd_id: required(&mut positional, "series ID")?,
+                number: required(&mut positional, "season number")?,
+                sort,
+            },
+            Some(other) => return Err(ArgsError(format!("unknown command {}", other))),
+        };
+        if let Some(extra) = positional.next() {
+            return Err(ArgsError(format!("unexpected argument {}", extra)));
+        }
+        Ok(Self { command, format })
+    }
+}
+
+/// Take the next positional argument as a number
+fn required<T: std::str::FromStr>(
+    positional: &mut impl Iterator<Item = String>,
+    what: &str,
+) -> Result<T, ArgsError> {
+    let value = positional
+        .next()
+        .ok_or_else(|| ArgsError(format!("missing {}", what)))?;
+    parse_number(what, &value)
+}
+
+fn parse_number<T: std::str::FromStr>(what: &str, value: &str) -> Result<T, ArgsError> {
+    value
+        .parse()
+        .map_err(|_| ArgsError(format!("invalid {}: {}", what, value)))
+}
+
+fn parse_format(value: &str) -> Result<Format, ArgsError> {
+    match value {
+        "table" => Ok(Format::Table),
+        "json" => Ok(Format::Json),
+        _ => Err(ArgsError(format!(
+            "unknown format {} (expected table or json)",
+            value
+        ))),
+    }
+}
+
+fn parse_sort(value: &str) -> Result<EpisodeSort, ArgsError> {
+    match value {
+        "aired" => Ok(EpisodeSort::Aired),
+        "rating" => Ok(EpisodeSort::Rating),
+        _ => Err(ArgsError(format!(
+            "unknown sort {} (expected aired or rating)",
+            value
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, ArgsError> {
+        Args::parse(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_search() {
+        let args = parse("search dr house --page 2 --json").unwrap();
+        assert_eq!(
+            args.command,
+            Command::Search {
+                query: "dr house".to_string(),
+                page: 2
+            }
+        );
+        assert_eq!(args.format, Format::Json);
+        assert!(parse("search").is_err());
+    }
+
+    #[test]
+    fn test_parse_season() {
+        let args = parse("--format=table season 264494 3 --sort rating").unwrap();
+        assert_eq!(
+            args.command,
+            Command::Season {
+                csfd_id: 264494,
+                number: 3,
+                sort: EpisodeSort::Rating
+            }
+        );
+        assert_eq!(args.format, Format::Table);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse("").unwrap().command, Command::Help);
+        assert_eq!(parse("series 1 --help").unwrap().command, Command::Help);
+        assert!(parse("series abc").is_err());
+        assert!(parse("series 1 2").is_err());
+        assert!(parse("episodes").is_err());
+        assert!(parse("episodes 1 --sort newest").is_err());
+        assert!(parse("series 1 --verbose").is_err());
+        assert!(parse("movie 1").is_err());
+        assert!(parse("series 1 -f").is_err());
+    }
+}
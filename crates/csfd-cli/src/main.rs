@@ -0,0 +1,115 @@
+//! `csfd` - ČSFD from the command line
+//!
+//! Searches series and prints their details and episodes as tables for
+//! reading, or as JSON for scripts:
+//!
+//! ```text
+//! csfd search teorie velkého třesku
+//! csfd series 234260
+//! csfd season 234260 1 --json | jq '.[].rating'
+//! ```
+//!
+//! Errors are printed in Czech when `LANG` is Czech or Slovak.
+
+mod args;
+mod output;
+
+use std::process::ExitCode;
+
+use csfd_core::{CsfdError, CsfdScraper, EpisodeSort, Locale, Result};
+
+use args::{Args, Command, USAGE};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("csfd: {}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args).await {
+        Ok(out) => {
+            print!("{}", out);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("csfd: {}", error.localized(locale()));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run a command and render its result
+async fn run(args: Args) -> Result<String> {
+    let scraper = CsfdScraper::new()?;
+    let format = args.format;
+    Ok(match args.command {
+        Command::Help => format!("{}\n", USAGE),
+        Command::Search { query, page } => {
+            let results = scraper.search_page(&query, page).await?;
+            output::render(&results, format, output::search_table)
+        }
+        Command::Series { csfd_id } => {
+            let detail = scraper.get_series(csfd_id).await?;
+            output::render(&detail, format, output::series_table)
+        }
+        Command::Episodes { csfd_id, sort } => {
+            let episodes = scraper.get_episodes(csfd_id, sort).await?;
+            output::render(&episodes, format, |episodes| {
+                output::episodes_table(episodes)
+            })
+        }
+        Command::Season {
+            csfd_id,
+            number,
+            sort,
+        } => {
+            let episodes = season_episodes(&scraper, csfd_id, number, sort).await?;
+            output::render(&episodes, format, |episodes| {
+                output::episodes_table(episodes)
+            })
+        }
+    })
+}
+
+/// Get the episodes of a season by its number.
+///
+/// Seasons labeled by year have no number and are counted by position. A
+/// series without seasons has all its episodes in season 1.
+async fn season_episodes(
+    scraper: &CsfdScraper,
+    csfd_id: u32,
+    number: u8,
+    sort: EpisodeSort,
+) -> Result<Vec<csfd_core::Episode>> {
+    let detail = scraper.get_series(csfd_id).await?;
+    if detail.seasons.is_empty() && number == 1 {
+        return scraper.get_episodes(csfd_id, sort).await;
+    }
+    let season = detail
+        .seasons
+        .iter()
+        .find(|season| season.number == Some(number))
+        .or_else(|| {
+            let index = usize::from(number).checked_sub(1)?;
+            detail
+                .seasons
+                .get(index)
+                .filter(|season| season.number.is_none())
+        })
+        .ok_or_else(|| CsfdError::NotFound(format!("season {} of series {}", number, csfd_id)))?;
+    scraper
+        .get_season_episodes(csfd_id, season.csfd_id, sort)
+        .await
+}
+
+/// Language of error messages, from `LANG` (English unless Czech or Slovak)
+fn locale() -> Locale {
+    std::env::var("LANG")
+        .ok()
+        .and_then(|lang| Locale::from_tag(&lang))
+        .unwrap_or_default()
+}
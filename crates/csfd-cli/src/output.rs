@@ -0,0 +1,193 @@
+//! Printing results as tables or JSON
+
+use std::fmt::Write;
+
+use csfd_core::{Episode, PaginatedResult, SearchResult, SeriesDetail, SeriesType};
+use serde::Serialize;
+
+use crate::args::Format;
+
+/// Placeholder for missing values
+const NONE: &str = "—";
+
+/// Text table with columns padded to their widest cell
+struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    fn new(headers: &[&'static str]) -> Self {
+        Self {
+            headers: headers.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    fn render(&self) -> String {
+        let width = |cell: &str| cell.chars().count();
+        let mut widths: Vec<usize> = self.headers.iter().map(|header| width(header)).collect();
+        for row in &self.rows {
+            for (column, cell) in row.iter().enumerate() {
+                widths[column] = widths[column].max(width(cell));
+            }
+        }
+
+        let mut out = String::new();
+        let headers = self.headers.iter().map(|header| header.to_string());
+        for cells in std::iter::once(headers.collect()).chain(self.rows.iter().cloned()) {
+            let line: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &column)| format!("{}{}", cell, " ".repeat(column - width(cell))))
+                .collect();
+            let _ = writeln!(out, "{}", line.join("  ").trim_end());
+        }
+        out
+    }
+}
+
+/// Render a result as JSON or as a table
+pub fn render<T: Serialize>(value: &T, format: Format, table: impl FnOnce(&T) -> String) -> String {
+    match format {
+        Format::Json => json(value),
+        Format::Table => table(value),
+    }
+}
+
+/// Pretty-printed JSON of a result
+fn json<T: Serialize>(value: &T) -> String {
+    let mut json = serde_json::to_string_pretty(value).expect("data types serialize to JSON");
+    json.push('\n');
+    json
+}
+
+/// Search results, one per line, with the page position
+pub fn search_table(results: &PaginatedResult<SearchResult>) -> String {
+    if results.items.is_empty() {
+        return "No series found\n".to_string();
+    }
+    let mut table = Table::new(&["ID", "NAME", "YEAR", "TYPE"]);
+    for result in &results.items {
+        table.row(vec![
+            result.csfd_id.to_string(),
+            result.name.clone(),
+            result.year.clone().unwrap_or_else(|| NONE.to_string()),
+            series_type(&result.series_type).to_string(),
+        ]);
+    }
+    let mut out = table.render();
+    let _ = write!(out, "\nPage {}", results.current_page);
+    if results.has_next_page {
+        let _ = write!(out, ", next with --page {}", results.current_page + 1);
+    }
+    out.push('\n');
+    out
+}
+
+/// Series overview followed by its seasons
+pub fn series_table(detail: &SeriesDetail) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{} ({})", detail.name, detail.csfd_id);
+    let mut fields = vec![
+        ("Original name", detail.original_name.clone()),
+        ("Years", detail.year_range.clone()),
+        ("Rating", detail.rating.map(percent)),
+        ("Genres", Some(detail.genres.join(", "))),
+        ("Countries", Some(detail.countries.join(", "))),
+        ("IMDb", detail.imdb_id.clone()),
+    ];
+    fields.retain(|(_, value)| value.as_deref().is_some_and(|value| !value.is_empty()));
+    for (label, value) in fields {
+        let _ = writeln!(
+            out,
+            "{:<14} {}",
+            format!("{}:", label),
+            value.unwrap_or_default()
+        );
+    }
+
+    if !detail.seasons.is_empty() {
+        let mut table = Table::new(&["#", "ID", "SEASON", "YEAR", "EPISODES", "RATING"]);
+        for (index, season) in detail.seasons.iter().enumerate() {
+            let number = season.number.map_or(index + 1, usize::from);
+            table.row(vec![
+                number.to_string(),
+                season.csfd_id.to_string(),
+                season.name.clone(),
+                season.year.clone().unwrap_or_else(|| NONE.to_string()),
+                season.episode_count.to_string(),
+                season.rating.map_or_else(|| NONE.to_string(), percent),
+            ]);
+        }
+        out.push('\n');
+        out.push_str(&table.render());
+    }
+    out
+}
+
+/// Episodes with their codes and ratings
+pub fn episodes_table(episodes: &[Episode]) -> String {
+    let mut table = Table::new(&["CODE", "ID", "NAME", "RATING"]);
+    for episode in episodes {
+        table.row(vec![
+            episode.code().to_string(),
+            episode.csfd_id.to_string(),
+            episode.name.clone(),
+            episode.rating.map_or_else(|| NONE.to_string(), percent),
+        ]);
+    }
+    table.render()
+}
+
+fn percent(rating: f32) -> String {
+    format!("{:.0}%", rating)
+}
+
+fn series_type(series_type: &SeriesType) -> &'static str {
+    match series_type {
+        SeriesType::Series => "series",
+        SeriesType::Season => "season",
+        SeriesType::MiniSeries => "miniseries",
+        SeriesType::TvFilm => "TV film",
+        SeriesType::Film => "film",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_pads_columns() {
+        let mut table = Table::new(&["CODE", "NAME"]);
+        table.row(vec!["S01E01".to_string(), "Pilot".to_string()]);
+        table.row(vec!["S01E02".to_string(), "Únava".to_string()]);
+        assert_eq!(
+            table.render(),
+            "CODE    NAME\nS01E01  Pilot\nS01E02  Únava\n"
+        );
+    }
+
+    #[test]
+    fn test_episodes_table() {
+        let episode = Episode {
+            csfd_id: 42,
+            name: "Pilot".to_string(),
+            season_number: 1,
+            episode_number: 1,
+            rating: Some(80.4),
+            url: String::new(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        };
+        let table = episodes_table(&[episode]);
+        assert_eq!(table.lines().nth(1), Some("S01E01  42  Pilot  80%"));
+    }
+}
@@ -0,0 +1,121 @@
+//! Allocation benchmark for the episode, search and series parsers
+//!
+//! Counts heap allocations made while parsing synthetic pages shaped like
+//! ČSFD listings and detail pages. Genres and countries are interned, so
+//! parsing the same series again allocates nothing for them. Run with:
+//!
+//! ```text
+//! cargo bench -p csfd-core --features unstable --bench parse_allocations
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use csfd_core::unstable::parser::{parse_episodes, parse_search_results, parse_series_detail};
+
+/// System allocator that counts allocations
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Episodes page with `count` episodes spread over seasons of 10
+fn episodes_page(count: u32) -> String {
+    let mut html = String::from("<html><body>");
+    for i in 0..count {
+        let (season, episode) = (i / 10 + 1, i % 10 + 1);
+        html.push_str(&format!(
+            r#"<article><h3 class="film-title">
+                <a href="/film/1000-serial/{id}-epizoda-{i}/prehled/" class="film-title-name">Epizoda {i}</a>
+                <span class="film-title-info"><span class="info">(S{season:02}E{episode:02})</span></span>
+            </h3></article>"#,
+            id = 20000 + i,
+        ));
+    }
+    html.push_str("</body></html>");
+    html
+}
+
+/// Search page with `count` results
+fn search_page(count: u32) -> String {
+    let mut html = String::from("<html><body>");
+    for i in 0..count {
+        html.push_str(&format!(
+            r#"<article class="article-poster-50"><h3 class="film-title-norating">
+                <a href="/film/{id}-serial-{i}/" class="film-title-name">Seriál {i}</a>
+                <span class="film-title-info"><span class="info">(2010)</span> <span class="info">(seriál)</span></span>
+            </h3></article>"#,
+            id = 30000 + i,
+        ));
+    }
+    html.push_str("</body></html>");
+    html
+}
+
+/// Series detail page with a typical genre and country header
+fn series_page() -> String {
+    r#"<html><body>
+        <h1>Seriál</h1>
+        <div class="film-header-origin">
+            <div class="genre"><a href="/zanr/1-drama/">Drama</a> / <a href="/zanr/5-krimi/">Krimi</a> / <a href="/zanr/9-thriller/">Thriller</a></div>
+            <div class="origin"><a href="/zeme/usa/">USA</a> / <a href="/zeme/kanada/">Kanada</a>, 2008–2013, 62 epizod</div>
+        </div>
+    </body></html>"#
+        .to_string()
+}
+
+/// Parse `html` repeatedly and print allocations per parse
+fn measure<T>(name: &str, html: &str, parse: impl Fn(&str) -> T) {
+    const RUNS: usize = 20;
+
+    // Warm up selector and regex caches
+    parse(html);
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        std::hint::black_box(parse(html));
+    }
+    let elapsed = start.elapsed() / RUNS as u32;
+
+    println!(
+        "{name}: {} allocations, {} KiB allocated, {:?} per parse",
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / RUNS,
+        (BYTES.load(Ordering::Relaxed) - bytes) / RUNS / 1024,
+        elapsed,
+    );
+}
+
+fn main() {
+    let episodes = episodes_page(300);
+    measure("parse_episodes (300 episodes)", &episodes, |html| {
+        parse_episodes(html).unwrap()
+    });
+
+    let series = series_page();
+    measure("parse_series_detail (5 labels)", &series, |html| {
+        parse_series_detail(html, 1000).unwrap()
+    });
+
+    let search = search_page(50);
+    measure("parse_search_results (50 results)", &search, |html| {
+        parse_search_results(html).unwrap()
+    });
+}
@@ -0,0 +1,19 @@
+//! Build a seed catalog of the best-rated series for bundling with an app.
+//!
+//! Usage: cargo run --example build_seed -- seed.json [limit]
+
+use csfd_core::CsfdScraper;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().unwrap_or_else(|| "seed.json".to_string());
+    let limit = args.next().map(|l| l.parse()).transpose()?.unwrap_or(500);
+
+    let scraper = CsfdScraper::new()?;
+    let series = scraper.get_top_series(limit).await?;
+    std::fs::write(&path, serde_json::to_vec(&series)?)?;
+
+    println!("Uloženo {} seriálů do {}", series.len(), path);
+    Ok(())
+}
@@ -0,0 +1,136 @@
+//! Scraper activity events
+//!
+//! The client reports what it is doing (requests, retries, rate-limit waits
+//! and adjustments, cache hits) on a broadcast channel. Frontends subscribe
+//! to it to show a live network activity panel during slow operations; when
+//! nobody listens, sending an event costs next to nothing.
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+/// Number of events buffered per subscriber before the oldest are dropped
+const ACTIVITY_CHANNEL_CAPACITY: usize = 256;
+
+/// Something the scraper did, as reported to activity subscribers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActivityEvent {
+    /// A request to ČSFD was sent
+    RequestStarted {
+        /// HTTP method (e.g., "GET")
+        method: String,
+        /// Relative path or absolute URL
+        path: String,
+    },
+    /// A request to ČSFD completed
+    RequestFinished {
+        /// HTTP method (e.g., "GET")
+        method: String,
+        /// Relative path or absolute URL
+        path: String,
+        /// HTTP status code, `None` if the request failed without a response
+        status: Option<u16>,
+        /// Time from sending to the response in milliseconds
+        elapsed_ms: u64,
+    },
+    /// A failed request will be retried after a delay
+    Retry {
+        /// Relative path or absolute URL
+        path: String,
+        /// Retry attempt (1-based)
+        attempt: u32,
+        /// Backoff delay before the retry in milliseconds
+        delay_ms: u64,
+    },
+    /// A request waited for the rate limiter
+    RateLimitWait {
+        /// Time spent waiting in milliseconds
+        waited_ms: u64,
+    },
+    /// Adaptive rate limiting changed the interval between requests
+    RateAdjusted {
+        /// New interval between requests in milliseconds
+        interval_ms: u64,
+    },
+    /// Data was served from a cache instead of ČSFD
+    CacheHit {
+        /// What was looked up (e.g., "cast:123")
+        key: String,
+    },
+}
+
+/// Broadcast channel of activity events
+///
+/// Cloning shares the channel, so a client and the scraper built on it
+/// report to the same subscribers.
+#[derive(Debug, Clone)]
+pub struct ActivityLog {
+    sender: broadcast::Sender<ActivityEvent>,
+}
+
+impl Default for ActivityLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActivityLog {
+    /// Create a channel without subscribers
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(ACTIVITY_CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    /// Subscribe to events emitted from now on
+    ///
+    /// A subscriber that falls behind by more than the channel capacity
+    /// misses the oldest events and gets `RecvError::Lagged`.
+    pub fn subscribe(&self) -> broadcast::Receiver<ActivityEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Report an event to all current subscribers
+    pub fn emit(&self, event: ActivityEvent) {
+        // Without subscribers the event is simply dropped
+        let _ = self.sender.send(event);
+    }
+
+    /// Check whether anyone is listening, to skip building costly events
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_activity_log_delivers_to_subscribers() {
+        let log = ActivityLog::new();
+        assert!(!log.has_subscribers());
+        log.emit(ActivityEvent::CacheHit {
+            key: "dropped".to_string(),
+        });
+
+        let mut receiver = log.subscribe();
+        assert!(log.has_subscribers());
+        log.emit(ActivityEvent::RateLimitWait { waited_ms: 250 });
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            ActivityEvent::RateLimitWait { waited_ms: 250 }
+        );
+    }
+
+    #[test]
+    fn test_activity_event_serializes_tagged() {
+        let event = ActivityEvent::Retry {
+            path: "/film/1/".to_string(),
+            attempt: 1,
+            delay_ms: 1000,
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "retry");
+        assert_eq!(json["delay_ms"], 1000);
+    }
+}
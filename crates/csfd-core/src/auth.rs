@@ -0,0 +1,187 @@
+//! Logging in to ČSFD.cz
+//!
+//! Personal pages (ratings, watchlist, custom lists) need a logged-in
+//! session. Logging in fetches the login form for its CSRF token, submits
+//! it with the credentials and keeps the session cookie in the client's
+//! cookie jar. A session restored from cookies (see
+//! `CsfdClient::import_cookies`) works the same way once validated.
+//!
+//! `PasswordLogin` implements `Reauthenticate`, so a client that logged in
+//! with a password logs in again by itself when ČSFD drops the session.
+
+use std::fmt;
+
+use futures::future::BoxFuture;
+
+use crate::client::{CsfdClient, Reauthenticate};
+use crate::error::{CsfdError, Result};
+use crate::parser::{is_logged_in, parse_form_action, parse_form_error, parse_hidden_inputs};
+
+/// Page with the login form
+pub const LOGIN_PATH: &str = "/prihlaseni/";
+
+/// Page fetched to check whether the session is still logged in
+const SESSION_CHECK_PATH: &str = "/";
+
+/// Username and password of a ČSFD account
+///
+/// `Debug` hides the password.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PasswordLogin {
+    username: String,
+    password: String,
+}
+
+impl PasswordLogin {
+    /// Create a login with a username (or e-mail) and password
+    pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Get the username
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Log the client in
+    ///
+    /// # Errors
+    /// - `CsfdError::InvalidUrl` - The username or password is empty
+    /// - `CsfdError::LoginFailed` - ČSFD rejected the credentials
+    /// - `CsfdError::ParseError` - The login form wasn't found
+    /// - Any error of fetching the form or submitting it
+    pub async fn login(&self, client: &CsfdClient) -> Result<()> {
+        login(client, &self.username, &self.password).await
+    }
+}
+
+impl fmt::Debug for PasswordLogin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PasswordLogin")
+            .field("username", &self.username)
+            .field("password", &"…")
+            .finish()
+    }
+}
+
+impl Reauthenticate for PasswordLogin {
+    fn reauthenticate<'a>(&'a self, client: &'a CsfdClient) -> BoxFuture<'a, Result<()>> {
+        Box::pin(self.login(client))
+    }
+}
+
+/// Log a client in with a username and password
+///
+/// The session cookie stays in the client's cookie jar; save it with
+/// `CsfdClient::save_cookies` to skip logging in on the next start.
+///
+/// # Arguments
+/// * `client` - Client to log in
+/// * `username` - ČSFD username or e-mail
+/// * `password` - ČSFD password
+///
+/// # Errors
+/// - `CsfdError::InvalidUrl` - The username or password is empty
+/// - `CsfdError::LoginFailed` - ČSFD rejected the credentials
+/// - `CsfdError::ParseError` - The login form wasn't found
+/// - Any error of fetching the form or submitting it
+pub async fn login(client: &CsfdClient, username: &str, password: &str) -> Result<()> {
+    let username = username.trim();
+    if username.is_empty() || password.is_empty() {
+        return Err(CsfdError::InvalidUrl(
+            "Username and password cannot be empty".to_string(),
+        ));
+    }
+
+    let form = client.fetch_personal(LOGIN_PATH).await?;
+    if is_logged_in(&form) {
+        return Ok(());
+    }
+    let action = login_action(&form)?;
+    let hidden = parse_hidden_inputs(&form);
+    let params = login_params(&hidden, username, password);
+
+    let html = client.post_login(&action, &params).await?;
+    if is_logged_in(&html) {
+        Ok(())
+    } else {
+        Err(CsfdError::LoginFailed(
+            parse_form_error(&html).unwrap_or_else(|| "the response is not logged in".to_string()),
+        ))
+    }
+}
+
+/// Check whether the client's session is logged in
+///
+/// Fetches a page past all caches, so it reflects the current cookies.
+///
+/// # Errors
+/// Any error of fetching the page
+pub async fn validate_session(client: &CsfdClient) -> Result<bool> {
+    let html = client.fetch_personal(SESSION_CHECK_PATH).await?;
+    Ok(is_logged_in(&html))
+}
+
+/// Path the login form is submitted to
+fn login_action(form: &str) -> Result<String> {
+    if !form.contains("name=\"password\"") && !form.contains("type=\"password\"") {
+        return Err(CsfdError::ParseError(
+            "login form not found - markup may have changed".to_string(),
+        ));
+    }
+    Ok(parse_form_action(form).unwrap_or_else(|| LOGIN_PATH.to_string()))
+}
+
+/// Form fields of a login: the hidden fields (CSRF token) and the credentials
+fn login_params<'a>(
+    hidden: &'a [(String, String)],
+    username: &'a str,
+    password: &'a str,
+) -> Vec<(&'a str, &'a str)> {
+    hidden
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .chain([
+            ("username", username),
+            ("password", password),
+            ("permanent", "on"),
+        ])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_login_params() {
+        let form = r#"
+            <form action="/prihlaseni/?do=form-submit" method="post">
+                <input type="text" name="username">
+                <input type="password" name="password">
+                <input type="hidden" name="_token_" value="csrf123">
+            </form>
+        "#;
+        assert_eq!(login_action(form).unwrap(), "/prihlaseni/?do=form-submit");
+        assert!(matches!(
+            login_action("<html><body>Údržba</body></html>"),
+            Err(CsfdError::ParseError(_))
+        ));
+
+        let hidden = parse_hidden_inputs(form);
+        let params = login_params(&hidden, "nick", "secret");
+        assert_eq!(
+            params,
+            vec![
+                ("_token_", "csrf123"),
+                ("username", "nick"),
+                ("password", "secret"),
+                ("permanent", "on"),
+            ]
+        );
+        assert!(!format!("{:?}", PasswordLogin::new("nick", "secret")).contains("secret"));
+    }
+}
@@ -0,0 +1,745 @@
+//! Caches of fetched pages
+//!
+//! `CsfdClient::fetch` keeps recently fetched pages in memory for the
+//! session, bounded by `ClientConfig::memory_cache_capacity` and evicting the
+//! least recently used page first. With the `cache` feature, `DiskCache`
+//! also keeps them as files keyed by their path, so repeated `get_series`
+//! or `get_episodes` calls, also across restarts, are served locally until
+//! the page's TTL runs out. Pages that ČSFD sends with an `ETag` or
+//! `Last-Modified` are also kept for conditional requests, so refreshing an
+//! unchanged page costs a `304 Not Modified` instead of the whole page.
+//! `CacheStats` reports how well a cache works.
+//!
+//! `CacheStrategy` decides what an expired page is good for: by default it
+//! is refetched before the call returns, while `StaleWhileRevalidate`
+//! returns it at once and leaves the refetch to `CsfdClient::revalidate`.
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "cache")]
+use std::path::PathBuf;
+#[cfg(feature = "cache")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "cache")]
+use crate::error::{CsfdError, Result};
+#[cfg(feature = "cache")]
+use crate::hash::ContentHasher;
+
+/// Counters of a page cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Lookups served from the cache
+    pub hits: u64,
+    /// Lookups that found no fresh page
+    pub misses: u64,
+    /// Pages stored
+    pub writes: u64,
+}
+
+impl CacheStats {
+    /// Get the share of lookups served from the cache, 0.0 before any lookup
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// What the client does with a cached page whose TTL ran out
+///
+/// # Example
+/// ```
+/// use csfd_core::cache::CacheStrategy;
+/// use csfd_core::ClientConfig;
+///
+/// let config = ClientConfig {
+///     cache_strategy: CacheStrategy::StaleWhileRevalidate,
+///     ..ClientConfig::default()
+/// };
+/// assert!(config.cache_strategy.serves_stale());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheStrategy {
+    /// Refetch an expired page before returning it, so every call sees
+    /// data within its TTL; suits scripts and the command line
+    #[default]
+    CacheAside,
+    /// Return an expired page at once and queue it for
+    /// `CsfdClient::revalidate`; suits apps that render cached data
+    /// immediately and refresh it in the background
+    StaleWhileRevalidate,
+}
+
+impl CacheStrategy {
+    /// Whether expired pages are returned instead of refetched
+    pub fn serves_stale(self) -> bool {
+        self == Self::StaleWhileRevalidate
+    }
+}
+
+/// Counters shared by the lookups of one cache
+#[derive(Debug, Default)]
+pub(crate) struct StatsCounter {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    writes: AtomicU64,
+}
+
+impl StatsCounter {
+    pub(crate) fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn write(&self) {
+        self.writes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            writes: self.writes.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Recently fetched pages kept in memory
+///
+/// Pages older than the TTL read as misses; when the cache is full, the
+/// least recently used page makes room for a new one. A capacity of 0
+/// disables the cache.
+#[derive(Debug)]
+pub(crate) struct MemoryCache {
+    capacity: usize,
+    ttl: Duration,
+    pages: Mutex<LruMap<String, (Instant, String)>>,
+    stats: StatsCounter,
+}
+
+/// Values keyed by path or ID, evicting the least recently used first
+#[derive(Debug)]
+pub(crate) struct LruMap<K, V> {
+    entries: HashMap<K, V>,
+    /// Keys from least to most recently used
+    order: VecDeque<K>,
+}
+
+impl<K, V> Default for LruMap<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+}
+
+impl<K: Eq + Hash + Clone, V> LruMap<K, V> {
+    pub(crate) fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.get(key)
+    }
+
+    /// Mark a key as most recently used
+    pub(crate) fn touch<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if let Some(index) = self.order.iter().position(|k| k.borrow() == key) {
+            if let Some(key) = self.order.remove(index) {
+                self.order.push_back(key);
+            }
+        }
+    }
+
+    /// Insert a value, evicting the least recently used ones beyond `capacity`
+    pub(crate) fn insert(&mut self, key: K, value: V, capacity: usize) {
+        if self.entries.insert(key.clone(), value).is_some() {
+            self.touch(&key);
+        } else {
+            self.order.push_back(key);
+        }
+        while self.order.len() > capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    pub(crate) fn remove<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entries.remove(key);
+        self.order.retain(|k| k.borrow() != key);
+    }
+
+    /// Remove the values of all keys matching a predicate
+    pub(crate) fn remove_matching(&mut self, matches: impl Fn(&K) -> bool) {
+        self.entries.retain(|key, _| !matches(key));
+        self.order.retain(|key| !matches(key));
+    }
+}
+
+impl MemoryCache {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            pages: Mutex::new(LruMap::default()),
+            stats: StatsCounter::default(),
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.capacity > 0 && !self.ttl.is_zero()
+    }
+
+    /// Get a page fetched less than the TTL ago
+    pub(crate) fn get(&self, path: &str) -> Option<String> {
+        self.lookup(path, false).map(|(html, _)| html)
+    }
+
+    /// Get a stored page of any age, with whether its TTL ran out
+    pub(crate) fn get_stale(&self, path: &str) -> Option<(String, bool)> {
+        self.lookup(path, true)
+    }
+
+    /// Get a page, dropping it if expired unless `keep_stale` is set
+    fn lookup(&self, path: &str, keep_stale: bool) -> Option<(String, bool)> {
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        let page = match pages.get(path) {
+            Some((stored, html)) if stored.elapsed() < self.ttl => Some((html.clone(), false)),
+            Some((_, html)) if keep_stale => Some((html.clone(), true)),
+            Some(_) => {
+                pages.remove(path);
+                None
+            }
+            None => None,
+        };
+        match page {
+            Some(_) => {
+                pages.touch(path);
+                self.stats.hit();
+            }
+            None => self.stats.miss(),
+        }
+        page
+    }
+
+    /// Store a page, evicting the least recently used one if the cache is full
+    pub(crate) fn put(&self, path: &str, html: &str) {
+        if !self.is_enabled() {
+            return;
+        }
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        pages.insert(
+            path.to_string(),
+            (Instant::now(), html.to_string()),
+            self.capacity,
+        );
+        self.stats.write();
+    }
+
+    /// Remove all pages, keeping the counters
+    pub(crate) fn clear(&self) {
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        *pages = LruMap::default();
+    }
+
+    /// Remove the pages whose paths match a predicate
+    pub(crate) fn remove_matching(&self, matches: impl Fn(&str) -> bool) {
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        pages.remove_matching(|path| matches(path));
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        self.stats.snapshot()
+    }
+}
+
+/// Validators and bodies of fetched pages for conditional requests
+///
+/// The client sends a stored page's `ETag` as `If-None-Match` and its
+/// `Last-Modified` as `If-Modified-Since`; a `304 Not Modified` answer is
+/// served from the stored body. Pages without validators aren't stored.
+#[derive(Debug)]
+pub(crate) struct ValidatorCache {
+    capacity: usize,
+    pages: Mutex<LruMap<String, ValidatedPage>>,
+    stats: StatsCounter,
+}
+
+#[derive(Debug)]
+struct ValidatedPage {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    body: String,
+}
+
+impl ValidatorCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            pages: Mutex::new(LruMap::default()),
+            stats: StatsCounter::default(),
+        }
+    }
+
+    /// Get the `ETag` and `Last-Modified` values stored for a page
+    pub(crate) fn validators(&self, path: &str) -> Option<(Option<String>, Option<String>)> {
+        let pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        pages
+            .get(path)
+            .map(|page| (page.etag.clone(), page.last_modified.clone()))
+    }
+
+    /// Get the stored body of a page ČSFD answered with 304
+    pub(crate) fn not_modified(&self, path: &str) -> Option<String> {
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        let body = pages.get(path).map(|page| page.body.clone());
+        match body {
+            Some(_) => {
+                pages.touch(path);
+                self.stats.hit();
+            }
+            None => self.stats.miss(),
+        }
+        body
+    }
+
+    /// Store a page fetched in full; a changed page counts as a miss
+    pub(crate) fn store(
+        &self,
+        path: &str,
+        etag: Option<String>,
+        last_modified: Option<String>,
+        body: &str,
+    ) {
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        if pages.get(path).is_some() {
+            self.stats.miss();
+        }
+        if self.capacity == 0 || (etag.is_none() && last_modified.is_none()) {
+            pages.remove(path);
+            return;
+        }
+        let page = ValidatedPage {
+            etag,
+            last_modified,
+            body: body.to_string(),
+        };
+        pages.insert(path.to_string(), page, self.capacity);
+        self.stats.write();
+    }
+
+    /// Remove all pages, keeping the counters
+    pub(crate) fn clear(&self) {
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        *pages = LruMap::default();
+    }
+
+    /// Remove the pages whose paths match a predicate
+    pub(crate) fn remove_matching(&self, matches: impl Fn(&str) -> bool) {
+        let mut pages = self.pages.lock().unwrap_or_else(|e| e.into_inner());
+        pages.remove_matching(|path| matches(path));
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        self.stats.snapshot()
+    }
+}
+
+/// Where and how long fetched pages are kept on disk
+#[cfg(feature = "cache")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskCacheConfig {
+    /// Directory of the cached pages, created on first write
+    pub dir: PathBuf,
+    /// How long a detail or episode page is served from the cache
+    pub ttl: Duration,
+    /// How long a search page is served from the cache
+    pub search_ttl: Duration,
+}
+
+#[cfg(feature = "cache")]
+impl DiskCacheConfig {
+    /// Cache pages in a directory with the TTLs of a freshness policy
+    ///
+    /// A cached page doesn't tell whether its series still runs, so pages
+    /// other than search use the shorter of the running series TTLs.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::cache::DiskCacheConfig;
+    /// use csfd_core::FreshnessPolicy;
+    ///
+    /// let config = DiskCacheConfig::new("/tmp/csfd-cache", &FreshnessPolicy::default());
+    /// assert!(config.search_ttl < config.ttl);
+    /// ```
+    pub fn new(dir: impl Into<PathBuf>, freshness: &crate::FreshnessPolicy) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: freshness.ongoing_series.min(freshness.ongoing_episodes),
+            search_ttl: freshness.search,
+        }
+    }
+
+    /// Get the TTL of a page; search results go stale sooner than details
+    fn ttl_for(&self, path: &str) -> Duration {
+        if path.starts_with("/hledat/") {
+            self.search_ttl
+        } else {
+            self.ttl
+        }
+    }
+}
+
+/// Fetched pages kept as files, keyed by path
+///
+/// Each page is one file named by the hash of its path. The file starts
+/// with the time it was stored and the path, so an expired page or a hash
+/// collision reads as a miss.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use csfd_core::cache::{DiskCache, DiskCacheConfig};
+///
+/// let dir = std::env::temp_dir().join("csfd-cache-doc");
+/// let cache = DiskCache::new(DiskCacheConfig {
+///     dir,
+///     ttl: Duration::from_secs(3600),
+///     search_ttl: Duration::from_secs(60),
+/// });
+/// cache.put("/film/264494/prehled/", "<html></html>").unwrap();
+/// assert_eq!(cache.get("/film/264494/prehled/").as_deref(), Some("<html></html>"));
+/// # cache.clear().unwrap();
+/// ```
+#[cfg(feature = "cache")]
+#[derive(Debug)]
+pub struct DiskCache {
+    config: DiskCacheConfig,
+    stats: StatsCounter,
+}
+
+#[cfg(feature = "cache")]
+impl DiskCache {
+    /// Create a cache; nothing is read or written until it is used
+    pub fn new(config: DiskCacheConfig) -> Self {
+        Self {
+            config,
+            stats: StatsCounter::default(),
+        }
+    }
+
+    /// Get the configuration of the cache
+    pub fn config(&self) -> &DiskCacheConfig {
+        &self.config
+    }
+
+    /// Get a stored page that is still fresh
+    ///
+    /// Unreadable files count as misses, so a damaged cache only costs a refetch.
+    pub fn get(&self, path: &str) -> Option<String> {
+        self.lookup(path, false).map(|(html, _)| html)
+    }
+
+    /// Get a stored page of any age, with whether its TTL ran out
+    ///
+    /// Used to serve stale pages while they are refetched, see
+    /// `CacheStrategy::StaleWhileRevalidate`.
+    pub fn get_stale(&self, path: &str) -> Option<(String, bool)> {
+        self.lookup(path, true)
+    }
+
+    fn lookup(&self, path: &str, keep_stale: bool) -> Option<(String, bool)> {
+        let page = self
+            .read(path)
+            .filter(|(_, expired)| keep_stale || !expired);
+        match page {
+            Some(_) => self.stats.hit(),
+            None => self.stats.miss(),
+        }
+        page
+    }
+
+    fn read(&self, path: &str) -> Option<(String, bool)> {
+        let contents = std::fs::read_to_string(self.file(path)).ok()?;
+        let (stored_at, rest) = contents.split_once('\n')?;
+        let (stored_path, body) = rest.split_once('\n')?;
+        if stored_path != path {
+            return None;
+        }
+
+        let stored_at = UNIX_EPOCH + Duration::from_secs(stored_at.parse().ok()?);
+        let age = SystemTime::now()
+            .duration_since(stored_at)
+            .unwrap_or_default();
+        Some((body.to_string(), age >= self.config.ttl_for(path)))
+    }
+
+    /// Store a page, replacing an older copy
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the file cannot be written
+    pub fn put(&self, path: &str, html: &str) -> Result<()> {
+        let storage_error = |e: std::io::Error| {
+            CsfdError::StorageError(format!("{}: {}", self.config.dir.display(), e))
+        };
+        std::fs::create_dir_all(&self.config.dir).map_err(storage_error)?;
+
+        let stored_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let file = self.file(path);
+        // Write next to the page and rename, so readers never see half a page
+        let partial = file.with_extension("tmp");
+        std::fs::write(&partial, format!("{}\n{}\n{}", stored_at, path, html))
+            .and_then(|()| std::fs::rename(&partial, &file))
+            .map_err(storage_error)?;
+
+        self.stats.write();
+        Ok(())
+    }
+
+    /// Remove all stored pages
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the directory cannot be removed
+    pub fn clear(&self) -> Result<()> {
+        match std::fs::remove_dir_all(&self.config.dir) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(CsfdError::StorageError(format!(
+                "{}: {}",
+                self.config.dir.display(),
+                e
+            ))),
+        }
+    }
+
+    /// Remove the stored pages whose paths match a predicate
+    ///
+    /// Reads the path at the start of every file, so it costs a pass over
+    /// the directory; unreadable files are left alone.
+    ///
+    /// # Returns
+    /// The number of pages removed
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the directory cannot be listed or
+    /// a matching page cannot be removed
+    pub fn remove_matching(&self, matches: impl Fn(&str) -> bool) -> Result<usize> {
+        let storage_error = |e: std::io::Error| {
+            CsfdError::StorageError(format!("{}: {}", self.config.dir.display(), e))
+        };
+        let entries = match std::fs::read_dir(&self.config.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(storage_error(e)),
+        };
+
+        let mut removed = 0;
+        for entry in entries {
+            let file = entry.map_err(storage_error)?.path();
+            let Ok(contents) = std::fs::read_to_string(&file) else {
+                continue;
+            };
+            let stored_path = contents.split('\n').nth(1);
+            if stored_path.is_some_and(&matches) {
+                std::fs::remove_file(&file).map_err(storage_error)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Get the hits, misses and writes since the cache was created
+    pub fn stats(&self) -> CacheStats {
+        self.stats.snapshot()
+    }
+
+    fn file(&self, path: &str) -> PathBuf {
+        let key = ContentHasher::new().str(path).finish();
+        self.config.dir.join(format!("{}.html", key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_cache_evicts_least_recently_used() {
+        let cache = MemoryCache::new(2, Duration::from_secs(60));
+        cache.put("/a/", "a");
+        cache.put("/b/", "b");
+        assert_eq!(cache.get("/a/").as_deref(), Some("a"));
+
+        cache.put("/c/", "c");
+        assert_eq!(cache.get("/b/"), None);
+        assert_eq!(cache.get("/a/").as_deref(), Some("a"));
+        assert_eq!(cache.get("/c/").as_deref(), Some("c"));
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 3,
+                misses: 1,
+                writes: 3
+            }
+        );
+
+        cache.clear();
+        assert_eq!(cache.get("/a/"), None);
+
+        cache.put("/film/1/prehled/", "1");
+        cache.put("/film/12/prehled/", "12");
+        cache.remove_matching(|path| path.starts_with("/film/1/"));
+        assert_eq!(cache.get("/film/1/prehled/"), None);
+        assert_eq!(cache.get("/film/12/prehled/").as_deref(), Some("12"));
+    }
+
+    #[test]
+    fn test_memory_cache_expired_or_disabled() {
+        let expired = MemoryCache::new(4, Duration::from_nanos(1));
+        expired.put("/a/", "a");
+        std::thread::sleep(Duration::from_millis(1));
+        assert_eq!(expired.get_stale("/a/"), Some(("a".to_string(), true)));
+        assert_eq!(expired.get("/a/"), None);
+        assert_eq!(expired.get_stale("/a/"), None);
+
+        let disabled = MemoryCache::new(0, Duration::from_secs(60));
+        assert!(!disabled.is_enabled());
+        disabled.put("/a/", "a");
+        assert_eq!(disabled.get("/a/"), None);
+        assert_eq!(disabled.stats().writes, 0);
+    }
+
+    #[test]
+    fn test_validator_cache() {
+        let cache = ValidatorCache::new(4);
+        cache.store("/a/", None, None, "no validators");
+        assert_eq!(cache.validators("/a/"), None);
+
+        cache.store("/a/", Some("\"v1\"".to_string()), None, "first");
+        assert_eq!(
+            cache.validators("/a/"),
+            Some((Some("\"v1\"".to_string()), None))
+        );
+        assert_eq!(cache.not_modified("/a/").as_deref(), Some("first"));
+
+        // A full response replaces the stored page
+        let modified = "Wed, 21 Oct 2026 07:28:00 GMT".to_string();
+        cache.store("/a/", None, Some(modified.clone()), "second");
+        assert_eq!(cache.validators("/a/"), Some((None, Some(modified))));
+        assert_eq!(cache.not_modified("/a/").as_deref(), Some("second"));
+        assert_eq!(cache.not_modified("/b/"), None);
+
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 2,
+                writes: 2
+            }
+        );
+    }
+
+    #[cfg(feature = "cache")]
+    fn cache(dir: &std::path::Path, ttl: Duration) -> DiskCache {
+        DiskCache::new(DiskCacheConfig {
+            dir: dir.join("pages"),
+            ttl,
+            search_ttl: Duration::ZERO,
+        })
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn test_disk_cache_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), Duration::from_secs(3600));
+
+        assert_eq!(cache.get("/film/1/prehled/"), None);
+        cache
+            .put("/film/1/prehled/", "<html>první\ndruhý</html>")
+            .unwrap();
+        assert_eq!(
+            cache.get("/film/1/prehled/").as_deref(),
+            Some("<html>první\ndruhý</html>")
+        );
+        assert_eq!(cache.get("/film/2/prehled/"), None);
+
+        // Search pages use their own TTL, zero here
+        cache.put("/hledat/?q=test", "<html></html>").unwrap();
+        assert_eq!(cache.get("/hledat/?q=test"), None);
+
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 1,
+                misses: 3,
+                writes: 2
+            }
+        );
+        assert_eq!(cache.stats().hit_ratio(), 0.25);
+
+        cache.clear().unwrap();
+        assert_eq!(cache.get("/film/1/prehled/"), None);
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn test_disk_cache_remove_matching() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), Duration::from_secs(3600));
+        assert_eq!(cache.remove_matching(|_| true).unwrap(), 0);
+
+        cache.put("/film/1/prehled/", "<html>1</html>").unwrap();
+        cache.put("/film/1/2/epizody/", "<html>2</html>").unwrap();
+        cache.put("/film/12/prehled/", "<html>12</html>").unwrap();
+        let removed = cache
+            .remove_matching(|path| path.starts_with("/film/1/"))
+            .unwrap();
+        assert_eq!(removed, 2);
+        assert_eq!(cache.get("/film/1/prehled/"), None);
+        assert!(cache.get("/film/12/prehled/").is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn test_disk_cache_expired() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), Duration::ZERO);
+        cache.put("/film/1/prehled/", "<html></html>").unwrap();
+        assert_eq!(cache.get("/film/1/prehled/"), None);
+        assert_eq!(
+            cache.get_stale("/film/1/prehled/"),
+            Some(("<html></html>".to_string(), true))
+        );
+        assert_eq!(cache.get_stale("/film/2/prehled/"), None);
+    }
+}
@@ -3,111 +3,676 @@
 //! This module provides a rate-limited HTTP client that respects ČSFD.cz
 //! server limits and implements retry logic with exponential backoff.
 
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::sync::RwLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::Mutex;
 use tokio::time::sleep;
 
-use crate::error::{CsfdError, Result};
-
-/// Base URL for ČSFD.cz
-const CSFD_BASE_URL: &str = "https://www.csfd.cz";
+use serde::{Deserialize, Serialize};
 
-/// Default User-Agent mimicking a modern browser
-const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
-
-/// Default Accept-Language header for Czech content
-const DEFAULT_ACCEPT_LANGUAGE: &str = "cs-CZ,cs;q=0.9,en;q=0.8";
+use crate::activity::{ActivityEvent, ActivityLog};
+use crate::cache::{CacheStats, CacheStrategy, MemoryCache, ValidatorCache};
+#[cfg(feature = "cache")]
+use crate::cache::{DiskCache, DiskCacheConfig};
+use crate::error::{CsfdError, Result};
+use crate::freshness::FreshnessPolicy;
+use crate::i18n::Locale;
+use crate::redact::redact_url;
+use crate::session::{
+    Lane, DEFAULT_BACKGROUND_MAX_INFLIGHT, DEFAULT_INTERACTIVE_MAX_INFLIGHT,
+    DEFAULT_SESSION_CONCURRENCY,
+};
+use crate::store::LocalStore;
+use crate::transport::{HttpRequest, HttpResponse, HttpTransport, ReqwestTransport};
+use crate::CSFD_BASE_URL;
 
-/// Maximum number of retry attempts for transient errors
+/// Default number of retry attempts for transient errors
 const MAX_RETRIES: u32 = 3;
 
+/// Default number of requests sent at once before throttling
+const DEFAULT_RATE_LIMIT_BURST: u32 = 5;
+
 /// Base delay for exponential backoff (in milliseconds)
 const BASE_RETRY_DELAY_MS: u64 = 1000;
 
+/// Longest `Retry-After` waited out before retrying; longer waits fail with
+/// the wait in `CsfdError::RateLimited`, so the caller can show it
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Default time a page is served from the memory cache
+const DEFAULT_MEMORY_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// Default number of pages kept for conditional requests
+const DEFAULT_VALIDATOR_CACHE_CAPACITY: usize = 64;
+
+/// Store key of the saved cookie jar
+const COOKIES_KEY: &str = "cookies";
+
+/// Hosts serving ČSFD posters and photos
+const IMAGE_HOSTS: [&str; 1] = ["image.pmgstatic.com"];
+
+tokio::task_local! {
+    /// Retry budget of the workflow the current task belongs to
+    static RETRY_BUDGET: RetryBudget;
+
+    /// Expired pages served to the operation the current task tracks,
+    /// see `CsfdClient::track_stale`
+    static SERVED_STALE: std::sync::Mutex<Vec<String>>;
+}
+
+/// Retry budget shared by all requests of a workflow
+///
+/// Without a shared budget, each request of a large batch retries on its
+/// own, so a ČSFD outage multiplies exponential backoffs across hundreds of
+/// requests. Requests run within `RetryBudget::scope` draw their retries from
+/// one pool instead; once it is drained, failing requests give up immediately.
+///
+/// # Example
+/// ```
+/// use csfd_core::RetryBudget;
+///
+/// let budget = RetryBudget::new(10);
+/// assert_eq!(budget.remaining(), 10);
+/// assert!(!budget.is_exhausted());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryBudget {
+    /// Retries left in the pool
+    remaining: Arc<AtomicU32>,
+    /// Retries taken from the pool
+    used: Arc<AtomicU32>,
+    /// Set once a retry was denied because the pool was empty
+    exhausted: Arc<AtomicBool>,
+}
+
+impl RetryBudget {
+    /// Create a budget allowing `max_retries` retries in total
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            remaining: Arc::new(AtomicU32::new(max_retries)),
+            used: Arc::new(AtomicU32::new(0)),
+            exhausted: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Take one retry from the budget, returning false if none are left
+    pub fn try_acquire(&self) -> bool {
+        let acquired = self
+            .remaining
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+
+        if acquired {
+            self.used.fetch_add(1, Ordering::SeqCst);
+        } else {
+            self.exhausted.store(true, Ordering::SeqCst);
+        }
+        acquired
+    }
+
+    /// Get the number of retries left
+    pub fn remaining(&self) -> u32 {
+        self.remaining.load(Ordering::SeqCst)
+    }
+
+    /// Get the number of retries taken so far
+    pub fn used(&self) -> u32 {
+        self.used.load(Ordering::SeqCst)
+    }
+
+    /// Check whether a request already needed a retry the budget couldn't give
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted.load(Ordering::SeqCst)
+    }
+
+    /// Run a future with all its requests drawing retries from this budget
+    pub async fn scope<F: Future>(&self, future: F) -> F::Output {
+        RETRY_BUDGET.scope(self.clone(), future).await
+    }
+}
+
+/// Take a retry from the current task's budget; unlimited outside a budget scope.
+pub(crate) fn try_acquire_retry() -> bool {
+    RETRY_BUDGET
+        .try_with(|budget| budget.try_acquire())
+        .unwrap_or(true)
+}
+
+/// Longest an adaptive rate limiter stretches the interval, as a multiple
+const MAX_SLOWDOWN: u32 = 16;
+
+/// Successful responses in a row after which an adaptive rate limiter
+/// halves its slowdown
+const SPEEDUP_AFTER: u32 = 20;
+
+/// Outcome of a request, fed back to the rate limiter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateFeedback {
+    /// The server answered normally
+    Success,
+    /// The server asked to slow down (429) or failed (5xx)
+    Overloaded,
+}
+
+impl RateFeedback {
+    /// Classify a response status; `None` for statuses that say nothing
+    /// about the server's load, such as 404
+    pub fn from_status(status: reqwest::StatusCode) -> Option<Self> {
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+            Some(Self::Overloaded)
+        } else if status.is_success() || status == reqwest::StatusCode::NOT_MODIFIED {
+            Some(Self::Success)
+        } else {
+            None
+        }
+    }
+}
+
 /// Rate limiter to control request frequency
 ///
-/// Ensures that requests are spaced at least `min_interval` apart
-/// to avoid overwhelming the ČSFD.cz server.
+/// A token bucket: up to `burst` requests go out at once, after which
+/// requests are spaced `min_interval` apart until the bucket refills at the
+/// sustained rate. A burst of 1 spaces every request.
+///
+/// In adaptive mode, each overloaded response reported with `record`
+/// doubles the interval (up to 16 times) and stops bursts; every 20
+/// successes in a row halve it again until the configured rate is back.
 pub struct RateLimiter {
-    /// Minimum interval between requests
+    /// Minimum interval between requests once the burst is used up
     min_interval: Duration,
-    /// Timestamp of the last request
-    last_request: Arc<Mutex<Instant>>,
+    /// Requests allowed at once
+    burst: u32,
+    /// Whether responses reported with `record` adjust the rate
+    adaptive: bool,
+    /// Multiple of `min_interval` currently in use
+    slowdown: AtomicU32,
+    /// Successful responses since the last adjustment
+    successes: AtomicU32,
+    /// Tokens left and when they were last refilled
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+/// State of a token bucket
+struct Bucket {
+    tokens: f64,
+    refilled: Instant,
 }
 
 impl RateLimiter {
     /// Create a new rate limiter with the specified requests per second
     ///
+    /// Every request is spaced; see `with_burst` to allow bursts.
+    ///
     /// # Arguments
     /// * `requests_per_second` - Maximum number of requests allowed per second
     ///
     /// # Example
     /// ```
-    /// use csfd_core::client::RateLimiter;
+    /// use csfd_core::RateLimiter;
     ///
     /// let limiter = RateLimiter::new(2.0); // 2 requests per second
     /// ```
     pub fn new(requests_per_second: f64) -> Self {
-        let min_interval = Duration::from_secs_f64(1.0 / requests_per_second);
+        Self::with_burst(requests_per_second, 1)
+    }
+
+    /// Create a rate limiter allowing a burst of requests before throttling
+    ///
+    /// # Arguments
+    /// * `requests_per_second` - Sustained number of requests per second
+    /// * `burst` - Requests allowed at once, at least 1
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::RateLimiter;
+    ///
+    /// // 5 requests at once, then 2 requests per second
+    /// let limiter = RateLimiter::with_burst(2.0, 5);
+    /// assert_eq!(limiter.burst(), 5);
+    /// ```
+    pub fn with_burst(requests_per_second: f64, burst: u32) -> Self {
+        let burst = burst.max(1);
         Self {
-            min_interval,
-            last_request: Arc::new(Mutex::new(Instant::now() - min_interval)),
+            min_interval: Duration::from_secs_f64(1.0 / requests_per_second),
+            burst,
+            adaptive: false,
+            slowdown: AtomicU32::new(1),
+            successes: AtomicU32::new(0),
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: f64::from(burst),
+                refilled: Instant::now(),
+            })),
         }
     }
 
-    /// Acquire permission to make a request
+    /// Slow down after overloaded responses and speed back up after successes
     ///
-    /// This method will wait if necessary to ensure the minimum interval
-    /// between requests is respected.
-    pub async fn acquire(&self) {
-        let mut last = self.last_request.lock().await;
-        let elapsed = last.elapsed();
+    /// # Example
+    /// ```
+    /// use csfd_core::{RateFeedback, RateLimiter};
+    ///
+    /// let limiter = RateLimiter::new(2.0).adaptive(true);
+    /// limiter.record(RateFeedback::Overloaded);
+    /// assert_eq!(limiter.interval(), limiter.min_interval() * 2);
+    /// ```
+    pub fn adaptive(mut self, adaptive: bool) -> Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    /// Check whether the limiter adapts to server responses
+    pub fn is_adaptive(&self) -> bool {
+        self.adaptive
+    }
 
-        if elapsed < self.min_interval {
-            let wait_time = self.min_interval - elapsed;
-            sleep(wait_time).await;
+    /// Report the outcome of a request; ignored unless adaptive
+    ///
+    /// # Returns
+    /// The new interval if the outcome changed it
+    pub fn record(&self, feedback: RateFeedback) -> Option<Duration> {
+        if !self.adaptive {
+            return None;
         }
+        let slowdown = self.slowdown.load(Ordering::Relaxed);
+        let adjusted = match feedback {
+            RateFeedback::Overloaded => {
+                self.successes.store(0, Ordering::Relaxed);
+                (slowdown < MAX_SLOWDOWN).then(|| (slowdown * 2).min(MAX_SLOWDOWN))
+            }
+            RateFeedback::Success if slowdown > 1 => {
+                let successes = self.successes.fetch_add(1, Ordering::Relaxed) + 1;
+                (successes >= SPEEDUP_AFTER).then(|| {
+                    self.successes.store(0, Ordering::Relaxed);
+                    slowdown / 2
+                })
+            }
+            RateFeedback::Success => None,
+        }?;
+        self.slowdown.store(adjusted, Ordering::Relaxed);
+        Some(self.interval())
+    }
+
+    /// Get the interval between requests in use, stretched after overloaded
+    /// responses in adaptive mode
+    pub fn interval(&self) -> Duration {
+        self.min_interval * self.slowdown.load(Ordering::Relaxed)
+    }
+
+    /// Acquire permission to make a request
+    ///
+    /// Takes a token from the bucket, waiting for one to refill if the
+    /// burst is used up.
+    ///
+    /// # Returns
+    /// The time spent waiting, including for other requests queued first
+    pub async fn acquire(&self) -> Duration {
+        let started = Instant::now();
+        let mut bucket = self.bucket.lock().await;
+        let interval = self.interval().as_secs_f64();
+        // A slowed-down limiter spaces every request
+        let burst = match self.slowdown.load(Ordering::Relaxed) {
+            1 => f64::from(self.burst),
+            _ => 1.0,
+        };
 
-        *last = Instant::now();
+        let refilled = bucket.refilled.elapsed().as_secs_f64() / interval;
+        bucket.tokens = (bucket.tokens + refilled).min(burst);
+        bucket.refilled = Instant::now();
+
+        if bucket.tokens < 1.0 {
+            sleep(Duration::from_secs_f64((1.0 - bucket.tokens) * interval)).await;
+            bucket.tokens = 0.0;
+            bucket.refilled = Instant::now();
+        } else {
+            bucket.tokens -= 1.0;
+        }
+        started.elapsed()
     }
 
-    /// Get the minimum interval between requests
+    /// Get the minimum interval between requests once the burst is used up
     pub fn min_interval(&self) -> Duration {
         self.min_interval
     }
+
+    /// Get the number of requests allowed at once
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+}
+
+/// How hard the client may load ČSFD.cz
+///
+/// Selects a coherent set of rate limit, concurrency, retry and freshness
+/// values for `ClientConfig::preset`, so a fast request rate isn't combined
+/// with short cache lifetimes by accident.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Politeness {
+    /// Slow and patient, for unattended bulk jobs
+    Conservative,
+    /// Balanced defaults
+    #[default]
+    Normal,
+    /// Quick responses for a user waiting in the UI, with few retries
+    Interactive,
+}
+
+/// Logs the client in again after ČSFD dropped its session
+///
+/// Implemented by the login layer, typically with credentials kept in the
+/// OS keychain. The client calls it when an authenticated request gets the
+/// logged-out page, then retries the request once.
+pub trait Reauthenticate: Send + Sync {
+    /// Log in again using the given client
+    fn reauthenticate<'a>(&'a self, client: &'a CsfdClient) -> BoxFuture<'a, Result<()>>;
+}
+
+/// Proxy all requests are routed through
+///
+/// Supports HTTP(S) proxies and SOCKS5, e.g. a corporate proxy or a VPN
+/// exit. `Debug` hides the password.
+///
+/// # Example
+/// ```
+/// use csfd_core::{ClientConfig, ProxyConfig};
+///
+/// let config = ClientConfig {
+///     proxy: Some(ProxyConfig::socks5("127.0.0.1:1080").with_auth("user", "secret")),
+///     ..ClientConfig::default()
+/// };
+/// assert!(!format!("{:?}", config).contains("secret"));
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    /// Proxy URL with an `http`, `https`, `socks5` or `socks5h` scheme
+    pub url: String,
+    /// User name, if the proxy requires authentication
+    pub username: Option<String>,
+    /// Password, if the proxy requires authentication
+    pub password: Option<String>,
 }
 
+impl ProxyConfig {
+    /// Use an HTTP proxy (e.g., "proxy.example.com:8080")
+    pub fn http(address: &str) -> Self {
+        Self::with_scheme("http", address)
+    }
+
+    /// Use a SOCKS5 proxy, resolving host names through the proxy
+    pub fn socks5(address: &str) -> Self {
+        Self::with_scheme("socks5h", address)
+    }
+
+    fn with_scheme(scheme: &str, address: &str) -> Self {
+        let url = if address.contains("://") {
+            address.to_string()
+        } else {
+            format!("{}://{}", scheme, address)
+        };
+        Self {
+            url,
+            username: None,
+            password: None,
+        }
+    }
+
+    /// Authenticate with a user name and password
+    pub fn with_auth(mut self, username: &str, password: &str) -> Self {
+        self.username = Some(username.to_string());
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Build the reqwest proxy
+    ///
+    /// SOCKS5 takes credentials from the URL, HTTP proxies from the
+    /// `Proxy-Authorization` header.
+    pub(crate) fn to_reqwest(&self) -> Result<reqwest::Proxy> {
+        let mut url = reqwest::Url::parse(&self.url)
+            .map_err(|e| CsfdError::InvalidUrl(format!("proxy {}: {}", self.url, e)))?;
+        let socks = match url.scheme() {
+            "http" | "https" => false,
+            "socks5" | "socks5h" => true,
+            scheme => {
+                return Err(CsfdError::InvalidUrl(format!(
+                    "proxy {}: unsupported scheme {}",
+                    self.url, scheme
+                )))
+            }
+        };
+
+        let username = self.username.as_deref().unwrap_or_default();
+        let password = self.password.as_deref().unwrap_or_default();
+        let has_auth = self.username.is_some() || self.password.is_some();
+        if socks && has_auth {
+            let invalid =
+                |_| CsfdError::InvalidUrl(format!("proxy {}: cannot hold credentials", self.url));
+            url.set_username(username).map_err(invalid)?;
+            url.set_password(Some(password)).map_err(invalid)?;
+        }
+
+        let proxy = reqwest::Proxy::all(url)?;
+        Ok(if !socks && has_auth {
+            proxy.basic_auth(username, password)
+        } else {
+            proxy
+        })
+    }
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
 
 /// Configuration for the ČSFD HTTP client
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
     /// Maximum requests per second (default: 2.0)
     pub requests_per_second: f64,
+    /// Requests sent at once before throttling to `requests_per_second`
+    /// (default: 5)
+    pub rate_limit_burst: u32,
+    /// Slow down after 429 or 5xx responses and speed back up after
+    /// successes (default: true)
+    pub adaptive_rate_limit: bool,
     /// Request timeout in seconds (default: 30)
     pub timeout_secs: u64,
+    /// Retries of a request failing with 429 or 5xx (default: 3)
+    pub max_retries: u32,
+    /// Operations a session runs at once (default: 4)
+    pub concurrency: usize,
+    /// Interactive operations running at once across all sessions (default: 4)
+    pub interactive_max_inflight: usize,
+    /// Background operations running at once across all sessions (default: 2)
+    pub background_max_inflight: usize,
+    /// How long fetched data stays fresh before it is refetched
+    pub freshness: FreshnessPolicy,
+    /// Record write actions on ČSFD instead of performing them; local files,
+    /// such as exports, are still written (default: false)
+    pub dry_run: bool,
+    /// Reject detail pages that don't match the requested ID (default: true)
+    pub verify_pages: bool,
+    /// Strip query strings, user identifiers and request URLs from errors
+    /// and activity events (default: false)
+    pub redact: bool,
+    /// Language of user-facing messages, see `CsfdError::localized` (default: English)
+    pub locale: Locale,
+    /// Fetched pages kept in memory, least recently used evicted first
+    /// (default: 0, no memory cache)
+    pub memory_cache_capacity: usize,
+    /// How long a page is served from memory (default: 5 minutes)
+    pub memory_cache_ttl: Duration,
+    /// Whether a cached page past its TTL is refetched before returning or
+    /// returned and refetched by `CsfdClient::revalidate` (default: refetched)
+    pub cache_strategy: CacheStrategy,
+    /// Pages kept with their `ETag` or `Last-Modified` for conditional
+    /// requests (default: 64, 0 disables conditional requests)
+    pub validator_cache_capacity: usize,
+    /// Route all requests through a proxy (default: none, or the system
+    /// proxy from environment variables)
+    pub proxy: Option<ProxyConfig>,
+    /// Keep fetched pages on disk and serve them until their TTL runs out
+    /// (default: no disk cache)
+    #[cfg(feature = "cache")]
+    pub disk_cache: Option<DiskCacheConfig>,
 }
 
 impl Default for ClientConfig {
     fn default() -> Self {
         Self {
             requests_per_second: 2.0,
+            rate_limit_burst: DEFAULT_RATE_LIMIT_BURST,
+            adaptive_rate_limit: true,
             timeout_secs: 30,
+            max_retries: MAX_RETRIES,
+            concurrency: DEFAULT_SESSION_CONCURRENCY,
+            interactive_max_inflight: DEFAULT_INTERACTIVE_MAX_INFLIGHT,
+            background_max_inflight: DEFAULT_BACKGROUND_MAX_INFLIGHT,
+            freshness: FreshnessPolicy::default(),
+            dry_run: false,
+            verify_pages: true,
+            redact: false,
+            locale: Locale::default(),
+            memory_cache_capacity: 0,
+            memory_cache_ttl: DEFAULT_MEMORY_CACHE_TTL,
+            validator_cache_capacity: DEFAULT_VALIDATOR_CACHE_CAPACITY,
+            cache_strategy: CacheStrategy::default(),
+            proxy: None,
+            #[cfg(feature = "cache")]
+            disk_cache: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// Create a configuration from a politeness preset
+    ///
+    /// Other settings keep their defaults and can be overridden with struct
+    /// update syntax.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::{ClientConfig, Politeness};
+    ///
+    /// let config = ClientConfig {
+    ///     dry_run: true,
+    ///     ..ClientConfig::preset(Politeness::Conservative)
+    /// };
+    /// assert_eq!(config.concurrency, 1);
+    /// ```
+    pub fn preset(politeness: Politeness) -> Self {
+        let defaults = Self::default();
+        match politeness {
+            Politeness::Conservative => Self {
+                requests_per_second: 0.5,
+                rate_limit_burst: 1,
+                timeout_secs: 60,
+                max_retries: 5,
+                concurrency: 1,
+                freshness: FreshnessPolicy {
+                    search: defaults.freshness.search * 6,
+                    ongoing_series: defaults.freshness.ongoing_series * 3,
+                    ended_series: defaults.freshness.ended_series * 3,
+                    ongoing_episodes: defaults.freshness.ongoing_episodes * 2,
+                    ended_episodes: defaults.freshness.ended_episodes * 3,
+                },
+                ..defaults
+            },
+            Politeness::Normal => defaults,
+            // Faster requests are only reasonable while the cache keeps absorbing
+            // repeated lookups, so freshness stays at the defaults.
+            Politeness::Interactive => Self {
+                requests_per_second: 4.0,
+                timeout_secs: 15,
+                max_retries: 1,
+                concurrency: 6,
+                ..defaults
+            },
         }
     }
 }
 
+/// Write action recorded instead of performed in dry-run mode
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlannedAction {
+    /// HTTP method (e.g., "POST")
+    pub method: String,
+    /// Relative path on ČSFD.cz
+    pub target: String,
+    /// Form fields of the action
+    pub params: Vec<(String, String)>,
+}
+
+/// Image downloaded from ČSFD's image servers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchedImage {
+    /// Content type reported by the server (e.g., "image/jpeg")
+    pub content_type: String,
+    /// Raw image data
+    pub bytes: Vec<u8>,
+}
+
 /// HTTP client for ČSFD.cz with rate limiting and retry logic
 ///
 /// This client automatically:
 /// - Limits request rate to avoid server overload
 /// - Retries on transient errors (429, 5xx) with exponential backoff
 /// - Sets appropriate headers for Czech content
+///
+/// Requests go through an `HttpTransport`, by default `ReqwestTransport`;
+/// see `with_transport` for serving canned responses.
 pub struct CsfdClient {
-    /// Underlying HTTP client
-    client: reqwest::Client,
+    /// Transport sending the requests
+    transport: Arc<dyn HttpTransport>,
+    /// Cookies sent to ČSFD, including the login session
+    cookies: Arc<reqwest::cookie::Jar>,
     /// Rate limiter for request throttling
     rate_limiter: RateLimiter,
+    /// Freshness policy consulted by caching layers
+    freshness: FreshnessPolicy,
+    /// Whether write actions are only recorded
+    dry_run: bool,
+    /// Whether detail pages are checked against the requested ID
+    verify_pages: bool,
+    /// Retries of a request failing with 429 or 5xx
+    max_retries: u32,
+    /// Operations a session runs at once
+    concurrency: usize,
+    /// Interactive operations running at once across all sessions
+    interactive_max_inflight: usize,
+    /// Background operations running at once across all sessions
+    background_max_inflight: usize,
+    /// Channel reporting requests, retries and waits
+    activity: ActivityLog,
+    /// Whether errors and activity events are redacted
+    redact: bool,
+    /// Language of user-facing messages
+    locale: Locale,
+    /// Recently fetched pages kept in memory
+    memory_cache: MemoryCache,
+    /// Whether expired pages are served while they wait for revalidation
+    cache_strategy: CacheStrategy,
+    /// Paths of expired pages served since the last revalidation
+    stale_pages: std::sync::Mutex<Vec<String>>,
+    /// Validators and bodies of fetched pages for conditional requests
+    validators: ValidatorCache,
+    /// Fetched pages kept on disk
+    #[cfg(feature = "cache")]
+    disk_cache: Option<DiskCache>,
+    /// Login layer hook used when an authenticated request finds the session expired
+    reauthenticator: RwLock<Option<Arc<dyn Reauthenticate>>>,
+    /// Write actions recorded in dry-run mode
+    planned_actions: std::sync::Mutex<Vec<PlannedAction>>,
 }
 
 impl CsfdClient {
@@ -125,32 +690,89 @@ impl CsfdClient {
     /// * `config` - Client configuration
     ///
     /// # Errors
-    /// Returns an error if the HTTP client cannot be created
+    /// Returns an error if the HTTP client cannot be created, or
+    /// `CsfdError::InvalidUrl` if the proxy URL is invalid
     pub fn with_config(config: ClientConfig) -> Result<Self> {
-        let client = reqwest::Client::builder()
-            .user_agent(DEFAULT_USER_AGENT)
-            .default_headers({
-                let mut headers = reqwest::header::HeaderMap::new();
-                headers.insert(
-                    reqwest::header::ACCEPT_LANGUAGE,
-                    DEFAULT_ACCEPT_LANGUAGE.parse().unwrap(),
-                );
-                headers
-            })
-            .timeout(Duration::from_secs(config.timeout_secs))
-            .build()?;
+        let cookies = Arc::new(reqwest::cookie::Jar::default());
+        let transport = ReqwestTransport::new(&config, Arc::clone(&cookies))?;
+        Ok(Self::with_parts(config, Arc::new(transport), cookies))
+    }
+
+    /// Create a client sending its requests through the given transport
+    ///
+    /// Rate limiting, retries, caching and status handling work as with the
+    /// default transport, which makes this the way to test scraper methods
+    /// against canned pages. The proxy and timeout of `config` only apply
+    /// to `ReqwestTransport`, and cookies set on the client (see
+    /// `set_session_cookie`) are only sent by a `ReqwestTransport` sharing
+    /// the client's jar; other transports keep their own cookies.
+    ///
+    /// # Arguments
+    /// * `config` - Client configuration
+    /// * `transport` - Transport sending the requests
+    ///
+    /// # Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use csfd_core::{ClientConfig, CsfdClient, ReqwestTransport};
+    ///
+    /// # fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let config = ClientConfig::default();
+    /// let transport = ReqwestTransport::new(&config, Arc::default())?;
+    /// let client = CsfdClient::with_transport(config, Arc::new(transport));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_transport(config: ClientConfig, transport: Arc<dyn HttpTransport>) -> Self {
+        Self::with_parts(config, transport, Arc::default())
+    }
 
-        let rate_limiter = RateLimiter::new(config.requests_per_second);
+    /// Create a client from its transport and the cookie jar shared with it
+    fn with_parts(
+        config: ClientConfig,
+        transport: Arc<dyn HttpTransport>,
+        cookies: Arc<reqwest::cookie::Jar>,
+    ) -> Self {
+        let rate_limiter =
+            RateLimiter::with_burst(config.requests_per_second, config.rate_limit_burst)
+                .adaptive(config.adaptive_rate_limit);
 
-        Ok(Self {
-            client,
+        Self {
+            transport,
+            cookies,
             rate_limiter,
-        })
+            freshness: config.freshness,
+            dry_run: config.dry_run,
+            verify_pages: config.verify_pages,
+            max_retries: config.max_retries,
+            concurrency: config.concurrency.max(1),
+            interactive_max_inflight: config.interactive_max_inflight.max(1),
+            background_max_inflight: config.background_max_inflight.max(1),
+            activity: ActivityLog::new(),
+            redact: config.redact,
+            locale: config.locale,
+            memory_cache: MemoryCache::new(config.memory_cache_capacity, config.memory_cache_ttl),
+            validators: ValidatorCache::new(config.validator_cache_capacity),
+            cache_strategy: config.cache_strategy,
+            stale_pages: std::sync::Mutex::new(Vec::new()),
+            #[cfg(feature = "cache")]
+            disk_cache: config.disk_cache.map(DiskCache::new),
+            reauthenticator: RwLock::new(None),
+            planned_actions: std::sync::Mutex::new(Vec::new()),
+        }
     }
 
     /// Fetch HTML content from a ČSFD.cz path
     ///
-    /// This method handles rate limiting and retries automatically.
+    /// This method handles rate limiting and retries automatically. A 429
+    /// is retried after the wait of its `Retry-After` header, or with
+    /// exponential backoff without one.
+    ///
+    /// With a memory or disk cache configured, a fresh cached copy is
+    /// returned without a request, and fetched pages are stored for later
+    /// calls. Memory is looked up before disk. With
+    /// `CacheStrategy::StaleWhileRevalidate`, a cached page past its TTL is
+    /// returned too and queued for `revalidate`.
     ///
     /// # Arguments
     /// * `path` - Relative path on ČSFD.cz (e.g., "/hledat/?q=test")
@@ -159,30 +781,598 @@ impl CsfdClient {
     /// The HTML content as a string
     ///
     /// # Errors
-    /// - `CsfdError::HttpError` - Network or HTTP error after all retries
-    /// - `CsfdError::RateLimited` - Server returned 429 after all retries
+    /// - `CsfdError::HttpError` - Network error after all retries
+    /// - `CsfdError::HttpStatus` - Other unsuccessful status, e.g. 403 or a
+    ///   5xx after all retries
+    /// - `CsfdError::RateLimited` - Server returned 429 after all retries, or
+    ///   asked to wait longer than a minute; carries the wait if known
     /// - `CsfdError::NotFound` - Server returned 404
     pub async fn fetch(&self, path: &str) -> Result<String> {
+        let serve_stale = self.cache_strategy.serves_stale();
+        if self.memory_cache.is_enabled() {
+            let cached = if serve_stale {
+                self.memory_cache.get_stale(path)
+            } else {
+                self.memory_cache.get(path).map(|html| (html, false))
+            };
+            if let Some((html, expired)) = cached {
+                self.emit_cache_hit(path);
+                if expired {
+                    self.mark_stale(path);
+                }
+                return Ok(html);
+            }
+        }
+
+        let (html, expired) = self.fetch_disk_cached(path, serve_stale).await?;
+        if expired {
+            self.mark_stale(path);
+        } else {
+            self.memory_cache.put(path, &html);
+        }
+        Ok(html)
+    }
+
+    /// Fetch a page through the disk cache, if one is configured, with
+    /// whether it was served past its TTL
+    async fn fetch_disk_cached(&self, path: &str, serve_stale: bool) -> Result<(String, bool)> {
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.disk_cache {
+            let cached = if serve_stale {
+                cache.get_stale(path)
+            } else {
+                cache.get(path).map(|html| (html, false))
+            };
+            if let Some(page) = cached {
+                self.emit_cache_hit(path);
+                return Ok(page);
+            }
+            let html = self.fetch_uncached(path).await?;
+            // A page that can't be stored is still a valid response
+            let _ = cache.put(path, &html);
+            return Ok((html, false));
+        }
+
+        #[cfg(not(feature = "cache"))]
+        let _ = serve_stale;
+        Ok((self.fetch_uncached(path).await?, false))
+    }
+
+    /// Queue an expired page that was served for revalidation, and record
+    /// it for the operation tracking stale pages, if any
+    fn mark_stale(&self, path: &str) {
+        self.queue_stale(path);
+        let _ = SERVED_STALE.try_with(|served| push_unique(served, path));
+    }
+
+    /// Queue an expired page for the next `revalidate`
+    fn queue_stale(&self, path: &str) {
+        push_unique(&self.stale_pages, path);
+    }
+
+    /// Get the paths of expired pages served since the last `revalidate`
+    ///
+    /// Covers every operation of the client; use `track_stale` for the
+    /// pages served to one operation.
+    pub fn stale_pages(&self) -> Vec<String> {
+        self.stale_pages
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Run an operation, collecting the expired pages served to it
+    ///
+    /// Unlike `stale_pages`, the returned paths only include pages this
+    /// operation read, so operations running at once can each revalidate
+    /// their own pages with `revalidate_pages`. Pages served inside a
+    /// nested `track_stale` are reported to both operations.
+    ///
+    /// # Returns
+    /// The operation's output and the paths of the expired pages it was
+    /// served, in the order they were served
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let client = scraper.client();
+    /// let (detail, stale) = client.track_stale(scraper.get_series(264494)).await;
+    /// if !stale.is_empty() {
+    ///     client.revalidate_pages(&stale).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn track_stale<T>(&self, operation: impl Future<Output = T>) -> (T, Vec<String>) {
+        let (output, served) = SERVED_STALE
+            .scope(std::sync::Mutex::new(Vec::new()), async {
+                let output = operation.await;
+                let served = SERVED_STALE.with(|served| {
+                    std::mem::take(&mut *served.lock().unwrap_or_else(|e| e.into_inner()))
+                });
+                (output, served)
+            })
+            .await;
+        let _ = SERVED_STALE.try_with(|outer| {
+            for path in &served {
+                push_unique(outer, path);
+            }
+        });
+        (output, served)
+    }
+
+    /// Refetch the expired pages served under
+    /// `CacheStrategy::StaleWhileRevalidate`
+    ///
+    /// Each queued page is fetched from ČSFD and stored in the memory and
+    /// disk caches, so the next `fetch` of it is fresh. Run it in the
+    /// background after serving stale data; concurrent calls split the
+    /// queue rather than fetch a page twice.
+    ///
+    /// # Returns
+    /// The paths of the refreshed pages, in the order they were served
+    ///
+    /// # Errors
+    /// Any error of fetching a page. The failed page and the ones not yet
+    /// refetched stay queued for the next call.
+    pub async fn revalidate(&self) -> Result<Vec<String>> {
+        let queued =
+            std::mem::take(&mut *self.stale_pages.lock().unwrap_or_else(|e| e.into_inner()));
+        self.refetch(queued).await
+    }
+
+    /// Refetch the given expired pages, e.g. those `track_stale` reported
+    /// for one operation
+    ///
+    /// The pages are taken off the client-wide queue and fetched even if
+    /// another call took them already, so the caller reads them fresh.
+    ///
+    /// # Returns
+    /// The paths of the refreshed pages
+    ///
+    /// # Errors
+    /// Any error of fetching a page. The failed page and the ones not yet
+    /// refetched are queued again.
+    pub async fn revalidate_pages(&self, paths: &[String]) -> Result<Vec<String>> {
+        self.stale_pages
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|path| !paths.contains(path));
+        self.refetch(paths.to_vec()).await
+    }
+
+    /// Fetch pages into the memory and disk caches, queueing the failed
+    /// page and the rest again on error
+    async fn refetch(&self, queued: Vec<String>) -> Result<Vec<String>> {
+        let mut refreshed = Vec::with_capacity(queued.len());
+        for (index, path) in queued.iter().enumerate() {
+            match self.fetch_uncached(path).await {
+                Ok(html) => {
+                    self.memory_cache.put(path, &html);
+                    #[cfg(feature = "cache")]
+                    if let Some(cache) = &self.disk_cache {
+                        let _ = cache.put(path, &html);
+                    }
+                    refreshed.push(path.clone());
+                }
+                Err(e) => {
+                    for path in &queued[index..] {
+                        self.queue_stale(path);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(refreshed)
+    }
+
+    fn emit_cache_hit(&self, path: &str) {
+        self.activity.emit(ActivityEvent::CacheHit {
+            key: format!("page:{}", self.activity_path(path)),
+        });
+    }
+
+    /// Fetch a page from ČSFD.cz, bypassing caches
+    pub(crate) async fn fetch_uncached(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", CSFD_BASE_URL, path);
+        self.fetch_with_retry(&url, 0, false)
+            .await
+            .map_err(|e| self.redact_error(e))
+    }
+
+    /// Fetch a personal page from ČSFD.cz, e.g. a form carrying a CSRF token
+    ///
+    /// Unlike `fetch_uncached`, the page isn't kept for conditional
+    /// requests either, so it never leaves memory or comes back from a 304.
+    pub(crate) async fn fetch_personal(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", CSFD_BASE_URL, path);
+        self.fetch_with_retry(&url, 0, true)
+            .await
+            .map_err(|e| self.redact_error(e))
+    }
+
+    /// Fetch a page that requires a logged-in session
+    ///
+    /// If ČSFD serves the logged-out version of the page, the client logs
+    /// in again through the registered `Reauthenticate` hook and retries the
+    /// request once.
+    ///
+    /// Personal pages are never cached, not even for conditional requests.
+    ///
+    /// # Errors
+    /// - `CsfdError::SessionExpired` - The page is still logged out after
+    ///   logging in again, or no hook is registered
+    /// - Any error of `fetch` or of the hook
+    pub async fn fetch_authenticated(&self, path: &str) -> Result<String> {
+        let html = self.fetch_personal(path).await?;
+        if crate::parser::is_logged_in(&html) {
+            return Ok(html);
+        }
+
+        self.reauthenticate().await?;
+
+        let html = self.fetch_personal(path).await?;
+        if crate::parser::is_logged_in(&html) {
+            Ok(html)
+        } else {
+            Err(CsfdError::SessionExpired)
+        }
+    }
+
+    /// Restore a login session from a stored cookie
+    ///
+    /// # Arguments
+    /// * `cookie` - Cookie in `name=value` form, as saved after logging in
+    pub fn set_session_cookie(&self, cookie: &str) {
+        if let Ok(url) = CSFD_BASE_URL.parse::<reqwest::Url>() {
+            self.cookies.add_cookie_str(cookie, &url);
+        }
+    }
+
+    /// Get the cookies the client sends to ČSFD, in `name=value` form
+    ///
+    /// Includes consent cookies, which change how pages render, and the
+    /// login session. Expiry dates aren't kept by the cookie jar.
+    pub fn export_cookies(&self) -> Vec<String> {
+        use reqwest::cookie::CookieStore;
+
+        let Ok(url) = CSFD_BASE_URL.parse::<reqwest::Url>() else {
+            return Vec::new();
+        };
+        self.cookies
+            .cookies(&url)
+            .and_then(|header| header.to_str().ok().map(str::to_string))
+            .map(|header| {
+                header
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|cookie| !cookie.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Add cookies exported with `export_cookies`
+    pub fn import_cookies<S: AsRef<str>>(&self, cookies: &[S]) {
+        for cookie in cookies {
+            self.set_session_cookie(cookie.as_ref());
+        }
+    }
+
+    /// Save the cookie jar in a local store, replacing a saved one
+    ///
+    /// The store keeps the login session in plain text; prefer the OS
+    /// keychain for the session cookie where one is available.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the cookies cannot be written
+    pub fn save_cookies(&self, store: &LocalStore) -> Result<()> {
+        store.save(COOKIES_KEY, &self.export_cookies())
+    }
+
+    /// Restore cookies saved with `save_cookies`
+    ///
+    /// # Returns
+    /// The number of restored cookies, 0 if none were saved
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the saved cookies cannot be read
+    pub fn restore_cookies(&self, store: &LocalStore) -> Result<usize> {
+        let cookies: Vec<String> = store.load(COOKIES_KEY)?;
+        self.import_cookies(&cookies);
+        Ok(cookies.len())
+    }
+
+    /// Register the hook that logs in again when the session expires
+    pub fn set_reauthenticator(&self, reauthenticator: Arc<dyn Reauthenticate>) {
+        if let Ok(mut hook) = self.reauthenticator.write() {
+            *hook = Some(reauthenticator);
+        }
+    }
+
+    /// Remove the hook, e.g. after the user logged out
+    pub fn clear_reauthenticator(&self) {
+        if let Ok(mut hook) = self.reauthenticator.write() {
+            *hook = None;
+        }
+    }
+
+    /// Download a poster or photo from ČSFD's image servers
+    ///
+    /// Goes through the rate limiter and sends ČSFD as referer, so proxies
+    /// serving images to web frontends avoid hotlink blocks without
+    /// hammering the origin. Only ČSFD image hosts are allowed, so this
+    /// can't be abused as an open proxy.
+    ///
+    /// # Arguments
+    /// * `url` - Image URL as found in parsed data; protocol-relative URLs are accepted
+    ///
+    /// # Errors
+    /// - `CsfdError::InvalidUrl` - The URL doesn't point to a ČSFD image host
+    /// - `CsfdError::HttpError` - Network error
+    /// - `CsfdError::HttpStatus` - Unsuccessful status other than 404
+    /// - `CsfdError::NotFound` - Server returned 404
+    pub async fn fetch_image(&self, url: &str) -> Result<FetchedImage> {
+        let url = image_url(url).ok_or_else(|| CsfdError::InvalidUrl(url.to_string()))?;
+        self.acquire_rate_limit().await;
+
+        self.emit_started("GET", url.as_str());
+        let started = Instant::now();
+        let mut request = HttpRequest::get(url.clone());
+        if let Ok(referer) = format!("{}/", CSFD_BASE_URL).parse() {
+            request = request.with_header(reqwest::header::REFERER, referer);
+        }
+        let response = self.transport.send(request).await;
+        let status = response.as_ref().ok().map(|r| r.status);
+        self.emit_finished("GET", url.as_str(), status, started);
+        let response = response.map_err(|e| self.redact_error(e))?;
+
+        let status = response.status;
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(CsfdError::NotFound(url.to_string()));
+        }
+        if !status.is_success() {
+            return Err(CsfdError::HttpStatus(status));
+        }
+        let content_type = response
+            .header(reqwest::header::CONTENT_TYPE)
+            .unwrap_or("application/octet-stream")
+            .to_string();
+
+        Ok(FetchedImage {
+            content_type,
+            bytes: response.body,
+        })
+    }
+
+    /// Submit a form to a ČSFD.cz path (write action)
+    ///
+    /// Writes are rate limited but never retried, since they are not
+    /// idempotent. In dry-run mode the action is only recorded (see
+    /// `planned_actions`) and nothing is sent. Dry-run covers ČSFD only:
+    /// exports like `write_parquet`, `write_series_nfo`, `build_dataset` and
+    /// `DiagnosticsBundle::write_zip` still write their files.
+    ///
+    /// # Arguments
+    /// * `path` - Relative path on ČSFD.cz
+    /// * `params` - Form fields
+    ///
+    /// # Returns
+    /// * `Ok(Some(html))` with the response body when the form was sent
+    /// * `Ok(None)` in dry-run mode
+    ///
+    /// # Errors
+    /// - `CsfdError::HttpError` - Network error
+    /// - `CsfdError::HttpStatus` - Unsuccessful status other than 404 and 429
+    /// - `CsfdError::RateLimited` - Server returned 429
+    /// - `CsfdError::NotFound` - Server returned 404
+    pub async fn post_form(&self, path: &str, params: &[(&str, &str)]) -> Result<Option<String>> {
+        if self.dry_run {
+            self.record_planned_action(PlannedAction {
+                method: "POST".to_string(),
+                target: path.to_string(),
+                params: params
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            });
+            return Ok(None);
+        }
+
+        self.send_form(path, params)
+            .await
+            .map_err(|e| self.redact_error(e))
+    }
+
+    /// Submit a form that requires a logged-in session (write action)
+    ///
+    /// Like `post_form`, but when ČSFD answers with the logged-out page the
+    /// write didn't happen, so the client logs in again through the
+    /// `Reauthenticate` hook and submits the form once more.
+    ///
+    /// # Errors
+    /// - `CsfdError::SessionExpired` - The response is still logged out
+    ///   after logging in again, or no hook is registered
+    /// - Any error of `post_form` or of the hook
+    pub async fn post_form_authenticated(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<Option<String>> {
+        let Some(html) = self.post_form(path, params).await? else {
+            return Ok(None);
+        };
+        if crate::parser::is_logged_in(&html) {
+            return Ok(Some(html));
+        }
+
+        self.reauthenticate().await?;
+        match self.post_form(path, params).await? {
+            Some(html) if !crate::parser::is_logged_in(&html) => Err(CsfdError::SessionExpired),
+            response => Ok(response),
+        }
+    }
+
+    /// Submit the login form and return the page ČSFD answers with
+    ///
+    /// Sent even in dry-run mode, since logging in changes nothing on the
+    /// profile, and never recorded as a planned action, so the password
+    /// isn't kept around.
+    pub(crate) async fn post_login(&self, path: &str, params: &[(&str, &str)]) -> Result<String> {
+        self.send_form(path, params)
+            .await
+            .map(Option::unwrap_or_default)
+            .map_err(|e| self.redact_error(e))
+    }
+
+    /// Log in again through the registered hook
+    async fn reauthenticate(&self) -> Result<()> {
+        let reauthenticator = self
+            .reauthenticator
+            .read()
+            .ok()
+            .and_then(|hook| hook.clone())
+            .ok_or(CsfdError::SessionExpired)?;
+        reauthenticator.reauthenticate(self).await
+    }
+
+    /// Send a form without retries
+    async fn send_form(&self, path: &str, params: &[(&str, &str)]) -> Result<Option<String>> {
+        self.acquire_rate_limit().await;
+
         let url = format!("{}{}", CSFD_BASE_URL, path);
-        self.fetch_with_retry(&url, 0).await
+        self.emit_started("POST", path);
+        let started = Instant::now();
+        let response = self
+            .transport
+            .send(HttpRequest::post_form(parse_url(&url)?, params))
+            .await;
+        self.emit_finished(
+            "POST",
+            path,
+            response.as_ref().ok().map(|r| r.status),
+            started,
+        );
+        let response = response?;
+        let status = response.status;
+        self.record_response(status);
+
+        if status.is_success() {
+            return Ok(Some(response.text()));
+        }
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(CsfdError::NotFound(url));
+        }
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(CsfdError::RateLimited(retry_after(&response)));
+        }
+        Err(CsfdError::HttpStatus(status))
+    }
+
+    /// Check whether the client only records write actions
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Record a write action that was skipped because of dry-run mode
+    pub fn record_planned_action(&self, action: PlannedAction) {
+        if let Ok(mut actions) = self.planned_actions.lock() {
+            actions.push(action);
+        }
+    }
+
+    /// Get the write actions recorded in dry-run mode so far
+    pub fn planned_actions(&self) -> Vec<PlannedAction> {
+        self.planned_actions
+            .lock()
+            .map(|actions| actions.clone())
+            .unwrap_or_default()
+    }
+
+    /// Take and clear the write actions recorded in dry-run mode
+    pub fn take_planned_actions(&self) -> Vec<PlannedAction> {
+        self.planned_actions
+            .lock()
+            .map(|mut actions| std::mem::take(&mut *actions))
+            .unwrap_or_default()
     }
 
     /// Internal method to fetch with retry logic
+    ///
+    /// Personal pages skip validators: they are neither sent nor stored.
     fn fetch_with_retry<'a>(
         &'a self,
         url: &'a str,
         attempt: u32,
+        personal: bool,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<String>> + Send + 'a>> {
         Box::pin(async move {
             // Wait for rate limiter before making request
-            self.rate_limiter.acquire().await;
+            self.acquire_rate_limit().await;
 
-            let response = self.client.get(url).send().await?;
-            let status = response.status();
+            let path = url.strip_prefix(CSFD_BASE_URL).unwrap_or(url);
+            self.emit_started("GET", path);
+            let started = Instant::now();
+            let mut request = HttpRequest::get(parse_url(url)?);
+            let validators = if personal {
+                None
+            } else {
+                self.validators.validators(path)
+            };
+            let conditional = validators.is_some();
+            if let Some((etag, last_modified)) = validators {
+                let validators = [
+                    (reqwest::header::IF_NONE_MATCH, etag),
+                    (reqwest::header::IF_MODIFIED_SINCE, last_modified),
+                ];
+                for (name, value) in validators {
+                    if let Some(value) = value.and_then(|value| value.parse().ok()) {
+                        request = request.with_header(name, value);
+                    }
+                }
+            }
+            let response = self.transport.send(request).await;
+            self.emit_finished(
+                "GET",
+                path,
+                response.as_ref().ok().map(|r| r.status),
+                started,
+            );
+            let response = response?;
+            let status = response.status;
+            self.record_response(status);
 
             // Handle different status codes
+            if status == reqwest::StatusCode::NOT_MODIFIED {
+                if conditional {
+                    if let Some(body) = self.validators.not_modified(path) {
+                        self.activity.emit(ActivityEvent::CacheHit {
+                            key: format!("not_modified:{}", self.activity_path(path)),
+                        });
+                        return Ok(body);
+                    }
+                    // The page was evicted meanwhile; without validators ČSFD sends it whole
+                    return self.fetch_with_retry(url, attempt, personal).await;
+                }
+                return Err(CsfdError::UnexpectedPage(format!(
+                    "304 Not Modified for an unconditional request: {}",
+                    url
+                )));
+            }
+
             if status.is_success() {
-                return Ok(response.text().await?);
+                let header = |name| response.header(name).map(str::to_string);
+                let etag = header(reqwest::header::ETAG);
+                let last_modified = header(reqwest::header::LAST_MODIFIED);
+                let body = response.text();
+                if !personal {
+                    self.validators.store(path, etag, last_modified, &body);
+                }
+                return Ok(body);
             }
 
             // Handle 404 - Not Found (no retry)
@@ -192,33 +1382,246 @@ impl CsfdClient {
 
             // Handle 429 - Rate Limited
             if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                if attempt < MAX_RETRIES {
-                    let delay = self.calculate_backoff_delay(attempt);
+                let retry_after = retry_after(&response);
+                let waitable = retry_after.is_none_or(|wait| wait <= MAX_RETRY_AFTER);
+                if waitable && attempt < self.max_retries && try_acquire_retry() {
+                    let delay =
+                        retry_after.unwrap_or_else(|| self.calculate_backoff_delay(attempt));
+                    self.emit_retry(path, attempt, delay);
                     sleep(delay).await;
-                    return self.fetch_with_retry(url, attempt + 1).await;
+                    return self.fetch_with_retry(url, attempt + 1, personal).await;
                 }
-                return Err(CsfdError::RateLimited);
+                return Err(CsfdError::RateLimited(retry_after));
             }
 
             // Handle 5xx - Server errors
             if status.is_server_error() {
-                if attempt < MAX_RETRIES {
+                if attempt < self.max_retries && try_acquire_retry() {
                     let delay = self.calculate_backoff_delay(attempt);
+                    self.emit_retry(path, attempt, delay);
                     sleep(delay).await;
-                    return self.fetch_with_retry(url, attempt + 1).await;
+                    return self.fetch_with_retry(url, attempt + 1, personal).await;
                 }
-                return Err(CsfdError::HttpError(
-                    response.error_for_status().unwrap_err(),
-                ));
+                return Err(CsfdError::HttpStatus(status));
             }
 
-            // Other errors - convert to HttpError
-            Err(CsfdError::HttpError(
-                response.error_for_status().unwrap_err(),
-            ))
+            // Other errors
+            Err(CsfdError::HttpStatus(status))
         })
     }
 
+    /// Get the freshness policy of this client
+    pub fn freshness(&self) -> &FreshnessPolicy {
+        &self.freshness
+    }
+
+    /// Check whether parsed detail pages are verified against the requested ID
+    pub fn verifies_pages(&self) -> bool {
+        self.verify_pages
+    }
+
+    /// Get the minimum interval between requests once a burst is used up
+    pub fn min_interval(&self) -> Duration {
+        self.rate_limiter.min_interval()
+    }
+
+    /// Get the number of requests sent at once before throttling
+    pub fn rate_limit_burst(&self) -> u32 {
+        self.rate_limiter.burst()
+    }
+
+    /// Get the interval between requests in use, which adaptive rate
+    /// limiting stretches while ČSFD is overloaded
+    pub fn current_interval(&self) -> Duration {
+        self.rate_limiter.interval()
+    }
+
+    /// Get what the client does with cached pages past their TTL
+    pub fn cache_strategy(&self) -> CacheStrategy {
+        self.cache_strategy
+    }
+
+    /// Get the language of user-facing messages
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Get how conditional requests fared
+    ///
+    /// Hits are pages ČSFD answered with `304 Not Modified`, misses are
+    /// stored pages that had changed, writes are pages stored with validators.
+    pub fn conditional_request_stats(&self) -> CacheStats {
+        self.validators.stats()
+    }
+
+    /// Get the hits, misses and writes of the memory cache
+    pub fn memory_cache_stats(&self) -> CacheStats {
+        self.memory_cache.stats()
+    }
+
+    /// Remove all pages from the memory cache
+    ///
+    /// Useful after a write action changed pages fetched before.
+    pub fn clear_memory_cache(&self) {
+        self.memory_cache.clear();
+    }
+
+    /// Remove the pages whose paths match a predicate from every page cache
+    ///
+    /// Clears them from memory, from the validators of conditional requests
+    /// and from disk, so the next fetch of each goes to ČSFD in full.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the disk cache cannot be updated
+    pub fn invalidate_pages(&self, matches: impl Fn(&str) -> bool) -> Result<()> {
+        self.memory_cache.remove_matching(&matches);
+        self.stale_pages
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|path| !matches(path));
+        self.validators.remove_matching(&matches);
+        #[cfg(feature = "cache")]
+        if let Some(cache) = &self.disk_cache {
+            cache.remove_matching(&matches)?;
+        }
+        Ok(())
+    }
+
+    /// Remove all pages from every page cache
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the disk cache cannot be removed
+    pub fn clear_page_caches(&self) -> Result<()> {
+        self.memory_cache.clear();
+        self.stale_pages
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        self.validators.clear();
+        #[cfg(feature = "cache")]
+        self.clear_disk_cache()?;
+        Ok(())
+    }
+
+    /// Get the hits, misses and writes of the disk cache, `None` without one
+    #[cfg(feature = "cache")]
+    pub fn disk_cache_stats(&self) -> Option<CacheStats> {
+        self.disk_cache.as_ref().map(DiskCache::stats)
+    }
+
+    /// Remove all pages from the disk cache, if one is configured
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the cache directory cannot be removed
+    #[cfg(feature = "cache")]
+    pub fn clear_disk_cache(&self) -> Result<()> {
+        match &self.disk_cache {
+            Some(cache) => cache.clear(),
+            None => Ok(()),
+        }
+    }
+
+    /// Check whether errors and activity events are redacted
+    pub fn redacts(&self) -> bool {
+        self.redact
+    }
+
+    /// Get the number of retries of a failing request
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Get the number of operations a session runs at once by default
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Get the number of operations of a lane running at once across all sessions
+    pub fn max_inflight(&self, lane: Lane) -> usize {
+        match lane {
+            Lane::Interactive => self.interactive_max_inflight,
+            Lane::Background => self.background_max_inflight,
+        }
+    }
+
+    /// Get the channel reporting this client's activity
+    pub fn activity(&self) -> &ActivityLog {
+        &self.activity
+    }
+
+    /// Wait for the rate limiter, reporting waits that were noticeable
+    async fn acquire_rate_limit(&self) {
+        let waited = self.rate_limiter.acquire().await;
+        if waited >= Duration::from_millis(1) {
+            self.activity.emit(ActivityEvent::RateLimitWait {
+                waited_ms: waited.as_millis() as u64,
+            });
+        }
+    }
+
+    /// Feed a response status back to the rate limiter, reporting rate changes
+    fn record_response(&self, status: reqwest::StatusCode) {
+        let Some(feedback) = RateFeedback::from_status(status) else {
+            return;
+        };
+        if let Some(interval) = self.rate_limiter.record(feedback) {
+            self.activity.emit(ActivityEvent::RateAdjusted {
+                interval_ms: interval.as_millis() as u64,
+            });
+        }
+    }
+
+    /// Apply redaction to an error if enabled
+    fn redact_error(&self, error: CsfdError) -> CsfdError {
+        if self.redact {
+            error.redacted()
+        } else {
+            error
+        }
+    }
+
+    /// Path as reported in activity events, redacted if enabled
+    fn activity_path(&self, path: &str) -> String {
+        if self.redact {
+            redact_url(path).into_owned()
+        } else {
+            path.to_string()
+        }
+    }
+
+    /// Report a sent request
+    fn emit_started(&self, method: &str, path: &str) {
+        self.activity.emit(ActivityEvent::RequestStarted {
+            method: method.to_string(),
+            path: self.activity_path(path),
+        });
+    }
+
+    /// Report a completed request
+    fn emit_finished(
+        &self,
+        method: &str,
+        path: &str,
+        status: Option<reqwest::StatusCode>,
+        started: Instant,
+    ) {
+        self.activity.emit(ActivityEvent::RequestFinished {
+            method: method.to_string(),
+            path: self.activity_path(path),
+            status: status.map(|s| s.as_u16()),
+            elapsed_ms: started.elapsed().as_millis() as u64,
+        });
+    }
+
+    /// Report a scheduled retry
+    fn emit_retry(&self, path: &str, attempt: u32, delay: Duration) {
+        self.activity.emit(ActivityEvent::Retry {
+            path: self.activity_path(path),
+            attempt: attempt + 1,
+            delay_ms: delay.as_millis() as u64,
+        });
+    }
+
     /// Calculate exponential backoff delay for retry
     fn calculate_backoff_delay(&self, attempt: u32) -> Duration {
         // Exponential backoff: 1s, 2s, 4s, ...
@@ -233,6 +1636,83 @@ impl CsfdClient {
     }
 }
 
+/// Get the wait a 429 response asks for in its `Retry-After` header
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    let value = response.header(reqwest::header::RETRY_AFTER)?;
+    parse_retry_after(value, SystemTime::now())
+}
+
+/// Add a path to a list unless it is there already
+fn push_unique(paths: &std::sync::Mutex<Vec<String>>, path: &str) {
+    let mut paths = paths.lock().unwrap_or_else(|e| e.into_inner());
+    if !paths.iter().any(|p| p == path) {
+        paths.push(path.to_string());
+    }
+}
+
+/// Parse the absolute URL of a request
+fn parse_url(url: &str) -> Result<reqwest::Url> {
+    reqwest::Url::parse(url).map_err(|e| CsfdError::InvalidUrl(format!("{}: {}", url, e)))
+}
+
+/// Parse a `Retry-After` value, either seconds or an HTTP date
+///
+/// A date in the past means no wait.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = parse_http_date(value)?;
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+/// Parse an HTTP date in the IMF-fixdate format ("Wed, 21 Oct 2026 07:28:00 GMT")
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let (_, date) = value.split_once(", ")?;
+    let mut parts = date.split(' ');
+    let day: u64 = parts.next()?.parse().ok()?;
+    let month = parts.next()?;
+    let month = MONTHS.iter().position(|m| *m == month)? as u64 + 1;
+    let year: u64 = parts.next()?.parse().ok()?;
+    let mut time = parts
+        .next()?
+        .split(':')
+        .map(|part| part.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (time.next()??, time.next()??, time.next()??);
+    if parts.next() != Some("GMT") || year < 1970 || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Days since the epoch of a proleptic Gregorian date, with years starting in March
+    let (y, m) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+    let era_days = y * 365 + y / 4 - y / 100 + y / 400 + (153 * m + 2) / 5 + day - 1;
+    let days = era_days.checked_sub(719_468)?;
+    let secs = days * 86_400 + hours * 3600 + minutes * 60 + seconds;
+    Some(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Parse an image URL, accepting only ČSFD image hosts over HTTPS.
+fn image_url(url: &str) -> Option<reqwest::Url> {
+    let url = match url.strip_prefix("//") {
+        Some(rest) => format!("https://{}", rest),
+        None => url.to_string(),
+    };
+    let parsed: reqwest::Url = url.parse().ok()?;
+    let allowed = parsed.scheme() == "https"
+        && parsed
+            .host_str()
+            .is_some_and(|host| IMAGE_HOSTS.contains(&host));
+    allowed.then_some(parsed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +1737,239 @@ mod tests {
         let config = ClientConfig::default();
         assert_eq!(config.requests_per_second, 2.0);
         assert_eq!(config.timeout_secs, 30);
+        assert!(!config.dry_run);
+    }
+
+    #[test]
+    fn test_client_config_presets() {
+        assert_eq!(
+            ClientConfig::preset(Politeness::Normal).requests_per_second,
+            2.0
+        );
+
+        let conservative = ClientConfig::preset(Politeness::Conservative);
+        let interactive = ClientConfig::preset(Politeness::Interactive);
+        assert!(conservative.requests_per_second < interactive.requests_per_second);
+        assert!(conservative.concurrency < interactive.concurrency);
+        assert!(conservative.max_retries > interactive.max_retries);
+        assert!(conservative.freshness.search > interactive.freshness.search);
+        assert_eq!(interactive.freshness, FreshnessPolicy::default());
+        assert!(interactive.verify_pages && !interactive.dry_run);
+
+        let client = CsfdClient::with_config(interactive).unwrap();
+        assert_eq!(client.max_retries(), 1);
+        assert_eq!(client.concurrency(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_wait_is_reported() {
+        let client = CsfdClient::with_config(ClientConfig {
+            requests_per_second: 20.0,
+            rate_limit_burst: 1,
+            ..ClientConfig::default()
+        })
+        .unwrap();
+        let mut activity = client.activity().subscribe();
+
+        client.acquire_rate_limit().await;
+        client.acquire_rate_limit().await;
+
+        match activity.recv().await.unwrap() {
+            ActivityEvent::RateLimitWait { waited_ms } => assert!(waited_ms > 0),
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert!(activity.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_redacted_activity_and_errors() {
+        let client = CsfdClient::with_config(ClientConfig {
+            redact: true,
+            ..ClientConfig::default()
+        })
+        .unwrap();
+        let mut activity = client.activity().subscribe();
+
+        client.emit_started("GET", "/hledat/?q=secret");
+        match activity.recv().await.unwrap() {
+            ActivityEvent::RequestStarted { path, .. } => assert_eq!(path, "/hledat/?…"),
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let error = client.redact_error(CsfdError::NotFound("/uzivatel/7-nick/".to_string()));
+        assert_eq!(error.to_string(), "Series not found: /uzivatel/…/");
+    }
+
+    #[test]
+    fn test_proxy_config() {
+        let socks = ProxyConfig::socks5("127.0.0.1:1080").with_auth("user", "secret");
+        assert_eq!(socks.url, "socks5h://127.0.0.1:1080");
+        assert!(!format!("{:?}", socks).contains("secret"));
+        assert!(socks.to_reqwest().is_ok());
+
+        let http = ProxyConfig::http("https://proxy.example.com:8443").with_auth("user", "secret");
+        assert_eq!(http.url, "https://proxy.example.com:8443");
+        let client = CsfdClient::with_config(ClientConfig {
+            proxy: Some(http),
+            ..ClientConfig::default()
+        });
+        assert!(client.is_ok());
+
+        let ftp = ProxyConfig::http("ftp://proxy.example.com");
+        assert!(matches!(ftp.to_reqwest(), Err(CsfdError::InvalidUrl(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_served_from_memory_cache() {
+        let client = CsfdClient::with_config(ClientConfig {
+            memory_cache_capacity: 8,
+            ..ClientConfig::default()
+        })
+        .unwrap();
+        client
+            .memory_cache
+            .put("/film/1/prehled/", "<html>v paměti</html>");
+
+        let html = client.fetch("/film/1/prehled/").await.unwrap();
+        assert_eq!(html, "<html>v paměti</html>");
+        assert_eq!(client.memory_cache_stats().hits, 1);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_serves_stale_while_revalidating() {
+        let client = CsfdClient::with_config(ClientConfig {
+            memory_cache_capacity: 8,
+            memory_cache_ttl: Duration::from_nanos(1),
+            cache_strategy: CacheStrategy::StaleWhileRevalidate,
+            ..ClientConfig::default()
+        })
+        .unwrap();
+        client
+            .memory_cache
+            .put("/film/1/prehled/", "<html>stará</html>");
+        std::thread::sleep(Duration::from_millis(1));
+
+        let html = client.fetch("/film/1/prehled/").await.unwrap();
+        assert_eq!(html, "<html>stará</html>");
+        client.fetch("/film/1/prehled/").await.unwrap();
+        assert_eq!(client.stale_pages(), vec!["/film/1/prehled/".to_string()]);
+
+        client
+            .memory_cache
+            .put("/film/2/prehled/", "<html>jiná</html>");
+        std::thread::sleep(Duration::from_millis(1));
+        let (_, served) = client.track_stale(client.fetch("/film/2/prehled/")).await;
+        assert_eq!(served, vec!["/film/2/prehled/".to_string()]);
+        assert_eq!(client.stale_pages().len(), 2);
+
+        client
+            .invalidate_pages(|path| path.starts_with("/film/"))
+            .unwrap();
+        assert!(client.stale_pages().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_personal_pages_skip_validators() {
+        struct Tagged;
+
+        impl HttpTransport for Tagged {
+            fn send<'a>(&'a self, request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+                assert!(!request.headers.contains_key(reqwest::header::IF_NONE_MATCH));
+                Box::pin(async move {
+                    Ok(HttpResponse::html("<html>můj profil</html>").with_header(
+                        reqwest::header::ETAG,
+                        reqwest::header::HeaderValue::from_static("\"abc\""),
+                    ))
+                })
+            }
+        }
+
+        let client = CsfdClient::with_transport(ClientConfig::default(), Arc::new(Tagged));
+        client.fetch_personal("/uzivatel/7-nick/").await.unwrap();
+        client.fetch_personal("/uzivatel/7-nick/").await.unwrap();
+        assert!(client.validators.validators("/uzivatel/7-nick/").is_none());
+
+        client.fetch_uncached("/film/1/prehled/").await.unwrap();
+        assert!(client.validators.validators("/film/1/prehled/").is_some());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "cache")]
+    async fn test_fetch_served_from_disk_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = DiskCacheConfig::new(dir.path(), &FreshnessPolicy::default());
+        DiskCache::new(config.clone())
+            .put("/film/1/prehled/", "<html>uložená</html>")
+            .unwrap();
+
+        let client = CsfdClient::with_config(ClientConfig {
+            disk_cache: Some(config),
+            ..ClientConfig::default()
+        })
+        .unwrap();
+        let mut activity = client.activity().subscribe();
+
+        let html = client.fetch("/film/1/prehled/").await.unwrap();
+        assert_eq!(html, "<html>uložená</html>");
+        match activity.recv().await.unwrap() {
+            ActivityEvent::CacheHit { key } => assert_eq!(key, "page:/film/1/prehled/"),
+            other => panic!("unexpected event {:?}", other),
+        }
+        assert_eq!(client.disk_cache_stats().unwrap().hits, 1);
+
+        client.clear_disk_cache().unwrap();
+        assert!(!dir.path().exists());
+    }
+
+    #[test]
+    fn test_image_url_allows_only_csfd_hosts() {
+        assert_eq!(
+            image_url("//image.pmgstatic.com/files/images/film/posters/1.jpg")
+                .unwrap()
+                .as_str(),
+            "https://image.pmgstatic.com/files/images/film/posters/1.jpg"
+        );
+        assert!(image_url("https://image.pmgstatic.com/cache/resized/w60h85/1.jpg").is_some());
+        assert!(image_url("http://image.pmgstatic.com/1.jpg").is_none());
+        assert!(image_url("https://example.com/1.jpg").is_none());
+        assert!(image_url("https://image.pmgstatic.com.example.com/1.jpg").is_none());
+        assert!(image_url("not a url").is_none());
+    }
+
+    #[test]
+    fn test_set_session_cookie() {
+        use reqwest::cookie::CookieStore;
+
+        let client = CsfdClient::new().unwrap();
+        client.set_session_cookie("PHPSESSID=abc123");
+        let url = format!("{}/uzivatel/", CSFD_BASE_URL).parse().unwrap();
+        let header = client.cookies.cookies(&url).unwrap();
+        assert_eq!(header.to_str().unwrap(), "PHPSESSID=abc123");
+    }
+
+    #[test]
+    fn test_cookie_jar_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+
+        let client = CsfdClient::new().unwrap();
+        client.import_cookies(&["PHPSESSID=abc123", "consent=1"]);
+        let mut exported = client.export_cookies();
+        exported.sort();
+        assert_eq!(exported, vec!["PHPSESSID=abc123", "consent=1"]);
+        client.save_cookies(&store).unwrap();
+
+        let restored = CsfdClient::new().unwrap();
+        assert_eq!(restored.restore_cookies(&store).unwrap(), 2);
+        let mut cookies = restored.export_cookies();
+        cookies.sort();
+        assert_eq!(cookies, exported);
+
+        let empty = LocalStore::open(dir.path().join("empty")).unwrap();
+        assert_eq!(
+            CsfdClient::new().unwrap().restore_cookies(&empty).unwrap(),
+            0
+        );
     }
 
     #[test]
@@ -270,30 +1983,177 @@ mod tests {
         let config = ClientConfig {
             requests_per_second: 1.0,
             timeout_secs: 60,
+            ..ClientConfig::default()
         };
         let client = CsfdClient::with_config(config);
         assert!(client.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_post_form_dry_run() {
+        let config = ClientConfig {
+            dry_run: true,
+            ..ClientConfig::default()
+        };
+        let client = CsfdClient::with_config(config).unwrap();
+        assert!(client.is_dry_run());
+
+        let response = client
+            .post_form("/film/264494/hodnotit/", &[("rating", "5")])
+            .await
+            .unwrap();
+        assert!(response.is_none());
+
+        let actions = client.take_planned_actions();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].method, "POST");
+        assert_eq!(actions[0].target, "/film/264494/hodnotit/");
+        assert_eq!(
+            actions[0].params,
+            vec![("rating".to_string(), "5".to_string())]
+        );
+        assert!(client.planned_actions().is_empty());
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_792_567_650); // Wed, 21 Oct 2026 07:27:30 GMT
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2026 07:28:00 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Thu, 01 Jan 2026 00:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            parse_http_date("Thu, 01 Jan 1970 00:00:00 GMT"),
+            Some(UNIX_EPOCH)
+        );
+        assert_eq!(
+            parse_http_date("Sun, 29 Feb 2032 12:00:00 GMT"),
+            Some(UNIX_EPOCH + Duration::from_secs(1_961_668_800))
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2026 07:28:00 CET", now),
+            None
+        );
+    }
+
     #[test]
     fn test_backoff_delay_calculation() {
         let client = CsfdClient::new().unwrap();
-        
-        assert_eq!(client.calculate_backoff_delay(0), Duration::from_millis(1000));
-        assert_eq!(client.calculate_backoff_delay(1), Duration::from_millis(2000));
-        assert_eq!(client.calculate_backoff_delay(2), Duration::from_millis(4000));
+
+        assert_eq!(
+            client.calculate_backoff_delay(0),
+            Duration::from_millis(1000)
+        );
+        assert_eq!(
+            client.calculate_backoff_delay(1),
+            Duration::from_millis(2000)
+        );
+        assert_eq!(
+            client.calculate_backoff_delay(2),
+            Duration::from_millis(4000)
+        );
+    }
+
+    #[test]
+    fn test_retry_budget_drains() {
+        let budget = RetryBudget::new(2);
+        assert!(budget.try_acquire());
+        assert!(budget.try_acquire());
+        assert!(!budget.is_exhausted());
+
+        assert!(!budget.try_acquire());
+        assert!(budget.is_exhausted());
+        assert_eq!(budget.used(), 2);
+        assert_eq!(budget.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_scope() {
+        // Outside a scope retries are unlimited
+        assert!(try_acquire_retry());
+
+        let budget = RetryBudget::new(1);
+        let shared = budget.clone();
+        budget
+            .scope(async {
+                assert!(try_acquire_retry());
+                assert!(!try_acquire_retry());
+            })
+            .await;
+        assert!(shared.is_exhausted());
+        assert_eq!(shared.used(), 1);
     }
 
     #[tokio::test]
     async fn test_rate_limiter_acquire() {
         let limiter = RateLimiter::new(10.0); // 10 requests per second = 100ms interval
-        
+
         let start = Instant::now();
         limiter.acquire().await;
         limiter.acquire().await;
         let elapsed = start.elapsed();
-        
+
         // Second acquire should wait at least 100ms
         assert!(elapsed >= Duration::from_millis(100));
     }
+
+    #[test]
+    fn test_rate_limiter_adaptive() {
+        let limiter = RateLimiter::new(10.0).adaptive(true);
+        let base = limiter.min_interval();
+
+        assert_eq!(limiter.record(RateFeedback::Success), None);
+        assert_eq!(limiter.record(RateFeedback::Overloaded), Some(base * 2));
+        for _ in 0..10 {
+            limiter.record(RateFeedback::Overloaded);
+        }
+        assert_eq!(limiter.interval(), base * MAX_SLOWDOWN);
+
+        // An overloaded response restarts the count of successes
+        for _ in 0..SPEEDUP_AFTER - 1 {
+            assert_eq!(limiter.record(RateFeedback::Success), None);
+        }
+        assert_eq!(limiter.record(RateFeedback::Overloaded), None);
+        for _ in 0..SPEEDUP_AFTER {
+            limiter.record(RateFeedback::Success);
+        }
+        assert_eq!(limiter.interval(), base * MAX_SLOWDOWN / 2);
+
+        let fixed = RateLimiter::new(10.0);
+        assert_eq!(fixed.record(RateFeedback::Overloaded), None);
+        assert_eq!(fixed.interval(), base);
+
+        assert_eq!(
+            RateFeedback::from_status(reqwest::StatusCode::SERVICE_UNAVAILABLE),
+            Some(RateFeedback::Overloaded)
+        );
+        assert_eq!(
+            RateFeedback::from_status(reqwest::StatusCode::NOT_FOUND),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_burst() {
+        let limiter = RateLimiter::with_burst(10.0, 3);
+
+        // The burst goes out at once, the next request waits for a token
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+        assert!(limiter.acquire().await >= Duration::from_millis(90));
+
+        assert_eq!(RateLimiter::with_burst(10.0, 0).burst(), 1);
+    }
 }
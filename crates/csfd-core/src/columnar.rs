@@ -0,0 +1,254 @@
+//! Arrow record batches and Parquet files
+//!
+//! Converts scraped episodes and search results into Arrow record batches
+//! and writes them as Parquet, so large datasets go straight into DuckDB,
+//! Polars or pandas without an intermediate CSV step. Available with the
+//! `arrow` feature.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::builder::{
+    Float32Builder, StringBuilder, UInt16Builder, UInt32Builder, UInt8Builder,
+};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+
+use crate::error::{CsfdError, Result};
+use crate::types::{Episode, SearchResult, SeriesType};
+
+/// Rows that convert into an Arrow record batch
+pub trait ToRecordBatch: Sized {
+    /// Schema of the record batches
+    fn schema() -> SchemaRef;
+
+    /// Convert rows into a record batch, one row per item
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the batch cannot be assembled
+    fn to_record_batch(rows: &[Self]) -> Result<RecordBatch>;
+}
+
+impl ToRecordBatch for Episode {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("csfd_id", DataType::UInt32, false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("season_number", DataType::UInt8, false),
+            Field::new("episode_number", DataType::UInt16, false),
+            Field::new("episode_code", DataType::Utf8, false),
+            Field::new("absolute_number", DataType::UInt32, true),
+            Field::new("list_position", DataType::UInt32, false),
+            Field::new("rating", DataType::Float32, true),
+            Field::new("rating_count", DataType::UInt32, true),
+            Field::new("comment_count", DataType::UInt32, true),
+            Field::new("url", DataType::Utf8, false),
+        ]))
+    }
+
+    fn to_record_batch(rows: &[Self]) -> Result<RecordBatch> {
+        let mut csfd_id = UInt32Builder::with_capacity(rows.len());
+        let mut name = StringBuilder::new();
+        let mut season_number = UInt8Builder::with_capacity(rows.len());
+        let mut episode_number = UInt16Builder::with_capacity(rows.len());
+        let mut episode_code = StringBuilder::new();
+        let mut absolute_number = UInt32Builder::with_capacity(rows.len());
+        let mut list_position = UInt32Builder::with_capacity(rows.len());
+        let mut rating = Float32Builder::with_capacity(rows.len());
+        let mut rating_count = UInt32Builder::with_capacity(rows.len());
+        let mut comment_count = UInt32Builder::with_capacity(rows.len());
+        let mut url = StringBuilder::new();
+
+        for episode in rows {
+            csfd_id.append_value(episode.csfd_id);
+            name.append_value(&episode.name);
+            season_number.append_value(episode.season_number);
+            episode_number.append_value(episode.episode_number);
+            episode_code.append_value(episode.code().to_string());
+            absolute_number.append_option(episode.absolute_number);
+            list_position.append_value(episode.list_position);
+            rating.append_option(episode.rating);
+            rating_count.append_option(episode.rating_count);
+            comment_count.append_option(episode.comment_count);
+            url.append_value(&episode.url);
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(csfd_id.finish()),
+            Arc::new(name.finish()),
+            Arc::new(season_number.finish()),
+            Arc::new(episode_number.finish()),
+            Arc::new(episode_code.finish()),
+            Arc::new(absolute_number.finish()),
+            Arc::new(list_position.finish()),
+            Arc::new(rating.finish()),
+            Arc::new(rating_count.finish()),
+            Arc::new(comment_count.finish()),
+            Arc::new(url.finish()),
+        ];
+        RecordBatch::try_new(Self::schema(), columns).map_err(storage_error)
+    }
+}
+
+impl ToRecordBatch for SearchResult {
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("csfd_id", DataType::UInt32, false),
+            Field::new("name", DataType::Utf8, false),
+            Field::new("original_name", DataType::Utf8, true),
+            Field::new("year", DataType::Utf8, true),
+            Field::new("series_type", DataType::Utf8, false),
+            Field::new("score", DataType::Float32, true),
+            Field::new("url", DataType::Utf8, false),
+        ]))
+    }
+
+    fn to_record_batch(rows: &[Self]) -> Result<RecordBatch> {
+        let mut csfd_id = UInt32Builder::with_capacity(rows.len());
+        let mut name = StringBuilder::new();
+        let mut original_name = StringBuilder::new();
+        let mut year = StringBuilder::new();
+        let mut series_type = StringBuilder::new();
+        let mut score = Float32Builder::with_capacity(rows.len());
+        let mut url = StringBuilder::new();
+
+        for result in rows {
+            csfd_id.append_value(result.csfd_id);
+            name.append_value(&result.name);
+            original_name.append_option(result.original_name.as_deref());
+            year.append_option(result.year.as_deref());
+            series_type.append_value(series_type_name(&result.series_type));
+            score.append_option(result.score);
+            url.append_value(&result.url);
+        }
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(csfd_id.finish()),
+            Arc::new(name.finish()),
+            Arc::new(original_name.finish()),
+            Arc::new(year.finish()),
+            Arc::new(series_type.finish()),
+            Arc::new(score.finish()),
+            Arc::new(url.finish()),
+        ];
+        RecordBatch::try_new(Self::schema(), columns).map_err(storage_error)
+    }
+}
+
+/// Write rows to a Parquet file, compressed with Snappy.
+///
+/// # Arguments
+/// * `rows` - Episodes, search results or other rows with a record batch schema
+/// * `path` - Parquet file to create or overwrite
+///
+/// The file is local, so it is written whatever `ClientConfig::dry_run` says.
+///
+/// # Errors
+/// Returns `CsfdError::StorageError` if the file cannot be written
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::columnar::write_parquet;
+/// use csfd_core::{CsfdScraper, EpisodeSort};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let episodes = scraper.get_episodes(71924, EpisodeSort::Aired).await?;
+/// write_parquet(&episodes, "dr-house.parquet")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_parquet<T: ToRecordBatch>(rows: &[T], path: impl AsRef<Path>) -> Result<()> {
+    let path = path.as_ref();
+    let batch = T::to_record_batch(rows)?;
+    let file = File::create(path)
+        .map_err(|e| CsfdError::StorageError(format!("{}: {}", path.display(), e)))?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+
+    let mut writer =
+        ArrowWriter::try_new(file, T::schema(), Some(properties)).map_err(storage_error)?;
+    writer.write(&batch).map_err(storage_error)?;
+    writer.close().map_err(storage_error)?;
+    Ok(())
+}
+
+/// Name of a series type as in its serialized form
+fn series_type_name(series_type: &SeriesType) -> &'static str {
+    match series_type {
+        SeriesType::Series => "Series",
+        SeriesType::Season => "Season",
+        SeriesType::MiniSeries => "MiniSeries",
+        SeriesType::TvFilm => "TvFilm",
+        SeriesType::Film => "Film",
+    }
+}
+
+fn storage_error(error: impl std::fmt::Display) -> CsfdError {
+    CsfdError::StorageError(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Array, StringArray, UInt16Array};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+    fn episode(csfd_id: u32, number: u16, rating: Option<f32>) -> Episode {
+        Episode {
+            csfd_id,
+            name: format!("Kapitola {}", number),
+            season_number: 1,
+            episode_number: number,
+            rating,
+            url: format!("/film/264494/{}/", csfd_id),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: u32::from(number) - 1,
+        }
+    }
+
+    #[test]
+    fn test_episodes_to_record_batch() {
+        let episodes = [episode(10, 1, Some(85.0)), episode(11, 2, None)];
+        let batch = Episode::to_record_batch(&episodes).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.num_columns(), Episode::schema().fields().len());
+
+        let codes = batch
+            .column_by_name("episode_code")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(codes.value(1), "S01E02");
+        assert!(batch.column_by_name("rating").unwrap().is_null(1));
+    }
+
+    #[test]
+    fn test_write_parquet_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("episodes.parquet");
+        write_parquet(&[episode(10, 1, Some(85.0)), episode(11, 2, None)], &path).unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<RecordBatch> = reader.map(|batch| batch.unwrap()).collect();
+        assert_eq!(batches.len(), 1);
+        let numbers = batches[0]
+            .column_by_name("episode_number")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<UInt16Array>()
+            .unwrap();
+        assert_eq!(numbers.values(), &[1, 2]);
+    }
+}
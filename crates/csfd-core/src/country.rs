@@ -0,0 +1,89 @@
+//! ISO 3166-1 codes of countries as ČSFD names them in Czech
+//!
+//! Lookups use the folded form of `text::normalize`, so "Česko" and
+//! "cesko" match. Countries that no longer exist, like Czechoslovakia or
+//! West Germany, have no code.
+
+use crate::text::normalize;
+
+/// Folded Czech names and their alpha-2 codes
+const COUNTRIES: &[(&str, &str)] = &[
+    ("argentina", "AR"),
+    ("australie", "AU"),
+    ("belgie", "BE"),
+    ("brazilie", "BR"),
+    ("bulharsko", "BG"),
+    ("cesko", "CZ"),
+    ("ceska republika", "CZ"),
+    ("chile", "CL"),
+    ("chorvatsko", "HR"),
+    ("cina", "CN"),
+    ("dansko", "DK"),
+    ("egypt", "EG"),
+    ("estonsko", "EE"),
+    ("filipiny", "PH"),
+    ("finsko", "FI"),
+    ("francie", "FR"),
+    ("hongkong", "HK"),
+    ("indie", "IN"),
+    ("indonesie", "ID"),
+    ("irsko", "IE"),
+    ("iran", "IR"),
+    ("island", "IS"),
+    ("italie", "IT"),
+    ("izrael", "IL"),
+    ("japonsko", "JP"),
+    ("jihoafricka republika", "ZA"),
+    ("jizni korea", "KR"),
+    ("kanada", "CA"),
+    ("kolumbie", "CO"),
+    ("litva", "LT"),
+    ("lotyssko", "LV"),
+    ("lucembursko", "LU"),
+    ("madarsko", "HU"),
+    ("mexiko", "MX"),
+    ("nemecko", "DE"),
+    ("nizozemsko", "NL"),
+    ("norsko", "NO"),
+    ("novy zeland", "NZ"),
+    ("polsko", "PL"),
+    ("portugalsko", "PT"),
+    ("rakousko", "AT"),
+    ("recko", "GR"),
+    ("rumunsko", "RO"),
+    ("rusko", "RU"),
+    ("slovensko", "SK"),
+    ("slovinsko", "SI"),
+    ("spanelsko", "ES"),
+    ("spojene kralovstvi", "GB"),
+    ("srbsko", "RS"),
+    ("svedsko", "SE"),
+    ("svycarsko", "CH"),
+    ("tchaj-wan", "TW"),
+    ("thajsko", "TH"),
+    ("turecko", "TR"),
+    ("ukrajina", "UA"),
+    ("usa", "US"),
+    ("velka britanie", "GB"),
+];
+
+/// Get the ISO 3166-1 alpha-2 code of a country named in Czech.
+pub(crate) fn iso2(name_cs: &str) -> Option<&'static str> {
+    let name = normalize(name_cs);
+    COUNTRIES
+        .iter()
+        .find(|(known, _)| *known == name)
+        .map(|(_, code)| *code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso2() {
+        assert_eq!(iso2("Velká Británie"), Some("GB"));
+        assert_eq!(iso2(" Česko "), Some("CZ"));
+        assert_eq!(iso2("Československo"), None);
+    }
+}
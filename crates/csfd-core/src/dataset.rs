@@ -0,0 +1,355 @@
+//! Rating dataset builder
+//!
+//! Builds a CSV dataset of title and episode ratings for a genre and year
+//! range, e.g. for studying Czech rating behavior in pandas or DuckDB. The
+//! builder walks ČSFD's advanced search, loads every matching series with
+//! its episodes and appends one row per series and per episode.
+//!
+//! Building is resumable: the search page and finished series are
+//! checkpointed in the local store after every series, so an interrupted
+//! run over thousands of titles continues where it stopped and appends to
+//! the same file.
+
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CsfdError, Result};
+use crate::session::ScrapeSession;
+use crate::store::LocalStore;
+use crate::types::{Episode, SeriesDetail, SeriesFilters};
+
+/// What to collect into a dataset
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatasetSpec {
+    /// Genre and year range of the series to collect
+    pub filters: SeriesFilters,
+    /// Maximum number of search pages to walk, unlimited if `None`
+    pub max_pages: Option<u32>,
+}
+
+impl DatasetSpec {
+    /// Store key of the checkpoint for this spec
+    fn checkpoint_key(&self) -> String {
+        let filters = &self.filters;
+        format!(
+            "dataset_{}_{}_{}",
+            filters
+                .genre
+                .as_deref()
+                .map(crate::text::normalize)
+                .unwrap_or_default()
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "-"),
+            filters.year_from.map(|y| y.to_string()).unwrap_or_default(),
+            filters.year_to.map(|y| y.to_string()).unwrap_or_default(),
+        )
+    }
+}
+
+/// Kind of entity a dataset row describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowKind {
+    /// The series as a whole
+    Series,
+    /// A single episode
+    Episode,
+}
+
+/// One row of a rating dataset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RatingRow {
+    /// Whether the row is a series or an episode
+    pub kind: RowKind,
+    /// ČSFD ID of the series
+    pub series_id: u32,
+    /// ČSFD ID of the series or episode
+    pub csfd_id: u32,
+    /// Name of the series or episode
+    pub name: String,
+    /// Year or year range of the series
+    pub year: Option<String>,
+    /// Season number of an episode
+    pub season: Option<u8>,
+    /// Episode number within the season
+    pub episode: Option<u16>,
+    /// Rating percentage (0-100)
+    pub rating: Option<f32>,
+    /// Number of user ratings, if known
+    pub rating_count: Option<u32>,
+}
+
+impl RatingRow {
+    /// Rows of a series followed by its episodes
+    pub fn for_series(detail: &SeriesDetail, episodes: &[Episode]) -> Vec<Self> {
+        let series = Self {
+            kind: RowKind::Series,
+            series_id: detail.csfd_id,
+            csfd_id: detail.csfd_id,
+            name: detail.name.clone(),
+            year: detail.year_range.clone(),
+            season: None,
+            episode: None,
+            rating: detail.rating,
+            rating_count: None,
+        };
+        let episodes = episodes.iter().map(|episode| Self {
+            kind: RowKind::Episode,
+            series_id: detail.csfd_id,
+            csfd_id: episode.csfd_id,
+            name: episode.name.clone(),
+            year: detail.year_range.clone(),
+            season: Some(episode.season_number),
+            episode: Some(episode.episode_number),
+            rating: episode.rating,
+            rating_count: episode.rating_count,
+        });
+        std::iter::once(series).chain(episodes).collect()
+    }
+}
+
+/// Summary of a dataset run
+#[derive(Debug, Default, Serialize)]
+pub struct DatasetReport {
+    /// Series written in this run
+    pub series_written: usize,
+    /// Rows written in this run
+    pub rows_written: usize,
+    /// Series skipped because their genre didn't match
+    pub skipped: usize,
+    /// Whether all search pages were walked; the checkpoint is then removed
+    pub finished: bool,
+}
+
+/// Progress of a dataset run kept between runs
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Checkpoint {
+    /// Search page to continue with
+    page: u32,
+    /// Series already written or skipped
+    done: HashSet<u32>,
+}
+
+/// Build a CSV dataset of series and episode ratings.
+///
+/// Lookups run within the session, so its cancellation, schedule window and
+/// retry budget apply. When a lookup fails, the run stops with the error and
+/// the next run retries that series. Only reading ČSFD, the run writes the
+/// file and checkpoints in dry-run mode too.
+///
+/// # Arguments
+/// * `session` - Session to run the lookups in
+/// * `spec` - Genre, year range and page limit
+/// * `path` - CSV file to write; appended to when resuming
+/// * `store` - Optional store for resumable checkpoints
+///
+/// # Returns
+/// * `Ok(DatasetReport)` summarizing the run
+/// * `Err(CsfdError::StorageError)` if the file or checkpoint cannot be written
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::dataset::{build_dataset, DatasetSpec};
+/// use csfd_core::types::SeriesFilters;
+/// use csfd_core::{CsfdScraper, LocalStore};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let store = LocalStore::open("/tmp/csfd-data")?;
+/// let spec = DatasetSpec {
+///     filters: SeriesFilters {
+///         genre: Some("Krimi".to_string()),
+///         year_from: Some(2000),
+///         year_to: Some(2020),
+///     },
+///     max_pages: None,
+/// };
+///
+/// let report = build_dataset(&scraper.session(), &spec, "krimi.csv", Some(&store)).await?;
+/// println!("{} rows", report.rows_written);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn build_dataset(
+    session: &ScrapeSession<'_>,
+    spec: &DatasetSpec,
+    path: impl AsRef<Path>,
+    store: Option<&LocalStore>,
+) -> Result<DatasetReport> {
+    let key = spec.checkpoint_key();
+    let mut checkpoint: Checkpoint = match store {
+        Some(store) => store.load(&key)?,
+        None => Checkpoint::default(),
+    };
+    let resuming = checkpoint.page > 0;
+    checkpoint.page = checkpoint.page.max(1);
+
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(!resuming)
+        .from_writer(open_output(path.as_ref(), resuming)?);
+    let scraper = session.scraper();
+    let mut report = DatasetReport::default();
+
+    loop {
+        let results = session
+            .run(scraper.advanced_search(&spec.filters, checkpoint.page))
+            .await?;
+
+        for result in &results.items {
+            if checkpoint.done.contains(&result.csfd_id) {
+                continue;
+            }
+
+            let detail = session.run(scraper.get_series(result.csfd_id)).await?;
+            if spec.filters.matches_genres(&detail.genres) {
+                let episodes = session
+                    .run(scraper.list_series_episodes(result.csfd_id))
+                    .await?;
+                let rows = RatingRow::for_series(&detail, &episodes);
+                for row in &rows {
+                    writer.serialize(row).map_err(storage_error)?;
+                }
+                writer.flush().map_err(storage_error)?;
+                report.series_written += 1;
+                report.rows_written += rows.len();
+            } else {
+                report.skipped += 1;
+            }
+
+            checkpoint.done.insert(result.csfd_id);
+            if let Some(store) = store {
+                store.save(&key, &checkpoint)?;
+            }
+        }
+
+        let reached_limit = spec.max_pages.is_some_and(|max| checkpoint.page >= max);
+        if !results.has_next_page || reached_limit {
+            break;
+        }
+        checkpoint.page += 1;
+        if let Some(store) = store {
+            store.save(&key, &checkpoint)?;
+        }
+    }
+
+    if let Some(store) = store {
+        store.remove(&key)?;
+    }
+    report.finished = true;
+    Ok(report)
+}
+
+/// Open the output file, appending when resuming a previous run.
+fn open_output(path: &Path, append: bool) -> Result<File> {
+    let file = if append {
+        OpenOptions::new().create(true).append(true).open(path)
+    } else {
+        File::create(path)
+    };
+    file.map_err(|e| CsfdError::StorageError(format!("{}: {}", path.display(), e)))
+}
+
+fn storage_error(error: impl std::fmt::Display) -> CsfdError {
+    CsfdError::StorageError(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use futures::future::BoxFuture;
+
+    use crate::client::{ClientConfig, CsfdClient};
+    use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+
+    #[test]
+    fn test_rating_rows_csv() {
+        let detail = SeriesDetail {
+            csfd_id: 71924,
+            name: "Dr. House".to_string(),
+            original_name: None,
+            year_range: Some("2004-2012".to_string()),
+            genres: vec!["Drama".into()],
+            genre_ids: Vec::new(),
+            status_label: None,
+            countries: Vec::new(),
+            seasons: Vec::new(),
+            directors: Vec::new(),
+            writers: Vec::new(),
+            cast: Vec::new(),
+            rating: Some(91.0),
+            recommendations: Vec::new(),
+            availability: Default::default(),
+            imdb_id: None,
+        };
+        let episode = Episode {
+            csfd_id: 100,
+            name: "Pilot".to_string(),
+            season_number: 1,
+            episode_number: 1,
+            rating: Some(85.0),
+            url: String::new(),
+            comment_count: None,
+            rating_count: Some(420),
+            absolute_number: None,
+            list_position: 0,
+        };
+
+        let rows = RatingRow::for_series(&detail, &[episode]);
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for row in &rows {
+            writer.serialize(row).unwrap();
+        }
+        let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+        assert_eq!(
+            csv,
+            "kind,series_id,csfd_id,name,year,season,episode,rating,rating_count\n\
+             series,71924,71924,Dr. House,2004-2012,,,91.0,\n\
+             episode,71924,100,Pilot,2004-2012,1,1,85.0,420\n"
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_key() {
+        let spec = DatasetSpec {
+            filters: SeriesFilters {
+                genre: Some("Sci-Fi".to_string()),
+                year_from: Some(2000),
+                year_to: None,
+            },
+            max_pages: Some(3),
+        };
+        assert_eq!(spec.checkpoint_key(), "dataset_sci-fi_2000_");
+    }
+
+    /// Answers every request with an empty page
+    struct Empty;
+
+    impl HttpTransport for Empty {
+        fn send<'a>(&'a self, _request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+            Box::pin(async { Ok(HttpResponse::html("<html></html>")) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_build_dataset_writes_in_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("dataset.csv");
+        let config = ClientConfig {
+            dry_run: true,
+            ..ClientConfig::default()
+        };
+        let client = CsfdClient::with_transport(config, Arc::new(Empty));
+        let scraper = crate::CsfdScraper::with_client(client);
+
+        let report = build_dataset(&scraper.session(), &DatasetSpec::default(), &path, None)
+            .await
+            .unwrap();
+        assert!(report.finished);
+        assert!(path.exists());
+        assert!(scraper.client().planned_actions().is_empty());
+    }
+}
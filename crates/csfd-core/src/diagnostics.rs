@@ -0,0 +1,261 @@
+//! Diagnostics bundles for parser bug reports
+//!
+//! When a title renders wrong, the page ČSFD served is needed to reproduce
+//! it. A bundle collects the redacted page, the outcome of parsing it, the
+//! parser version and capabilities and the client settings, and is written
+//! as a zip file users can attach to a GitHub issue.
+
+use std::io::Write;
+use std::path::Path;
+
+use serde::Serialize;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+use crate::cache::CacheStrategy;
+use crate::client::CsfdClient;
+use crate::error::{CsfdError, Result};
+use crate::parser::capabilities::{capabilities, Capabilities, VERSION};
+use crate::parser::parse_series_detail;
+use crate::redact::redact_html;
+use crate::types::DetailAvailability;
+
+/// Client settings relevant to a bug report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigSummary {
+    /// Maximum requests per second
+    pub requests_per_second: f64,
+    /// Requests sent at once before throttling
+    pub rate_limit_burst: u32,
+    /// Retries of a request failing with 429 or 5xx
+    pub max_retries: u32,
+    /// Operations a session runs at once
+    pub concurrency: usize,
+    /// Whether write actions are only recorded
+    pub dry_run: bool,
+    /// Whether detail pages are checked against the requested ID
+    pub verify_pages: bool,
+    /// Whether errors and activity events are redacted
+    pub redact: bool,
+    /// Whether expired cached pages are served while refetched
+    pub cache_strategy: CacheStrategy,
+}
+
+impl ConfigSummary {
+    /// Summarize the settings of a client
+    pub fn of(client: &CsfdClient) -> Self {
+        let interval = client.min_interval().as_secs_f64();
+        Self {
+            requests_per_second: if interval > 0.0 { 1.0 / interval } else { 0.0 },
+            rate_limit_burst: client.rate_limit_burst(),
+            max_retries: client.max_retries(),
+            concurrency: client.concurrency(),
+            dry_run: client.is_dry_run(),
+            verify_pages: client.verifies_pages(),
+            redact: client.redacts(),
+            cache_strategy: client.cache_strategy(),
+        }
+    }
+}
+
+/// Outcome of parsing the page of a bundle
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParseReport {
+    /// Parsed series name
+    pub name: Option<String>,
+    /// Number of parsed seasons
+    pub season_count: usize,
+    /// Which sections were found and parsed
+    pub availability: Option<DetailAvailability>,
+    /// Parse error, if parsing failed
+    pub error: Option<String>,
+}
+
+impl ParseReport {
+    /// Parse a series page and record the outcome instead of failing
+    pub fn of_series(html: &str, csfd_id: u32) -> Self {
+        match parse_series_detail(html, csfd_id) {
+            Ok(detail) => Self {
+                name: Some(detail.name),
+                season_count: detail.seasons.len(),
+                availability: Some(detail.availability),
+                error: None,
+            },
+            Err(e) => Self {
+                error: Some(e.to_string()),
+                ..Self::default()
+            },
+        }
+    }
+}
+
+/// Everything needed to reproduce how a title was parsed
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::CsfdScraper;
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let bundle = scraper.create_diagnostics_bundle(264494).await?;
+/// bundle.write_zip("/tmp/csfd-264494-diagnostics.zip")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DiagnosticsBundle {
+    /// ČSFD ID of the title
+    pub csfd_id: u32,
+    /// Path of the fetched page
+    pub path: String,
+    /// Fetched page, redacted with `redact_html`
+    pub html: String,
+    /// Outcome of parsing the page
+    pub report: ParseReport,
+    /// Parser version and the fields each parser extracts
+    pub capabilities: Capabilities,
+    /// Settings of the client that fetched the page
+    pub config: ConfigSummary,
+}
+
+/// Summary file of a bundle, next to the page itself
+#[derive(Serialize)]
+struct Manifest<'a> {
+    csfd_id: u32,
+    path: &'a str,
+    crate_version: &'static str,
+    parser_version: u32,
+    report: &'a ParseReport,
+    capabilities: &'a Capabilities,
+    config: &'a ConfigSummary,
+}
+
+impl DiagnosticsBundle {
+    /// Create a bundle from a fetched series page, redacting and parsing it
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD ID of the series
+    /// * `path` - Path the page was fetched from
+    /// * `html` - Page as served by ČSFD
+    /// * `config` - Settings of the client that fetched the page
+    pub fn from_series_page(csfd_id: u32, path: &str, html: &str, config: ConfigSummary) -> Self {
+        Self {
+            csfd_id,
+            path: path.to_string(),
+            html: redact_html(html).into_owned(),
+            report: ParseReport::of_series(html, csfd_id),
+            capabilities: capabilities(),
+            config,
+        }
+    }
+
+    /// Get the name of the zip file for this bundle
+    pub fn file_name(&self) -> String {
+        format!("csfd-{}-diagnostics.zip", self.csfd_id)
+    }
+
+    /// Pack the bundle as a zip archive with `page.html` and `report.json`
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the archive cannot be written
+    pub fn to_zip(&self) -> Result<Vec<u8>> {
+        let manifest = Manifest {
+            csfd_id: self.csfd_id,
+            path: &self.path,
+            crate_version: env!("CARGO_PKG_VERSION"),
+            parser_version: VERSION,
+            report: &self.report,
+            capabilities: &self.capabilities,
+            config: &self.config,
+        };
+        let report = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| CsfdError::StorageError(format!("report.json: {}", e)))?;
+
+        let mut zip = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default();
+        for (name, data) in [
+            ("report.json", report.as_slice()),
+            ("page.html", self.html.as_bytes()),
+        ] {
+            zip.start_file(name, options).map_err(zip_error)?;
+            zip.write_all(data)
+                .map_err(|e| CsfdError::StorageError(format!("{}: {}", name, e)))?;
+        }
+        Ok(zip.finish().map_err(zip_error)?.into_inner())
+    }
+
+    /// Write the bundle as a zip file
+    ///
+    /// Written even when `ConfigSummary::dry_run` is set, as dry-run only
+    /// concerns write actions on ČSFD.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the file cannot be written
+    pub fn write_zip(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_zip()?)
+            .map_err(|e| CsfdError::StorageError(format!("{}: {}", path.display(), e)))
+    }
+}
+
+fn zip_error(e: zip::result::ZipError) -> CsfdError {
+    CsfdError::StorageError(format!("diagnostics zip: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_zip() {
+        let html = r#"
+            <html>
+                <head><meta name="csrf-token" content="secret"></head>
+                <body>
+                    <div class="film-header"><h1>Perníkový táta</h1></div>
+                    <a href="/uzivatel/12345-nick/">nick</a>
+                </body>
+            </html>
+        "#;
+        let config = ConfigSummary {
+            requests_per_second: 2.0,
+            rate_limit_burst: 5,
+            max_retries: 3,
+            concurrency: 4,
+            dry_run: false,
+            verify_pages: true,
+            redact: false,
+            cache_strategy: CacheStrategy::CacheAside,
+        };
+        let bundle =
+            DiagnosticsBundle::from_series_page(264494, "/film/264494/prehled/", html, config);
+        assert!(!bundle.html.contains("secret"));
+        assert!(!bundle.html.contains("12345-nick"));
+        assert_eq!(bundle.report.name.as_deref(), Some("Perníkový táta"));
+        assert_eq!(bundle.file_name(), "csfd-264494-diagnostics.zip");
+
+        let zip = bundle.to_zip().unwrap();
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(zip)).unwrap();
+        let mut names: Vec<_> = archive.file_names().collect();
+        names.sort();
+        assert_eq!(names, vec!["page.html", "report.json"]);
+
+        let report: serde_json::Value =
+            serde_json::from_reader(archive.by_name("report.json").unwrap()).unwrap();
+        assert_eq!(report["parser_version"], VERSION);
+        assert_eq!(report["report"]["name"], "Perníkový táta");
+        assert_eq!(report["config"]["max_retries"], 3);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(bundle.file_name());
+        let dry_run = DiagnosticsBundle {
+            config: ConfigSummary {
+                dry_run: true,
+                ..bundle.config.clone()
+            },
+            ..bundle
+        };
+        dry_run.write_zip(&path).unwrap();
+        assert!(path.exists());
+    }
+}
@@ -0,0 +1,345 @@
+//! Differences between two snapshots of a series
+//!
+//! Sync and watch features compare a stored series with a freshly fetched
+//! one. `SeriesDiff` carries only what changed (new seasons and episodes,
+//! rating moves and metadata edits), so changelog UIs get a small structured
+//! payload instead of diffing two full series in the frontend. `WatchEvent`
+//! splits a diff into per-episode events for streaming to subscribers.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Episode, EpisodeCode, Label, Season, SeriesDetail};
+
+/// Rating of an episode that changed between two snapshots
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RatingChange {
+    /// ČSFD ID of the episode
+    pub episode_id: u32,
+    /// Season and episode number
+    pub code: EpisodeCode,
+    /// Previous rating percentage
+    pub old: Option<f32>,
+    /// Current rating percentage
+    pub new: Option<f32>,
+}
+
+/// Series field whose value changed between two snapshots
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MetadataChange {
+    /// Field name as in `SeriesDetail` (e.g., "year_range")
+    pub field: String,
+    /// Previous value, list fields joined with ", "
+    pub old: Option<String>,
+    /// Current value, list fields joined with ", "
+    pub new: Option<String>,
+}
+
+/// One change of a watched series, as pushed to subscribers
+///
+/// Event streams (e.g. server-sent events) send these one by one instead of
+/// whole diffs; `name` gives the event name to send them under.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
+pub enum WatchEvent {
+    /// An episode appeared
+    NewEpisode {
+        /// ČSFD ID of the series
+        series_id: u32,
+        /// The new episode
+        episode: Episode,
+    },
+    /// The rating of an episode changed
+    RatingChange {
+        /// ČSFD ID of the series
+        series_id: u32,
+        /// Old and new rating of the episode
+        change: RatingChange,
+    },
+}
+
+impl WatchEvent {
+    /// Get the ČSFD ID of the series the event belongs to
+    pub fn series_id(&self) -> u32 {
+        match self {
+            Self::NewEpisode { series_id, .. } | Self::RatingChange { series_id, .. } => *series_id,
+        }
+    }
+
+    /// Get the event name ("new_episode" or "rating_change")
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NewEpisode { .. } => "new_episode",
+            Self::RatingChange { .. } => "rating_change",
+        }
+    }
+}
+
+/// Changes of a series between a stored and a fresh snapshot
+///
+/// # Example
+/// ```
+/// use csfd_core::diff::SeriesDiff;
+/// # use csfd_core::types::SeriesDetail;
+/// # fn example(stored: &SeriesDetail, fresh: &SeriesDetail) {
+/// let diff = SeriesDiff::between(stored, &[], fresh, &[]);
+/// if !diff.is_empty() {
+///     println!("{} new seasons", diff.new_seasons.len());
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SeriesDiff {
+    /// ČSFD ID of the series
+    pub csfd_id: u32,
+    /// Seasons not present in the stored snapshot
+    pub new_seasons: Vec<Season>,
+    /// Episodes not present in the stored snapshot
+    pub new_episodes: Vec<Episode>,
+    /// Episodes present in both snapshots whose rating changed
+    pub rating_changes: Vec<RatingChange>,
+    /// Series fields whose value changed
+    pub metadata_changes: Vec<MetadataChange>,
+}
+
+impl SeriesDiff {
+    /// Compare a stored snapshot of a series with a fresh one.
+    ///
+    /// Seasons and episodes are matched by ČSFD ID. Pass empty episode
+    /// slices to compare only the series detail.
+    ///
+    /// # Arguments
+    /// * `old` / `old_episodes` - Stored series detail and episodes
+    /// * `new` / `new_episodes` - Freshly fetched series detail and episodes
+    pub fn between(
+        old: &SeriesDetail,
+        old_episodes: &[Episode],
+        new: &SeriesDetail,
+        new_episodes: &[Episode],
+    ) -> Self {
+        let new_seasons = new
+            .seasons
+            .iter()
+            .filter(|season| !old.seasons.iter().any(|s| s.csfd_id == season.csfd_id))
+            .cloned()
+            .collect();
+
+        let mut new_list = Vec::new();
+        let mut rating_changes = Vec::new();
+        for episode in new_episodes {
+            match old_episodes.iter().find(|e| e.csfd_id == episode.csfd_id) {
+                None => new_list.push(episode.clone()),
+                Some(previous) if previous.rating != episode.rating => {
+                    rating_changes.push(RatingChange {
+                        episode_id: episode.csfd_id,
+                        code: episode.code(),
+                        old: previous.rating,
+                        new: episode.rating,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+
+        Self {
+            csfd_id: new.csfd_id,
+            new_seasons,
+            new_episodes: new_list,
+            rating_changes,
+            metadata_changes: metadata_changes(old, new),
+        }
+    }
+
+    /// Split the episode changes into events, new episodes first
+    pub fn events(&self) -> Vec<WatchEvent> {
+        let series_id = self.csfd_id;
+        let new_episodes = self
+            .new_episodes
+            .iter()
+            .map(|episode| WatchEvent::NewEpisode {
+                series_id,
+                episode: episode.clone(),
+            });
+        let rating_changes = self
+            .rating_changes
+            .iter()
+            .map(|change| WatchEvent::RatingChange {
+                series_id,
+                change: change.clone(),
+            });
+        new_episodes.chain(rating_changes).collect()
+    }
+
+    /// Check whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.new_seasons.is_empty()
+            && self.new_episodes.is_empty()
+            && self.rating_changes.is_empty()
+            && self.metadata_changes.is_empty()
+    }
+}
+
+/// Compare the descriptive fields of two series snapshots.
+fn metadata_changes(old: &SeriesDetail, new: &SeriesDetail) -> Vec<MetadataChange> {
+    let joined = |values: &[Label]| (!values.is_empty()).then(|| values.join(", "));
+    let fields = [
+        ("name", Some(old.name.clone()), Some(new.name.clone())),
+        (
+            "original_name",
+            old.original_name.clone(),
+            new.original_name.clone(),
+        ),
+        ("year_range", old.year_range.clone(), new.year_range.clone()),
+        ("genres", joined(&old.genres), joined(&new.genres)),
+        ("countries", joined(&old.countries), joined(&new.countries)),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(field, old, new)| MetadataChange {
+            field: field.to_string(),
+            old,
+            new,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(year_range: &str, season_ids: &[u32]) -> SeriesDetail {
+        SeriesDetail {
+            csfd_id: 264494,
+            name: "Stranger Things".to_string(),
+            original_name: None,
+            year_range: Some(year_range.to_string()),
+            genres: vec!["Drama".into()],
+            genre_ids: Vec::new(),
+            status_label: None,
+            countries: vec!["USA".into()],
+            seasons: season_ids
+                .iter()
+                .map(|&id| Season {
+                    csfd_id: id,
+                    name: format!("Série {}", id),
+                    number: u8::try_from(id).ok(),
+                    label: id.to_string(),
+                    year: None,
+                    episode_count: 8,
+                    url: String::new(),
+                    poster_url: None,
+                    rating: None,
+                })
+                .collect(),
+            directors: Vec::new(),
+            writers: Vec::new(),
+            cast: Vec::new(),
+            rating: None,
+            recommendations: Vec::new(),
+            availability: Default::default(),
+            imdb_id: None,
+        }
+    }
+
+    fn episode(csfd_id: u32, number: u16, rating: Option<f32>) -> Episode {
+        Episode {
+            csfd_id,
+            name: format!("Kapitola {}", number),
+            season_number: 1,
+            episode_number: number,
+            rating,
+            url: String::new(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        }
+    }
+
+    #[test]
+    fn test_series_diff_unchanged() {
+        let old = series("2016", &[1]);
+        let episodes = [episode(10, 1, Some(85.0))];
+        assert!(SeriesDiff::between(&old, &episodes, &old, &episodes).is_empty());
+    }
+
+    #[test]
+    fn test_series_diff_changes() {
+        let old = series("2016", &[1]);
+        let new = series("2016-2025", &[1, 2]);
+        let old_episodes = [episode(10, 1, Some(85.0)), episode(11, 2, Some(80.0))];
+        let new_episodes = [
+            episode(10, 1, Some(86.0)),
+            episode(11, 2, Some(80.0)),
+            episode(12, 3, None),
+        ];
+
+        let diff = SeriesDiff::between(&old, &old_episodes, &new, &new_episodes);
+        assert_eq!(diff.csfd_id, 264494);
+        assert_eq!(diff.new_seasons.len(), 1);
+        assert_eq!(diff.new_seasons[0].csfd_id, 2);
+        assert_eq!(diff.new_episodes.len(), 1);
+        assert_eq!(diff.new_episodes[0].csfd_id, 12);
+        assert_eq!(
+            diff.rating_changes,
+            vec![RatingChange {
+                episode_id: 10,
+                code: EpisodeCode {
+                    season: 1,
+                    episode: 1
+                },
+                old: Some(85.0),
+                new: Some(86.0),
+            }]
+        );
+        assert_eq!(
+            diff.metadata_changes,
+            vec![MetadataChange {
+                field: "year_range".to_string(),
+                old: Some("2016".to_string()),
+                new: Some("2016-2025".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_series_diff_events() {
+        let old = series("2016", &[1]);
+        let old_episodes = [episode(10, 1, Some(85.0))];
+        let new_episodes = [episode(10, 1, Some(86.0)), episode(12, 2, None)];
+
+        let events = SeriesDiff::between(&old, &old_episodes, &old, &new_episodes).events();
+        let names: Vec<_> = events.iter().map(WatchEvent::name).collect();
+        assert_eq!(names, vec!["new_episode", "rating_change"]);
+        assert!(events.iter().all(|event| event.series_id() == 264494));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_watch_event_serializes() {
+        let old = series("2016", &[1]);
+        let old_episodes = [episode(10, 1, Some(85.0))];
+        let new_episodes = [episode(10, 1, Some(86.0)), episode(12, 2, None)];
+
+        let events = SeriesDiff::between(&old, &old_episodes, &old, &new_episodes).events();
+        let json = serde_json::to_value(&events[1]).unwrap();
+        assert_eq!(json["type"], "rating_change");
+        assert_eq!(json["change"]["new"], 86.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_series_diff_serializes() {
+        let old = series("2016", &[1]);
+        let diff = SeriesDiff::between(&old, &[], &old, &[episode(10, 1, None)]);
+        let json = serde_json::to_value(&diff).unwrap();
+        assert_eq!(json["new_episodes"][0]["episode_code"], "S01E01");
+        assert!(json["rating_changes"].as_array().unwrap().is_empty());
+    }
+}
@@ -1,18 +1,30 @@
 //! Error types for ČSFD Scraper
 //!
 //! This module defines all error types used throughout the library.
-//! CsfdError implements Serialize for Tauri compatibility.
+//! With the `serde` feature, CsfdError implements Serialize for Tauri compatibility.
 
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
 use serde::{Serialize, Serializer};
 use thiserror::Error;
 
+use crate::i18n::{Locale, Message};
+
 /// Error type for ČSFD Scraper operations
 #[derive(Error, Debug)]
 pub enum CsfdError {
     /// HTTP request failed
+    #[cfg(feature = "client")]
     #[error("HTTP request failed: {0}")]
     HttpError(#[from] reqwest::Error),
 
+    /// ČSFD answered with a status the client doesn't handle, e.g. 403 or
+    /// a 5xx that persisted through retries
+    #[cfg(feature = "client")]
+    #[error("Unexpected HTTP status: {0}")]
+    HttpStatus(reqwest::StatusCode),
+
     /// Failed to parse HTML content
     #[error("Failed to parse HTML: {0}")]
     ParseError(String),
@@ -25,9 +37,10 @@ pub enum CsfdError {
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
 
-    /// Rate limited by the server (HTTP 429)
-    #[error("Rate limited - too many requests")]
-    RateLimited,
+    /// Rate limited by the server (HTTP 429), with the wait it asked for
+    /// in `Retry-After`
+    #[error("Rate limited - too many requests{}", retry_after_suffix(*.0))]
+    RateLimited(Option<Duration>),
 
     /// Requested resource was not found (HTTP 404)
     #[error("Series not found: {0}")]
@@ -36,9 +49,251 @@ pub enum CsfdError {
     /// Invalid CSFD ID provided
     #[error("Invalid CSFD ID: {0}")]
     InvalidId(u32),
+
+    /// Operation was cancelled before it completed
+    #[error("Operation cancelled")]
+    Cancelled,
+
+    /// The workflow used up its shared retry budget
+    #[error("Retry budget exhausted - giving up on remaining requests")]
+    RetryBudgetExhausted,
+
+    /// Reading or writing the local store failed
+    #[error("Storage error: {0}")]
+    StorageError(String),
+
+    /// The fetched page is not the requested one (e.g., an error page)
+    #[error("Unexpected page: {0}")]
+    UnexpectedPage(String),
+
+    /// The login session expired and logging in again failed or wasn't possible
+    #[error("Session expired - please log in again")]
+    SessionExpired,
+
+    /// ČSFD rejected the login, e.g. because of a wrong password
+    #[error("Login failed: {0}")]
+    LoginFailed(String),
+
+    /// A library query was rejected or failed
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+
+    /// An operation ran past its deadline and was aborted
+    #[error("Operation timed out: {} s", .0.as_secs())]
+    Timeout(Duration),
+}
+
+/// Wait suggested after a rate limit without `Retry-After` or a drained retry budget
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// What a user can do about a failed operation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RetrySuggestion {
+    /// Nothing to offer, e.g. after the user cancelled
+    #[default]
+    None,
+    /// Try again right away, e.g. after a network failure
+    RetryNow,
+    /// Try again after a wait, e.g. when rate limited
+    RetryLater,
+    /// Log in again before retrying
+    LogIn,
+    /// Correct the ID, link or query
+    FixInput,
+    /// The page couldn't be read; report it with a diagnostics bundle
+    Report,
+}
+
+/// Whether and when to offer retrying a failed operation
+///
+/// Lets every frontend show the same "retry in 30 s" button instead of
+/// deriving it from error messages. The default advises against retrying.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use csfd_core::{CsfdError, RetrySuggestion};
+///
+/// let advice = CsfdError::RateLimited(Some(Duration::from_secs(10))).retry_advice();
+/// assert!(advice.retryable);
+/// assert_eq!(advice.after_ms, Some(10_000));
+/// assert_eq!(advice.suggestion, RetrySuggestion::RetryLater);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RetryAdvice {
+    /// Retrying the same operation may succeed
+    pub retryable: bool,
+    /// Milliseconds to wait before retrying, if a wait is needed
+    pub after_ms: Option<u64>,
+    /// What to offer the user
+    pub suggestion: RetrySuggestion,
+}
+
+impl RetryAdvice {
+    /// Advice to retry right away
+    fn now() -> Self {
+        Self {
+            retryable: true,
+            after_ms: None,
+            suggestion: RetrySuggestion::RetryNow,
+        }
+    }
+
+    /// Advice to retry after a wait
+    fn after(wait: Duration) -> Self {
+        Self {
+            retryable: true,
+            after_ms: Some(u64::try_from(wait.as_millis()).unwrap_or(u64::MAX)),
+            suggestion: RetrySuggestion::RetryLater,
+        }
+    }
+
+    /// Advice not to retry as is, offering something else
+    fn instead(suggestion: RetrySuggestion) -> Self {
+        Self {
+            retryable: false,
+            after_ms: None,
+            suggestion,
+        }
+    }
+}
+
+/// English suffix of a rate limit error with a known wait
+fn retry_after_suffix(retry_after: Option<Duration>) -> String {
+    retry_after
+        .map(|wait| {
+            format!(
+                ": {} {} s",
+                Locale::En.text(Message::RetryAfter),
+                wait.as_secs()
+            )
+        })
+        .unwrap_or_default()
+}
+
+impl CsfdError {
+    /// Get how long the server asked to wait before the next request
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use csfd_core::CsfdError;
+    ///
+    /// let error = CsfdError::RateLimited(Some(Duration::from_secs(30)));
+    /// assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+    /// assert_eq!(error.to_string(), "Rate limited - too many requests: retry after 30 s");
+    /// ```
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited(retry_after) => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Get whether and when a frontend should offer retrying.
+    ///
+    /// Network failures, server errors, timeouts and unexpected pages (often
+    /// a temporary error page) can be retried right away; rate limits after the wait
+    /// ČSFD asked for, or `DEFAULT_RETRY_DELAY`. Invalid input, an expired
+    /// session and unreadable pages need something else first.
+    pub fn retry_advice(&self) -> RetryAdvice {
+        match self {
+            #[cfg(feature = "client")]
+            Self::HttpError(_) => RetryAdvice::now(),
+            #[cfg(feature = "client")]
+            Self::HttpStatus(status) if status.is_server_error() => RetryAdvice::now(),
+            Self::Timeout(_) | Self::UnexpectedPage(_) => RetryAdvice::now(),
+            Self::RateLimited(retry_after) => {
+                RetryAdvice::after(retry_after.unwrap_or(DEFAULT_RETRY_DELAY))
+            }
+            Self::RetryBudgetExhausted => RetryAdvice::after(DEFAULT_RETRY_DELAY),
+            Self::SessionExpired | Self::LoginFailed(_) => {
+                RetryAdvice::instead(RetrySuggestion::LogIn)
+            }
+            Self::InvalidUrl(_) | Self::InvalidId(_) | Self::InvalidQuery(_) => {
+                RetryAdvice::instead(RetrySuggestion::FixInput)
+            }
+            Self::ParseError(_) | Self::ElementNotFound(_) => {
+                RetryAdvice::instead(RetrySuggestion::Report)
+            }
+            Self::NotFound(_) | Self::Cancelled | Self::StorageError(_) => RetryAdvice::default(),
+            #[cfg(feature = "client")]
+            Self::HttpStatus(_) => RetryAdvice::default(),
+        }
+    }
+
+    /// Remove query strings, user identifiers and URLs of failed requests.
+    ///
+    /// Used when redaction is enabled (see `ClientConfig::redact`), so errors
+    /// can be logged or attached to bug reports safely.
+    pub fn redacted(self) -> Self {
+        let redact = |text: String| crate::redact::redact_text(&text).into_owned();
+        match self {
+            #[cfg(feature = "client")]
+            Self::HttpError(error) => Self::HttpError(error.without_url()),
+            Self::ParseError(message) => Self::ParseError(redact(message)),
+            Self::ElementNotFound(message) => Self::ElementNotFound(redact(message)),
+            Self::InvalidUrl(url) => Self::InvalidUrl(crate::redact::redact_url(&url).into_owned()),
+            Self::NotFound(url) => Self::NotFound(crate::redact::redact_url(&url).into_owned()),
+            Self::StorageError(message) => Self::StorageError(redact(message)),
+            Self::UnexpectedPage(message) => Self::UnexpectedPage(redact(message)),
+            other => other,
+        }
+    }
+}
+
+impl CsfdError {
+    /// Message for showing the error to users in the given language.
+    ///
+    /// English messages are the same as `Display`.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::i18n::Locale;
+    /// use csfd_core::CsfdError;
+    ///
+    /// let error = CsfdError::InvalidId(0);
+    /// assert_eq!(error.localized(Locale::En), error.to_string());
+    /// assert_eq!(error.localized(Locale::Cs), "Neplatné ID ČSFD: 0");
+    /// ```
+    pub fn localized(&self, locale: Locale) -> String {
+        let (message, detail) = match self {
+            #[cfg(feature = "client")]
+            Self::HttpError(error) => (Message::HttpError, Some(error.to_string())),
+            #[cfg(feature = "client")]
+            Self::HttpStatus(status) => (Message::HttpStatus, Some(status.to_string())),
+            Self::ParseError(detail) => (Message::ParseError, Some(detail.clone())),
+            Self::ElementNotFound(detail) => (Message::ElementNotFound, Some(detail.clone())),
+            Self::InvalidUrl(url) => (Message::InvalidUrl, Some(url.clone())),
+            Self::RateLimited(retry_after) => (
+                Message::RateLimited,
+                retry_after.map(|wait| {
+                    format!("{} {} s", locale.text(Message::RetryAfter), wait.as_secs())
+                }),
+            ),
+            Self::NotFound(url) => (Message::NotFound, Some(url.clone())),
+            Self::InvalidId(id) => (Message::InvalidId, Some(id.to_string())),
+            Self::Cancelled => (Message::Cancelled, None),
+            Self::RetryBudgetExhausted => (Message::RetryBudgetExhausted, None),
+            Self::StorageError(detail) => (Message::StorageError, Some(detail.clone())),
+            Self::UnexpectedPage(detail) => (Message::UnexpectedPage, Some(detail.clone())),
+            Self::SessionExpired => (Message::SessionExpired, None),
+            Self::LoginFailed(detail) => (Message::LoginFailed, Some(detail.clone())),
+            Self::InvalidQuery(detail) => (Message::InvalidQuery, Some(detail.clone())),
+            Self::Timeout(limit) => (Message::Timeout, Some(format!("{} s", limit.as_secs()))),
+        };
+        match detail {
+            Some(detail) => format!("{}: {}", locale.text(message), detail),
+            None => locale.text(message).to_string(),
+        }
+    }
 }
 
 /// Serialize CsfdError as a string for Tauri compatibility
+#[cfg(feature = "serde")]
 impl Serialize for CsfdError {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -64,6 +319,101 @@ mod tests {
         assert!(display.contains("invalid HTML"));
     }
 
+    #[test]
+    fn test_csfd_error_localized_english_matches_display() {
+        let errors = [
+            CsfdError::ParseError("invalid HTML".to_string()),
+            CsfdError::ElementNotFound("series name".to_string()),
+            CsfdError::InvalidUrl("ftp://x".to_string()),
+            CsfdError::RateLimited(None),
+            CsfdError::RateLimited(Some(Duration::from_secs(30))),
+            CsfdError::NotFound("/film/1/".to_string()),
+            CsfdError::InvalidId(0),
+            CsfdError::Cancelled,
+            CsfdError::RetryBudgetExhausted,
+            CsfdError::StorageError("disk full".to_string()),
+            CsfdError::UnexpectedPage("error page".to_string()),
+            CsfdError::SessionExpired,
+            CsfdError::LoginFailed("wrong password".to_string()),
+            CsfdError::InvalidQuery("DROP".to_string()),
+            CsfdError::Timeout(Duration::from_secs(45)),
+        ];
+        for error in errors {
+            assert_eq!(error.localized(Locale::En), error.to_string());
+            assert_ne!(error.localized(Locale::Cs), error.to_string());
+        }
+        assert_eq!(
+            CsfdError::Cancelled.localized(Locale::Cs),
+            "Operace byla zrušena"
+        );
+    }
+
+    #[test]
+    fn test_csfd_error_redacted() {
+        let error = CsfdError::NotFound("https://www.csfd.cz/uzivatel/42-nick/?page=2".to_string());
+        assert_eq!(
+            error.redacted().to_string(),
+            "Series not found: https://www.csfd.cz/uzivatel/…/?…"
+        );
+
+        let error = CsfdError::UnexpectedPage("redirected to /uzivatel/42-nick/".to_string());
+        assert_eq!(
+            error.redacted().to_string(),
+            "Unexpected page: redirected to /uzivatel/…/"
+        );
+        assert!(matches!(
+            CsfdError::RateLimited(None).redacted(),
+            CsfdError::RateLimited(None)
+        ));
+    }
+
+    #[test]
+    fn test_csfd_error_retry_advice() {
+        let advice = CsfdError::RateLimited(None).retry_advice();
+        assert_eq!(advice.after_ms, Some(30_000));
+        assert_eq!(
+            CsfdError::Timeout(Duration::from_secs(45)).retry_advice(),
+            RetryAdvice::now()
+        );
+
+        let advice = CsfdError::SessionExpired.retry_advice();
+        assert!(!advice.retryable);
+        assert_eq!(advice.suggestion, RetrySuggestion::LogIn);
+        assert_eq!(
+            CsfdError::InvalidId(0).retry_advice().suggestion,
+            RetrySuggestion::FixInput
+        );
+        assert_eq!(CsfdError::Cancelled.retry_advice(), RetryAdvice::default());
+    }
+
+    #[cfg(feature = "client")]
+    #[test]
+    fn test_csfd_error_http_status() {
+        let error = CsfdError::HttpStatus(reqwest::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(error.retry_advice(), RetryAdvice::now());
+        assert_eq!(error.localized(Locale::En), error.to_string());
+        let error = CsfdError::HttpStatus(reqwest::StatusCode::FORBIDDEN);
+        assert_eq!(error.retry_advice(), RetryAdvice::default());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_retry_advice_serialize() {
+        let json = serde_json::to_value(CsfdError::RetryBudgetExhausted.retry_advice()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "retryable": true, "after_ms": 30_000, "suggestion": "retry_later" })
+        );
+    }
+
+    #[test]
+    fn test_csfd_error_display_session_expired() {
+        assert_eq!(
+            CsfdError::SessionExpired.to_string(),
+            "Session expired - please log in again"
+        );
+    }
+
     #[test]
     fn test_csfd_error_display_parse_error() {
         let error = CsfdError::ParseError("missing element".to_string());
@@ -84,7 +434,7 @@ mod tests {
 
     #[test]
     fn test_csfd_error_display_rate_limited() {
-        let error = CsfdError::RateLimited;
+        let error = CsfdError::RateLimited(None);
         assert_eq!(error.to_string(), "Rate limited - too many requests");
     }
 
@@ -100,6 +450,37 @@ mod tests {
         assert_eq!(error.to_string(), "Invalid CSFD ID: 0");
     }
 
+    #[test]
+    fn test_csfd_error_display_cancelled() {
+        let error = CsfdError::Cancelled;
+        assert_eq!(error.to_string(), "Operation cancelled");
+    }
+
+    #[test]
+    fn test_csfd_error_display_retry_budget_exhausted() {
+        let error = CsfdError::RetryBudgetExhausted;
+        assert_eq!(
+            error.to_string(),
+            "Retry budget exhausted - giving up on remaining requests"
+        );
+    }
+
+    #[test]
+    fn test_csfd_error_display_storage_error() {
+        let error = CsfdError::StorageError("disk full".to_string());
+        assert_eq!(error.to_string(), "Storage error: disk full");
+    }
+
+    #[test]
+    fn test_csfd_error_display_unexpected_page() {
+        let error = CsfdError::UnexpectedPage("expected ID 1, page is 2".to_string());
+        assert_eq!(
+            error.to_string(),
+            "Unexpected page: expected ID 1, page is 2"
+        );
+    }
+
+    #[cfg(feature = "serde")]
     #[test]
     fn test_csfd_error_serialize() {
         let error = CsfdError::ParseError("test error".to_string());
@@ -107,13 +488,15 @@ mod tests {
         assert_eq!(json, "\"Failed to parse HTML: test error\"");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_csfd_error_serialize_rate_limited() {
-        let error = CsfdError::RateLimited;
+        let error = CsfdError::RateLimited(None);
         let json = serde_json::to_string(&error).unwrap();
         assert_eq!(json, "\"Rate limited - too many requests\"");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_csfd_error_serialize_invalid_id() {
         let error = CsfdError::InvalidId(42);
@@ -0,0 +1,199 @@
+//! Data freshness policies
+//!
+//! Decides how long fetched data stays fresh before the caching and storage
+//! layers refetch it. Lifetimes depend on the kind of entity and on whether
+//! the series is still running: an ended series can't gain episodes, so its
+//! data can be kept much longer than an ongoing one's.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::SeriesDetail;
+
+/// One hour
+const HOUR: Duration = Duration::from_secs(3600);
+
+/// One day
+const DAY: Duration = Duration::from_secs(24 * 3600);
+
+/// Kind of fetched data, as seen by the freshness policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EntityKind {
+    /// A page of search results
+    Search,
+    /// Series detail page
+    Series {
+        /// Whether the series is still running
+        ongoing: bool,
+    },
+    /// Episode list of a series or season
+    Episodes {
+        /// Whether the series is still running
+        ongoing: bool,
+    },
+}
+
+impl EntityKind {
+    /// Kind of a season's episode list from the season's year or years
+    ///
+    /// A season whose last year has passed can't gain episodes, so it counts
+    /// as ended; an unknown year counts as ongoing.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::freshness::EntityKind;
+    ///
+    /// assert_eq!(EntityKind::season_episodes(Some("2008")), EntityKind::Episodes { ongoing: false });
+    /// assert_eq!(EntityKind::season_episodes(None), EntityKind::Episodes { ongoing: true });
+    /// ```
+    pub fn season_episodes(year: Option<&str>) -> Self {
+        let ended = year
+            .and_then(crate::text::year_span)
+            .and_then(|(_, end)| end)
+            .is_some_and(|end| end < current_year());
+        Self::Episodes { ongoing: !ended }
+    }
+
+    /// Kind of a series detail, see `SeriesDetail::is_ongoing`
+    pub fn series(detail: &SeriesDetail) -> Self {
+        Self::Series {
+            ongoing: detail.is_ongoing(),
+        }
+    }
+
+    /// Kind of the episode list of a series, see `SeriesDetail::is_ongoing`
+    pub fn series_episodes(detail: &SeriesDetail) -> Self {
+        Self::Episodes {
+            ongoing: detail.is_ongoing(),
+        }
+    }
+}
+
+/// Current year in UTC, give or take a day around New Year
+pub(crate) fn current_year() -> u16 {
+    const YEAR_SECS: u64 = 31_556_952; // 365.2425 days
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    (1970 + secs / YEAR_SECS) as u16
+}
+
+/// Maximum age of fetched data per entity kind
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use csfd_core::freshness::{EntityKind, FreshnessPolicy};
+///
+/// let policy = FreshnessPolicy {
+///     search: Duration::from_secs(10 * 60),
+///     ..FreshnessPolicy::default()
+/// };
+/// assert!(!policy.is_fresh(EntityKind::Search, Duration::from_secs(11 * 60)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FreshnessPolicy {
+    /// Search results (default: 1 hour)
+    pub search: Duration,
+    /// Detail of a running series (default: 1 day)
+    pub ongoing_series: Duration,
+    /// Detail of an ended series (default: 30 days)
+    pub ended_series: Duration,
+    /// Episodes of a running series (default: 6 hours)
+    pub ongoing_episodes: Duration,
+    /// Episodes of an ended series (default: 30 days)
+    pub ended_episodes: Duration,
+}
+
+impl Default for FreshnessPolicy {
+    fn default() -> Self {
+        Self {
+            search: HOUR,
+            ongoing_series: DAY,
+            ended_series: 30 * DAY,
+            ongoing_episodes: 6 * HOUR,
+            ended_episodes: 30 * DAY,
+        }
+    }
+}
+
+impl FreshnessPolicy {
+    /// Get the maximum age of data of the given kind
+    pub fn max_age(&self, kind: EntityKind) -> Duration {
+        match kind {
+            EntityKind::Search => self.search,
+            EntityKind::Series { ongoing: true } => self.ongoing_series,
+            EntityKind::Series { ongoing: false } => self.ended_series,
+            EntityKind::Episodes { ongoing: true } => self.ongoing_episodes,
+            EntityKind::Episodes { ongoing: false } => self.ended_episodes,
+        }
+    }
+
+    /// Check whether data of the given kind and age can still be served
+    pub fn is_fresh(&self, kind: EntityKind, age: Duration) -> bool {
+        age < self.max_age(kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy() {
+        let policy = FreshnessPolicy::default();
+        assert_eq!(policy.max_age(EntityKind::Search), HOUR);
+        assert_eq!(
+            policy.max_age(EntityKind::Series { ongoing: false }),
+            30 * DAY
+        );
+        assert_eq!(policy.max_age(EntityKind::Series { ongoing: true }), DAY);
+        assert_eq!(
+            policy.max_age(EntityKind::Episodes { ongoing: true }),
+            6 * HOUR
+        );
+        assert_eq!(
+            policy.max_age(EntityKind::Episodes { ongoing: false }),
+            30 * DAY
+        );
+    }
+
+    #[test]
+    fn test_season_episodes_kind() {
+        let this_year = current_year().to_string();
+        let running = format!("{}–", current_year() - 3);
+        let ongoing = EntityKind::Episodes { ongoing: true };
+        assert_eq!(EntityKind::season_episodes(Some(&this_year)), ongoing);
+        assert_eq!(EntityKind::season_episodes(Some(&running)), ongoing);
+        assert_eq!(
+            EntityKind::season_episodes(Some("2004-2012")),
+            EntityKind::Episodes { ongoing: false }
+        );
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let policy = FreshnessPolicy::default();
+        let ongoing = EntityKind::Episodes { ongoing: true };
+        let ended = EntityKind::Episodes { ongoing: false };
+
+        assert!(policy.is_fresh(ongoing, 5 * HOUR));
+        assert!(!policy.is_fresh(ongoing, 6 * HOUR));
+        assert!(policy.is_fresh(ended, 6 * HOUR));
+    }
+
+    #[test]
+    fn test_policy_override() {
+        let policy = FreshnessPolicy {
+            search: Duration::ZERO,
+            ..FreshnessPolicy::default()
+        };
+        assert!(!policy.is_fresh(EntityKind::Search, Duration::ZERO));
+        assert_eq!(policy.ended_series, 30 * DAY);
+    }
+}
@@ -0,0 +1,98 @@
+//! Stable content hashing
+//!
+//! `std::hash::DefaultHasher` may change between Rust releases, so hashes
+//! persisted by the storage layer would go stale on a toolchain update.
+//! This is a plain FNV-1a over explicitly written fields instead.
+
+use crate::types::ContentHash;
+
+/// FNV-1a 64-bit offset basis
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Hasher over normalized entity fields
+pub(crate) struct ContentHasher(u64);
+
+impl ContentHasher {
+    pub(crate) fn new() -> Self {
+        Self(FNV_OFFSET)
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    /// Hash a number
+    pub(crate) fn u32(&mut self, value: u32) -> &mut Self {
+        self.write_bytes(&value.to_le_bytes());
+        self
+    }
+
+    /// Hash trimmed text, length-prefixed so adjacent fields can't run together
+    pub(crate) fn str(&mut self, value: &str) -> &mut Self {
+        let value = value.trim();
+        self.u32(value.len() as u32);
+        self.write_bytes(value.as_bytes());
+        self
+    }
+
+    /// Hash optional text, distinguishing `None` from an empty string
+    pub(crate) fn opt_str(&mut self, value: Option<&str>) -> &mut Self {
+        match value {
+            Some(value) => self.u32(1).str(value),
+            None => self.u32(0),
+        }
+    }
+
+    /// Hash an optional number
+    pub(crate) fn opt_u32(&mut self, value: Option<u32>) -> &mut Self {
+        match value {
+            Some(value) => self.u32(1).u32(value),
+            None => self.u32(0),
+        }
+    }
+
+    /// Hash an optional percentage, rounded to one decimal place
+    pub(crate) fn opt_percent(&mut self, value: Option<f32>) -> &mut Self {
+        self.opt_u32(value.map(|v| (v * 10.0).round() as u32))
+    }
+
+    pub(crate) fn finish(&self) -> ContentHash {
+        ContentHash(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_hasher_is_stable() {
+        // Empty input is the FNV offset basis; pinned so the algorithm can't drift
+        assert_eq!(ContentHasher::new().finish(), ContentHash(FNV_OFFSET));
+        assert_eq!(
+            ContentHasher::new().str("a").finish().to_string(),
+            "d80d0daea7dbdd7f"
+        );
+    }
+
+    #[test]
+    fn test_content_hasher_separates_fields() {
+        let ab = ContentHasher::new().str("ab").str("").finish();
+        let a_b = ContentHasher::new().str("a").str("b").finish();
+        assert_ne!(ab, a_b);
+        assert_ne!(
+            ContentHasher::new().opt_str(None).finish(),
+            ContentHasher::new().opt_str(Some("")).finish()
+        );
+        assert_eq!(
+            ContentHasher::new().str(" Pilot ").finish(),
+            ContentHasher::new().str("Pilot").finish()
+        );
+    }
+}
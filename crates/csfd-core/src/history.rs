@@ -0,0 +1,520 @@
+//! Search history and type-ahead suggestions
+//!
+//! Records past search queries and the results the user picked, persisted
+//! in the local store. Repeated queries and choices are merged into a single
+//! entry with a use count, so history never fills up with duplicates.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::store::LocalStore;
+use crate::text::normalize;
+use crate::types::SearchResult;
+
+/// Store key of the search history document
+const HISTORY_KEY: &str = "search_history";
+
+/// Maximum number of remembered queries
+const MAX_QUERIES: usize = 200;
+
+/// Maximum number of remembered chosen results
+const MAX_CHOICES: usize = 200;
+
+/// Age after which an entry's weight is halved (30 days, in seconds)
+const HALF_LIFE_SECS: f64 = 30.0 * 24.0 * 3600.0;
+
+/// A previously searched query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryEntry {
+    /// Query as typed the last time
+    pub query: String,
+    /// Number of times the query was searched
+    pub count: u32,
+    /// Unix timestamp (seconds) of the last search
+    pub last_used: u64,
+}
+
+/// A search result the user opened
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChoiceEntry {
+    /// The chosen result
+    pub result: SearchResult,
+    /// Query that led to the result the last time
+    pub query: String,
+    /// Number of times the result was chosen
+    pub count: u32,
+    /// Unix timestamp (seconds) of the last choice
+    pub last_used: u64,
+}
+
+/// Persisted history document
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct HistoryData {
+    queries: Vec<QueryEntry>,
+    choices: Vec<ChoiceEntry>,
+}
+
+/// Kind of a type-ahead suggestion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SuggestionKind {
+    /// A previously searched query
+    Query,
+    /// A previously chosen title
+    Title,
+}
+
+/// Type-ahead suggestion built from search history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suggestion {
+    /// Kind of the suggestion
+    pub kind: SuggestionKind,
+    /// Text to display (query or title name)
+    pub text: String,
+    /// ČSFD ID for title suggestions
+    pub csfd_id: Option<u32>,
+    /// Ranking score (higher is better)
+    pub score: f32,
+}
+
+/// Search history backed by the local store
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::history::SearchHistory;
+/// use csfd_core::store::LocalStore;
+///
+/// # fn example() -> Result<(), csfd_core::CsfdError> {
+/// let mut history = SearchHistory::open(LocalStore::open("/tmp/csfd-data")?)?;
+/// history.record_query("Perníkový táta")?;
+///
+/// for suggestion in history.suggest("pern", 5) {
+///     println!("{:?}: {}", suggestion.kind, suggestion.text);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct SearchHistory {
+    data: HistoryData,
+    store: Option<LocalStore>,
+}
+
+impl SearchHistory {
+    /// Create an in-memory history that is not persisted
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the history persisted in the given store
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the stored history cannot be read
+    pub fn open(store: LocalStore) -> Result<Self> {
+        let data = store.load(HISTORY_KEY)?;
+        Ok(Self {
+            data,
+            store: Some(store),
+        })
+    }
+
+    /// Get remembered queries
+    pub fn queries(&self) -> &[QueryEntry] {
+        &self.data.queries
+    }
+
+    /// Get remembered chosen results
+    pub fn choices(&self) -> &[ChoiceEntry] {
+        &self.data.choices
+    }
+
+    /// Record a searched query
+    ///
+    /// Queries differing only in case, diacritics or whitespace are merged.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the history cannot be persisted
+    pub fn record_query(&mut self, query: &str) -> Result<()> {
+        self.record_query_at(query, unix_now())
+    }
+
+    /// Record a result the user picked for a query
+    ///
+    /// Choices are merged by ČSFD ID.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the history cannot be persisted
+    pub fn record_choice(&mut self, query: &str, result: &SearchResult) -> Result<()> {
+        self.record_choice_at(query, result, unix_now())
+    }
+
+    /// Forget all queries and choices
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the history cannot be persisted
+    pub fn clear(&mut self) -> Result<()> {
+        self.data = HistoryData::default();
+        self.persist()
+    }
+
+    /// Suggest previous queries and titles matching a typed prefix.
+    ///
+    /// Matching ignores case and diacritics; titles match on any word of
+    /// their Czech or original name. Results are ranked by use count decayed
+    /// by age, and a query is dropped when a chosen title has the same text.
+    ///
+    /// # Arguments
+    /// * `prefix` - Text typed so far
+    /// * `limit` - Maximum number of suggestions
+    pub fn suggest(&self, prefix: &str, limit: usize) -> Vec<Suggestion> {
+        self.suggest_at(prefix, limit, unix_now())
+    }
+
+    /// Set `local_rank` on results the user opened before.
+    ///
+    /// Opened titles are ranked by frecency (use count decayed by age),
+    /// 1 being the most frecent; other results get `None`. ČSFD order is kept
+    /// unless `pin` is set, in which case locally ranked titles are moved to
+    /// the top in rank order, followed by the rest in ČSFD order.
+    ///
+    /// # Arguments
+    /// * `items` - Search results in ČSFD order
+    /// * `pin` - Whether to move locally ranked titles to the top
+    pub fn rank_results(&self, items: &mut [SearchResult], pin: bool) {
+        self.rank_results_at(items, pin, unix_now())
+    }
+
+    fn rank_results_at(&self, items: &mut [SearchResult], pin: bool, now: u64) {
+        let mut opened: Vec<(u32, f32)> = items
+            .iter()
+            .filter_map(|item| {
+                self.data
+                    .choices
+                    .iter()
+                    .find(|e| e.result.csfd_id == item.csfd_id)
+                    .map(|e| (item.csfd_id, weight(e.count, e.last_used, now)))
+            })
+            .collect();
+        opened.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        for item in items.iter_mut() {
+            item.local_rank = opened
+                .iter()
+                .position(|(id, _)| *id == item.csfd_id)
+                .map(|pos| pos as u32 + 1);
+        }
+
+        if pin {
+            // Stable sort keeps ČSFD order among unranked results
+            items.sort_by_key(|item| item.local_rank.unwrap_or(u32::MAX));
+        }
+    }
+
+    fn record_query_at(&mut self, query: &str, now: u64) -> Result<()> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(());
+        }
+
+        let key = normalize(query);
+        match self
+            .data
+            .queries
+            .iter_mut()
+            .find(|e| normalize(&e.query) == key)
+        {
+            Some(entry) => {
+                entry.query = query.to_string();
+                entry.count += 1;
+                entry.last_used = now;
+            }
+            None => self.data.queries.push(QueryEntry {
+                query: query.to_string(),
+                count: 1,
+                last_used: now,
+            }),
+        }
+
+        trim_oldest(&mut self.data.queries, MAX_QUERIES, |e| e.last_used);
+        self.persist()
+    }
+
+    fn record_choice_at(&mut self, query: &str, result: &SearchResult, now: u64) -> Result<()> {
+        let query = query.trim().to_string();
+        // The rank is derived from history, never stored in it
+        let result = SearchResult {
+            local_rank: None,
+            score: None,
+            ..result.clone()
+        };
+        match self
+            .data
+            .choices
+            .iter_mut()
+            .find(|e| e.result.csfd_id == result.csfd_id)
+        {
+            Some(entry) => {
+                entry.result = result;
+                entry.query = query;
+                entry.count += 1;
+                entry.last_used = now;
+            }
+            None => self.data.choices.push(ChoiceEntry {
+                result,
+                query,
+                count: 1,
+                last_used: now,
+            }),
+        }
+
+        trim_oldest(&mut self.data.choices, MAX_CHOICES, |e| e.last_used);
+        self.persist()
+    }
+
+    fn suggest_at(&self, prefix: &str, limit: usize, now: u64) -> Vec<Suggestion> {
+        let prefix = normalize(prefix);
+        if prefix.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut suggestions: Vec<Suggestion> = Vec::new();
+
+        for entry in &self.data.choices {
+            let names =
+                std::iter::once(&entry.result.name).chain(entry.result.original_name.as_ref());
+            if names
+                .map(|n| normalize(n))
+                .any(|n| matches_prefix(&n, &prefix))
+            {
+                suggestions.push(Suggestion {
+                    kind: SuggestionKind::Title,
+                    text: entry.result.name.clone(),
+                    csfd_id: Some(entry.result.csfd_id),
+                    score: weight(entry.count, entry.last_used, now),
+                });
+            }
+        }
+
+        for entry in &self.data.queries {
+            let key = normalize(&entry.query);
+            if !key.starts_with(&prefix) {
+                continue;
+            }
+            // A chosen title with the same text is the better suggestion
+            if suggestions.iter().any(|s| normalize(&s.text) == key) {
+                continue;
+            }
+            suggestions.push(Suggestion {
+                kind: SuggestionKind::Query,
+                text: entry.query.clone(),
+                csfd_id: None,
+                score: weight(entry.count, entry.last_used, now),
+            });
+        }
+
+        suggestions.sort_by(|a, b| b.score.total_cmp(&a.score));
+        suggestions.truncate(limit);
+        suggestions
+    }
+
+    /// Write the history to the store, if it has one
+    fn persist(&self) -> Result<()> {
+        match &self.store {
+            Some(store) => store.save(HISTORY_KEY, &self.data),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Check whether normalized text or any of its words starts with the prefix.
+pub(crate) fn matches_prefix(text: &str, prefix: &str) -> bool {
+    text.starts_with(prefix) || text.split(' ').any(|word| word.starts_with(prefix))
+}
+
+/// Use count decayed by age with a 30-day half-life.
+fn weight(count: u32, last_used: u64, now: u64) -> f32 {
+    let age = now.saturating_sub(last_used) as f64;
+    (count as f64 * 0.5f64.powf(age / HALF_LIFE_SECS)) as f32
+}
+
+/// Drop the least recently used entries beyond `max`.
+fn trim_oldest<T>(entries: &mut Vec<T>, max: usize, last_used: impl Fn(&T) -> u64) {
+    if entries.len() > max {
+        entries.sort_by_key(|e| std::cmp::Reverse(last_used(e)));
+        entries.truncate(max);
+    }
+}
+
+/// Current Unix time in seconds.
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SeriesType;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn result(name: &str, original: Option<&str>, csfd_id: u32) -> SearchResult {
+        SearchResult {
+            name: name.to_string(),
+            original_name: original.map(str::to_string),
+            year: None,
+            series_type: SeriesType::Series,
+            url: format!("/film/{}-test/", csfd_id),
+            csfd_id,
+            local_rank: None,
+            score: None,
+        }
+    }
+
+    #[test]
+    fn test_record_query_merges_duplicates() {
+        let mut history = SearchHistory::new();
+        history.record_query_at("Perníkový táta", NOW).unwrap();
+        history
+            .record_query_at("  pernikovy   TATA ", NOW + 10)
+            .unwrap();
+        history.record_query_at("   ", NOW + 20).unwrap();
+
+        assert_eq!(history.queries().len(), 1);
+        assert_eq!(history.queries()[0].count, 2);
+        assert_eq!(history.queries()[0].last_used, NOW + 10);
+    }
+
+    #[test]
+    fn test_record_choice_merges_by_id() {
+        let mut history = SearchHistory::new();
+        let bb = result("Perníkový táta", Some("Breaking Bad"), 264494);
+        history.record_choice_at("pernik", &bb, NOW).unwrap();
+        history.record_choice_at("breaking", &bb, NOW + 5).unwrap();
+
+        assert_eq!(history.choices().len(), 1);
+        assert_eq!(history.choices()[0].count, 2);
+        assert_eq!(history.choices()[0].query, "breaking");
+    }
+
+    #[test]
+    fn test_suggest_queries_and_titles() {
+        let mut history = SearchHistory::new();
+        history
+            .record_query_at("Teorie velkého třesku", NOW)
+            .unwrap();
+        history.record_query_at("Přátelé", NOW).unwrap();
+        history
+            .record_choice_at(
+                "big bang",
+                &result("Teorie velkého třesku", Some("The Big Bang Theory"), 234260),
+                NOW,
+            )
+            .unwrap();
+
+        // Query with the same text as the chosen title is suppressed
+        let suggestions = history.suggest_at("teorie", 10, NOW);
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].kind, SuggestionKind::Title);
+        assert_eq!(suggestions[0].csfd_id, Some(234260));
+
+        // Original name words match too
+        let suggestions = history.suggest_at("bang", 10, NOW);
+        assert_eq!(suggestions.len(), 1);
+
+        // Diacritics-insensitive query match
+        let suggestions = history.suggest_at("prat", 10, NOW);
+        assert_eq!(suggestions[0].text, "Přátelé");
+        assert_eq!(suggestions[0].kind, SuggestionKind::Query);
+
+        assert!(history.suggest_at("", 10, NOW).is_empty());
+        assert!(history.suggest_at("xyz", 10, NOW).is_empty());
+    }
+
+    #[test]
+    fn test_suggest_ranking_and_limit() {
+        let mut history = SearchHistory::new();
+        history.record_query_at("Dr. House", NOW).unwrap();
+        history.record_query_at("Dexter", NOW).unwrap();
+        history.record_query_at("Dexter", NOW).unwrap();
+        history
+            .record_query_at("Doctor Who", NOW - 90 * 24 * 3600)
+            .unwrap();
+        history
+            .record_query_at("Doctor Who", NOW - 90 * 24 * 3600)
+            .unwrap();
+        history
+            .record_query_at("Doctor Who", NOW - 90 * 24 * 3600)
+            .unwrap();
+
+        let texts: Vec<String> = history
+            .suggest_at("d", 10, NOW)
+            .into_iter()
+            .map(|s| s.text)
+            .collect();
+        assert_eq!(texts, vec!["Dexter", "Dr. House", "Doctor Who"]);
+
+        assert_eq!(history.suggest_at("d", 2, NOW).len(), 2);
+    }
+
+    #[test]
+    fn test_rank_results() {
+        let mut history = SearchHistory::new();
+        let house = result("Dr. House", None, 1);
+        let dexter = result("Dexter", None, 2);
+        history.record_choice_at("d", &house, NOW).unwrap();
+        history.record_choice_at("d", &dexter, NOW).unwrap();
+        history.record_choice_at("d", &dexter, NOW).unwrap();
+
+        let mut items = vec![result("Dallas", None, 3), house.clone(), dexter.clone()];
+        history.rank_results_at(&mut items, false, NOW);
+        let ranks: Vec<(u32, Option<u32>)> =
+            items.iter().map(|r| (r.csfd_id, r.local_rank)).collect();
+        assert_eq!(ranks, vec![(3, None), (1, Some(2)), (2, Some(1))]);
+
+        history.rank_results_at(&mut items, true, NOW);
+        let order: Vec<u32> = items.iter().map(|r| r.csfd_id).collect();
+        assert_eq!(order, vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn test_record_choice_drops_local_rank() {
+        let mut history = SearchHistory::new();
+        let mut ranked = result("Dexter", None, 2);
+        ranked.local_rank = Some(1);
+        history.record_choice_at("dexter", &ranked, NOW).unwrap();
+        assert_eq!(history.choices()[0].result.local_rank, None);
+    }
+
+    #[test]
+    fn test_history_trims_oldest() {
+        let mut history = SearchHistory::new();
+        for i in 0..(MAX_QUERIES as u64 + 5) {
+            history
+                .record_query_at(&format!("query {}", i), NOW + i)
+                .unwrap();
+        }
+        assert_eq!(history.queries().len(), MAX_QUERIES);
+        assert!(history.queries().iter().all(|e| e.last_used >= NOW + 5));
+    }
+
+    #[test]
+    fn test_history_persists_in_store() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let mut history = SearchHistory::open(LocalStore::open(dir.path()).unwrap()).unwrap();
+            history.record_query("Simpsonovi").unwrap();
+        }
+
+        let mut history = SearchHistory::open(LocalStore::open(dir.path()).unwrap()).unwrap();
+        assert_eq!(history.queries().len(), 1);
+        assert_eq!(history.queries()[0].query, "Simpsonovi");
+
+        history.clear().unwrap();
+        let history = SearchHistory::open(LocalStore::open(dir.path()).unwrap()).unwrap();
+        assert!(history.queries().is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+//! Language of user-facing strings
+//!
+//! Errors shown in a UI and generated texts such as share text come from
+//! small Czech and English message catalogs. The language is chosen with
+//! `ClientConfig::locale`; `Display` of errors stays English for logs.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Language of user-facing strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Locale {
+    /// English
+    #[default]
+    En,
+    /// Czech
+    Cs,
+}
+
+impl Locale {
+    /// Get a locale from a language tag such as "cs", "cs-CZ" or "en_US".
+    ///
+    /// Slovak falls back to Czech; other languages aren't supported.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::i18n::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("cs-CZ"), Some(Locale::Cs));
+    /// assert_eq!(Locale::from_tag("EN"), Some(Locale::En));
+    /// assert_eq!(Locale::from_tag("de"), None);
+    /// ```
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_']).next()?.to_ascii_lowercase();
+        match language.as_str() {
+            "cs" | "sk" => Some(Self::Cs),
+            "en" => Some(Self::En),
+            _ => None,
+        }
+    }
+
+    /// Get the language tag ("en" or "cs")
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Cs => "cs",
+        }
+    }
+
+    /// Get the text of a message in this language
+    pub(crate) fn text(self, message: Message) -> &'static str {
+        match self {
+            Self::En => english(message),
+            Self::Cs => czech(message),
+        }
+    }
+}
+
+/// Keys of the message catalogs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Message {
+    #[cfg(feature = "client")]
+    HttpError,
+    #[cfg(feature = "client")]
+    HttpStatus,
+    ParseError,
+    ElementNotFound,
+    InvalidUrl,
+    RateLimited,
+    /// Precedes the wait of a rate limit error ("retry after 30 s")
+    RetryAfter,
+    NotFound,
+    InvalidId,
+    Cancelled,
+    RetryBudgetExhausted,
+    StorageError,
+    UnexpectedPage,
+    SessionExpired,
+    LoginFailed,
+    InvalidQuery,
+    Timeout,
+    /// Joins the share summary and the link ("94 % na ČSFD: <url>")
+    ShareOnCsfd,
+}
+
+/// English catalog, matching the `Display` of errors
+fn english(message: Message) -> &'static str {
+    match message {
+        #[cfg(feature = "client")]
+        Message::HttpError => "HTTP request failed",
+        #[cfg(feature = "client")]
+        Message::HttpStatus => "Unexpected HTTP status",
+        Message::ParseError => "Failed to parse HTML",
+        Message::ElementNotFound => "Element not found",
+        Message::InvalidUrl => "Invalid URL",
+        Message::RateLimited => "Rate limited - too many requests",
+        Message::RetryAfter => "retry after",
+        Message::NotFound => "Series not found",
+        Message::InvalidId => "Invalid CSFD ID",
+        Message::Cancelled => "Operation cancelled",
+        Message::RetryBudgetExhausted => "Retry budget exhausted - giving up on remaining requests",
+        Message::StorageError => "Storage error",
+        Message::UnexpectedPage => "Unexpected page",
+        Message::SessionExpired => "Session expired - please log in again",
+        Message::LoginFailed => "Login failed",
+        Message::InvalidQuery => "Invalid query",
+        Message::Timeout => "Operation timed out",
+        Message::ShareOnCsfd => "on ČSFD",
+    }
+}
+
+/// Czech catalog
+fn czech(message: Message) -> &'static str {
+    match message {
+        #[cfg(feature = "client")]
+        Message::HttpError => "Požadavek HTTP selhal",
+        #[cfg(feature = "client")]
+        Message::HttpStatus => "Neočekávaný stav HTTP",
+        Message::ParseError => "Stránku se nepodařilo zpracovat",
+        Message::ElementNotFound => "Na stránce chybí",
+        Message::InvalidUrl => "Neplatná adresa",
+        Message::RateLimited => "Příliš mnoho požadavků - ČSFD dočasně omezilo přístup",
+        Message::RetryAfter => "zkuste to znovu za",
+        Message::NotFound => "Titul nenalezen",
+        Message::InvalidId => "Neplatné ID ČSFD",
+        Message::Cancelled => "Operace byla zrušena",
+        Message::RetryBudgetExhausted => {
+            "Vyčerpán limit opakování - zbývající požadavky se neprovedou"
+        }
+        Message::StorageError => "Chyba úložiště",
+        Message::UnexpectedPage => "Neočekávaná stránka",
+        Message::SessionExpired => "Přihlášení vypršelo - přihlaste se znovu",
+        Message::LoginFailed => "Přihlášení se nezdařilo",
+        Message::InvalidQuery => "Neplatný dotaz",
+        Message::Timeout => "Vypršel časový limit operace",
+        Message::ShareOnCsfd => "na ČSFD",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locale_tag_round_trip() {
+        for locale in [Locale::En, Locale::Cs] {
+            assert_eq!(Locale::from_tag(locale.tag()), Some(locale));
+        }
+        assert_eq!(Locale::from_tag("sk_SK"), Some(Locale::Cs));
+        assert_eq!(Locale::from_tag(""), None);
+    }
+}
@@ -0,0 +1,720 @@
+//! Import of ratings from other services
+//!
+//! Reads ratings exported from a spreadsheet (CSV) or from Trakt (JSON),
+//! resolves every title to a ČSFD ID with `import_ratings` and rates the
+//! matches on ČSFD with `submit_ratings`. Both steps are resumable: outcomes
+//! are checkpointed in the local store, so an interrupted import of thousands
+//! of ratings continues where it stopped instead of searching or rating
+//! everything again.
+//!
+//! Submitting needs a session logged in with `CsfdScraper::login`. Ratings
+//! are sent one at a time, spaced by the client's rate limiter; in dry-run
+//! mode they are only recorded as planned actions and not checkpointed.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CsfdError, Result};
+use crate::session::ScrapeSession;
+use crate::store::LocalStore;
+use crate::text::{normalize, year_span};
+use crate::types::{SearchResult, SeriesType, UserStarRating};
+use crate::workflows::{pick_series, pick_title};
+
+/// Store key of the resolution checkpoint
+const CHECKPOINT_KEY: &str = "rating_import";
+
+/// Store key of the submission checkpoint
+const SUBMITTED_KEY: &str = "rating_import_submitted";
+
+/// Source of ratings to import, holding the exported file contents
+#[derive(Debug, Clone)]
+pub enum RatingSource {
+    /// CSV with a header row and `title`, optional `year` and `type`
+    /// ("movie" or "show"), and either `stars` (0-5) or `rating` (1-10) columns
+    Csv(String),
+    /// Trakt ratings export (`ratings-shows.json` or `ratings-movies.json`)
+    Trakt(String),
+}
+
+/// Kind of a rated title, as named by the source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TitleKind {
+    /// TV series or show
+    Series,
+    /// Film
+    Movie,
+}
+
+/// A single rating read from the source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RatingRecord {
+    /// Title as written in the source
+    pub title: String,
+    /// Release or premiere year, if known
+    pub year: Option<u16>,
+    /// Whether the title is a series or a film, if the source says
+    #[serde(default)]
+    pub kind: Option<TitleKind>,
+    /// Rating in ČSFD stars
+    pub stars: UserStarRating,
+}
+
+/// A rating matched to a ČSFD title
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedRating {
+    /// The source rating
+    pub record: RatingRecord,
+    /// ČSFD ID of the matched title
+    pub csfd_id: u32,
+    /// ČSFD name of the matched title
+    pub name: String,
+}
+
+/// Reconciliation report of an import
+#[derive(Debug, Default, Serialize)]
+pub struct ImportReport {
+    /// Ratings matched to a ČSFD title
+    pub resolved: Vec<ResolvedRating>,
+    /// Ratings for which no title with a matching year was found
+    pub unresolved: Vec<RatingRecord>,
+    /// Ratings whose lookup failed; they are retried on the next run
+    pub failed: Vec<(RatingRecord, CsfdError)>,
+}
+
+/// Outcome of submitting resolved ratings
+#[derive(Debug, Default, Serialize)]
+pub struct SubmitReport {
+    /// Ratings sent to ČSFD
+    pub submitted: Vec<ResolvedRating>,
+    /// Ratings sent with the same stars in an earlier run
+    pub skipped: Vec<ResolvedRating>,
+    /// Ratings only recorded as planned actions in dry-run mode
+    pub planned: Vec<ResolvedRating>,
+    /// Ratings whose submission failed; they are retried on the next run
+    pub failed: Vec<(ResolvedRating, CsfdError)>,
+}
+
+/// Checkpointed outcome of a resolved record: `Some((csfd_id, name))` or `None` if unmatched
+type Checkpoint = HashMap<String, Option<(u32, String)>>;
+
+/// Checkpointed stars of submitted ratings, keyed by ČSFD ID
+type Submitted = HashMap<u32, UserStarRating>;
+
+impl RatingSource {
+    /// Parse the source into rating records.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::ParseError` if the file is malformed
+    pub fn parse(&self) -> Result<Vec<RatingRecord>> {
+        match self {
+            RatingSource::Csv(contents) => parse_csv(contents),
+            RatingSource::Trakt(contents) => parse_trakt(contents),
+        }
+    }
+}
+
+/// Resolve ratings from a source to ČSFD titles.
+///
+/// Every title is searched on ČSFD and matched among results whose year fits
+/// the record: films named as such by the source with `pick_title`, other
+/// titles with `pick_series`. Lookups run within the session, so its
+/// cancellation and retry budget apply. With a store, outcomes are
+/// checkpointed after each record and reused on the next run.
+///
+/// # Arguments
+/// * `session` - Session to run the lookups in
+/// * `source` - Ratings to import
+/// * `store` - Optional store for resumable checkpoints
+///
+/// # Returns
+/// * `Ok(ImportReport)` reconciling resolved, unresolved and failed ratings
+/// * `Err(CsfdError::ParseError)` if the source is malformed
+/// * `Err(CsfdError::StorageError)` if the checkpoint cannot be read or written
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::import::{import_ratings, RatingSource};
+/// use csfd_core::{CsfdScraper, LocalStore};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let store = LocalStore::open("/tmp/csfd-data")?;
+/// let source = RatingSource::Csv("title,year,stars\nBreaking Bad,2008,5\n".to_string());
+///
+/// let report = import_ratings(&scraper.session(), &source, Some(&store)).await?;
+/// println!("{} resolved, {} unresolved", report.resolved.len(), report.unresolved.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn import_ratings(
+    session: &ScrapeSession<'_>,
+    source: &RatingSource,
+    store: Option<&LocalStore>,
+) -> Result<ImportReport> {
+    let records = source.parse()?;
+    let mut checkpoint: Checkpoint = match store {
+        Some(store) => store.load(CHECKPOINT_KEY)?,
+        None => HashMap::new(),
+    };
+
+    let mut report = ImportReport::default();
+    for record in records {
+        let key = checkpoint_key(&record);
+        let outcome = match checkpoint.get(&key) {
+            Some(outcome) => outcome.clone(),
+            None => {
+                let results = match session.run(session.scraper().search(&record.title)).await {
+                    Ok(results) => results,
+                    Err(error) => {
+                        report.failed.push((record, error));
+                        continue;
+                    }
+                };
+                let outcome =
+                    pick_match(&results.items, &record).map(|r| (r.csfd_id, r.name.clone()));
+
+                checkpoint.insert(key, outcome.clone());
+                if let Some(store) = store {
+                    store.save(CHECKPOINT_KEY, &checkpoint)?;
+                }
+                outcome
+            }
+        };
+
+        match outcome {
+            Some((csfd_id, name)) => report.resolved.push(ResolvedRating {
+                record,
+                csfd_id,
+                name,
+            }),
+            None => report.unresolved.push(record),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Rate resolved titles on ČSFD as the logged-in user.
+///
+/// Ratings are submitted one at a time within the session, so its
+/// cancellation and retry budget apply, and spaced by the client's rate
+/// limiter. With a store, each successful submission is checkpointed, and
+/// ratings already submitted with the same stars are skipped on the next run.
+/// In dry-run mode ratings are recorded as planned actions instead, and
+/// nothing is checkpointed.
+///
+/// # Arguments
+/// * `session` - Session of a logged-in scraper to submit in
+/// * `ratings` - Resolved ratings, e.g. `ImportReport::resolved`
+/// * `store` - Optional store for resumable checkpoints
+///
+/// # Returns
+/// * `Ok(SubmitReport)` reconciling submitted, skipped, planned and failed ratings
+/// * `Err(CsfdError::StorageError)` if the checkpoint cannot be read or written
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::import::{import_ratings, submit_ratings, RatingSource};
+/// use csfd_core::{CsfdScraper, LocalStore};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// scraper.login("nick", "heslo").await?;
+/// let store = LocalStore::open("/tmp/csfd-data")?;
+/// let source = RatingSource::Csv("title,year,stars\nBreaking Bad,2008,5\n".to_string());
+///
+/// let session = scraper.session();
+/// let report = import_ratings(&session, &source, Some(&store)).await?;
+/// let submitted = submit_ratings(&session, &report.resolved, Some(&store)).await?;
+/// println!("{} rated, {} failed", submitted.submitted.len(), submitted.failed.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn submit_ratings(
+    session: &ScrapeSession<'_>,
+    ratings: &[ResolvedRating],
+    store: Option<&LocalStore>,
+) -> Result<SubmitReport> {
+    let mut submitted: Submitted = match store {
+        Some(store) => store.load(SUBMITTED_KEY)?,
+        None => HashMap::new(),
+    };
+    let dry_run = session.scraper().client().is_dry_run();
+
+    let mut report = SubmitReport::default();
+    for rating in ratings {
+        let stars = rating.record.stars;
+        if submitted.get(&rating.csfd_id) == Some(&stars) {
+            report.skipped.push(rating.clone());
+            continue;
+        }
+
+        let submit = session.scraper().rate_title(rating.csfd_id, stars);
+        match session.run(submit).await {
+            Ok(()) if dry_run => report.planned.push(rating.clone()),
+            Ok(()) => {
+                submitted.insert(rating.csfd_id, stars);
+                if let Some(store) = store {
+                    store.save(SUBMITTED_KEY, &submitted)?;
+                }
+                report.submitted.push(rating.clone());
+            }
+            Err(error) => report.failed.push((rating.clone(), error)),
+        }
+    }
+
+    Ok(report)
+}
+
+/// Pick the search result matching a record, respecting its year and kind.
+fn pick_match<'a>(items: &'a [SearchResult], record: &RatingRecord) -> Option<&'a SearchResult> {
+    let candidates: Vec<SearchResult> = items
+        .iter()
+        .filter(|r| {
+            record
+                .year
+                .is_none_or(|y| year_matches(r.year.as_deref(), y))
+        })
+        .cloned()
+        .collect();
+    let picked = match record.kind {
+        Some(TitleKind::Movie) => pick_title(
+            &candidates,
+            &record.title,
+            &[SeriesType::Film, SeriesType::TvFilm],
+        ),
+        Some(TitleKind::Series) | None => pick_series(&candidates, &record.title),
+    }?;
+    items.iter().find(|r| r.csfd_id == picked.csfd_id)
+}
+
+/// Check whether a ČSFD year or year range ("2008", "2008-2013", "2008-") covers a year.
+fn year_matches(csfd_year: Option<&str>, year: u16) -> bool {
+    match csfd_year.and_then(year_span) {
+        Some((start, end)) => start <= year && end.is_none_or(|end| year <= end),
+        None => true,
+    }
+}
+
+/// Key identifying a record in the checkpoint.
+///
+/// Films get their own key, so a film and a series of the same name don't
+/// share a match.
+fn checkpoint_key(record: &RatingRecord) -> String {
+    let key = match record.year {
+        Some(year) => format!("{}|{}", normalize(&record.title), year),
+        None => normalize(&record.title),
+    };
+    match record.kind {
+        Some(TitleKind::Movie) => format!("{}|movie", key),
+        Some(TitleKind::Series) | None => key,
+    }
+}
+
+/// Parse the kind of a title as named in a CSV `type` column.
+fn parse_kind(value: &str) -> Option<TitleKind> {
+    match normalize(value).as_str() {
+        "movie" | "film" => Some(TitleKind::Movie),
+        "show" | "series" | "tv" | "serial" => Some(TitleKind::Series),
+        _ => None,
+    }
+}
+
+/// Parse a CSV ratings file.
+fn parse_csv(contents: &str) -> Result<Vec<RatingRecord>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(contents.as_bytes());
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| CsfdError::ParseError(format!("CSV header: {}", e)))?
+        .iter()
+        .map(|h| h.to_lowercase())
+        .collect();
+    let column = |name: &str| headers.iter().position(|h| h == name);
+
+    let title_col = column("title")
+        .ok_or_else(|| CsfdError::ParseError("CSV is missing a 'title' column".to_string()))?;
+    let year_col = column("year");
+    let type_col = column("type");
+    let stars_col = column("stars");
+    let rating_col = column("rating");
+    if stars_col.is_none() && rating_col.is_none() {
+        return Err(CsfdError::ParseError(
+            "CSV needs a 'stars' or 'rating' column".to_string(),
+        ));
+    }
+
+    let mut records = Vec::new();
+    for (line, row) in reader.records().enumerate() {
+        let row = row.map_err(|e| CsfdError::ParseError(format!("CSV row {}: {}", line + 2, e)))?;
+        let invalid =
+            |what: &str| CsfdError::ParseError(format!("CSV row {}: invalid {}", line + 2, what));
+
+        let title = row.get(title_col).unwrap_or_default().to_string();
+        if title.is_empty() {
+            continue;
+        }
+        let year = match year_col.and_then(|c| row.get(c)).filter(|v| !v.is_empty()) {
+            Some(value) => Some(value.parse().map_err(|_| invalid("year"))?),
+            None => None,
+        };
+        let kind = match type_col.and_then(|c| row.get(c)).filter(|v| !v.is_empty()) {
+            Some(value) => Some(parse_kind(value).ok_or_else(|| invalid("type"))?),
+            None => None,
+        };
+        let stars = match (stars_col, rating_col) {
+            (Some(c), _) => row
+                .get(c)
+                .and_then(|v| v.parse::<u8>().ok())
+                .and_then(UserStarRating::new)
+                .ok_or_else(|| invalid("stars"))?,
+            (None, Some(c)) => row
+                .get(c)
+                .and_then(|v| v.parse::<u8>().ok())
+                .filter(|r| (1..=10).contains(r))
+                .map(UserStarRating::from_ten_point)
+                .ok_or_else(|| invalid("rating"))?,
+            (None, None) => unreachable!("checked above"),
+        };
+
+        records.push(RatingRecord {
+            title,
+            year,
+            kind,
+            stars,
+        });
+    }
+
+    Ok(records)
+}
+
+/// Entry of a Trakt ratings export.
+#[derive(Deserialize)]
+struct TraktRating {
+    rating: u8,
+    show: Option<TraktTitle>,
+    movie: Option<TraktTitle>,
+}
+
+/// Show or movie of a Trakt ratings export entry.
+#[derive(Deserialize)]
+struct TraktTitle {
+    title: String,
+    year: Option<u16>,
+}
+
+/// Parse a Trakt ratings export.
+fn parse_trakt(contents: &str) -> Result<Vec<RatingRecord>> {
+    let entries: Vec<TraktRating> = serde_json::from_str(contents)
+        .map_err(|e| CsfdError::ParseError(format!("Trakt export: {}", e)))?;
+
+    Ok(entries
+        .into_iter()
+        .filter_map(|entry| {
+            let (title, kind) = match (entry.show, entry.movie) {
+                (Some(show), _) => (show, TitleKind::Series),
+                (None, Some(movie)) => (movie, TitleKind::Movie),
+                (None, None) => return None,
+            };
+            Some(RatingRecord {
+                title: title.title,
+                year: title.year,
+                kind: Some(kind),
+                stars: UserStarRating::from_ten_point(entry.rating),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use futures::future::BoxFuture;
+
+    use crate::client::{ClientConfig, CsfdClient};
+    use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+
+    fn result(name: &str, year: Option<&str>, csfd_id: u32) -> SearchResult {
+        typed_result(name, year, SeriesType::Series, csfd_id)
+    }
+
+    fn typed_result(
+        name: &str,
+        year: Option<&str>,
+        series_type: SeriesType,
+        csfd_id: u32,
+    ) -> SearchResult {
+        SearchResult {
+            name: name.to_string(),
+            original_name: None,
+            year: year.map(str::to_string),
+            series_type,
+            url: format!("/film/{}-test/", csfd_id),
+            csfd_id,
+            local_rank: None,
+            score: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_stars() {
+        let csv = "Title,Year,Stars\nPerníkový táta,2008,5\n\"Dr. House, M.D.\",,4\n";
+        let records = RatingSource::Csv(csv.to_string()).parse().unwrap();
+        assert_eq!(
+            records,
+            vec![
+                RatingRecord {
+                    title: "Perníkový táta".to_string(),
+                    year: Some(2008),
+                    kind: None,
+                    stars: UserStarRating::new(5).unwrap()
+                },
+                RatingRecord {
+                    title: "Dr. House, M.D.".to_string(),
+                    year: None,
+                    kind: None,
+                    stars: UserStarRating::new(4).unwrap()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_csv_ten_point_rating() {
+        let csv = "title,rating\nDexter,7\nLost,10\n";
+        let records = RatingSource::Csv(csv.to_string()).parse().unwrap();
+        assert_eq!(records[0].stars.stars(), 4);
+        assert_eq!(records[1].stars.stars(), 5);
+    }
+
+    #[test]
+    fn test_parse_csv_type() {
+        let csv = "title,type,stars\nFargo,movie,5\nFargo,show,4\nDexter,,3\n";
+        let records = RatingSource::Csv(csv.to_string()).parse().unwrap();
+        assert_eq!(records[0].kind, Some(TitleKind::Movie));
+        assert_eq!(records[1].kind, Some(TitleKind::Series));
+        assert_eq!(records[2].kind, None);
+
+        let unknown = RatingSource::Csv("title,type,stars\nFargo,podcast,5\n".to_string());
+        assert!(matches!(unknown.parse(), Err(CsfdError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_parse_csv_errors() {
+        let missing = RatingSource::Csv("name,stars\nDexter,4\n".to_string());
+        assert!(matches!(missing.parse(), Err(CsfdError::ParseError(_))));
+
+        let no_rating = RatingSource::Csv("title,year\nDexter,2006\n".to_string());
+        assert!(matches!(no_rating.parse(), Err(CsfdError::ParseError(_))));
+
+        let out_of_range = RatingSource::Csv("title,stars\nDexter,6\n".to_string());
+        match out_of_range.parse() {
+            Err(CsfdError::ParseError(msg)) => assert!(msg.contains("row 2")),
+            other => panic!("Expected ParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_trakt() {
+        let json = r#"[
+            {"rated_at": "2020-01-01T00:00:00.000Z", "rating": 9, "type": "show",
+             "show": {"title": "Breaking Bad", "year": 2008, "ids": {"trakt": 1388}}},
+            {"rated_at": "2020-01-02T00:00:00.000Z", "rating": 3, "type": "movie",
+             "movie": {"title": "Cars 2", "year": 2011, "ids": {"trakt": 1}}},
+            {"rated_at": "2020-01-03T00:00:00.000Z", "rating": 8, "type": "episode"}
+        ]"#;
+        let records = RatingSource::Trakt(json.to_string()).parse().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].title, "Breaking Bad");
+        assert_eq!(records[0].year, Some(2008));
+        assert_eq!(records[0].kind, Some(TitleKind::Series));
+        assert_eq!(records[0].stars.stars(), 5);
+        assert_eq!(records[1].kind, Some(TitleKind::Movie));
+        assert_eq!(records[1].stars.stars(), 2);
+
+        assert!(RatingSource::Trakt("{".to_string()).parse().is_err());
+    }
+
+    #[test]
+    fn test_year_matches() {
+        assert!(year_matches(Some("2008"), 2008));
+        assert!(!year_matches(Some("2008"), 2009));
+        assert!(year_matches(Some("2008-2013"), 2010));
+        assert!(!year_matches(Some("2008-2013"), 2014));
+        assert!(year_matches(Some("2019–"), 2022));
+        assert!(year_matches(None, 2008));
+    }
+
+    #[test]
+    fn test_pick_match_respects_year() {
+        let items = vec![
+            result("Battlestar Galactica", Some("1978-1979"), 1),
+            result("Battlestar Galactica", Some("2004-2009"), 2),
+        ];
+        let record = RatingRecord {
+            title: "Battlestar Galactica".to_string(),
+            year: Some(2004),
+            kind: None,
+            stars: UserStarRating::new(5).unwrap(),
+        };
+        assert_eq!(pick_match(&items, &record).unwrap().csfd_id, 2);
+
+        let unknown = RatingRecord {
+            year: Some(1990),
+            ..record
+        };
+        assert!(pick_match(&items, &unknown).is_none());
+    }
+
+    #[test]
+    fn test_pick_match_respects_kind() {
+        let items = vec![
+            typed_result("Fargo", Some("2014"), SeriesType::Series, 1),
+            typed_result("Fargo", Some("1996"), SeriesType::Film, 2),
+        ];
+        let movie = RatingRecord {
+            title: "Fargo".to_string(),
+            year: None,
+            kind: Some(TitleKind::Movie),
+            stars: UserStarRating::new(5).unwrap(),
+        };
+        assert_eq!(pick_match(&items, &movie).unwrap().csfd_id, 2);
+
+        let show = RatingRecord {
+            kind: Some(TitleKind::Series),
+            ..movie.clone()
+        };
+        assert_eq!(pick_match(&items, &show).unwrap().csfd_id, 1);
+        assert_ne!(checkpoint_key(&movie), checkpoint_key(&show));
+    }
+
+    #[test]
+    fn test_checkpoint_key() {
+        let record = RatingRecord {
+            title: "Perníkový Táta".to_string(),
+            year: Some(2008),
+            kind: None,
+            stars: UserStarRating::new(5).unwrap(),
+        };
+        assert_eq!(checkpoint_key(&record), "pernikovy tata|2008");
+    }
+
+    #[tokio::test]
+    async fn test_import_ratings_resumes_from_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        let mut checkpoint = Checkpoint::new();
+        checkpoint.insert(
+            "breaking bad|2008".to_string(),
+            Some((264494, "Perníkový táta".to_string())),
+        );
+        checkpoint.insert("unknown show".to_string(), None);
+        store.save(CHECKPOINT_KEY, &checkpoint).unwrap();
+
+        // Everything is checkpointed, so no request is made
+        let scraper = crate::CsfdScraper::new().unwrap();
+        let session = scraper.session();
+        let source = RatingSource::Csv(
+            "title,year,stars\nBreaking Bad,2008,5\nUnknown Show,,1\n".to_string(),
+        );
+        let report = import_ratings(&session, &source, Some(&store))
+            .await
+            .unwrap();
+
+        assert_eq!(report.resolved.len(), 1);
+        assert_eq!(report.resolved[0].csfd_id, 264494);
+        assert_eq!(report.unresolved.len(), 1);
+        assert!(report.failed.is_empty());
+        assert_eq!(session.metrics().started, 0);
+    }
+
+    /// Answers every request with a logged-in page and counts the requests
+    struct LoggedIn(AtomicUsize);
+
+    impl HttpTransport for LoggedIn {
+        fn send<'a>(&'a self, _request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                Ok(HttpResponse::html(
+                    r#"<a href="/odhlaseni/">Odhlásit</a><input type="hidden" name="_token" value="t">"#,
+                ))
+            })
+        }
+    }
+
+    fn resolved(csfd_id: u32, stars: u8) -> ResolvedRating {
+        ResolvedRating {
+            record: RatingRecord {
+                title: "Perníkový táta".to_string(),
+                year: Some(2008),
+                kind: Some(TitleKind::Series),
+                stars: UserStarRating::new(stars).unwrap(),
+            },
+            csfd_id,
+            name: "Perníkový táta".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_ratings_checkpoints_submissions() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        let transport = Arc::new(LoggedIn(AtomicUsize::new(0)));
+        let client = CsfdClient::with_transport(ClientConfig::default(), transport.clone());
+        let scraper = crate::CsfdScraper::with_client(client);
+        let session = scraper.session();
+
+        let report = submit_ratings(&session, &[resolved(264494, 5)], Some(&store))
+            .await
+            .unwrap();
+        assert_eq!(report.submitted.len(), 1);
+        let requests = transport.0.load(Ordering::SeqCst);
+        assert!(requests > 0);
+
+        // Unchanged ratings are skipped, a changed one is submitted again
+        let ratings = [resolved(264494, 5), resolved(264494, 4)];
+        let report = submit_ratings(&session, &ratings[..1], Some(&store))
+            .await
+            .unwrap();
+        assert_eq!(report.skipped.len(), 1);
+        assert_eq!(transport.0.load(Ordering::SeqCst), requests);
+        let report = submit_ratings(&session, &ratings[1..], Some(&store))
+            .await
+            .unwrap();
+        assert_eq!(report.submitted.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_submit_ratings_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        let config = ClientConfig {
+            dry_run: true,
+            ..ClientConfig::default()
+        };
+        let client = CsfdClient::with_transport(config, Arc::new(LoggedIn(AtomicUsize::new(0))));
+        let scraper = crate::CsfdScraper::with_client(client);
+
+        let report = submit_ratings(&scraper.session(), &[resolved(264494, 5)], Some(&store))
+            .await
+            .unwrap();
+        assert_eq!(report.planned.len(), 1);
+        assert!(report.submitted.is_empty());
+
+        let actions = scraper.client().take_planned_actions();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].target, "/film/264494/hodnotit/");
+        assert!(actions[0]
+            .params
+            .contains(&("rating".to_string(), "5".to_string())));
+        let submitted: Submitted = store.load(SUBMITTED_KEY).unwrap();
+        assert!(submitted.is_empty());
+    }
+}
@@ -0,0 +1,45 @@
+//! Interning of strings that repeat across titles
+//!
+//! Genres, countries and filmography roles come from a small vocabulary,
+//! so a library of hundreds of series would otherwise hold hundreds of
+//! copies of "Drama" or "USA". Interned strings share one allocation.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Most distinct strings kept in the pool
+///
+/// The vocabulary is a few hundred entries; unusual pages past this bound
+/// still get their strings, just not shared.
+const POOL_CAPACITY: usize = 4096;
+
+/// Shared copy of `value`, allocating it only the first time it is seen.
+pub(crate) fn intern(value: &str) -> Arc<str> {
+    static POOL: OnceLock<Mutex<HashSet<Arc<str>>>> = OnceLock::new();
+    let mut pool = POOL
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(shared) = pool.get(value) {
+        return Arc::clone(shared);
+    }
+
+    let shared: Arc<str> = Arc::from(value);
+    if pool.len() < POOL_CAPACITY {
+        pool.insert(Arc::clone(&shared));
+    }
+    shared
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern_shares_allocation() {
+        let first = intern("Drama");
+        let second = intern(&String::from("Drama"));
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(!Arc::ptr_eq(&first, &intern("Krimi")));
+    }
+}
@@ -0,0 +1,8 @@
+//! Matching ČSFD titles to other databases
+//!
+//! ČSFD pages carry no TMDB ID, so titles are matched by original name and
+//! year. Each submodule is behind its own feature:
+//! - `tmdb` - Candidate TMDB and IMDb IDs of a series
+
+#[cfg(feature = "tmdb")]
+pub mod tmdb;
@@ -0,0 +1,328 @@
+//! Cross-matching series with TMDB
+//!
+//! Searches The Movie Database by the original name and first year of a
+//! ČSFD series and scores each result, so a library can link titles to
+//! TMDB and IMDb without the user picking them by hand. Needs a TMDB API
+//! key (v3); requests go to TMDB directly, outside the rate limits of
+//! [`CsfdClient`](crate::client::CsfdClient).
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CsfdError, Result};
+use crate::text::{normalize, similarity, year_span};
+use crate::types::SeriesDetail;
+
+/// Base URL of the TMDB API
+pub const TMDB_API_URL: &str = "https://api.themoviedb.org/3";
+
+/// Candidates whose IMDb ID is looked up, best first
+const EXTERNAL_ID_LOOKUPS: usize = 3;
+
+/// Weight of the title in the confidence; the year makes up the rest
+const TITLE_WEIGHT: f32 = 0.7;
+
+/// TMDB series that may be the same title as a ČSFD series
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TmdbCandidate {
+    /// TMDB ID of the series
+    pub tmdb_id: u64,
+    /// IMDb ID (e.g., "tt0412142"), looked up for the best candidates only
+    pub imdb_id: Option<String>,
+    /// Name on TMDB
+    pub name: String,
+    /// Original name on TMDB
+    pub original_name: String,
+    /// Year of the first episode on TMDB
+    pub first_air_year: Option<u16>,
+    /// How likely this is the same series (0.0 - 1.0)
+    pub confidence: f32,
+}
+
+/// Client of the TMDB API
+#[derive(Debug, Clone)]
+pub struct TmdbClient {
+    http: reqwest::Client,
+    api_key: String,
+    base_url: String,
+}
+
+impl TmdbClient {
+    /// Create a client with a TMDB API key (v3).
+    ///
+    /// # Errors
+    /// `CsfdError::HttpError` if the HTTP client cannot be built
+    pub fn new(api_key: impl Into<String>) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(15))
+            .build()?;
+        Ok(Self {
+            http,
+            api_key: api_key.into(),
+            base_url: TMDB_API_URL.to_string(),
+        })
+    }
+
+    /// Send requests to another base URL, e.g. a proxy or a test server
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Find TMDB series matching a ČSFD series.
+    ///
+    /// Searches by the original name (or the Czech name when there is none)
+    /// and the first year; if nothing aired that year, searches again without
+    /// it, since ČSFD and TMDB sometimes disagree on premieres. IMDb IDs are
+    /// looked up for the few best candidates.
+    ///
+    /// # Arguments
+    /// * `detail` - The ČSFD series
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TmdbCandidate>)` sorted by confidence, best first; empty if
+    ///   TMDB knows no such series
+    ///
+    /// # Errors
+    /// * `CsfdError::RateLimited` if TMDB throttles the key
+    /// * `CsfdError::HttpError` if a request fails or the key is rejected
+    /// * `CsfdError::ParseError` if TMDB answers with unexpected JSON
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::interop::tmdb::TmdbClient;
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let detail = scraper.get_series(264494).await?;
+    /// let tmdb = TmdbClient::new("api-key")?;
+    /// if let Some(best) = tmdb.match_series(&detail).await?.first() {
+    ///     println!("TMDB {} ({:.0} %)", best.tmdb_id, best.confidence * 100.0);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn match_series(&self, detail: &SeriesDetail) -> Result<Vec<TmdbCandidate>> {
+        let query = detail.original_name.as_deref().unwrap_or(&detail.name);
+        let year = detail
+            .year_range
+            .as_deref()
+            .and_then(year_span)
+            .map(|(start, _)| start);
+
+        let mut results = self.search_tv(query, year).await?;
+        if results.is_empty() && year.is_some() {
+            results = self.search_tv(query, None).await?;
+        }
+
+        let mut candidates: Vec<TmdbCandidate> = results
+            .into_iter()
+            .map(|result| {
+                let first_air_year = result.first_air_date.get(..4).and_then(|y| y.parse().ok());
+                let confidence = confidence(
+                    detail,
+                    &result.name,
+                    &result.original_name,
+                    year,
+                    first_air_year,
+                );
+                TmdbCandidate {
+                    confidence,
+                    tmdb_id: result.id,
+                    imdb_id: None,
+                    name: result.name,
+                    original_name: result.original_name,
+                    first_air_year,
+                }
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+        for candidate in candidates.iter_mut().take(EXTERNAL_ID_LOOKUPS) {
+            let ids: ExternalIds = self
+                .get(&format!("/tv/{}/external_ids", candidate.tmdb_id), &[])
+                .await?;
+            candidate.imdb_id = ids.imdb_id.filter(|id| !id.is_empty());
+        }
+        Ok(candidates)
+    }
+
+    async fn search_tv(&self, query: &str, year: Option<u16>) -> Result<Vec<SearchResult>> {
+        let year = year.map(|year| year.to_string());
+        let mut params = vec![("query", query)];
+        if let Some(year) = &year {
+            params.push(("first_air_date_year", year));
+        }
+        let page: SearchPage = self.get("/search/tv", &params).await?;
+        Ok(page.results)
+    }
+
+    async fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T> {
+        let response = self
+            .http
+            .get(format!("{}{}", self.base_url, path))
+            .query(&[("api_key", self.api_key.as_str())])
+            .query(params)
+            .send()
+            .await?;
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok()?.parse().ok())
+                .map(Duration::from_secs);
+            return Err(CsfdError::RateLimited(retry_after));
+        }
+        let body = response.error_for_status()?.text().await?;
+        serde_json::from_str(&body)
+            .map_err(|e| CsfdError::ParseError(format!("TMDB {}: {}", path, e)))
+    }
+}
+
+/// Confidence that a TMDB series is the ČSFD series.
+///
+/// The better of the original and Czech name similarities, weighted with
+/// the year: same year counts fully, a year apart half. An unknown year on
+/// either side counts half, so it neither confirms nor rules out a match.
+fn confidence(
+    detail: &SeriesDetail,
+    name: &str,
+    original_name: &str,
+    year: Option<u16>,
+    first_air_year: Option<u16>,
+) -> f32 {
+    let (name, original_name) = (normalize(name), normalize(original_name));
+    let title = [detail.original_name.as_deref(), Some(detail.name.as_str())]
+        .into_iter()
+        .flatten()
+        .map(normalize)
+        .flat_map(|csfd| [similarity(&csfd, &original_name), similarity(&csfd, &name)])
+        .fold(0.0, f32::max);
+    let year = match (year, first_air_year) {
+        (Some(a), Some(b)) if a == b => 1.0,
+        (Some(a), Some(b)) if a.abs_diff(b) == 1 => 0.5,
+        (Some(_), Some(_)) => 0.0,
+        _ => 0.5,
+    };
+    TITLE_WEIGHT * title + (1.0 - TITLE_WEIGHT) * year
+}
+
+#[derive(Deserialize)]
+struct SearchPage {
+    results: Vec<SearchResult>,
+}
+
+#[derive(Deserialize)]
+struct SearchResult {
+    id: u64,
+    name: String,
+    original_name: String,
+    #[serde(default)]
+    first_air_date: String,
+}
+
+#[derive(Deserialize)]
+struct ExternalIds {
+    imdb_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn series() -> SeriesDetail {
+        SeriesDetail {
+            csfd_id: 264494,
+            name: "Dr. House".to_string(),
+            original_name: Some("House M.D.".to_string()),
+            year_range: Some("2004-2012".to_string()),
+            status_label: None,
+            genres: Vec::new(),
+            genre_ids: Vec::new(),
+            countries: Vec::new(),
+            seasons: Vec::new(),
+            directors: Vec::new(),
+            writers: Vec::new(),
+            cast: Vec::new(),
+            rating: None,
+            recommendations: Vec::new(),
+            availability: Default::default(),
+            imdb_id: None,
+        }
+    }
+
+    #[test]
+    fn test_confidence() {
+        let detail = series();
+        let exact = confidence(&detail, "House", "House M.D.", Some(2004), Some(2004));
+        let next_year = confidence(&detail, "House", "House M.D.", Some(2004), Some(2005));
+        let other = confidence(
+            &detail,
+            "Housewives",
+            "Desperate Housewives",
+            Some(2004),
+            Some(2004),
+        );
+        assert!((exact - 1.0).abs() < f32::EPSILON);
+        assert!(exact > next_year && next_year > other);
+    }
+
+    #[tokio::test]
+    async fn test_match_series() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/search/tv"))
+            .and(query_param("query", "House M.D."))
+            .and(query_param("first_air_date_year", "2004"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"results": [
+                    {"id": 9, "name": "House of Cards", "original_name": "House of Cards",
+                     "first_air_date": "2013-02-01"},
+                    {"id": 1408, "name": "House", "original_name": "House M.D.",
+                     "first_air_date": "2004-11-16"}
+                ]}"#,
+            ))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tv/1408/external_ids"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"imdb_id": "tt0412142"}"#))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tv/9/external_ids"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"imdb_id": null}"#))
+            .mount(&server)
+            .await;
+
+        let tmdb = TmdbClient::new("key").unwrap().with_base_url(server.uri());
+        let candidates = tmdb.match_series(&series()).await.unwrap();
+        assert_eq!(candidates.len(), 2);
+        assert_eq!(candidates[0].tmdb_id, 1408);
+        assert_eq!(candidates[0].imdb_id.as_deref(), Some("tt0412142"));
+        assert_eq!(candidates[0].first_air_year, Some(2004));
+        assert_eq!(candidates[1].imdb_id, None);
+        assert!(candidates[0].confidence > candidates[1].confidence);
+    }
+
+    #[tokio::test]
+    async fn test_match_series_rate_limited() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "10"))
+            .mount(&server)
+            .await;
+
+        let tmdb = TmdbClient::new("key").unwrap().with_base_url(server.uri());
+        let error = tmdb.match_series(&series()).await.unwrap_err();
+        assert!(matches!(error, CsfdError::RateLimited(Some(d)) if d == Duration::from_secs(10)));
+    }
+}
@@ -4,19 +4,183 @@
 //! (Česko-Slovenská filmová databáze).
 //!
 //! # Features
-//! - Search for TV series by name
+//! - Search for TV series by name, with relevance scores
 //! - Get series details including seasons
+//! - Get film details (runtime, rating, directors, cast)
 //! - Get episode lists with ratings
-//! - Rate-limited HTTP client to avoid server overload
+//! - Get episode details (plot, air date, runtime, directors, cast)
+//! - Get people with biography and filmography by role
+//! - Get user reviews of a title, page by page
+//! - Log in to a ČSFD account, logging in again when the session expires
+//! - Get the titles the logged-in user rated
+//! - Manage the logged-in user's "Chci vidět" watchlist
+//! - Get the most-visited films and series from the homepage
+//! - Get a digest of the homepage (news, trending, premieres, birthdays)
+//! - Rate-limited HTTP client to avoid server overload, allowing short bursts
+//!   and slowing down while ČSFD answers with 429 or 5xx
+//! - Pluggable HTTP transport, e.g. for testing against canned pages
+//! - Activity events (requests, retries, waits) for network activity panels
+//! - Local search history with type-ahead suggestions
+//! - Bundled seed catalog for instant local matches on first run
+//! - High-level workflows composing the calls above
+//! - Import of ratings exported from CSV files or Trakt
+//! - Resumable CSV datasets of series and episode ratings
+//! - Library of saved series for offline browsing and SQL analytics
+//! - Structured diffs between two snapshots of a series
+//! - Share text for copying a title to the clipboard
+//! - Kodi and Jellyfin NFO files of series and episodes
+//! - Matching series to TMDB and IMDb IDs
+//! - Czech or English messages for errors and texts shown to users
+//! - Diagnostics bundles with the redacted page for parser bug reports
+//!
+//! # API stability
+//! [`prelude`] collects the stable surface (scraper, data types, errors)
+//! that follows semver. Parsers change together with ČSFD markup and are
+//! only exposed through the `unstable` module, behind the `unstable`
+//! feature, without semver guarantees. The same goes for the internals of
+//! the HTTP client; its configuration and client types are re-exported from
+//! the crate root. [`parser_capabilities`] reports the
+//! parser version and which fields the parsers currently extract.
+//!
+//! # Cargo features
+//! - `client` (default) - HTTP client, scraper, sessions and workflows
+//! - `serde` (default) - Serde derives on data types, local store and search history
+//! - `unstable` - Parsers, e.g. for use with your own transport
+//! - `arrow` - Arrow record batches and Parquet files of episodes and search results
+//! - `sql` - Read-only SQL queries over the saved library (bundled SQLite)
+//! - `diagnostics` - Zipped diagnostics bundles for reporting parser bugs
+//! - `tmdb` - Candidate TMDB and IMDb IDs of series (needs an API key)
+//!
+//! Parsers and data types work without `client`, so
+//! `default-features = false, features = ["unstable"]` leaves out the HTTP stack.
+//!
+//! ```no_run
+//! use csfd_core::prelude::*;
+//!
+//! # #[cfg(feature = "client")]
+//! # async fn example() -> Result<()> {
+//! let scraper = CsfdScraper::new()?;
+//! let results = scraper.search("Dr. House").await?;
+//! # Ok(())
+//! # }
+//! ```
 
-pub mod client;
+#[cfg(feature = "client")]
+pub mod activity;
+#[cfg(feature = "client")]
+pub mod auth;
+#[cfg(feature = "client")]
+pub mod cache;
+#[cfg(feature = "client")]
+mod client;
+#[cfg(feature = "arrow")]
+pub mod columnar;
+#[cfg(feature = "client")]
+pub mod dataset;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+pub mod diff;
 pub mod error;
-pub mod parser;
+pub mod freshness;
+#[cfg(feature = "serde")]
+pub mod history;
+pub mod i18n;
+#[cfg(feature = "client")]
+pub mod import;
+#[cfg(feature = "tmdb")]
+pub mod interop;
+#[cfg(feature = "serde")]
+pub mod library;
+pub mod nfo;
+mod parser;
+pub mod prelude;
+#[cfg(feature = "sql")]
+pub mod query;
+pub mod redact;
+pub mod relevance;
+#[cfg(feature = "client")]
 pub mod scraper;
+#[cfg(feature = "serde")]
+pub mod seed;
+#[cfg(feature = "client")]
+pub mod session;
+pub mod share;
+#[cfg(feature = "serde")]
+pub mod store;
+#[cfg(feature = "client")]
+pub mod transport;
 pub mod types;
+#[cfg(feature = "client")]
+pub mod workflows;
+
+mod country;
+mod hash;
+mod intern;
+mod text;
+
+/// Base URL for ČSFD.cz
+pub(crate) const CSFD_BASE_URL: &str = "https://www.csfd.cz";
+
+/// Parsers without semver guarantees.
+///
+/// These follow ČSFD markup and may change in any release.
+#[cfg(feature = "unstable")]
+pub mod unstable {
+    /// HTML parsers for ČSFD pages
+    pub mod parser {
+        pub use crate::parser::*;
+    }
+
+    /// HTTP client internals, e.g. the rate limiter and retry budget
+    #[cfg(feature = "client")]
+    pub mod client {
+        pub use crate::client::*;
+    }
+}
 
 // Re-export main types for convenience
-pub use client::{ClientConfig, CsfdClient, RateLimiter};
-pub use error::{CsfdError, Result};
+#[cfg(feature = "client")]
+pub use activity::{ActivityEvent, ActivityLog};
+#[cfg(feature = "client")]
+pub use auth::PasswordLogin;
+#[cfg(feature = "client")]
+pub use client::{
+    ClientConfig, CsfdClient, FetchedImage, PlannedAction, Politeness, ProxyConfig, RateFeedback,
+    RateLimiter, Reauthenticate, RetryBudget,
+};
+pub use diff::{MetadataChange, RatingChange, SeriesDiff, WatchEvent};
+pub use error::{CsfdError, Result, RetryAdvice, RetrySuggestion};
+pub use freshness::{EntityKind, FreshnessPolicy};
+#[cfg(feature = "serde")]
+pub use history::{SearchHistory, Suggestion, SuggestionKind};
+pub use i18n::Locale;
+#[cfg(feature = "serde")]
+pub use library::{Library, LibraryEntry};
+pub use parser::capabilities::{
+    capabilities as parser_capabilities, Capabilities, ParserCapability, VERSION as PARSER_VERSION,
+};
+#[cfg(feature = "client")]
 pub use scraper::CsfdScraper;
-pub use types::{Episode, PaginatedResult, SearchResult, Season, SeriesDetail, SeriesType};
+#[cfg(feature = "serde")]
+pub use seed::SeedCatalog;
+#[cfg(feature = "client")]
+pub use session::{
+    BatchReport, CancelGuard, CancelRegistry, Lane, LaneUsage, ScheduleWindow, ScrapeSession,
+    ScraperMetrics, SessionMetrics,
+};
+pub use share::{format_share_text, format_share_text_in, Shareable};
+#[cfg(feature = "serde")]
+pub use store::LocalStore;
+#[cfg(feature = "client")]
+pub use tokio_util::sync::CancellationToken;
+#[cfg(feature = "client")]
+pub use transport::{HttpRequest, HttpResponse, HttpTransport, ReqwestTransport};
+pub use types::{
+    Availability, Birthday, CastMember, ContentHash, Country, CsfdLink, DateRange,
+    DetailAvailability, DiscoveredId, DiscoveryKind, Episode, EpisodeCode, EpisodeDetail,
+    EpisodeSort, EpisodesGrouped, FilmType, FilmographyEntry, FilmographyGroup, FullSeries,
+    HomepageDigest, Label, MovieDetail, NewsItem, OriginLine, PaginatedResult, Part, PersonDetail,
+    PersonRole, PremiereDay, PremiereMedium, Progress, RatingTier, Review, SearchResult, Season,
+    SeriesDetail, SeriesFilters, SeriesType, TrendingKind, UserList, UserListEntry, UserRating,
+    UserStarRating,
+};
@@ -0,0 +1,203 @@
+//! Saved series library
+//!
+//! Keeps series the user saved, with their episodes, in the local store so
+//! they can be browsed offline and analysed (see `query` with the `sql`
+//! feature). Saving a series again replaces the stored snapshot.
+
+use std::collections::BTreeMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::freshness::{EntityKind, FreshnessPolicy};
+use crate::store::LocalStore;
+use crate::types::{Episode, SeriesDetail};
+
+/// Store key of the library document
+const LIBRARY_KEY: &str = "library";
+
+/// A saved series with its episodes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LibraryEntry {
+    /// Detail of the series when it was saved
+    pub detail: SeriesDetail,
+    /// Episodes of all seasons
+    pub episodes: Vec<Episode>,
+    /// Unix timestamp (seconds) of the last save
+    pub saved_at: u64,
+}
+
+impl LibraryEntry {
+    /// Check whether the snapshot is due for a refresh
+    ///
+    /// Running series (see `SeriesDetail::is_ongoing`) are due after
+    /// `FreshnessPolicy::ongoing_series`, ended ones after `ended_series`.
+    pub fn is_due(&self, policy: &FreshnessPolicy) -> bool {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let age = Duration::from_secs(now.saturating_sub(self.saved_at));
+        !policy.is_fresh(EntityKind::series(&self.detail), age)
+    }
+}
+
+/// Series library backed by the local store
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::library::Library;
+/// use csfd_core::store::LocalStore;
+/// use csfd_core::{CsfdScraper, EpisodeSort};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let mut library = Library::open(LocalStore::open("/tmp/csfd-data")?)?;
+///
+/// let detail = scraper.get_series(71924).await?;
+/// let episodes = scraper.get_episodes(71924, EpisodeSort::Aired).await?;
+/// library.save_series(detail, episodes)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct Library {
+    entries: BTreeMap<u32, LibraryEntry>,
+    store: Option<LocalStore>,
+}
+
+impl Library {
+    /// Create an in-memory library that is not persisted
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the library persisted in the given store
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the stored library cannot be read
+    pub fn open(store: LocalStore) -> Result<Self> {
+        let entries = store.load(LIBRARY_KEY)?;
+        Ok(Self {
+            entries,
+            store: Some(store),
+        })
+    }
+
+    /// Get saved series, ordered by ČSFD ID
+    pub fn entries(&self) -> impl Iterator<Item = &LibraryEntry> {
+        self.entries.values()
+    }
+
+    /// Get a saved series
+    pub fn get(&self, csfd_id: u32) -> Option<&LibraryEntry> {
+        self.entries.get(&csfd_id)
+    }
+
+    /// Get the number of saved series
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether no series is saved
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Save a series and its episodes, replacing a previous snapshot
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the library cannot be saved
+    pub fn save_series(&mut self, detail: SeriesDetail, episodes: Vec<Episode>) -> Result<()> {
+        let saved_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        self.entries.insert(
+            detail.csfd_id,
+            LibraryEntry {
+                detail,
+                episodes,
+                saved_at,
+            },
+        );
+        self.persist()
+    }
+
+    /// Remove a saved series; removing a missing series is not an error
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the library cannot be saved
+    pub fn remove(&mut self, csfd_id: u32) -> Result<()> {
+        if self.entries.remove(&csfd_id).is_some() {
+            self.persist()?;
+        }
+        Ok(())
+    }
+
+    /// Write the library to the store, if it has one
+    fn persist(&self) -> Result<()> {
+        match &self.store {
+            Some(store) => store.save(LIBRARY_KEY, &self.entries),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(csfd_id: u32) -> SeriesDetail {
+        SeriesDetail {
+            csfd_id,
+            name: format!("Seriál {}", csfd_id),
+            original_name: None,
+            year_range: Some("2004-2012".to_string()),
+            genres: vec!["Drama".into()],
+            genre_ids: Vec::new(),
+            status_label: None,
+            countries: vec!["USA".into()],
+            seasons: Vec::new(),
+            directors: Vec::new(),
+            writers: Vec::new(),
+            cast: Vec::new(),
+            rating: Some(91.0),
+            recommendations: Vec::new(),
+            availability: Default::default(),
+            imdb_id: None,
+        }
+    }
+
+    #[test]
+    fn test_library_persists() {
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let mut library = Library::open(LocalStore::open(dir.path()).unwrap()).unwrap();
+            library.save_series(detail(71924), Vec::new()).unwrap();
+            library.save_series(detail(264494), Vec::new()).unwrap();
+            library.remove(264494).unwrap();
+        }
+
+        let library = Library::open(LocalStore::open(dir.path()).unwrap()).unwrap();
+        assert_eq!(library.len(), 1);
+        assert_eq!(library.get(71924).unwrap().detail.name, "Seriál 71924");
+        assert!(library.get(264494).is_none());
+    }
+
+    #[test]
+    fn test_library_entry_is_due() {
+        let mut library = Library::new();
+        library.save_series(detail(71924), Vec::new()).unwrap();
+        let mut entry = library.get(71924).unwrap().clone();
+        let policy = FreshnessPolicy::default();
+        assert!(!entry.is_due(&policy));
+
+        // Two days old: due while running, still fresh once ended
+        entry.saved_at -= 2 * 24 * 3600;
+        assert!(!entry.is_due(&policy));
+        entry.detail.year_range = Some("2004–".to_string());
+        assert!(entry.is_due(&policy));
+    }
+}
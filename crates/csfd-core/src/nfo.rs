@@ -0,0 +1,353 @@
+//! Kodi and Jellyfin NFO export
+//!
+//! Renders series and episodes as the `tvshow.nfo` and episode `.nfo` XML
+//! files that Kodi and Jellyfin read for local metadata, so a media library
+//! can be tagged from ČSFD without a scraper add-on. Ratings are converted
+//! to the 0-10 scale and ČSFD IDs are written as `csfd` unique IDs, next
+//! to the IMDb ID when the page links one.
+//!
+//! Kodi matches an episode NFO to the video file of the same name, e.g.
+//! `Dr. House S01E01.mkv` and `Dr. House S01E01.nfo`; `write_series_nfo`
+//! names episode files by their code, to be renamed or written next to the
+//! videos with `episode_nfo`.
+
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::{CsfdError, Result};
+use crate::types::{CastMember, Episode, EpisodeDetail, SeriesDetail};
+
+/// Name of the unique ID type and rating source written for ČSFD
+pub const NFO_SOURCE: &str = "csfd";
+
+/// Render the `tvshow.nfo` of a series.
+///
+/// # Arguments
+/// * `detail` - The series
+/// * `plot` - Plot summary, if known (series details don't carry one)
+///
+/// # Example
+/// ```no_run
+/// # #[cfg(feature = "client")]
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// use csfd_core::{nfo, CsfdScraper};
+///
+/// let scraper = CsfdScraper::new()?;
+/// let detail = scraper.get_series(264494).await?;
+/// println!("{}", nfo::tvshow_nfo(&detail, None));
+/// # Ok(())
+/// # }
+/// ```
+pub fn tvshow_nfo(detail: &SeriesDetail, plot: Option<&str>) -> String {
+    let mut xml = NfoWriter::new("tvshow");
+    xml.element("title", &detail.name);
+    if let Some(original_name) = &detail.original_name {
+        xml.element("originaltitle", original_name);
+    }
+    xml.rating(detail.rating);
+    if let Some(plot) = plot {
+        xml.element("plot", plot);
+    }
+    if let Some(year) = detail.year_range.as_deref().and_then(first_year) {
+        xml.element("year", year);
+    }
+    for genre in &detail.genres {
+        xml.element("genre", genre);
+    }
+    for country in &detail.countries {
+        xml.element("country", country);
+    }
+    for (name, _) in &detail.directors {
+        xml.element("director", name);
+    }
+    for (name, _) in &detail.writers {
+        xml.element("credits", name);
+    }
+    xml.unique_id(detail.csfd_id);
+    if let Some(imdb_id) = &detail.imdb_id {
+        let _ = writeln!(
+            xml.out,
+            "  <uniqueid type=\"imdb\">{}</uniqueid>",
+            escape(imdb_id)
+        );
+    }
+    xml.actors(&detail.cast);
+    for season in &detail.seasons {
+        if let Some(number) = season.number {
+            let _ = writeln!(
+                xml.out,
+                "  <namedseason number=\"{}\">{}</namedseason>",
+                number,
+                escape(&season.name)
+            );
+        }
+    }
+    xml.finish()
+}
+
+/// Render the NFO of an episode.
+///
+/// The episode detail adds the plot, air date, runtime and credits, which
+/// episode lists don't carry.
+///
+/// # Arguments
+/// * `episode` - The episode from an episode list
+/// * `detail` - Its detail, if fetched
+pub fn episode_nfo(episode: &Episode, detail: Option<&EpisodeDetail>) -> String {
+    let mut xml = NfoWriter::new("episodedetails");
+    xml.element("title", &episode.name);
+    xml.element("season", &episode.season_number.to_string());
+    xml.element("episode", &episode.episode_number.to_string());
+    xml.rating(
+        episode
+            .rating
+            .or_else(|| detail.and_then(|detail| detail.rating)),
+    );
+    if let Some(detail) = detail {
+        if let Some(plot) = &detail.plot {
+            xml.element("plot", plot);
+        }
+        if let Some(runtime) = detail.runtime_minutes {
+            xml.element("runtime", &runtime.to_string());
+        }
+        if let Some(air_date) = &detail.air_date {
+            xml.element("aired", air_date);
+        }
+        for (name, _) in &detail.directors {
+            xml.element("director", name);
+        }
+    }
+    xml.unique_id(episode.csfd_id);
+    if let Some(detail) = detail {
+        xml.actors(&detail.cast);
+    }
+    xml.finish()
+}
+
+/// Write `tvshow.nfo` and one NFO per episode into a directory.
+///
+/// Episode files are named by their code (`S01E01.nfo`). The directory is
+/// created if missing and existing files are overwritten, also for a
+/// scraper in dry-run mode, which only holds back writes to ČSFD.
+///
+/// # Arguments
+/// * `dir` - Directory of the series in the media library
+/// * `detail` - The series
+/// * `plot` - Plot summary of the series, if known
+/// * `episodes` - Episodes with their details, if fetched
+///
+/// # Returns
+/// * `Ok(Vec<PathBuf>)` with the written files, `tvshow.nfo` first
+/// * `Err(CsfdError::StorageError)` if a file cannot be written
+pub fn write_series_nfo(
+    dir: &Path,
+    detail: &SeriesDetail,
+    plot: Option<&str>,
+    episodes: &[(Episode, Option<EpisodeDetail>)],
+) -> Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir).map_err(|e| storage_error(dir, e))?;
+
+    let mut files = vec![(dir.join("tvshow.nfo"), tvshow_nfo(detail, plot))];
+    files.extend(episodes.iter().map(|(episode, detail)| {
+        (
+            dir.join(format!("{}.nfo", episode.code())),
+            episode_nfo(episode, detail.as_ref()),
+        )
+    }));
+
+    files
+        .into_iter()
+        .map(|(path, contents)| {
+            std::fs::write(&path, contents).map_err(|e| storage_error(&path, e))?;
+            Ok(path)
+        })
+        .collect()
+}
+
+/// Indented XML document with a single root element
+struct NfoWriter {
+    out: String,
+    root: &'static str,
+}
+
+impl NfoWriter {
+    fn new(root: &'static str) -> Self {
+        let mut out =
+            String::from("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\n");
+        let _ = writeln!(out, "<{}>", root);
+        Self { out, root }
+    }
+
+    fn element(&mut self, name: &str, value: &str) {
+        let _ = writeln!(self.out, "  <{}>{}</{}>", name, escape(value), name);
+    }
+
+    /// ČSFD rating as the default rating, on Kodi's 0-10 scale
+    fn rating(&mut self, rating: Option<f32>) {
+        let Some(rating) = rating else {
+            return;
+        };
+        let _ = writeln!(self.out, "  <ratings>");
+        let _ = writeln!(
+            self.out,
+            "    <rating name=\"{}\" max=\"10\" default=\"true\">",
+            NFO_SOURCE
+        );
+        let _ = writeln!(self.out, "      <value>{:.1}</value>", rating / 10.0);
+        let _ = writeln!(self.out, "    </rating>\n  </ratings>");
+    }
+
+    fn unique_id(&mut self, csfd_id: u32) {
+        let _ = writeln!(
+            self.out,
+            "  <uniqueid type=\"{}\" default=\"true\">{}</uniqueid>",
+            NFO_SOURCE, csfd_id
+        );
+    }
+
+    fn actors(&mut self, cast: &[CastMember]) {
+        for (order, member) in cast.iter().enumerate() {
+            let _ = writeln!(
+                self.out,
+                "  <actor>\n    <name>{}</name>",
+                escape(&member.name)
+            );
+            if let Some(character) = &member.character {
+                let _ = writeln!(self.out, "    <role>{}</role>", escape(character));
+            }
+            let _ = writeln!(self.out, "    <order>{}</order>\n  </actor>", order);
+        }
+    }
+
+    fn finish(mut self) -> String {
+        let _ = writeln!(self.out, "</{}>", self.root);
+        self.out
+    }
+}
+
+/// First year of a year range ("2008-2013" -> "2008")
+fn first_year(year_range: &str) -> Option<&str> {
+    let year = year_range.split(['-', '–']).next()?.trim();
+    (year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit())).then_some(year)
+}
+
+/// Escape text for XML element content and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn storage_error(path: &Path, error: std::io::Error) -> CsfdError {
+    CsfdError::StorageError(format!("{}: {}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Season;
+
+    fn series() -> SeriesDetail {
+        SeriesDetail {
+            csfd_id: 264494,
+            name: "Dr. House".to_string(),
+            original_name: Some("House M.D.".to_string()),
+            year_range: Some("2004-2012".to_string()),
+            status_label: None,
+            genres: vec!["Drama".into()],
+            genre_ids: Vec::new(),
+            countries: vec!["USA".into()],
+            seasons: vec![Season {
+                csfd_id: 1,
+                name: "Série 1".to_string(),
+                number: Some(1),
+                label: "1".to_string(),
+                year: Some("2004".to_string()),
+                episode_count: 22,
+                url: String::new(),
+                poster_url: None,
+                rating: None,
+            }],
+            directors: Vec::new(),
+            writers: vec![("David Shore".to_string(), 7)],
+            cast: vec![CastMember {
+                name: "Hugh Laurie".to_string(),
+                creator_id: 1,
+                character: Some("Gregory House".to_string()),
+            }],
+            rating: Some(89.4),
+            recommendations: Vec::new(),
+            availability: Default::default(),
+            imdb_id: Some("tt0412142".to_string()),
+        }
+    }
+
+    fn episode() -> Episode {
+        Episode {
+            csfd_id: 42,
+            name: "Pilot <1>".to_string(),
+            season_number: 1,
+            episode_number: 1,
+            rating: Some(80.0),
+            url: String::new(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        }
+    }
+
+    #[test]
+    fn test_tvshow_nfo() {
+        let xml = tvshow_nfo(&series(), Some("Lékař & cynik"));
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<originaltitle>House M.D.</originaltitle>"));
+        assert!(xml.contains("<value>8.9</value>"));
+        assert!(xml.contains("<plot>Lékař &amp; cynik</plot>"));
+        assert!(xml.contains("<year>2004</year>"));
+        assert!(xml.contains("<uniqueid type=\"csfd\" default=\"true\">264494</uniqueid>"));
+        assert!(xml.contains("<uniqueid type=\"imdb\">tt0412142</uniqueid>"));
+        assert!(xml.contains("<role>Gregory House</role>"));
+        assert!(xml.contains("<namedseason number=\"1\">Série 1</namedseason>"));
+        assert!(xml.trim_end().ends_with("</tvshow>"));
+    }
+
+    #[test]
+    fn test_episode_nfo() {
+        let xml = episode_nfo(&episode(), None);
+        assert!(xml.contains("<title>Pilot &lt;1&gt;</title>"));
+        assert!(xml.contains("<season>1</season>"));
+        assert!(xml.contains("<value>8.0</value>"));
+        assert!(!xml.contains("<plot>"));
+        assert!(xml.contains(">42</uniqueid>"));
+    }
+
+    #[test]
+    fn test_write_series_nfo() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = write_series_nfo(dir.path(), &series(), None, &[(episode(), None)]).unwrap();
+        assert_eq!(
+            files,
+            vec![dir.path().join("tvshow.nfo"), dir.path().join("S01E01.nfo")]
+        );
+        assert!(std::fs::read_to_string(&files[1])
+            .unwrap()
+            .contains("<episodedetails>"));
+    }
+
+    #[test]
+    fn test_first_year() {
+        assert_eq!(first_year("2004-2012"), Some("2004"));
+        assert_eq!(first_year("2004"), Some("2004"));
+        assert_eq!(first_year("n/a"), None);
+    }
+}
@@ -0,0 +1,173 @@
+//! Login state detection for ČSFD.cz
+//!
+//! ČSFD doesn't signal an expired session with a status code; it serves the
+//! logged-out variant of the requested page instead. The header of every
+//! page shows either the user menu with a logout link or a login link.
+//!
+//! Forms of logged-in users carry hidden fields (such as a CSRF token) that
+//! must be sent back with every write. The login form carries one too, and
+//! a rejected login comes back as the same form with an error message.
+
+use scraper::Html;
+
+/// Check whether a page was rendered for a logged-in user.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::is_logged_in;
+///
+/// assert!(is_logged_in(r#"<header><a href="/odhlaseni/?token=1">Odhlásit</a></header>"#));
+/// assert!(!is_logged_in(r#"<header><a href="/prihlaseni/">Přihlásit</a></header>"#));
+/// ```
+pub fn is_logged_in(html: &str) -> bool {
+    let document = Html::parse_document(html);
+    document
+        .select(selector!("a[href*='/odhlaseni/'], .user-logged"))
+        .next()
+        .is_some()
+}
+
+/// Read the profile path of the logged-in user from the page header.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::parse_profile_path;
+///
+/// let html = r#"<div class="user-logged"><a href="/uzivatel/42-nick/prehled/">nick</a></div>"#;
+/// assert_eq!(parse_profile_path(html).as_deref(), Some("/uzivatel/42-nick/"));
+/// ```
+pub fn parse_profile_path(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    document
+        .select(selector!(
+            ".user-logged a[href*='/uzivatel/'], .user-menu a[href*='/uzivatel/']"
+        ))
+        .filter_map(|link| link.value().attr("href"))
+        .find_map(|href| regex!(r"/uzivatel/\d+-[^/?#]+/").find(href))
+        .map(|path| path.as_str().to_string())
+}
+
+/// Read the hidden fields of the first form on a page.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::parse_hidden_inputs;
+///
+/// let html = r#"<form><input type="hidden" name="_token_" value="abc"><input name="name"></form>"#;
+/// assert_eq!(parse_hidden_inputs(html), vec![("_token_".to_string(), "abc".to_string())]);
+/// ```
+pub fn parse_hidden_inputs(html: &str) -> Vec<(String, String)> {
+    let document = Html::parse_document(html);
+    let Some(form) = document.select(selector!("form")).next() else {
+        return Vec::new();
+    };
+    form.select(selector!("input[type='hidden'][name]"))
+        .filter_map(|input| {
+            let name = input.value().attr("name")?;
+            let value = input.value().attr("value").unwrap_or_default();
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Read where the first form on a page is submitted, as a path on ČSFD.cz.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::parse_form_action;
+///
+/// let html = r#"<form action="https://www.csfd.cz/prihlaseni/?do=form-submit" method="post"></form>"#;
+/// assert_eq!(parse_form_action(html).as_deref(), Some("/prihlaseni/?do=form-submit"));
+/// ```
+pub fn parse_form_action(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let action = document
+        .select(selector!("form[action]"))
+        .next()?
+        .value()
+        .attr("action")?
+        .trim();
+    let path = action
+        .strip_prefix("https:")
+        .or_else(|| action.strip_prefix("http:"))
+        .unwrap_or(action);
+    let path = path
+        .strip_prefix("//www.csfd.cz")
+        .or_else(|| path.strip_prefix("//csfd.cz"))
+        .unwrap_or(path);
+    (path.starts_with('/') && !path.starts_with("//")).then(|| path.to_string())
+}
+
+/// Read the error message shown above a rejected form, e.g. a wrong password.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::parse_form_error;
+///
+/// let html = r#"<div class="flash-message error">Neplatné heslo.</div><form></form>"#;
+/// assert_eq!(parse_form_error(html).as_deref(), Some("Neplatné heslo."));
+/// ```
+pub fn parse_form_error(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    document
+        .select(selector!(
+            ".flash-message.error, .form-error, form ul.error li"
+        ))
+        .map(|element| element.text().collect::<String>().trim().to_string())
+        .find(|message| !message.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_logged_in_user_menu() {
+        let html = r#"
+            <html><body>
+                <header class="page-header">
+                    <div class="user-logged"><a href="/uzivatel/42-nick/">nick</a></div>
+                </header>
+            </body></html>
+        "#;
+        assert!(is_logged_in(html));
+        assert!(!is_logged_in(
+            "<html><body><h1>Breaking Bad</h1></body></html>"
+        ));
+        assert_eq!(
+            parse_profile_path(html).as_deref(),
+            Some("/uzivatel/42-nick/")
+        );
+    }
+
+    #[test]
+    fn test_parse_login_form() {
+        let html = r#"
+            <html><body>
+                <form action="/prihlaseni/?do=form-submit" method="post" id="frm-form">
+                    <ul class="error"><li>Uživatel s tímto jménem neexistuje.</li></ul>
+                    <input type="text" name="username">
+                    <input type="password" name="password">
+                    <input type="hidden" name="_token_" value="csrf123">
+                </form>
+            </body></html>
+        "#;
+        assert_eq!(
+            parse_form_action(html).as_deref(),
+            Some("/prihlaseni/?do=form-submit")
+        );
+        assert_eq!(
+            parse_hidden_inputs(html),
+            vec![("_token_".to_string(), "csrf123".to_string())]
+        );
+        assert_eq!(
+            parse_form_error(html).as_deref(),
+            Some("Uživatel s tímto jménem neexistuje.")
+        );
+        assert_eq!(
+            parse_form_action(r#"<form action="https://example.com/x"></form>"#),
+            None
+        );
+        assert_eq!(parse_form_error("<form></form>"), None);
+    }
+}
@@ -0,0 +1,226 @@
+//! Fields each parser can currently extract
+//!
+//! Maintained together with the parsers: when a ČSFD markup change breaks
+//! an extractor, its field is dropped here and `VERSION` is bumped, so UIs
+//! can hide the affected feature until the parser is fixed.
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// Version of the parsers, bumped whenever their output or capabilities change
+pub const VERSION: u32 = 13;
+
+/// Fields one parser extracts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ParserCapability {
+    /// Parser name (e.g., "episode_detail")
+    pub parser: &'static str,
+    /// Names of the fields it fills, as in the returned types
+    pub fields: &'static [&'static str],
+}
+
+impl ParserCapability {
+    /// Check whether the parser extracts a field
+    pub fn supports(&self, field: &str) -> bool {
+        self.fields.contains(&field)
+    }
+}
+
+/// Parser version with the fields of all parsers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Capabilities {
+    /// Parser version, see `VERSION`
+    pub version: u32,
+    /// Fields per parser
+    pub parsers: &'static [ParserCapability],
+}
+
+impl Capabilities {
+    /// Get the capability of a parser by name
+    pub fn parser(&self, name: &str) -> Option<&ParserCapability> {
+        self.parsers.iter().find(|p| p.parser == name)
+    }
+
+    /// Check whether a parser extracts a field; unknown parsers extract nothing
+    pub fn supports(&self, parser: &str, field: &str) -> bool {
+        self.parser(parser).is_some_and(|p| p.supports(field))
+    }
+}
+
+const SEARCH_FIELDS: &[&str] = &[
+    "csfd_id",
+    "name",
+    "original_name",
+    "year",
+    "series_type",
+    "url",
+];
+
+const PARSERS: &[ParserCapability] = &[
+    ParserCapability {
+        parser: "search",
+        fields: SEARCH_FIELDS,
+    },
+    ParserCapability {
+        parser: "chart",
+        fields: SEARCH_FIELDS,
+    },
+    ParserCapability {
+        parser: "series",
+        fields: &[
+            "csfd_id",
+            "name",
+            "original_name",
+            "year_range",
+            "status_label",
+            "genres",
+            "genre_ids",
+            "countries",
+            "seasons",
+            "season_ratings",
+            "directors",
+            "writers",
+            "cast",
+            "rating",
+            "recommendations",
+            "imdb_id",
+        ],
+    },
+    ParserCapability {
+        parser: "episodes",
+        fields: &[
+            "csfd_id",
+            "name",
+            "season_number",
+            "episode_number",
+            "rating",
+            "rating_count",
+            "comment_count",
+            "url",
+        ],
+    },
+    ParserCapability {
+        parser: "episode_detail",
+        fields: &[
+            "name",
+            "code",
+            "plot",
+            "air_date",
+            "runtime_minutes",
+            "rating",
+            "directors",
+            "cast",
+        ],
+    },
+    ParserCapability {
+        parser: "movie",
+        fields: &[
+            "name",
+            "original_name",
+            "year",
+            "genres",
+            "genre_ids",
+            "countries",
+            "runtime_minutes",
+            "rating",
+            "directors",
+            "cast",
+            "film_type",
+            "parts",
+            "imdb_id",
+        ],
+    },
+    ParserCapability {
+        parser: "lists",
+        fields: &[
+            "list_id",
+            "name",
+            "item_count",
+            "url",
+            "position",
+            "title",
+            "note",
+        ],
+    },
+    ParserCapability {
+        parser: "person",
+        fields: &[
+            "name",
+            "biography",
+            "birth_date",
+            "birth_place",
+            "photo_url",
+            "filmography",
+        ],
+    },
+    ParserCapability {
+        parser: "premieres",
+        fields: &["date", "titles"],
+    },
+    ParserCapability {
+        parser: "reviews",
+        fields: &["author", "rating", "date", "text"],
+    },
+    ParserCapability {
+        parser: "ratings",
+        fields: &["csfd_id", "name", "year", "url", "rating", "date"],
+    },
+    ParserCapability {
+        parser: "homepage",
+        fields: &[
+            "news",
+            "trending_movies",
+            "trending_series",
+            "premieres",
+            "birthdays",
+        ],
+    },
+    ParserCapability {
+        parser: "trending",
+        fields: SEARCH_FIELDS,
+    },
+    ParserCapability {
+        parser: "sitemap",
+        fields: &["sitemaps", "urls"],
+    },
+];
+
+/// Get the parser version and the fields each parser currently extracts.
+///
+/// # Example
+/// ```
+/// let capabilities = csfd_core::parser_capabilities();
+/// if capabilities.supports("episode_detail", "air_date") {
+///     // show air dates
+/// }
+/// ```
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        version: VERSION,
+        parsers: PARSERS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = capabilities();
+        assert_eq!(capabilities.version, VERSION);
+        assert!(capabilities.supports("episode_detail", "air_date"));
+        assert!(!capabilities.supports("episodes", "plot"));
+        assert!(capabilities.supports("person", "filmography"));
+        assert!(capabilities.supports("reviews", "text"));
+        assert!(!capabilities.supports("gallery", "url"));
+
+        // Parser names are unique
+        let mut names: Vec<_> = capabilities.parsers.iter().map(|p| p.parser).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), capabilities.parsers.len());
+    }
+}
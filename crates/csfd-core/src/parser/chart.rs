@@ -0,0 +1,60 @@
+//! Chart parser for ČSFD.cz
+//!
+//! Parses chart pages ("žebříčky"), whose entries use the same article
+//! markup as search results.
+
+use scraper::Html;
+
+use crate::types::SearchResult;
+
+use super::search::parse_search_item;
+
+/// Parse the entries of a chart page, in chart order.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the chart page
+///
+/// # Returns
+/// Chart entries; empty if none could be parsed
+pub fn parse_chart(html: &str) -> Vec<SearchResult> {
+    let document = Html::parse_document(html);
+    document
+        .select(selector!("article"))
+        .filter_map(|element| parse_search_item(&element))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chart() {
+        let html = r#"
+            <html><body>
+                <section class="box">
+                    <article class="article-poster-60">
+                        <h3 class="film-title-norating">
+                            <span class="film-title-user">1.</span>
+                            <a href="/film/264494-pernikovy-tata/" class="film-title-name">Perníkový táta</a>
+                            <span class="film-title-info"><span class="info">(2008)</span></span>
+                        </h3>
+                    </article>
+                    <article class="article-poster-60">
+                        <h3 class="film-title-norating">
+                            <a href="/film/71924-dr-house/" class="film-title-name">Dr. House</a>
+                            <span class="film-title-info"><span class="info">(2004)</span></span>
+                        </h3>
+                    </article>
+                    <article class="article-banner"><p>Reklama</p></article>
+                </section>
+            </body></html>
+        "#;
+        let entries = parse_chart(html);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "Perníkový táta");
+        assert_eq!(entries[0].csfd_id, 264494);
+        assert_eq!(entries[0].year.as_deref(), Some("2008"));
+        assert_eq!(entries[1].csfd_id, 71924);
+    }
+}
@@ -0,0 +1,167 @@
+//! Episode detail parser for ČSFD.cz
+//!
+//! Episode pages use the film page layout, so this reuses the film and
+//! series extractors and adds the plot, the episode code from the header
+//! ("Pilot (S01E01)") and the first air date from the premieres box.
+
+use scraper::Html;
+
+use crate::error::{CsfdError, Result};
+use crate::types::{EpisodeCode, EpisodeDetail};
+
+use super::episodes::{extract_episode_id, parse_episode_code, parse_rating};
+use super::origin::extract_origin_line;
+use super::premieres::parse_czech_date;
+use super::search::extract_csfd_id;
+use super::series::{extract_cast, extract_creator_group, extract_series_name, verify_title_name};
+use super::{canonical_url, element_text};
+
+/// Parse episode detail from a ČSFD episode page.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the episode page
+/// * `series_id` - The CSFD ID of the series (used in the result)
+/// * `episode_id` - The CSFD ID of the episode (used in the result)
+///
+/// # Returns
+/// * `Ok(EpisodeDetail)` with parsed episode information
+/// * `Err(CsfdError)` if parsing fails
+pub fn parse_episode_detail(html: &str, series_id: u32, episode_id: u32) -> Result<EpisodeDetail> {
+    let document = Html::parse_document(html);
+    parse_episode_document(&document, series_id, episode_id)
+}
+
+/// Parse episode detail, first checking that the page is the requested one.
+///
+/// # Returns
+/// * `Ok(EpisodeDetail)` with parsed episode information
+/// * `Err(CsfdError::UnexpectedPage)` if the page belongs to another title
+/// * `Err(CsfdError)` if parsing fails
+pub fn parse_episode_detail_verified(
+    html: &str,
+    series_id: u32,
+    episode_id: u32,
+) -> Result<EpisodeDetail> {
+    let document = Html::parse_document(html);
+    if let Some(url) = canonical_url(&document) {
+        let page_ids = (extract_csfd_id(url), extract_episode_id(url));
+        if let (Some(page_series), Some(page_episode)) = page_ids {
+            if (page_series, page_episode) != (series_id, episode_id) {
+                return Err(CsfdError::UnexpectedPage(format!(
+                    "requested episode {}/{}, page is {}/{}",
+                    series_id, episode_id, page_series, page_episode
+                )));
+            }
+        }
+    }
+    let detail = parse_episode_document(&document, series_id, episode_id)?;
+    verify_title_name(&detail.name, episode_id)?;
+    Ok(detail)
+}
+
+/// Parse episode detail from an already parsed document.
+fn parse_episode_document(
+    document: &Html,
+    series_id: u32,
+    episode_id: u32,
+) -> Result<EpisodeDetail> {
+    let header = extract_series_name(document)
+        .ok_or_else(|| CsfdError::ElementNotFound("episode name".to_string()))?;
+    let code = parse_episode_code(&header).map(|(season, episode)| EpisodeCode { season, episode });
+    let name = regex!(r"\s*\(\s*(?:S\d{1,2}E\d{1,4}|\d{1,2}x\d{1,4})\s*\)\s*$")
+        .replace(&header, "")
+        .into_owned();
+
+    let plot = document
+        .select(selector!(
+            ".plot-full p, .plot-full, .plot-preview p, .plot-preview"
+        ))
+        .map(|el| element_text(&el).into_owned())
+        .find(|text| !text.is_empty());
+
+    let air_date = document
+        .select(selector!(".box-premieres li, .film-premieres li"))
+        .find_map(|el| parse_czech_date(&element_text(&el)));
+
+    let runtime_minutes = extract_origin_line(document).and_then(|origin| origin.runtime_minutes);
+
+    let rating = document
+        .select(selector!(".film-rating-average"))
+        .next()
+        .and_then(|el| parse_rating(&element_text(&el)));
+
+    Ok(EpisodeDetail {
+        csfd_id: episode_id,
+        series_id,
+        name,
+        code,
+        plot,
+        air_date,
+        runtime_minutes,
+        rating,
+        directors: extract_creator_group(document, &["Režie"]),
+        cast: extract_cast(document),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPISODE_HTML: &str = r#"
+        <html>
+            <head><link rel="canonical" href="https://www.csfd.cz/film/71924-dr-house/233581-pilot/prehled/"></head>
+            <body>
+                <div class="film-header">
+                    <h1>Pilot (S01E01)</h1>
+                </div>
+                <div class="film-header-origin">
+                    <div class="origin"><a href="/zeme/usa/">USA</a>, <span>2004</span>, 44 min</div>
+                </div>
+                <div class="film-rating-average">88%</div>
+                <div class="plot-full"><p>Učitelka mateřské školy zkolabuje během vyučování.</p></div>
+                <div class="creators">
+                    <div><h4>Režie:</h4><a href="/tvurce/4560-bryan-singer/">Bryan Singer</a></div>
+                    <div><h4>Hrají:</h4>
+                        <a href="/tvurce/1345-hugh-laurie/">Hugh Laurie</a>,
+                        <a href="/tvurce/1346-lisa-edelstein/">Lisa Edelstein</a>
+                    </div>
+                </div>
+                <div class="box-premieres">
+                    <ul><li title="V TV">USA V TV 16.11.2004 Fox</li></ul>
+                </div>
+            </body>
+        </html>
+    "#;
+
+    #[test]
+    fn test_parse_episode_detail() {
+        let episode = parse_episode_detail_verified(EPISODE_HTML, 71924, 233581).unwrap();
+        assert_eq!(episode.name, "Pilot");
+        assert_eq!(
+            episode.code,
+            Some(EpisodeCode {
+                season: 1,
+                episode: 1
+            })
+        );
+        assert_eq!(
+            episode.plot.as_deref(),
+            Some("Učitelka mateřské školy zkolabuje během vyučování.")
+        );
+        assert_eq!(episode.air_date.as_deref(), Some("2004-11-16"));
+        assert_eq!(episode.runtime_minutes, Some(44));
+        assert_eq!(episode.rating, Some(88.0));
+        assert_eq!(episode.directors, vec![("Bryan Singer".to_string(), 4560)]);
+        assert_eq!(episode.cast.len(), 2);
+        assert_eq!(episode.cast[0].name, "Hugh Laurie");
+    }
+
+    #[test]
+    fn test_parse_episode_detail_wrong_page() {
+        assert!(matches!(
+            parse_episode_detail_verified(EPISODE_HTML, 71924, 1),
+            Err(CsfdError::UnexpectedPage(_))
+        ));
+    }
+}
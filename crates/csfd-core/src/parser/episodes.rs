@@ -7,10 +7,14 @@ use scraper::{Html, Selector};
 use crate::error::Result;
 use crate::types::Episode;
 
+use super::element_text;
 use super::search::extract_csfd_id;
 
 /// Parse episodes list from ČSFD episodes page HTML.
 ///
+/// Episodes are returned in listing order, with `list_position` set to
+/// their index in it, which may differ from the order of their codes.
+///
 /// # Arguments
 /// * `html` - Raw HTML content of the episodes page
 ///
@@ -19,22 +23,30 @@ use super::search::extract_csfd_id;
 /// * `Err(CsfdError)` if parsing fails
 pub fn parse_episodes(html: &str) -> Result<Vec<Episode>> {
     let document = Html::parse_document(html);
+    let mut episodes = collect_episodes(&document);
+    for (position, episode) in (0..).zip(episodes.iter_mut()) {
+        episode.list_position = position;
+    }
+    Ok(episodes)
+}
+
+/// Collect episodes from the first episode listing structure that matches.
+fn collect_episodes(document: &Html) -> Vec<Episode> {
     let mut episodes = Vec::new();
-    
+
     // Current ČSFD structure: episodes are in h3.film-title with a.film-title-name links
     // Similar to seasons structure
-    if let Ok(selector) = Selector::parse("h3.film-title") {
-        for h3 in document.select(&selector) {
-            if let Some(episode) = parse_episode_from_h3(&h3) {
-                episodes.push(episode);
-            }
+    for h3 in document.select(selector!("h3.film-title")) {
+        let position = u16::try_from(episodes.len() + 1).unwrap_or(u16::MAX);
+        if let Some(episode) = parse_episode_from_h3(&h3, position) {
+            episodes.push(episode);
         }
     }
-    
+
     if !episodes.is_empty() {
-        return Ok(episodes);
+        return episodes;
     }
-    
+
     // Fallback: try different selectors for episodes table/list
     let container_selectors = [
         ".film-episodes table tbody",
@@ -42,38 +54,38 @@ pub fn parse_episodes(html: &str) -> Result<Vec<Episode>> {
         "table.episodes tbody",
         ".box-content table tbody",
     ];
-    
+
     for container_sel in &container_selectors {
         if let Ok(container_selector) = Selector::parse(container_sel) {
             for container in document.select(&container_selector) {
                 if let Ok(row_selector) = Selector::parse("tr") {
                     let mut current_season: u8 = 1;
-                    
+
                     for row in container.select(&row_selector) {
                         // Check if this is a season header row
                         if let Some(season_num) = extract_season_header(&row) {
                             current_season = season_num;
                             continue;
                         }
-                        
+
                         // Try to parse as episode row
                         if let Some(episode) = parse_episode_row(&row, current_season) {
                             episodes.push(episode);
                         }
                     }
-                    
+
                     if !episodes.is_empty() {
-                        return Ok(episodes);
+                        return episodes;
                     }
                 }
             }
         }
     }
-    
+
     // Alternative: look for episode links directly
     if let Ok(selector) = Selector::parse(".episode-item, .film-episodes a[href*='/film/']") {
         let mut current_season: u8 = 1;
-        
+
         for el in document.select(&selector) {
             if let Some(episode) = parse_episode_element(&el, current_season) {
                 // Update season from episode code if available
@@ -82,58 +94,73 @@ pub fn parse_episodes(html: &str) -> Result<Vec<Episode>> {
             }
         }
     }
-    
-    Ok(episodes)
+
+    episodes
 }
 
 /// Parse episode from h3.film-title element (current ČSFD structure).
-fn parse_episode_from_h3(h3: &scraper::ElementRef) -> Option<Episode> {
+///
+/// Episodes without a code, as in year-labeled seasons of reality shows,
+/// are numbered from their name or else from their 1-based `position`.
+fn parse_episode_from_h3(h3: &scraper::ElementRef, position: u16) -> Option<Episode> {
     // Find the link inside h3
-    let link_selector = Selector::parse("a.film-title-name").ok()?;
-    let link = h3.select(&link_selector).next()?;
-    
+    let link = h3.select(selector!("a.film-title-name")).next()?;
+
     // Get URL
-    let url = link.value().attr("href")?.to_string();
-    
+    let url = link.value().attr("href")?;
+
     // Get name from link text
-    let name = link.text().collect::<String>().trim().to_string();
+    let name = element_text(&link);
     if name.is_empty() {
         return None;
     }
-    
+
     // Skip season links - they have names like "Série 1", "Série 2", etc.
     // and their URLs end with /prehled/ (not episode URLs which have episode codes)
-    if is_season_link(&name, &url) {
+    if is_season_link(&name, url) {
         return None;
     }
-    
+
     // Extract CSFD ID
-    let csfd_id = extract_episode_id(&url)?;
-    
+    let csfd_id = extract_episode_id(url)?;
+
     // Get info from span.film-title-info - contains episode code like "(S01E01)"
-    let info_selector = Selector::parse(".film-title-info").ok()?;
-    let info_text = h3.select(&info_selector)
+    let info_text = h3
+        .select(selector!(".film-title-info"))
         .next()
-        .map(|el| el.text().collect::<String>())
+        .map(|el| element_text(&el))
         .unwrap_or_default();
-    
+
     // Extract episode code from info
-    let (season_number, episode_number) = parse_episode_code(&info_text)
-        .unwrap_or((1, 1));
-    
-    let episode_code = format!("S{:02}E{:02}", season_number, episode_number);
-    
+    let (season_number, episode_number) = parse_episode_code(&info_text).unwrap_or_else(|| {
+        (
+            1,
+            extract_episode_number_from_name(&name).unwrap_or(position),
+        )
+    });
+
     // Rating is not typically shown in the episode list on ČSFD
     let rating = None;
-    
+
+    // Comment and rating counters sit next to the header in the enclosing article
+    let (comment_count, rating_count) = h3
+        .ancestors()
+        .filter_map(scraper::ElementRef::wrap)
+        .find(|el| el.value().name() == "article")
+        .map(|article| extract_counts(&element_text(&article)))
+        .unwrap_or_default();
+
     Some(Episode {
         csfd_id,
-        name,
-        episode_code,
+        name: name.into_owned(),
         season_number,
         episode_number,
         rating,
-        url,
+        url: url.to_string(),
+        comment_count,
+        rating_count,
+        absolute_number: None,
+        list_position: 0,
     })
 }
 
@@ -141,39 +168,41 @@ fn parse_episode_from_h3(h3: &scraper::ElementRef) -> Option<Episode> {
 /// Season links have names like "Série 1" and URLs ending in /prehled/
 fn is_season_link(name: &str, url: &str) -> bool {
     // Season names match pattern "Série X" or "Season X"
-    let season_pattern = regex_lite::Regex::new(r"(?i)^(série|season|řada)\s*\d+$").unwrap();
+    let season_pattern = regex!(r"(?i)^(série|season|řada)\s*\d+$");
     if season_pattern.is_match(name.trim()) {
         return true;
     }
-    
+
     // Season URLs typically end with /{season_id}-serie-X/prehled/
     // Episode URLs have the episode slug and end with /prehled/ but contain episode info
     if url.contains("-serie-") && url.ends_with("/prehled/") {
         // Check if URL path has only series_id/season_id (no episode segment)
-        let parts: Vec<&str> = url.trim_matches('/').split('/').collect();
+        let mut parts = url.trim_matches('/').rsplit('/').skip(1);
         // Season URL: /film/{series_id}-slug/{season_id}-serie-X/prehled/
         // Episode URL: /film/{series_id}-slug/{episode_id}-episode-slug/prehled/
-        if parts.len() >= 3 {
-            let last_segment = parts[parts.len() - 2]; // segment before "prehled"
+        if let (Some(last_segment), Some(_)) = (parts.next(), parts.next()) {
+            // last_segment is the segment before "prehled"
             if last_segment.contains("-serie-") {
                 return true;
             }
         }
     }
-    
+
     false
 }
 
 /// Extract episode ID from URL (the episode part of the path).
-fn extract_episode_id(url: &str) -> Option<u32> {
+pub(super) fn extract_episode_id(url: &str) -> Option<u32> {
     // URL format: /film/{series_id}-{slug}/{episode_id}-{episode_slug}/prehled/
-    let parts: Vec<&str> = url.trim_matches('/').split('/').collect();
-    
+    let mut parts = url.trim_matches('/').split('/');
+
     // Find the film segment and look for the episode segment
-    for (i, part) in parts.iter().enumerate() {
-        if *part == "film" && i + 2 < parts.len() {
-            // The episode ID is in parts[i+2]
-            let episode_segment = parts[i + 2];
+    while let Some(part) = parts.next() {
+        if part == "film" {
+            // The episode ID is in the second segment after "film"
+            let Some(episode_segment) = parts.nth(1) else {
+                break;
+            };
             if let Some(id_str) = episode_segment.split('-').next() {
                 if let Ok(id) = id_str.parse::<u32>() {
                     if id > 0 {
@@ -183,32 +212,33 @@ fn extract_episode_id(url: &str) -> Option<u32> {
             }
         }
     }
-    
+
     None
 }
 
 /// Check if a row is a season header and extract season number.
 fn extract_season_header(row: &scraper::ElementRef) -> Option<u8> {
     // Season headers often have colspan or special class
-    if let Ok(selector) = Selector::parse("th[colspan], td.season-header, .season-title") {
-        if row.select(&selector).next().is_some() {
-            let text = row.text().collect::<String>();
-            return extract_season_number_from_text(&text);
-        }
+    if row
+        .select(selector!("th[colspan], td.season-header, .season-title"))
+        .next()
+        .is_some()
+    {
+        return extract_season_number_from_text(&element_text(row));
     }
-    
+
     // Check for "Série X" or "Season X" pattern in row text
-    let text = row.text().collect::<String>().to_lowercase();
+    let text = element_text(row).to_lowercase();
     if text.contains("série") || text.contains("season") || text.contains("řada") {
         return extract_season_number_from_text(&text);
     }
-    
+
     None
 }
 
 /// Extract season number from text like "Série 1" or "Season 2".
 fn extract_season_number_from_text(text: &str) -> Option<u8> {
-    let re = regex_lite::Regex::new(r"(?i)(?:série|season|řada|s)\s*(\d+)").ok()?;
+    let re = regex!(r"(?i)(?:série|season|řada|s)\s*(\d+)");
     if let Some(caps) = re.captures(text) {
         return caps.get(1)?.as_str().parse().ok();
     }
@@ -218,85 +248,92 @@ fn extract_season_number_from_text(text: &str) -> Option<u8> {
 /// Parse a single episode from a table row.
 fn parse_episode_row(row: &scraper::ElementRef, default_season: u8) -> Option<Episode> {
     // Find the episode link
-    let link_selector = Selector::parse("a[href*='/film/']").ok()?;
-    let link = row.select(&link_selector).next()?;
-    
+    let link = row.select(selector!("a[href*='/film/']")).next()?;
+
     // Get URL
-    let url = link.value().attr("href")?.to_string();
-    
+    let url = link.value().attr("href")?;
+
     // Extract CSFD ID
-    let csfd_id = extract_csfd_id(&url)?;
-    
+    let csfd_id = extract_csfd_id(url)?;
+
     // Get episode name
-    let name = link.text().collect::<String>().trim().to_string();
+    let name = element_text(&link);
     if name.is_empty() {
         return None;
     }
-    
+
     // Try to find episode code in the row
-    let row_text = row.text().collect::<String>();
+    let row_text = element_text(row);
     let (season_number, episode_number) = parse_episode_code(&row_text)
         .or_else(|| parse_episode_code(&name))
         .unwrap_or((default_season, 0));
-    
+
     // If we couldn't find episode number, try to extract from position or name
     let episode_number = if episode_number == 0 {
         extract_episode_number_from_name(&name).unwrap_or(1)
     } else {
         episode_number
     };
-    
-    // Format episode code
-    let episode_code = format!("S{:02}E{:02}", season_number, episode_number);
-    
+
     // Try to find rating
     let rating = extract_rating_from_row(row);
-    
+    let (comment_count, rating_count) = extract_counts(&row_text);
+
     Some(Episode {
         csfd_id,
         name: clean_episode_name(&name),
-        episode_code,
         season_number,
         episode_number,
         rating,
-        url,
+        url: url.to_string(),
+        comment_count,
+        rating_count,
+        absolute_number: None,
+        list_position: 0,
     })
 }
 
 /// Parse a single episode from a generic element.
 fn parse_episode_element(element: &scraper::ElementRef, default_season: u8) -> Option<Episode> {
     // Get URL - either from href or find a link inside
-    let url = element.value().attr("href").map(|s| s.to_string()).or_else(|| {
-        let link_selector = Selector::parse("a[href*='/film/']").ok()?;
-        element.select(&link_selector).next()?.value().attr("href").map(|s| s.to_string())
+    let url = element.value().attr("href").or_else(|| {
+        element
+            .select(selector!("a[href*='/film/']"))
+            .next()?
+            .value()
+            .attr("href")
     })?;
-    
+
     // Extract CSFD ID
-    let csfd_id = extract_csfd_id(&url)?;
-    
+    let csfd_id = extract_csfd_id(url)?;
+
     // Get episode name
-    let name = element.text().collect::<String>().trim().to_string();
+    let name = element_text(element);
     if name.is_empty() {
         return None;
     }
-    
+
     // Try to find episode code
-    let (season_number, episode_number) = parse_episode_code(&name)
-        .unwrap_or((default_season, extract_episode_number_from_name(&name).unwrap_or(1)));
-    
-    let episode_code = format!("S{:02}E{:02}", season_number, episode_number);
-    
+    let (season_number, episode_number) = parse_episode_code(&name).unwrap_or((
+        default_season,
+        extract_episode_number_from_name(&name).unwrap_or(1),
+    ));
+
     // Try to find rating
     let rating = extract_rating_from_element(element);
-    
+    let (comment_count, rating_count) = extract_counts(&name);
+
     Some(Episode {
         csfd_id,
         name: clean_episode_name(&name),
-        episode_code,
         season_number,
         episode_number,
         rating,
-        url,
+        url: url.to_string(),
+        comment_count,
+        rating_count,
+        absolute_number: None,
+        list_position: 0,
     })
 }
 
@@ -311,46 +348,46 @@ fn parse_episode_element(element: &scraper::ElementRef, default_season: u8) -> O
 ///
 /// # Examples
 /// ```
-/// use csfd_core::parser::parse_episode_code;
+/// use csfd_core::unstable::parser::parse_episode_code;
 ///
 /// assert_eq!(parse_episode_code("S01E05"), Some((1, 5)));
 /// assert_eq!(parse_episode_code("Episode S02E10 - Title"), Some((2, 10)));
 /// assert_eq!(parse_episode_code("no code here"), None);
 /// ```
-pub fn parse_episode_code(text: &str) -> Option<(u8, u8)> {
+pub fn parse_episode_code(text: &str) -> Option<(u8, u16)> {
     // Pattern: S01E01, s01e01, S1E1, etc.
-    let re = regex_lite::Regex::new(r"(?i)S(\d{1,2})E(\d{1,2})").ok()?;
+    let re = regex!(r"(?i)S(\d{1,2})E(\d{1,4})");
     if let Some(caps) = re.captures(text) {
         let season: u8 = caps.get(1)?.as_str().parse().ok()?;
-        let episode: u8 = caps.get(2)?.as_str().parse().ok()?;
+        let episode: u16 = caps.get(2)?.as_str().parse().ok()?;
         return Some((season, episode));
     }
-    
+
     // Alternative pattern: 1x05, 01x05
-    let re_alt = regex_lite::Regex::new(r"(\d{1,2})x(\d{1,2})").ok()?;
+    let re_alt = regex!(r"(\d{1,2})x(\d{1,4})");
     if let Some(caps) = re_alt.captures(text) {
         let season: u8 = caps.get(1)?.as_str().parse().ok()?;
-        let episode: u8 = caps.get(2)?.as_str().parse().ok()?;
+        let episode: u16 = caps.get(2)?.as_str().parse().ok()?;
         return Some((season, episode));
     }
-    
+
     None
 }
 
 /// Extract episode number from name like "1. Episode Title" or "Episode 5".
-fn extract_episode_number_from_name(name: &str) -> Option<u8> {
+fn extract_episode_number_from_name(name: &str) -> Option<u16> {
     // Pattern: "1. Title" or "01. Title"
-    let re = regex_lite::Regex::new(r"^(\d{1,2})\.\s").ok()?;
+    let re = regex!(r"^(\d{1,4})\.\s");
     if let Some(caps) = re.captures(name) {
         return caps.get(1)?.as_str().parse().ok();
     }
-    
+
     // Pattern: "Episode 5" or "Epizoda 5"
-    let re_ep = regex_lite::Regex::new(r"(?i)(?:episode|epizoda|díl)\s*(\d{1,2})").ok()?;
+    let re_ep = regex!(r"(?i)(?:episode|epizoda|díl)\s*(\d{1,4})");
     if let Some(caps) = re_ep.captures(name) {
         return caps.get(1)?.as_str().parse().ok();
     }
-    
+
     None
 }
 
@@ -365,7 +402,7 @@ fn extract_episode_number_from_name(name: &str) -> Option<u8> {
 ///
 /// # Examples
 /// ```
-/// use csfd_core::parser::parse_rating;
+/// use csfd_core::unstable::parser::parse_rating;
 ///
 /// assert_eq!(parse_rating("85%"), Some(85.0));
 /// assert_eq!(parse_rating("Rating: 72.5%"), Some(72.5));
@@ -373,7 +410,7 @@ fn extract_episode_number_from_name(name: &str) -> Option<u8> {
 /// ```
 pub fn parse_rating(text: &str) -> Option<f32> {
     // Pattern: 85%, 72.5%, etc.
-    let re = regex_lite::Regex::new(r"(\d{1,3}(?:\.\d+)?)\s*%").ok()?;
+    let re = regex!(r"(\d{1,3}(?:\.\d+)?)\s*%");
     if let Some(caps) = re.captures(text) {
         let rating: f32 = caps.get(1)?.as_str().parse().ok()?;
         // Validate range
@@ -381,7 +418,7 @@ pub fn parse_rating(text: &str) -> Option<f32> {
             return Some(rating);
         }
     }
-    
+
     None
 }
 
@@ -389,56 +426,69 @@ pub fn parse_rating(text: &str) -> Option<f32> {
 fn extract_rating_from_row(row: &scraper::ElementRef) -> Option<f32> {
     // Look for rating in specific cells
     let selectors = [
-        ".rating",
-        ".film-rating",
-        "td:last-child",
-        ".stars",
+        selector!(".rating"),
+        selector!(".film-rating"),
+        selector!("td:last-child"),
+        selector!(".stars"),
     ];
-    
-    for selector_str in &selectors {
-        if let Ok(selector) = Selector::parse(selector_str) {
-            if let Some(el) = row.select(&selector).next() {
-                let text = el.text().collect::<String>();
-                if let Some(rating) = parse_rating(&text) {
-                    return Some(rating);
-                }
+
+    for selector in selectors {
+        if let Some(el) = row.select(selector).next() {
+            if let Some(rating) = parse_rating(&element_text(&el)) {
+                return Some(rating);
             }
         }
     }
-    
+
     // Try the whole row text
-    let row_text = row.text().collect::<String>();
-    parse_rating(&row_text)
+    parse_rating(&element_text(row))
 }
 
 /// Extract rating from an element.
 fn extract_rating_from_element(element: &scraper::ElementRef) -> Option<f32> {
     let selectors = [
-        ".rating",
-        ".film-rating",
-        ".stars",
+        selector!(".rating"),
+        selector!(".film-rating"),
+        selector!(".stars"),
     ];
-    
-    for selector_str in &selectors {
-        if let Ok(selector) = Selector::parse(selector_str) {
-            if let Some(el) = element.select(&selector).next() {
-                let text = el.text().collect::<String>();
-                if let Some(rating) = parse_rating(&text) {
-                    return Some(rating);
-                }
+
+    for selector in selectors {
+        if let Some(el) = element.select(selector).next() {
+            if let Some(rating) = parse_rating(&element_text(&el)) {
+                return Some(rating);
             }
         }
     }
-    
+
     // Try the whole element text
-    let text = element.text().collect::<String>();
-    parse_rating(&text)
+    parse_rating(&element_text(element))
+}
+
+/// Extract comment and rating counters from text like "12 komentářů, 1 234 hodnocení".
+///
+/// # Returns
+/// `(comment_count, rating_count)`, each `None` if not present
+fn extract_counts(text: &str) -> (Option<u32>, Option<u32>) {
+    let count = |re: &regex_lite::Regex| {
+        let caps = re.captures(text)?;
+        caps[1]
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>()
+            .parse()
+            .ok()
+    };
+
+    (
+        count(regex!(r"(?i)(\d[\d\s\u{a0}]*)\s*komentář")),
+        count(regex!(r"(?i)(\d[\d\s\u{a0}]*)\s*hodnocení")),
+    )
 }
 
 /// Clean episode name by removing episode code prefix.
 fn clean_episode_name(name: &str) -> String {
     // Remove patterns like "S01E01 - " or "1. " from the beginning
-    let re = regex_lite::Regex::new(r"^(?:S\d{1,2}E\d{1,2}\s*[-:]\s*|\d{1,2}\.\s*)").unwrap();
+    let re = regex!(r"^(?:S\d{1,2}E\d{1,4}\s*[-:]\s*|\d{1,4}\.\s*)");
     re.replace(name, "").trim().to_string()
 }
 
@@ -446,6 +496,83 @@ fn clean_episode_name(name: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extract_counts() {
+        assert_eq!(
+            extract_counts("12 komentářů, 1 234 hodnocení"),
+            (Some(12), Some(1234))
+        );
+        assert_eq!(extract_counts("1 komentář"), (Some(1), None));
+        assert_eq!(extract_counts("S01E01 Pilot"), (None, None));
+    }
+
+    #[test]
+    fn test_parse_episodes_with_counts() {
+        let html = r#"
+            <html><body>
+                <article>
+                    <h3 class="film-title">
+                        <a href="/film/100-serial/200-pilot/prehled/" class="film-title-name">Pilot</a>
+                        <span class="film-title-info"><span class="info">(S01E01)</span></span>
+                    </h3>
+                    <p class="counters"><span>35 komentářů</span> <span>2&nbsp;480 hodnocení</span></p>
+                </article>
+                <article>
+                    <h3 class="film-title">
+                        <a href="/film/100-serial/201-druhy/prehled/" class="film-title-name">Druhý</a>
+                        <span class="film-title-info"><span class="info">(S01E02)</span></span>
+                    </h3>
+                </article>
+            </body></html>
+        "#;
+        let episodes = parse_episodes(html).unwrap();
+        assert_eq!(episodes.len(), 2);
+        assert_eq!(episodes[0].comment_count, Some(35));
+        assert_eq!(episodes[0].rating_count, Some(2480));
+        assert_eq!(episodes[1].comment_count, None);
+        assert_eq!(episodes[1].rating_count, None);
+    }
+
+    #[test]
+    fn test_parse_episodes_keeps_list_position() {
+        // Broadcast order differs from the production order in the codes
+        let html = r#"
+            <html><body>
+                <h3 class="film-title">
+                    <a href="/film/100-serial/202-treti/prehled/" class="film-title-name">Třetí</a>
+                    <span class="film-title-info"><span class="info">(S01E03)</span></span>
+                </h3>
+                <h3 class="film-title">
+                    <a href="/film/100-serial/200-pilot/prehled/" class="film-title-name">Pilot</a>
+                    <span class="film-title-info"><span class="info">(S01E01)</span></span>
+                </h3>
+            </body></html>
+        "#;
+        let episodes = parse_episodes(html).unwrap();
+        assert_eq!(episodes[0].episode_number, 3);
+        assert_eq!(episodes[0].list_position, 0);
+        assert_eq!(episodes[1].episode_number, 1);
+        assert_eq!(episodes[1].list_position, 1);
+    }
+
+    #[test]
+    fn test_parse_episodes_without_codes() {
+        // Year-labeled reality show seasons list episodes without codes
+        let html = r#"
+            <html><body>
+                <h3 class="film-title">
+                    <a href="/film/100-survivor/301-epizoda-1/prehled/" class="film-title-name">Epizoda 1</a>
+                </h3>
+                <h3 class="film-title">
+                    <a href="/film/100-survivor/302-finale/prehled/" class="film-title-name">Finále</a>
+                </h3>
+            </body></html>
+        "#;
+        let episodes = parse_episodes(html).unwrap();
+        assert_eq!(episodes[0].episode_number, 1);
+        assert_eq!(episodes[1].episode_number, 2);
+    }
+
     #[test]
     fn test_parse_episode_code_standard() {
         assert_eq!(parse_episode_code("S01E05"), Some((1, 5)));
@@ -460,6 +587,35 @@ mod tests {
         assert_eq!(parse_episode_code("Breaking Bad S05E16"), Some((5, 16)));
     }
 
+    #[test]
+    fn test_parse_episode_code_long_season() {
+        assert_eq!(parse_episode_code("S21E1085"), Some((21, 1085)));
+        assert_eq!(parse_episode_code("(S01E120)"), Some((1, 120)));
+        assert_eq!(parse_episode_code("1x300"), Some((1, 300)));
+    }
+
+    #[test]
+    fn test_parse_episodes_long_season() {
+        let articles: String = (99..=101)
+            .map(|n| {
+                format!(
+                    r#"<h3 class="film-title">
+                        <a href="/film/100-one-piece/{id}-epizoda/prehled/" class="film-title-name">Epizoda {n}</a>
+                        <span class="film-title-info"><span class="info">(S01E{n:03})</span></span>
+                    </h3>"#,
+                    id = 1000 + n,
+                    n = n
+                )
+            })
+            .collect();
+        let html = format!("<html><body>{}</body></html>", articles);
+
+        let episodes = parse_episodes(&html).unwrap();
+        let numbers: Vec<u16> = episodes.iter().map(|e| e.episode_number).collect();
+        assert_eq!(numbers, vec![99, 100, 101]);
+        assert_eq!(episodes[2].code().to_string(), "S01E101");
+    }
+
     #[test]
     fn test_parse_episode_code_alternative() {
         assert_eq!(parse_episode_code("1x05"), Some((1, 5)));
@@ -503,9 +659,16 @@ mod tests {
     #[test]
     fn test_extract_episode_number_from_name() {
         assert_eq!(extract_episode_number_from_name("1. Pilot"), Some(1));
-        assert_eq!(extract_episode_number_from_name("05. Episode Title"), Some(5));
+        assert_eq!(
+            extract_episode_number_from_name("05. Episode Title"),
+            Some(5)
+        );
         assert_eq!(extract_episode_number_from_name("Episode 3"), Some(3));
         assert_eq!(extract_episode_number_from_name("Epizoda 7"), Some(7));
+        assert_eq!(
+            extract_episode_number_from_name("1085. Epizoda"),
+            Some(1085)
+        );
         assert_eq!(extract_episode_number_from_name("Just a title"), None);
     }
 
@@ -534,14 +697,29 @@ mod tests {
     #[test]
     fn test_is_season_link() {
         // Season links should be detected
-        assert!(is_season_link("Série 1", "/film/234260-teorie-velkeho-tresku/470330-serie-1/prehled/"));
-        assert!(is_season_link("Série 12", "/film/234260-teorie-velkeho-tresku/628856-serie-12/prehled/"));
+        assert!(is_season_link(
+            "Série 1",
+            "/film/234260-teorie-velkeho-tresku/470330-serie-1/prehled/"
+        ));
+        assert!(is_season_link(
+            "Série 12",
+            "/film/234260-teorie-velkeho-tresku/628856-serie-12/prehled/"
+        ));
         assert!(is_season_link("Season 5", "/film/123/456-serie-5/prehled/"));
-        
+
         // Episode links should NOT be detected as seasons
-        assert!(!is_season_link("Pilot", "/film/234260-teorie-velkeho-tresku/628857-pilot/prehled/"));
-        assert!(!is_season_link("Manželský kompromis", "/film/234260/628857-manzelsky-kompromis/prehled/"));
-        assert!(!is_season_link("The One Where Monica Gets a Roommate", "/film/123/456-episode/prehled/"));
+        assert!(!is_season_link(
+            "Pilot",
+            "/film/234260-teorie-velkeho-tresku/628857-pilot/prehled/"
+        ));
+        assert!(!is_season_link(
+            "Manželský kompromis",
+            "/film/234260/628857-manzelsky-kompromis/prehled/"
+        ));
+        assert!(!is_season_link(
+            "The One Where Monica Gets a Roommate",
+            "/film/123/456-episode/prehled/"
+        ));
     }
 
     #[test]
@@ -557,9 +735,13 @@ mod tests {
             </h3>
         </body></html>
         "#;
-        
+
         let result = parse_episodes(html).unwrap();
         // Should return empty - these are season links, not episodes
-        assert!(result.is_empty(), "Season links should be filtered out, got {:?}", result);
+        assert!(
+            result.is_empty(),
+            "Season links should be filtered out, got {:?}",
+            result
+        );
     }
 }
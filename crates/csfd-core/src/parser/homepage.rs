@@ -0,0 +1,197 @@
+//! Homepage parser for ČSFD.cz
+//!
+//! The homepage is a column of boxes (news, most-visited titles, current
+//! premieres, today's birthdays), each with a heading naming it. Boxes are
+//! found by their heading, so a missing or renamed box leaves only its
+//! section of the digest empty.
+
+use scraper::{ElementRef, Html};
+
+use crate::types::{Birthday, HomepageDigest, NewsItem, SearchResult, TrendingKind};
+
+use super::element_text;
+use super::premieres::parse_czech_date;
+use super::search::{extract_creator_id, parse_search_item};
+use super::trending::extract_trending;
+
+/// Parse the sections of the ČSFD homepage.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the homepage
+///
+/// # Returns
+/// The digest; sections the page doesn't have are empty
+pub fn parse_homepage(html: &str) -> HomepageDigest {
+    let document = Html::parse_document(html);
+    HomepageDigest {
+        news: extract_news(&document),
+        trending_movies: extract_trending(&document, TrendingKind::Movies).unwrap_or_default(),
+        trending_series: extract_trending(&document, TrendingKind::Series).unwrap_or_default(),
+        premieres: extract_premieres(&document),
+        birthdays: extract_birthdays(&document),
+    }
+}
+
+/// Find the first homepage box whose lowercased heading matches.
+pub(super) fn find_box<'a>(
+    document: &'a Html,
+    matches: impl Fn(&str) -> bool,
+) -> Option<ElementRef<'a>> {
+    document
+        .select(selector!("section.box, div.box"))
+        .find(|section| {
+            section
+                .select(selector!("h2, .box-header h3"))
+                .next()
+                .is_some_and(|heading| matches(&element_text(&heading).to_lowercase()))
+        })
+}
+
+/// Extract news teasers, linking to `/novinky/` articles.
+fn extract_news(document: &Html) -> Vec<NewsItem> {
+    let Some(section) = find_box(document, |heading| heading.contains("novinky")) else {
+        return Vec::new();
+    };
+
+    section
+        .select(selector!("article"))
+        .filter_map(|article| {
+            let link = article
+                .select(selector!("a[href*='/novinky/']"))
+                .find(|link| !element_text(link).is_empty())?;
+            let date = article
+                .select(selector!("time, .date, .article-header-date"))
+                .find_map(|el| parse_czech_date(&element_text(&el)));
+            let perex = article
+                .select(selector!(".perex, .article-content p, p"))
+                .map(|el| element_text(&el).into_owned())
+                .find(|text| !text.is_empty());
+            Some(NewsItem {
+                title: element_text(&link).into_owned(),
+                url: link.value().attr("href")?.to_string(),
+                date,
+                perex,
+            })
+        })
+        .collect()
+}
+
+/// Extract titles of the current premieres box.
+fn extract_premieres(document: &Html) -> Vec<SearchResult> {
+    find_box(document, |heading| {
+        heading.contains("premiér") || heading.contains("v kinech")
+    })
+    .map(|section| {
+        section
+            .select(selector!("article"))
+            .filter_map(|article| parse_search_item(&article))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// Extract creators with a birthday today; the age follows the name as "(65)".
+fn extract_birthdays(document: &Html) -> Vec<Birthday> {
+    let Some(section) = find_box(document, |heading| heading.contains("narozeniny")) else {
+        return Vec::new();
+    };
+
+    let mut birthdays: Vec<Birthday> = Vec::new();
+    for link in section.select(selector!("a[href*='/tvurce/']")) {
+        let Some(creator_id) = link.value().attr("href").and_then(extract_creator_id) else {
+            continue;
+        };
+        let name = element_text(&link);
+        if name.is_empty() || birthdays.iter().any(|b| b.creator_id == creator_id) {
+            continue;
+        }
+
+        let age = link
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|parent| matches!(parent.value().name(), "article" | "li" | "tr"))
+            .and_then(|parent| {
+                regex!(r"\((\d{1,3})\)")
+                    .captures(&element_text(&parent))
+                    .and_then(|caps| caps[1].parse().ok())
+            });
+        birthdays.push(Birthday {
+            name: name.into_owned(),
+            creator_id,
+            age,
+        });
+    }
+    birthdays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_homepage() {
+        let html = r#"
+            <html><body>
+                <section class="box">
+                    <header class="box-header"><h2>Novinky</h2></header>
+                    <article>
+                        <h3><a href="/novinky/5012-oscary-2024/">Oscary 2024</a></h3>
+                        <span class="date">11.3.2024</span>
+                        <p class="perex">Kdo si odnesl sošku?</p>
+                    </article>
+                </section>
+                <section class="box">
+                    <header class="box-header"><h2>Nejnavštěvovanější seriály</h2></header>
+                    <article>
+                        <h3 class="film-title-norating">
+                            <a href="/film/264494-pernikovy-tata/" class="film-title-name">Perníkový táta</a>
+                        </h3>
+                    </article>
+                </section>
+                <section class="box">
+                    <header class="box-header"><h2>Právě v kinech</h2></header>
+                    <article>
+                        <h3 class="film-title-norating">
+                            <a href="/film/1234-duna-cast-druha/" class="film-title-name">Duna: Část druhá</a>
+                        </h3>
+                    </article>
+                </section>
+                <section class="box">
+                    <header class="box-header"><h2>Narozeniny</h2></header>
+                    <ul>
+                        <li><a href="/tvurce/3-frank-darabont/">Frank Darabont</a> (65)</li>
+                        <li><a href="/tvurce/103-tim-robbins/">Tim Robbins</a></li>
+                    </ul>
+                </section>
+            </body></html>
+        "#;
+        let digest = parse_homepage(html);
+        assert_eq!(
+            digest.news,
+            vec![NewsItem {
+                title: "Oscary 2024".to_string(),
+                url: "/novinky/5012-oscary-2024/".to_string(),
+                date: Some("2024-03-11".to_string()),
+                perex: Some("Kdo si odnesl sošku?".to_string()),
+            }]
+        );
+        assert!(digest.trending_movies.is_empty());
+        assert_eq!(digest.trending_series[0].csfd_id, 264494);
+        assert_eq!(digest.premieres[0].name, "Duna: Část druhá");
+        assert_eq!(
+            digest.birthdays,
+            vec![
+                Birthday {
+                    name: "Frank Darabont".to_string(),
+                    creator_id: 3,
+                    age: Some(65),
+                },
+                Birthday {
+                    name: "Tim Robbins".to_string(),
+                    creator_id: 103,
+                    age: None,
+                },
+            ]
+        );
+    }
+}
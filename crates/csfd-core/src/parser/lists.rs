@@ -0,0 +1,209 @@
+//! User list parser for ČSFD.cz
+//!
+//! Parses the overview of a user's custom lists ("seznamy") and the pages
+//! of a single list. List entries use the same article markup as search
+//! results, with an optional note by the list author.
+
+use scraper::{ElementRef, Html};
+
+use crate::error::{CsfdError, Result};
+use crate::types::{PaginatedResult, UserList, UserListEntry};
+
+use super::pagination::{detect_next_page, extract_current_page};
+use super::search::parse_search_item;
+use super::{canonical_url, element_text};
+
+/// Extract the list ID from a list URL.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::extract_list_id;
+///
+/// assert_eq!(extract_list_id("/uzivatel/2544-verbal/seznamy/8123-nejlepsi-ceske-serialy/"), Some(8123));
+/// assert_eq!(extract_list_id("/uzivatel/2544-verbal/seznamy/"), None);
+/// ```
+pub fn extract_list_id(url: &str) -> Option<u32> {
+    regex!(r"/seznamy/(\d+)")
+        .captures(url)
+        .and_then(|caps| caps[1].parse().ok())
+}
+
+/// Extract the ID of the list a page shows, e.g. after creating it.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of a list page
+pub fn parse_list_id(html: &str) -> Option<u32> {
+    canonical_url(&Html::parse_document(html)).and_then(extract_list_id)
+}
+
+/// Parse the overview of a user's custom lists.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the user's lists page
+///
+/// # Returns
+/// Lists in the order shown; empty if the user has none
+pub fn parse_user_lists(html: &str) -> Vec<UserList> {
+    let document = Html::parse_document(html);
+    let mut lists: Vec<UserList> = Vec::new();
+
+    for link in document.select(selector!("a[href*='/seznamy/']")) {
+        let Some(url) = link.value().attr("href") else {
+            continue;
+        };
+        let Some(list_id) = extract_list_id(url) else {
+            continue;
+        };
+        let name = element_text(&link);
+        if name.is_empty() || lists.iter().any(|list| list.list_id == list_id) {
+            continue;
+        }
+
+        let item_count = link
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .find(|parent| matches!(parent.value().name(), "article" | "li" | "tr"))
+            .and_then(|parent| extract_item_count(&element_text(&parent)));
+
+        lists.push(UserList {
+            list_id,
+            name: name.into_owned(),
+            item_count,
+            url: url.to_string(),
+        });
+    }
+
+    lists
+}
+
+/// Parse one page of a custom list.
+///
+/// Positions are 1-based within the page; callers fetching later pages
+/// offset them.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the list page
+///
+/// # Returns
+/// * `Ok(PaginatedResult<UserListEntry>)` with entries in list order
+/// * `Err(CsfdError::ParseError)` if the page has no list content
+pub fn parse_user_list(html: &str) -> Result<PaginatedResult<UserListEntry>> {
+    let document = Html::parse_document(html);
+
+    let items: Vec<UserListEntry> = document
+        .select(selector!("article"))
+        .filter_map(|element| {
+            let title = parse_search_item(&element)?;
+            let note = element
+                .select(selector!(".film-note, .note, .article-content p"))
+                .map(|note| element_text(&note))
+                .find(|note| !note.is_empty())
+                .map(|note| note.into_owned());
+            Some((title, note))
+        })
+        .enumerate()
+        .map(|(index, (title, note))| UserListEntry {
+            position: index as u32 + 1,
+            title,
+            note,
+        })
+        .collect();
+
+    if items.is_empty()
+        && document
+            .select(selector!(".box-content, .user-list"))
+            .next()
+            .is_none()
+    {
+        return Err(CsfdError::ParseError(
+            "no list entries found - markup may have changed".to_string(),
+        ));
+    }
+
+    let current_page = extract_current_page(&document).unwrap_or(1);
+    Ok(PaginatedResult::new(
+        items,
+        current_page,
+        detect_next_page(&document),
+    ))
+}
+
+/// Extract a title count such as "24 položek" from a list summary.
+fn extract_item_count(text: &str) -> Option<u32> {
+    regex!(r"(\d+)\s*(?:položek|položky|položka|titulů|tituly|titul|filmů|filmy|film)")
+        .captures(text)
+        .and_then(|caps| caps[1].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_lists() {
+        let html = r#"
+            <html><body>
+                <section class="box">
+                    <article class="article-list">
+                        <h2><a href="/uzivatel/2544-verbal/seznamy/8123-nejlepsi-ceske-serialy/">Nejlepší české seriály</a></h2>
+                        <p class="info">24 položek</p>
+                    </article>
+                    <article class="article-list">
+                        <h2><a href="/uzivatel/2544-verbal/seznamy/8130-na-vanoce/">Na Vánoce</a></h2>
+                    </article>
+                    <a href="/uzivatel/2544-verbal/seznamy/8123-nejlepsi-ceske-serialy/">Nejlepší české seriály</a>
+                </section>
+            </body></html>
+        "#;
+        let lists = parse_user_lists(html);
+        assert_eq!(lists.len(), 2);
+        assert_eq!(lists[0].list_id, 8123);
+        assert_eq!(lists[0].name, "Nejlepší české seriály");
+        assert_eq!(lists[0].item_count, Some(24));
+        assert_eq!(lists[1].list_id, 8130);
+        assert_eq!(lists[1].item_count, None);
+    }
+
+    #[test]
+    fn test_parse_user_list() {
+        let html = r#"
+            <html><body>
+                <div class="box-content">
+                    <article class="article-poster-60">
+                        <h3 class="film-title-norating">
+                            <a href="/film/71924-dr-house/" class="film-title-name">Dr. House</a>
+                            <span class="film-title-info"><span class="info">(2004)</span></span>
+                        </h3>
+                        <p class="film-note">Hlavně první série.</p>
+                    </article>
+                    <article class="article-poster-60">
+                        <h3 class="film-title-norating">
+                            <a href="/film/264494-pernikovy-tata/" class="film-title-name">Perníkový táta</a>
+                        </h3>
+                    </article>
+                </div>
+                <div class="pagination"><span class="active">1</span><a class="next" href="?page=2">Další</a></div>
+            </body></html>
+        "#;
+        let result = parse_user_list(html).unwrap();
+        assert_eq!(result.items.len(), 2);
+        assert!(result.has_next_page);
+        assert_eq!(result.items[0].position, 1);
+        assert_eq!(result.items[0].title.csfd_id, 71924);
+        assert_eq!(result.items[0].note.as_deref(), Some("Hlavně první série."));
+        assert_eq!(result.items[1].position, 2);
+        assert_eq!(result.items[1].note, None);
+    }
+
+    #[test]
+    fn test_parse_list_id() {
+        let html = r#"<html><head><link rel="canonical" href="https://www.csfd.cz/uzivatel/2544-verbal/seznamy/8140-k-doplneni/"></head></html>"#;
+        assert_eq!(parse_list_id(html), Some(8140));
+        assert_eq!(parse_list_id("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_parse_user_list_unexpected_markup() {
+        assert!(parse_user_list("<html><body><p>Jiná stránka</p></body></html>").is_err());
+    }
+}
@@ -1,15 +1,205 @@
 //! HTML parsers for ČSFD.cz pages
 //!
 //! This module contains parsers for extracting data from ČSFD HTML pages:
+//! - `auth`: Detect logged-in pages and read login and hidden form fields
+//! - `capabilities`: Parser version and the fields each parser extracts
+//! - `chart`: Parse chart pages (žebříčky)
 //! - `search`: Parse search results page
 //! - `series`: Parse series detail page
 //! - `episodes`: Parse episodes list page
+//! - `episode_detail`: Parse episode detail page
+//! - `homepage`: Parse the homepage digest (news, trending, premieres, birthdays)
+//! - `lists`: Parse users' custom lists
+//! - `movie`: Parse film detail page
+//! - `origin`: Parse the country, year, episodes and runtime line of titles
+//! - `pagination`: Parse pagination shared by search and title tabs
+//! - `person`: Parse creator pages with biography and filmography
+//! - `premieres`: Parse upcoming premiere overviews
+//! - `ratings`: Parse the titles a user rated
+//! - `reviews`: Parse user reviews of a title
+//! - `sitemap`: Parse sitemap files listing title and creator pages
+//! - `structured`: Read schema.org JSON-LD embedded in detail pages
+//! - `trending`: Parse the homepage's most-visited titles
+//!
+//! Only `capabilities` is built without the `client` and `unstable`
+//! features, since nothing else reads pages then.
+
+#[cfg(any(feature = "client", feature = "unstable"))]
+use std::borrow::Cow;
+
+#[cfg(any(feature = "client", feature = "unstable"))]
+use scraper::{ElementRef, Html};
 
+/// Compile a CSS selector on first use and reuse it afterwards.
+#[cfg(any(feature = "client", feature = "unstable"))]
+macro_rules! selector {
+    ($css:literal) => {{
+        static SELECTOR: std::sync::OnceLock<scraper::Selector> = std::sync::OnceLock::new();
+        SELECTOR.get_or_init(|| scraper::Selector::parse($css).expect("valid CSS selector"))
+    }};
+}
+
+/// Compile a regex on first use and reuse it afterwards.
+#[cfg(any(feature = "client", feature = "unstable"))]
+macro_rules! regex {
+    ($pattern:literal) => {{
+        static REGEX: std::sync::OnceLock<regex_lite::Regex> = std::sync::OnceLock::new();
+        REGEX.get_or_init(|| regex_lite::Regex::new($pattern).expect("valid regex"))
+    }};
+}
+
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod auth;
+pub mod capabilities;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod chart;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod episode_detail;
+#[cfg(any(feature = "client", feature = "unstable"))]
 pub mod episodes;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod homepage;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod lists;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod movie;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod origin;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod pagination;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod person;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod premieres;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod ratings;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod reviews;
+#[cfg(any(feature = "client", feature = "unstable"))]
 pub mod search;
+#[cfg(any(feature = "client", feature = "unstable"))]
 pub mod series;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod sitemap;
+#[cfg(any(feature = "client", feature = "unstable"))]
+mod structured;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub mod trending;
+
+// Re-export main parsing functions; items only reached through `unstable`
+// are re-exported with it
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use auth::{
+    is_logged_in, parse_form_action, parse_form_error, parse_hidden_inputs, parse_profile_path,
+};
+#[cfg(feature = "unstable")]
+pub use capabilities::{capabilities, Capabilities, ParserCapability, VERSION};
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use chart::parse_chart;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use episode_detail::{parse_episode_detail, parse_episode_detail_verified};
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use episodes::parse_episodes;
+#[cfg(feature = "unstable")]
+pub use episodes::{parse_episode_code, parse_rating};
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use homepage::parse_homepage;
+#[cfg(feature = "unstable")]
+pub use lists::extract_list_id;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use lists::{parse_list_id, parse_user_list, parse_user_lists};
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use movie::{parse_movie_detail, parse_movie_detail_verified};
+#[cfg(feature = "unstable")]
+pub use origin::parse_origin_line;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use pagination::{has_next_page, page_path};
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use person::{parse_person, parse_person_verified};
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use premieres::parse_premieres;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use ratings::parse_user_ratings;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use reviews::parse_reviews;
+#[cfg(feature = "unstable")]
+pub use search::extract_genre_id;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use search::{extract_creator_id, extract_csfd_id, parse_search_results};
+#[cfg(feature = "unstable")]
+pub use series::parse_seasons;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use series::{parse_cast, parse_series_detail, parse_series_detail_verified};
+#[cfg(feature = "unstable")]
+pub use sitemap::Sitemap;
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use sitemap::{parse_sitemap, site_path};
+#[cfg(any(feature = "client", feature = "unstable"))]
+pub use trending::parse_trending;
+#[cfg(any(feature = "client", feature = "unstable"))]
+/// Trimmed text content of an element.
+///
+/// Borrows from the document when the element holds a single text node,
+/// which is the common case for titles and info spans, and only allocates
+/// when several text nodes have to be joined.
+pub(crate) fn element_text<'a>(element: &ElementRef<'a>) -> Cow<'a, str> {
+    let mut nodes = element.text();
+    let Some(first) = nodes.next() else {
+        return Cow::Borrowed("");
+    };
+    let Some(second) = nodes.next() else {
+        return Cow::Borrowed(first.trim());
+    };
+
+    let mut text = String::with_capacity(first.len() + second.len());
+    text.push_str(first);
+    text.push_str(second);
+    text.extend(nodes);
+
+    let trimmed = text.trim();
+    if trimmed.len() == text.len() {
+        Cow::Owned(text)
+    } else {
+        Cow::Owned(trimmed.to_string())
+    }
+}
+
+#[cfg(any(feature = "client", feature = "unstable"))]
+/// URL of the page from its canonical link or `og:url`.
+pub(crate) fn canonical_url(document: &Html) -> Option<&str> {
+    document
+        .select(selector!("link[rel='canonical']"))
+        .next()
+        .and_then(|el| el.value().attr("href"))
+        .or_else(|| {
+            document
+                .select(selector!("meta[property='og:url']"))
+                .next()
+                .and_then(|el| el.value().attr("content"))
+        })
+}
+
+#[cfg(all(test, any(feature = "client", feature = "unstable")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element_text_borrows_single_node() {
+        let html = Html::parse_fragment("<h3> Pilot </h3>");
+        let h3 = html.select(selector!("h3")).next().unwrap();
+        let text = element_text(&h3);
+        assert_eq!(text, "Pilot");
+        assert!(matches!(text, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_element_text_joins_nodes() {
+        let html = Html::parse_fragment("<h3> <a>Pilot</a> (S01E01) </h3>");
+        let h3 = html.select(selector!("h3")).next().unwrap();
+        assert_eq!(element_text(&h3), "Pilot (S01E01)");
 
-// Re-export main parsing functions
-pub use episodes::{parse_episode_code, parse_episodes, parse_rating};
-pub use search::{extract_csfd_id, parse_search_results};
-pub use series::{parse_seasons, parse_series_detail};
+        let empty = Html::parse_fragment("<h3></h3>");
+        let h3 = empty.select(selector!("h3")).next().unwrap();
+        assert_eq!(element_text(&h3), "");
+    }
+}
@@ -0,0 +1,240 @@
+//! Film detail parser for ČSFD.cz
+//!
+//! Film pages share the header and creators markup of series pages, so this
+//! reuses the series extractors and adds the runtime from the origin line
+//! ("USA, 1994, 142 min"). Like series, fields found in the page's JSON-LD
+//! are read from it first. Multi-part TV films list their parts the way
+//! series list seasons, so parts are read with the season extractors.
+
+use scraper::Html;
+
+use crate::error::{CsfdError, Result};
+use crate::types::{FilmType, MovieDetail, Part};
+
+use super::element_text;
+use super::episodes::parse_rating;
+use super::origin::extract_origin_line;
+use super::series::{
+    extract_cast, extract_countries, extract_creator_group, extract_genre_ids, extract_genres,
+    extract_imdb_id, extract_original_name, extract_series_name, extract_year_range, or_selectors,
+    parse_seasons, structured_cast, verify_canonical_id, verify_title_name,
+};
+use super::structured::extract_structured_data;
+
+/// Parse film detail from a ČSFD film page.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the film page
+/// * `csfd_id` - The CSFD ID of the film (used in the result)
+///
+/// # Returns
+/// * `Ok(MovieDetail)` with parsed film information
+/// * `Err(CsfdError)` if parsing fails
+pub fn parse_movie_detail(html: &str, csfd_id: u32) -> Result<MovieDetail> {
+    let document = Html::parse_document(html);
+    parse_movie_document(&document, csfd_id)
+}
+
+/// Parse film detail, first checking that the page is the requested one.
+///
+/// # Returns
+/// * `Ok(MovieDetail)` with parsed film information
+/// * `Err(CsfdError::UnexpectedPage)` if the page belongs to something else
+/// * `Err(CsfdError)` if parsing fails
+pub fn parse_movie_detail_verified(html: &str, csfd_id: u32) -> Result<MovieDetail> {
+    let document = Html::parse_document(html);
+    verify_canonical_id(&document, csfd_id)?;
+    let detail = parse_movie_document(&document, csfd_id)?;
+    verify_title_name(&detail.name, csfd_id)?;
+    Ok(detail)
+}
+
+/// Parse film detail from an already parsed document.
+fn parse_movie_document(document: &Html, csfd_id: u32) -> Result<MovieDetail> {
+    let structured = extract_structured_data(document).unwrap_or_default();
+
+    let name = structured
+        .name
+        .or_else(|| extract_series_name(document))
+        .ok_or_else(|| CsfdError::ElementNotFound("film name".to_string()))?;
+
+    let origin = extract_origin_line(document).unwrap_or_default();
+
+    let year = structured.year.or_else(|| {
+        extract_year_range(document, &origin)
+            .and_then(|year| year.get(..4).and_then(|y| y.parse().ok()))
+    });
+
+    let runtime_minutes = structured.runtime_minutes.or(origin.runtime_minutes);
+
+    let rating = structured.rating.or_else(|| {
+        document
+            .select(selector!(".film-rating-average"))
+            .next()
+            .and_then(|el| parse_rating(&element_text(&el)))
+    });
+
+    let film_type = extract_film_type(document);
+    let parts = match film_type {
+        FilmType::TvFilm => extract_parts(document),
+        FilmType::Film => Vec::new(),
+    };
+
+    Ok(MovieDetail {
+        csfd_id,
+        name,
+        original_name: extract_original_name(document),
+        year,
+        genres: or_selectors(structured.genres, || extract_genres(document)),
+        genre_ids: extract_genre_ids(document),
+        countries: or_selectors(structured.countries, || {
+            extract_countries(document, &origin)
+        }),
+        runtime_minutes,
+        rating,
+        directors: or_selectors(structured.directors, || {
+            extract_creator_group(document, &["Režie"])
+        }),
+        cast: or_selectors(extract_cast(document), || {
+            structured_cast(&structured.actors)
+        }),
+        film_type,
+        parts,
+        imdb_id: extract_imdb_id(document),
+    })
+}
+
+/// Extract the film type from the label next to the title ("(TV film)").
+fn extract_film_type(document: &Html) -> FilmType {
+    let is_tv_film = document
+        .select(selector!(".film-header-name .type, .film-header .type"))
+        .any(|el| element_text(&el).to_lowercase().contains("tv film"));
+    if is_tv_film {
+        FilmType::TvFilm
+    } else {
+        FilmType::Film
+    }
+}
+
+/// Extract the parts of a multi-part film, numbered by name or by position.
+fn extract_parts(document: &Html) -> Vec<Part> {
+    parse_seasons(document)
+        .into_iter()
+        .zip(1..)
+        .map(|(season, position)| Part {
+            csfd_id: season.csfd_id,
+            number: part_number(&season.name).unwrap_or(position),
+            name: season.name,
+            rating: season.rating,
+            url: season.url,
+        })
+        .collect()
+}
+
+/// Read the number of a part from its name ("2. díl", "Část 2", "Part 2").
+fn part_number(name: &str) -> Option<u16> {
+    let re = regex!(r"(?i)(\d{1,2})\.\s*(?:díl|[čČ]ást)|(?:díl|[čČ]ást|part)\s*(\d{1,2})");
+    let caps = re.captures(name)?;
+    caps.get(1).or_else(|| caps.get(2))?.as_str().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOVIE_HTML: &str = r#"
+        <html>
+            <head><link rel="canonical" href="https://www.csfd.cz/film/2294-vykoupeni-z-veznice-shawshank/prehled/"></head>
+            <body>
+                <div class="film-header">
+                    <h1>Vykoupení z věznice Shawshank</h1>
+                    <ul class="film-names"><li>The Shawshank Redemption</li></ul>
+                </div>
+                <div class="film-header-origin">
+                    <div class="genre"><a href="/zanr/drama/">Drama</a> / <a href="/zanr/krimi/">Krimi</a></div>
+                    <div class="origin"><a href="/zeme/usa/">USA</a>, <span>1994</span>, 142 min</div>
+                </div>
+                <div class="film-rating-average">95%</div>
+                <div class="creators">
+                    <div><h4>Režie:</h4><a href="/tvurce/3016-frank-darabont/">Frank Darabont</a></div>
+                    <div><h4>Hrají:</h4>
+                        <a href="/tvurce/103-tim-robbins/">Tim Robbins</a>,
+                        <a href="/tvurce/104-morgan-freeman/">Morgan Freeman</a>
+                    </div>
+                </div>
+                <div class="film-links">
+                    <a href="https://www.imdb.com/title/tt0111161/" class="button button-imdb">IMDb</a>
+                </div>
+            </body>
+        </html>
+    "#;
+
+    #[test]
+    fn test_parse_movie_detail() {
+        let movie = parse_movie_detail_verified(MOVIE_HTML, 2294).unwrap();
+        assert_eq!(movie.name, "Vykoupení z věznice Shawshank");
+        assert_eq!(
+            movie.original_name.as_deref(),
+            Some("The Shawshank Redemption")
+        );
+        assert_eq!(movie.year, Some(1994));
+        assert_eq!(movie.genres, vec!["Drama", "Krimi"]);
+        assert_eq!(movie.countries, vec!["USA"]);
+        assert_eq!(movie.runtime_minutes, Some(142));
+        assert_eq!(movie.rating, Some(95.0));
+        assert_eq!(movie.directors, vec![("Frank Darabont".to_string(), 3016)]);
+        assert_eq!(movie.cast.len(), 2);
+        assert_eq!(movie.cast[1].name, "Morgan Freeman");
+        assert_eq!(movie.imdb_id.as_deref(), Some("tt0111161"));
+    }
+
+    #[test]
+    fn test_parse_multi_part_tv_film() {
+        let html = r#"
+            <html><body>
+                <div class="film-header">
+                    <div class="film-header-name"><h1>Marie Terezie</h1><span class="type">(TV film)</span></div>
+                </div>
+                <div class="film-header-origin">
+                    <div class="origin">Česko / Rakousko, <span>2017</span>, 204 min</div>
+                </div>
+                <section class="film-episodes-list">
+                    <article><h3 class="film-title">
+                        <a class="film-title-name" href="/film/443208-marie-terezie/476251-1-dil/prehled/">1. díl</a>
+                        <span class="film-title-info">(2017)</span>
+                    </h3></article>
+                    <article><h3 class="film-title">
+                        <a class="film-title-name" href="/film/443208-marie-terezie/476252-2-dil/prehled/">2. díl</a>
+                        <span class="film-title-info">(2017)</span>
+                    </h3></article>
+                </section>
+            </body></html>
+        "#;
+
+        let movie = parse_movie_detail(html, 443208).unwrap();
+        assert_eq!(movie.film_type, FilmType::TvFilm);
+        assert_eq!(movie.parts.len(), 2);
+        assert_eq!(movie.part(2).unwrap().csfd_id, 476252);
+        assert_eq!(movie.parts[0].name, "1. díl");
+
+        let cinema = parse_movie_detail(MOVIE_HTML, 2294).unwrap();
+        assert_eq!(cinema.film_type, FilmType::Film);
+        assert!(cinema.parts.is_empty());
+    }
+
+    #[test]
+    fn test_part_number() {
+        assert_eq!(part_number("2. díl"), Some(2));
+        assert_eq!(part_number("Část 3"), Some(3));
+        assert_eq!(part_number("Part 1"), Some(1));
+        assert_eq!(part_number("Finále"), None);
+    }
+
+    #[test]
+    fn test_parse_movie_detail_wrong_page() {
+        assert!(matches!(
+            parse_movie_detail_verified(MOVIE_HTML, 1),
+            Err(CsfdError::UnexpectedPage(_))
+        ));
+    }
+}
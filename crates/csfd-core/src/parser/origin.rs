@@ -0,0 +1,143 @@
+//! Origin line parser for ČSFD.cz
+//!
+//! Detail pages summarize a title in one line under its name, e.g.
+//! "USA / Kanada, 2008–2013, 62 epizod, 45 min"; season lists use a shorter
+//! "(2008) - 7 epizod". Parts may be missing or reordered, so each is
+//! recognized by its form: episode counts and runtimes by their unit, the
+//! year span by four digits, and countries as the text before the year.
+//! Co-productions list all countries separated by slashes
+//! ("USA / Velká Británie / Kanada"); their order is kept.
+
+use scraper::Html;
+
+use crate::types::{Country, OriginLine};
+
+use super::element_text;
+
+/// Parse an origin line in one pass.
+///
+/// # Arguments
+/// * `text` - Text of the origin line or of a season's info
+///
+/// # Example
+/// ```
+/// use csfd_core::unstable::parser::parse_origin_line;
+///
+/// let origin = parse_origin_line("USA / Kanada, 2008–2013, 62 epizod, 45 min");
+/// let codes: Vec<_> = origin.countries.iter().map(|c| c.iso2.as_deref()).collect();
+/// assert_eq!(codes, vec![Some("US"), Some("CA")]);
+/// assert_eq!(origin.year_range().as_deref(), Some("2008-2013"));
+/// assert_eq!(origin.episode_count, Some(62));
+/// assert_eq!(origin.runtime_minutes, Some(45));
+/// ```
+pub fn parse_origin_line(text: &str) -> OriginLine {
+    let episodes = regex!(r"(\d+)\s*epizod\w*");
+    let runtime = regex!(r"(?:(\d+)\s*h\s*)?(\d+)\s*min");
+
+    let episode_count = episodes
+        .captures(text)
+        .and_then(|caps| caps[1].parse().ok());
+    let runtime_minutes = runtime.captures(text).and_then(|caps| {
+        let hours: u16 = caps.get(1).map_or(Some(0), |h| h.as_str().parse().ok())?;
+        let minutes: u16 = caps[2].parse().ok()?;
+        Some(hours * 60 + minutes)
+    });
+
+    // Counts and runtimes can have four digits too, so they are removed first
+    let rest = episodes.replace_all(text, "");
+    let rest = runtime.replace_all(&rest, "");
+
+    let mut origin = OriginLine {
+        episode_count,
+        runtime_minutes,
+        ..OriginLine::default()
+    };
+    let years = regex!(r"(\d{4})(?:\s*[-–]\s*(\d{4}))?(\s*[-–])?");
+    if let Some(caps) = years.captures(&rest) {
+        origin.year_from = caps[1].parse().ok();
+        origin.year_to = caps.get(2).and_then(|to| to.as_str().parse().ok());
+        origin.open_ended = origin.year_to.is_none() && caps.get(3).is_some();
+    }
+
+    // Countries come before the first part holding a number
+    origin.countries = rest
+        .split(',')
+        .take_while(|part| !part.chars().any(|c| c.is_ascii_digit()))
+        .flat_map(|part| part.split('/'))
+        .map(str::trim)
+        .filter(|country| country.chars().any(char::is_alphabetic))
+        .map(Country::from_name_cs)
+        .collect();
+
+    origin
+}
+
+/// Parse the origin line of a detail page, if it has one.
+pub(super) fn extract_origin_line(document: &Html) -> Option<OriginLine> {
+    document
+        .select(selector!(".film-header-origin .origin, div.origin"))
+        .next()
+        .map(|el| parse_origin_line(&element_text(&el)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_origin_line() {
+        let origin = parse_origin_line("Velká Británie, 2019–, 12 epizod");
+        assert_eq!(
+            origin.countries,
+            vec![Country::from_name_cs("Velká Británie")]
+        );
+        assert_eq!(origin.year_from, Some(2019));
+        assert!(origin.open_ended);
+        assert_eq!(origin.year_range().as_deref(), Some("2019-"));
+        assert_eq!(origin.episode_count, Some(12));
+        assert_eq!(origin.runtime_minutes, None);
+
+        let movie = parse_origin_line("USA, 1994, 2 h 22 min");
+        assert_eq!(movie.year_range().as_deref(), Some("1994"));
+        assert_eq!(movie.runtime_minutes, Some(142));
+
+        let no_country = parse_origin_line("2007 - 2019, 279 epizod");
+        assert!(no_country.countries.is_empty());
+        assert_eq!(no_country.year_range().as_deref(), Some("2007-2019"));
+        assert_eq!(no_country.episode_count, Some(279));
+
+        let season = parse_origin_line("(2007) - 17 epizod");
+        assert_eq!(season.year_range().as_deref(), Some("2007"));
+        assert!(!season.open_ended);
+        assert_eq!(season.episode_count, Some(17));
+
+        assert_eq!(parse_origin_line(""), OriginLine::default());
+    }
+
+    #[test]
+    fn test_parse_origin_line_co_production() {
+        let origin = parse_origin_line("USA / Velká Británie / Kanada / Československo, 1990");
+        let countries: Vec<_> = origin
+            .countries
+            .iter()
+            .map(|c| (c.name_cs.as_str(), c.iso2.as_deref()))
+            .collect();
+        assert_eq!(
+            countries,
+            vec![
+                ("USA", Some("US")),
+                ("Velká Británie", Some("GB")),
+                ("Kanada", Some("CA")),
+                ("Československo", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_origin_line_runtime() {
+        let runtime = |text| parse_origin_line(text).runtime_minutes;
+        assert_eq!(runtime("USA, 1994, 142 min"), Some(142));
+        assert_eq!(runtime("2 h 22 min"), Some(142));
+        assert_eq!(runtime("USA, 1994"), None);
+    }
+}
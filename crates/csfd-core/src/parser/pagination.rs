@@ -0,0 +1,126 @@
+//! Pagination parser for ČSFD.cz
+//!
+//! Search results and title tabs (reviews, gallery, trivia, ...) share the
+//! same pagination markup. This module extracts it in one place.
+
+use scraper::{Html, Selector};
+
+/// Check whether a page links to a next page.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of a paginated page
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::has_next_page;
+///
+/// assert!(has_next_page(r#"<div class="pagination"><a class="next" href="?page=2">Další</a></div>"#));
+/// assert!(!has_next_page("<html><body></body></html>"));
+/// ```
+pub fn has_next_page(html: &str) -> bool {
+    detect_next_page(&Html::parse_document(html))
+}
+
+/// Build the path of a given page of a paginated listing.
+///
+/// The first page uses the bare path; later pages append a `page` query parameter.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::page_path;
+///
+/// assert_eq!(page_path("/film/123/recenze/", 1), "/film/123/recenze/");
+/// assert_eq!(page_path("/film/123/recenze/", 2), "/film/123/recenze/?page=2");
+/// assert_eq!(page_path("/hledat/?q=test", 3), "/hledat/?q=test&page=3");
+/// ```
+pub fn page_path(path: &str, page: u32) -> String {
+    if page <= 1 {
+        path.to_string()
+    } else if path.contains('?') {
+        format!("{}&page={}", path, page)
+    } else {
+        format!("{}?page={}", path, page)
+    }
+}
+
+/// Detect if there are more pages of results.
+pub(crate) fn detect_next_page(document: &Html) -> bool {
+    // Look for "next page" link in pagination
+    let next_selectors = [
+        ".pagination .next:not(.disabled)",
+        ".paging a.next",
+        "a[rel='next']",
+        ".pagination-next:not(.disabled)",
+    ];
+
+    for selector_str in &next_selectors {
+        if let Ok(selector) = Selector::parse(selector_str) {
+            if document.select(&selector).next().is_some() {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Extract current page number from pagination.
+pub(crate) fn extract_current_page(document: &Html) -> Option<u32> {
+    let selectors = [
+        ".pagination .active",
+        ".paging .current",
+        ".pagination-current",
+    ];
+
+    for selector_str in &selectors {
+        if let Ok(selector) = Selector::parse(selector_str) {
+            if let Some(el) = document.select(&selector).next() {
+                let text = el.text().collect::<String>();
+                if let Ok(page) = text.trim().parse::<u32>() {
+                    return Some(page);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_path() {
+        assert_eq!(page_path("/film/123/galerie/", 0), "/film/123/galerie/");
+        assert_eq!(page_path("/film/123/galerie/", 1), "/film/123/galerie/");
+        assert_eq!(
+            page_path("/film/123/galerie/", 4),
+            "/film/123/galerie/?page=4"
+        );
+        assert_eq!(page_path("/hledat/?q=dr", 2), "/hledat/?q=dr&page=2");
+    }
+
+    #[test]
+    fn test_has_next_page() {
+        let html = r#"
+            <div class="pagination">
+                <span class="active">1</span>
+                <a href="?page=2">2</a>
+                <a class="next" href="?page=2">Další</a>
+            </div>
+        "#;
+        assert!(has_next_page(html));
+
+        let last = r#"<div class="pagination"><span class="next disabled">Další</span></div>"#;
+        assert!(!has_next_page(last));
+    }
+
+    #[test]
+    fn test_extract_current_page() {
+        let html =
+            r#"<div class="pagination"><a href="?page=1">1</a><span class="active">2</span></div>"#;
+        assert_eq!(extract_current_page(&Html::parse_document(html)), Some(2));
+        assert_eq!(extract_current_page(&Html::parse_document("<p></p>")), None);
+    }
+}
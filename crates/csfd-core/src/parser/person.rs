@@ -0,0 +1,269 @@
+//! Person parser for ČSFD.cz
+//!
+//! Parses creator pages (`/tvurce/`): the profile header with name, birth
+//! line ("nar. 11.06.1959 Oxford, Velká Británie") and photo, the biography
+//! and the filmography tables, which ČSFD lists in one box per role.
+
+use scraper::{ElementRef, Html};
+
+use crate::error::{CsfdError, Result};
+use crate::types::{FilmographyEntry, FilmographyGroup, PersonDetail, PersonRole};
+
+use super::episodes::extract_episode_id;
+use super::premieres::parse_czech_date;
+use super::search::{extract_creator_id, extract_csfd_id};
+use super::series::normalize_image_url;
+use super::{canonical_url, element_text};
+
+/// Parse a person from a ČSFD creator page.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the creator page
+/// * `csfd_id` - The creator ID of the person (used in the result)
+///
+/// # Returns
+/// * `Ok(PersonDetail)` with parsed profile and filmography
+/// * `Err(CsfdError)` if parsing fails
+pub fn parse_person(html: &str, csfd_id: u32) -> Result<PersonDetail> {
+    let document = Html::parse_document(html);
+    parse_person_document(&document, csfd_id)
+}
+
+/// Parse a person, first checking that the page is the requested one.
+///
+/// # Returns
+/// * `Ok(PersonDetail)` with parsed profile and filmography
+/// * `Err(CsfdError::UnexpectedPage)` if the page belongs to someone else
+/// * `Err(CsfdError)` if parsing fails
+pub fn parse_person_verified(html: &str, csfd_id: u32) -> Result<PersonDetail> {
+    let document = Html::parse_document(html);
+    match canonical_url(&document).and_then(extract_creator_id) {
+        Some(page_id) if page_id != csfd_id => Err(CsfdError::UnexpectedPage(format!(
+            "requested creator {}, page is creator {}",
+            csfd_id, page_id
+        ))),
+        _ => parse_person_document(&document, csfd_id),
+    }
+}
+
+/// Parse a person from an already parsed document.
+fn parse_person_document(document: &Html, csfd_id: u32) -> Result<PersonDetail> {
+    let name = document
+        .select(selector!(
+            ".creator-profile h1, .creator-profile-content h1, h1"
+        ))
+        .map(|el| element_text(&el).into_owned())
+        .find(|text| !text.is_empty())
+        .ok_or_else(|| CsfdError::ElementNotFound("person name".to_string()))?;
+
+    let (birth_date, birth_place) = document
+        .select(selector!(
+            ".creator-profile-details p, .creator-profile-content p"
+        ))
+        .find_map(|el| parse_birth_line(&element_text(&el)))
+        .unwrap_or_default();
+
+    let photo_url = document
+        .select(selector!(
+            ".creator-profile img, .creator-profile-photo img"
+        ))
+        .next()
+        .and_then(|img| {
+            img.value()
+                .attr("data-src")
+                .or_else(|| img.value().attr("src"))
+        })
+        .and_then(normalize_image_url);
+
+    let paragraphs: Vec<String> = document
+        .select(selector!(
+            ".creator-about .article-content p, .creator-biography p, .biography p"
+        ))
+        .map(|el| element_text(&el).into_owned())
+        .filter(|text| !text.is_empty())
+        .collect();
+    let biography = (!paragraphs.is_empty()).then(|| paragraphs.join("\n\n"));
+
+    Ok(PersonDetail {
+        csfd_id,
+        name,
+        biography,
+        birth_date,
+        birth_place,
+        photo_url,
+        filmography: extract_filmography(document),
+    })
+}
+
+/// Parse "nar. 11.06.1959 Oxford, Velká Británie" into date and place.
+fn parse_birth_line(text: &str) -> Option<(Option<String>, Option<String>)> {
+    let caps = regex!(r"nar\.\s*(\d{1,2}\.\s*\d{1,2}\.\s*\d{4})\s*(.*)").captures(text)?;
+    let place = caps[2]
+        .lines()
+        .next()
+        .map(|line| line.trim().trim_end_matches(',').trim())
+        .filter(|line| !line.is_empty())
+        .map(String::from);
+    Some((parse_czech_date(&caps[1]), place))
+}
+
+/// Collect the filmography boxes, one group per role heading.
+fn extract_filmography(document: &Html) -> Vec<FilmographyGroup> {
+    let mut groups: Vec<FilmographyGroup> = Vec::new();
+    for section in document.select(selector!(".creator-filmography section, section.box")) {
+        let Some(heading) = section.select(selector!("h2, h3.box-header")).next() else {
+            continue;
+        };
+        let titles = extract_filmography_titles(&section);
+        if titles.is_empty() {
+            continue;
+        }
+
+        let role = PersonRole::from_heading(&element_text(&heading));
+        match groups.iter_mut().find(|g| g.role == role) {
+            Some(group) => group.titles.extend(titles),
+            None => groups.push(FilmographyGroup { role, titles }),
+        }
+    }
+    groups
+}
+
+/// Titles of one filmography table.
+///
+/// ČSFD prints the year only on the first row of each year, so it carries
+/// over to the following rows.
+fn extract_filmography_titles(section: &ElementRef) -> Vec<FilmographyEntry> {
+    let mut titles = Vec::new();
+    let mut year = None;
+    for row in section.select(selector!("tr")) {
+        if let Some(cell) = row.select(selector!("td.year, th.year")).next() {
+            if let Ok(row_year) = element_text(&cell).parse() {
+                year = Some(row_year);
+            }
+        }
+
+        let Some(link) = row
+            .select(selector!("a.film-title-name, a[href*='/film/']"))
+            .next()
+        else {
+            continue;
+        };
+        let Some(url) = link.value().attr("href") else {
+            continue;
+        };
+        // Episode links carry the series ID first and the episode ID second
+        let Some(csfd_id) = extract_episode_id(url).or_else(|| extract_csfd_id(url)) else {
+            continue;
+        };
+        titles.push(FilmographyEntry {
+            csfd_id,
+            name: element_text(&link).into_owned(),
+            year,
+            url: url.to_string(),
+        });
+    }
+    titles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PERSON_HTML: &str = r#"
+        <html>
+            <head><link rel="canonical" href="https://www.csfd.cz/tvurce/3101-vince-gilligan/prehled/"></head>
+            <body>
+                <div class="creator-profile">
+                    <img src="//image.pmgstatic.com/files/images/creator/photos/3101.jpg" alt="">
+                    <div class="creator-profile-content">
+                        <h1>Vince Gilligan</h1>
+                        <div class="creator-profile-details">
+                            <p>nar. 10.02.1967 Richmond, Virginie, USA</p>
+                        </div>
+                    </div>
+                </div>
+                <div class="creator-about">
+                    <div class="article-content">
+                        <p>Americký scenárista a režisér.</p>
+                        <p>Proslavil se seriálem Perníkový táta.</p>
+                    </div>
+                </div>
+                <div class="creator-filmography">
+                    <section class="box">
+                        <header class="box-header"><h2>Režie</h2></header>
+                        <table>
+                            <tr><td class="year">2013</td><td><a href="/film/264494-pernikovy-tata/1028393-felina/" class="film-title-name">Felina</a></td></tr>
+                            <tr><td class="year"></td><td><a href="/film/264494-pernikovy-tata/" class="film-title-name">Perníkový táta</a></td></tr>
+                        </table>
+                    </section>
+                    <section class="box">
+                        <header class="box-header"><h2>Scénář</h2></header>
+                        <table>
+                            <tr><td class="year">2022</td><td><a href="/film/1012341-better-call-saul/" class="film-title-name">Better Call Saul</a></td></tr>
+                        </table>
+                    </section>
+                    <section class="box">
+                        <header class="box-header"><h2>Producent</h2></header>
+                        <table>
+                            <tr><td class="year">2019</td><td><a href="/film/658003-el-camino/" class="film-title-name">El Camino</a></td></tr>
+                        </table>
+                    </section>
+                </div>
+            </body>
+        </html>
+    "#;
+
+    #[test]
+    fn test_parse_person() {
+        let person = parse_person_verified(PERSON_HTML, 3101).unwrap();
+        assert_eq!(person.name, "Vince Gilligan");
+        assert_eq!(person.birth_date.as_deref(), Some("1967-02-10"));
+        assert_eq!(
+            person.birth_place.as_deref(),
+            Some("Richmond, Virginie, USA")
+        );
+        assert_eq!(
+            person.photo_url.as_deref(),
+            Some("https://image.pmgstatic.com/files/images/creator/photos/3101.jpg")
+        );
+        assert_eq!(
+            person.biography.as_deref(),
+            Some("Americký scenárista a režisér.\n\nProslavil se seriálem Perníkový táta.")
+        );
+
+        let roles: Vec<&PersonRole> = person.filmography.iter().map(|g| &g.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                &PersonRole::Director,
+                &PersonRole::Writer,
+                &PersonRole::Other("Producent".into())
+            ]
+        );
+        let directed = &person.filmography[0].titles;
+        assert_eq!(directed.len(), 2);
+        assert_eq!(directed[0].csfd_id, 1028393);
+        assert_eq!(directed[1].csfd_id, 264494);
+        assert_eq!(directed[1].name, "Perníkový táta");
+        assert_eq!(directed[1].year, Some(2013));
+    }
+
+    #[test]
+    fn test_parse_person_wrong_page() {
+        assert!(matches!(
+            parse_person_verified(PERSON_HTML, 1),
+            Err(CsfdError::UnexpectedPage(_))
+        ));
+    }
+
+    #[test]
+    fn test_person_role_from_heading() {
+        assert_eq!(PersonRole::from_heading("Herečka:"), PersonRole::Actor);
+        assert_eq!(PersonRole::from_heading(" Režie "), PersonRole::Director);
+        assert_eq!(PersonRole::from_heading("Scénář"), PersonRole::Writer);
+        assert_eq!(
+            PersonRole::from_heading("Hudba"),
+            PersonRole::Other("Hudba".into())
+        );
+    }
+}
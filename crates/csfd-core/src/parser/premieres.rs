@@ -0,0 +1,105 @@
+//! Premiere overview parser for ČSFD.cz
+//!
+//! Parses the upcoming premiere overviews (cinema, TV, VOD), where title
+//! entries are listed under date headings.
+
+use scraper::Html;
+
+use crate::types::SearchResult;
+
+use super::element_text;
+use super::search::parse_search_item;
+
+/// Parse a premiere overview into (date, title) pairs.
+///
+/// Titles are attributed to the nearest preceding heading containing a date
+/// like "čtvrtek 7.3.2024"; titles before the first date heading are skipped.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the premiere overview
+///
+/// # Returns
+/// Pairs of ISO date (YYYY-MM-DD) and title, in page order
+pub fn parse_premieres(html: &str) -> Vec<(String, SearchResult)> {
+    let document = Html::parse_document(html);
+    let mut current_date: Option<String> = None;
+    let mut premieres = Vec::new();
+
+    for element in document.select(selector!("h2, h3, h4, .box-header, .date, article")) {
+        if element.value().name() == "article" {
+            if let (Some(date), Some(title)) = (&current_date, parse_search_item(&element)) {
+                premieres.push((date.clone(), title));
+            }
+        } else if let Some(date) = parse_czech_date(&element_text(&element)) {
+            current_date = Some(date);
+        }
+    }
+
+    premieres
+}
+
+/// Parse a Czech date ("7.3.2024", "7. 3. 2024") into ISO format.
+pub(super) fn parse_czech_date(text: &str) -> Option<String> {
+    let caps = regex!(r"\b(\d{1,2})\.\s*(\d{1,2})\.\s*(\d{4})\b").captures(text)?;
+    let day: u8 = caps[1].parse().ok()?;
+    let month: u8 = caps[2].parse().ok()?;
+    if !(1..=31).contains(&day) || !(1..=12).contains(&month) {
+        return None;
+    }
+    Some(format!("{}-{:02}-{:02}", &caps[3], month, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_czech_date() {
+        assert_eq!(
+            parse_czech_date("čtvrtek 7.3.2024"),
+            Some("2024-03-07".to_string())
+        );
+        assert_eq!(
+            parse_czech_date("7. 3. 2024"),
+            Some("2024-03-07".to_string())
+        );
+        assert_eq!(parse_czech_date("32.1.2024"), None);
+        assert_eq!(parse_czech_date("Perníkový táta (2008)"), None);
+    }
+
+    #[test]
+    fn test_parse_premieres() {
+        let html = r#"
+            <html><body>
+                <article class="article-poster-50">
+                    <h3><a href="/film/1-bez-data/" class="film-title-name">Bez data</a></h3>
+                </article>
+                <div class="box-header"><h2>čtvrtek 7.3.2024</h2></div>
+                <article class="article-poster-50">
+                    <h3><a href="/film/100-duna/" class="film-title-name">Duna: Část druhá</a>
+                    <span class="film-title-info"><span class="info">(2024)</span></span></h3>
+                </article>
+                <article class="article-poster-50">
+                    <h3><a href="/film/200-kung-fu-panda/" class="film-title-name">Kung Fu Panda 4</a></h3>
+                </article>
+                <h2>čtvrtek 14. 3. 2024</h2>
+                <article class="article-poster-50">
+                    <h3><a href="/film/300-godzilla/" class="film-title-name">Godzilla x Kong</a></h3>
+                </article>
+            </body></html>
+        "#;
+        let premieres = parse_premieres(html);
+        let summary: Vec<(&str, u32)> = premieres
+            .iter()
+            .map(|(date, title)| (date.as_str(), title.csfd_id))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("2024-03-07", 100),
+                ("2024-03-07", 200),
+                ("2024-03-14", 300)
+            ]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! User ratings parser for ČSFD.cz
+//!
+//! Parses the ratings tab of a user profile (`/uzivatel/{id}-{nick}/hodnoceni/`).
+//! Each row links the rated title, shows its year next to the name, the
+//! stars the user gave (`.stars-4`, or `.trash` for "odpad") and the date.
+
+use scraper::{ElementRef, Html};
+
+use crate::error::{CsfdError, Result};
+use crate::types::{PaginatedResult, UserRating};
+
+use super::element_text;
+use super::pagination::{detect_next_page, extract_current_page};
+use super::premieres::parse_czech_date;
+use super::reviews::extract_stars;
+use super::search::extract_csfd_id;
+
+/// Parse one page of a user's ratings.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the ratings page
+///
+/// # Returns
+/// * `Ok(PaginatedResult<UserRating>)` with ratings in page order (newest first)
+/// * `Err(CsfdError::ParseError)` if the page has no ratings section
+pub fn parse_user_ratings(html: &str) -> Result<PaginatedResult<UserRating>> {
+    let document = Html::parse_document(html);
+
+    let items: Vec<UserRating> = document
+        .select(selector!(".box-user-rating tr, .user-ratings tr, table tr"))
+        .filter_map(|row| parse_rating_row(&row))
+        .collect();
+
+    if items.is_empty()
+        && document
+            .select(selector!(".box-user-rating, .user-ratings, .box-content"))
+            .next()
+            .is_none()
+    {
+        return Err(CsfdError::ParseError(
+            "no ratings section found - markup may have changed".to_string(),
+        ));
+    }
+
+    let current_page = extract_current_page(&document).unwrap_or(1);
+    Ok(PaginatedResult::new(
+        items,
+        current_page,
+        detect_next_page(&document),
+    ))
+}
+
+/// Parse one row; `None` if it has no title link or no stars.
+fn parse_rating_row(row: &ElementRef) -> Option<UserRating> {
+    let link = row
+        .select(selector!("a.film-title-name, a[href*='/film/']"))
+        .next()?;
+    let url = link.value().attr("href")?;
+    let csfd_id = extract_csfd_id(url)?;
+    let name = element_text(&link);
+    if name.is_empty() {
+        return None;
+    }
+
+    let year = row
+        .select(selector!(".film-title-info .info, .film-title-info"))
+        .find_map(|info| {
+            regex!(r"\b(\d{4})\b")
+                .captures(&element_text(&info))
+                .and_then(|caps| caps[1].parse().ok())
+        });
+
+    let date = row
+        .select(selector!(".date-only, .date, time"))
+        .find_map(|el| parse_czech_date(&element_text(&el)));
+
+    Some(UserRating {
+        csfd_id,
+        name: name.into_owned(),
+        year,
+        url: url.to_string(),
+        rating: extract_stars(row)?,
+        date,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_ratings() {
+        let html = r#"
+            <html><body>
+                <section class="box box-user-rating">
+                    <table>
+                        <tr>
+                            <td class="name">
+                                <a href="/film/10135-forrest-gump/" class="film-title-name">Forrest Gump</a>
+                                <span class="film-title-info"><span class="info">(1994)</span></span>
+                            </td>
+                            <td class="star-rating-only"><span class="stars stars-5"></span></td>
+                            <td class="date-only">19.11.2023</td>
+                        </tr>
+                        <tr>
+                            <td class="name">
+                                <a href="/film/71924-pernikovy-tata/" class="film-title-name">Perníkový táta</a>
+                                <span class="film-title-info"><span class="info">(2008)</span> <span class="info">(seriál)</span></span>
+                            </td>
+                            <td class="star-rating-only"><span class="stars trash"></span></td>
+                            <td class="date-only">2.1.2023</td>
+                        </tr>
+                        <tr>
+                            <td class="name"><a href="/film/1-bez-hodnoceni/" class="film-title-name">Bez hodnocení</a></td>
+                        </tr>
+                    </table>
+                </section>
+                <div class="pagination"><a class="next" href="?page=2">další</a></div>
+            </body></html>
+        "#;
+        let result = parse_user_ratings(html).unwrap();
+        assert_eq!(result.items.len(), 2);
+        assert!(result.has_next_page);
+
+        let first = &result.items[0];
+        assert_eq!(first.csfd_id, 10135);
+        assert_eq!(first.name, "Forrest Gump");
+        assert_eq!(first.year, Some(1994));
+        assert_eq!(first.rating.stars(), 5);
+        assert_eq!(first.date.as_deref(), Some("2023-11-19"));
+
+        let trash = &result.items[1];
+        assert_eq!(trash.year, Some(2008));
+        assert_eq!(trash.rating.stars(), 0);
+        assert_eq!(trash.date.as_deref(), Some("2023-01-02"));
+    }
+
+    #[test]
+    fn test_parse_user_ratings_no_section() {
+        assert!(parse_user_ratings("<html><body><h1>Chyba</h1></body></html>").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+//! Reviews parser for ČSFD.cz
+//!
+//! Parses the reviews tab of a title (`/recenze/`). Each review is an
+//! article with the author link, a star rating (`.stars-4`, or `.trash`
+//! for "odpad"), the date and the text.
+
+use scraper::{ElementRef, Html};
+
+use crate::error::{CsfdError, Result};
+use crate::types::{PaginatedResult, Review, UserStarRating};
+
+use super::element_text;
+use super::pagination::{detect_next_page, extract_current_page};
+use super::premieres::parse_czech_date;
+
+/// Parse user reviews from a ČSFD reviews tab.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the reviews page
+///
+/// # Returns
+/// * `Ok(PaginatedResult<Review>)` with reviews in page order
+/// * `Err(CsfdError::ParseError)` if the page has no reviews section
+pub fn parse_reviews(html: &str) -> Result<PaginatedResult<Review>> {
+    let document = Html::parse_document(html);
+
+    let items: Vec<Review> = document
+        .select(selector!(
+            "article.article, .box-reviews article, .reviews article"
+        ))
+        .filter_map(|article| parse_review(&article))
+        .collect();
+
+    if items.is_empty()
+        && document
+            .select(selector!(".box-reviews, .reviews, .box-content"))
+            .next()
+            .is_none()
+    {
+        return Err(CsfdError::ParseError(
+            "no reviews section found - markup may have changed".to_string(),
+        ));
+    }
+
+    let current_page = extract_current_page(&document).unwrap_or(1);
+    Ok(PaginatedResult::new(
+        items,
+        current_page,
+        detect_next_page(&document),
+    ))
+}
+
+/// Parse one review article; `None` if it has no author or text.
+fn parse_review(article: &ElementRef) -> Option<Review> {
+    let author = article
+        .select(selector!(".user-title-name, a[href*='/uzivatel/']"))
+        .map(|el| element_text(&el).into_owned())
+        .find(|name| !name.is_empty())?;
+
+    let text = article
+        .select(selector!(".comment, .review-text, .article-content p"))
+        .map(|el| element_text(&el).into_owned())
+        .find(|text| !text.is_empty())?;
+
+    let date = article
+        .select(selector!("time, .article-header-date, .date"))
+        .find_map(|el| {
+            el.value()
+                .attr("datetime")
+                .and_then(parse_iso_date)
+                .or_else(|| parse_czech_date(&element_text(&el)))
+        });
+
+    Some(Review {
+        author,
+        rating: extract_stars(article),
+        date,
+        text,
+    })
+}
+
+/// Read the star count from classes like `stars stars-4`, or 0 for `trash`.
+pub(super) fn extract_stars(article: &ElementRef) -> Option<UserStarRating> {
+    let stars = article
+        .select(selector!(".star-rating .stars, .stars"))
+        .next()?;
+    if stars
+        .value()
+        .has_class("trash", scraper::CaseSensitivity::CaseSensitive)
+    {
+        return UserStarRating::new(0);
+    }
+    stars
+        .value()
+        .classes()
+        .find_map(|class| class.strip_prefix("stars-")?.parse().ok())
+        .and_then(UserStarRating::new)
+}
+
+/// Take the date part of a `datetime` attribute ("2021-03-14" or "2021-03-14T20:15").
+fn parse_iso_date(value: &str) -> Option<String> {
+    regex!(r"^(\d{4}-\d{2}-\d{2})")
+        .captures(value)
+        .map(|caps| caps[1].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REVIEWS_HTML: &str = r#"
+        <html><body>
+            <section class="box box-reviews">
+                <div class="box-content">
+                    <article class="article article-white">
+                        <header class="article-header">
+                            <a href="/uzivatel/2544-verbal/" class="user-title-name">verbal</a>
+                            <span class="star-rating"><span class="stars stars-5"></span></span>
+                            <span class="article-header-date"><time datetime="2021-03-14">14.03.2021</time></span>
+                        </header>
+                        <div class="article-content"><p class="comment">Nejlepší seriál všech dob.</p></div>
+                    </article>
+                    <article class="article article-white">
+                        <header class="article-header">
+                            <a href="/uzivatel/1234-kritik/" class="user-title-name">kritik</a>
+                            <span class="star-rating"><span class="stars trash"></span></span>
+                            <span class="article-header-date">2.1.2020</span>
+                        </header>
+                        <div class="article-content"><p class="comment">Nuda.</p></div>
+                    </article>
+                    <article class="article article-white">
+                        <header class="article-header">
+                            <a href="/uzivatel/999-tichy/" class="user-title-name">tichy</a>
+                        </header>
+                        <div class="article-content"><p class="comment">Bez hodnocení.</p></div>
+                    </article>
+                </div>
+            </section>
+            <div class="pagination"><span class="active">1</span><a class="next" href="?page=2">Další</a></div>
+        </body></html>
+    "#;
+
+    #[test]
+    fn test_parse_reviews() {
+        let result = parse_reviews(REVIEWS_HTML).unwrap();
+        assert_eq!(result.current_page, 1);
+        assert!(result.has_next_page);
+        assert_eq!(result.items.len(), 3);
+
+        let first = &result.items[0];
+        assert_eq!(first.author, "verbal");
+        assert_eq!(first.rating, UserStarRating::new(5));
+        assert_eq!(first.date.as_deref(), Some("2021-03-14"));
+        assert_eq!(first.text, "Nejlepší seriál všech dob.");
+
+        assert_eq!(result.items[1].rating, UserStarRating::new(0));
+        assert_eq!(result.items[1].date.as_deref(), Some("2020-01-02"));
+        assert_eq!(result.items[2].rating, None);
+        assert_eq!(result.items[2].date, None);
+    }
+
+    #[test]
+    fn test_parse_reviews_unexpected_markup() {
+        assert!(parse_reviews("<html><body><p>Jiná stránka</p></body></html>").is_err());
+    }
+}
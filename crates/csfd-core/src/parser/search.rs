@@ -7,6 +7,9 @@ use scraper::{Html, Selector};
 use crate::error::{CsfdError, Result};
 use crate::types::{PaginatedResult, SearchResult, SeriesType};
 
+use super::element_text;
+use super::pagination::{detect_next_page, extract_current_page};
+
 /// Extract CSFD ID from a URL path.
 ///
 /// Parses URLs in formats:
@@ -23,7 +26,7 @@ use crate::types::{PaginatedResult, SearchResult, SeriesType};
 ///
 /// # Examples
 /// ```
-/// use csfd_core::parser::extract_csfd_id;
+/// use csfd_core::unstable::parser::extract_csfd_id;
 ///
 /// assert_eq!(extract_csfd_id("/film/12345-breaking-bad/"), Some(12345));
 /// assert_eq!(extract_csfd_id("/film/999-test/prehled/"), Some(999));
@@ -32,20 +35,20 @@ use crate::types::{PaginatedResult, SearchResult, SeriesType};
 pub fn extract_csfd_id(url: &str) -> Option<u32> {
     // Look for pattern: /film/{id}-{slug}/ or similar
     // The ID is always the first numeric part after /film/
-    
+
     // Find the /film/ prefix
     let film_idx = url.find("/film/")?;
     let after_film = &url[film_idx + 6..]; // Skip "/film/"
-    
+
     // Find the first segment (up to next / or end)
     let segment = after_film.split('/').next()?;
-    
+
     // Extract the numeric prefix before the first dash
     let id_str = segment.split('-').next()?;
-    
+
     // Parse as u32, must be positive (non-zero)
     let id: u32 = id_str.parse().ok()?;
-    
+
     if id > 0 {
         Some(id)
     } else {
@@ -53,67 +56,148 @@ pub fn extract_csfd_id(url: &str) -> Option<u32> {
     }
 }
 
+/// Extract creator ID from a `/tvurce/` URL path.
+///
+/// Parses URLs in formats:
+/// - `/tvurce/{id}-{slug}/` -> Some(id)
+/// - `/tvurce/{id}-{slug}/prehled/` -> Some(id)
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::extract_creator_id;
+///
+/// assert_eq!(extract_creator_id("/tvurce/3101-vince-gilligan/"), Some(3101));
+/// assert_eq!(extract_creator_id("/film/12345-breaking-bad/"), None);
+/// ```
+pub fn extract_creator_id(url: &str) -> Option<u32> {
+    let creator_idx = url.find("/tvurce/")?;
+    let after_creator = &url[creator_idx + 8..]; // Skip "/tvurce/"
+
+    let segment = after_creator.split('/').next()?;
+    let id: u32 = segment.split('-').next()?.parse().ok()?;
+
+    if id > 0 {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+/// Extract the advanced search filter ID of a genre from its link.
+///
+/// Parses URLs in formats:
+/// - `/zanr/{id}-{slug}/` -> Some(id)
+/// - `/podrobne-vyhledavani/?genre[include][0]={id}`, also URL-encoded -> Some(id)
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::extract_genre_id;
+///
+/// assert_eq!(extract_genre_id("/zanr/5-krimi/"), Some(5));
+/// assert_eq!(extract_genre_id("/podrobne-vyhledavani/?genre%5Binclude%5D%5B0%5D=2"), Some(2));
+/// assert_eq!(extract_genre_id("/zanr/drama/"), None);
+/// ```
+pub fn extract_genre_id(url: &str) -> Option<u32> {
+    let caps = regex!(r"/zanr/(\d+)(?:[-/]|$)|[?&]genre[^=&]*=(\d+)").captures(url)?;
+    let id: u32 = caps.get(1).or_else(|| caps.get(2))?.as_str().parse().ok()?;
+    (id > 0).then_some(id)
+}
+
 /// Parse search results from ČSFD search page HTML.
 ///
 /// # Arguments
 /// * `html` - Raw HTML content of the search results page
 ///
+/// A page without hits is only accepted when it carries ČSFD's "no results"
+/// message; the result then has `no_results` set. A page where no result
+/// could be parsed and no such message is found most likely means the
+/// markup changed, and is reported as a parse error.
+///
 /// # Returns
 /// * `Ok(PaginatedResult<SearchResult>)` with parsed results
-/// * `Err(CsfdError)` if parsing fails
+/// * `Err(CsfdError::ParseError)` if no results were found and the page doesn't say so
 pub fn parse_search_results(html: &str) -> Result<PaginatedResult<SearchResult>> {
     let document = Html::parse_document(html);
-    
+
     // ČSFD search results are in article elements with class "article-poster-50"
     // or in older format with "ui-film-list"
-    let results_selector = Selector::parse("article.article-poster-50, .ui-film-list .film-item")
-        .map_err(|e| CsfdError::ParseError(format!("Invalid selector: {:?}", e)))?;
-    
+    let results_selector =
+        Selector::parse("article.article-poster-50, .ui-film-list .film-item")
+            .map_err(|e| CsfdError::ParseError(format!("Invalid selector: {:?}", e)))?;
+
     let mut items = Vec::new();
-    
+
     for element in document.select(&results_selector) {
         if let Some(result) = parse_search_item(&element) {
             items.push(result);
         }
     }
-    
+
     // Check for pagination - look for "next page" link
-    let has_next_page = detect_pagination(&document);
-    
+    let has_next_page = detect_next_page(&document);
+
     // Extract current page from pagination if available
     let current_page = extract_current_page(&document).unwrap_or(1);
-    
+
+    if items.is_empty() {
+        if !is_no_results_page(&document) {
+            return Err(CsfdError::ParseError(
+                "no search results found and no \"no results\" message - markup may have changed"
+                    .to_string(),
+            ));
+        }
+        let mut result = PaginatedResult::empty();
+        result.current_page = current_page;
+        return Ok(result);
+    }
+
     Ok(PaginatedResult::new(items, current_page, has_next_page))
 }
 
+/// Check whether a search page says that nothing was found.
+fn is_no_results_page(document: &Html) -> bool {
+    const MESSAGES: [&str; 3] = ["žádné výsledky", "nebylo nic nalezeno", "nic nenalezeno"];
+
+    let text = document
+        .root_element()
+        .text()
+        .collect::<String>()
+        .to_lowercase();
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    MESSAGES.iter().any(|message| text.contains(message))
+}
+
 /// Parse a single search result item from an HTML element.
-fn parse_search_item(element: &scraper::ElementRef) -> Option<SearchResult> {
+pub(super) fn parse_search_item(element: &scraper::ElementRef) -> Option<SearchResult> {
     // Try to find the link element with the title
     // Current ČSFD uses "a.film-title-name" inside article elements
-    let link_selector = Selector::parse("a.film-title-name, a.name, h3 a, .article-header a").ok()?;
-    let link = element.select(&link_selector).next()?;
-    
+    let link = element
+        .select(selector!(
+            "a.film-title-name, a.name, h3 a, .article-header a"
+        ))
+        .next()?;
+
     // Get the URL from href attribute
     let url = link.value().attr("href")?.to_string();
-    
+
     // Extract CSFD ID from URL
     let csfd_id = extract_csfd_id(&url)?;
-    
+
     // Get the name from link text
-    let name = link.text().collect::<String>().trim().to_string();
+    let name = element_text(&link).into_owned();
     if name.is_empty() {
         return None;
     }
-    
+
     // Try to get original name (usually in parentheses or separate element)
     let original_name = extract_original_name(element);
-    
+
     // Try to get year
     let year = extract_year(element);
-    
+
     // Determine series type
     let series_type = extract_series_type(element);
-    
+
     Some(SearchResult {
         name,
         original_name,
@@ -121,6 +205,8 @@ fn parse_search_item(element: &scraper::ElementRef) -> Option<SearchResult> {
         series_type,
         url,
         csfd_id,
+        local_rank: None,
+        score: None,
     })
 }
 
@@ -128,23 +214,21 @@ fn parse_search_item(element: &scraper::ElementRef) -> Option<SearchResult> {
 fn extract_original_name(element: &scraper::ElementRef) -> Option<String> {
     // Original name is often in a span with class "film-title-info" or similar
     let selectors = [
-        ".film-title-info .info",
-        ".origin-name",
-        ".original-name",
-        ".info span:first-child",
+        selector!(".film-title-info .info"),
+        selector!(".origin-name"),
+        selector!(".original-name"),
+        selector!(".info span:first-child"),
     ];
-    
-    for selector_str in &selectors {
-        if let Ok(selector) = Selector::parse(selector_str) {
-            if let Some(el) = element.select(&selector).next() {
-                let text = el.text().collect::<String>().trim().to_string();
-                if !text.is_empty() && text != "-" {
-                    return Some(text);
-                }
+
+    for selector in selectors {
+        if let Some(el) = element.select(selector).next() {
+            let text = element_text(&el);
+            if !text.is_empty() && text != "-" {
+                return Some(text.into_owned());
             }
         }
     }
-    
+
     None
 }
 
@@ -152,102 +236,59 @@ fn extract_original_name(element: &scraper::ElementRef) -> Option<String> {
 fn extract_year(element: &scraper::ElementRef) -> Option<String> {
     // Year is often in a span with class containing "year" or in parentheses
     let selectors = [
-        ".film-title-info .info",
-        ".year",
-        ".info-year",
-        "span.year",
+        selector!(".film-title-info .info"),
+        selector!(".year"),
+        selector!(".info-year"),
+        selector!("span.year"),
     ];
-    
-    for selector_str in &selectors {
-        if let Ok(selector) = Selector::parse(selector_str) {
-            if let Some(el) = element.select(&selector).next() {
-                let text = el.text().collect::<String>();
-                // Look for year pattern (4 digits)
-                if let Some(year) = extract_year_from_text(&text) {
-                    return Some(year);
-                }
+
+    for selector in selectors {
+        if let Some(el) = element.select(selector).next() {
+            // Look for year pattern (4 digits)
+            if let Some(year) = extract_year_from_text(&element_text(&el)) {
+                return Some(year);
             }
         }
     }
-    
+
     // Also check the full element text for year pattern
-    let full_text = element.text().collect::<String>();
-    extract_year_from_text(&full_text)
+    extract_year_from_text(&element_text(element))
 }
 
 /// Extract year pattern from text (e.g., "2020" or "2020-2023").
 fn extract_year_from_text(text: &str) -> Option<String> {
     // Look for patterns like (2020) or (2020-2023)
-    let re_range = regex_lite::Regex::new(r"\((\d{4}(?:-\d{4})?)\)").ok()?;
+    let re_range = regex!(r"\((\d{4}(?:-\d{4})?)\)");
     if let Some(caps) = re_range.captures(text) {
         return Some(caps.get(1)?.as_str().to_string());
     }
-    
+
     // Look for standalone year
-    let re_year = regex_lite::Regex::new(r"\b((?:19|20)\d{2})\b").ok()?;
+    let re_year = regex!(r"\b((?:19|20)\d{2})\b");
     if let Some(caps) = re_year.captures(text) {
         return Some(caps.get(1)?.as_str().to_string());
     }
-    
+
     None
 }
 
 /// Determine series type from element.
 fn extract_series_type(element: &scraper::ElementRef) -> SeriesType {
     let text = element.text().collect::<String>().to_lowercase();
-    
-    if text.contains("minisérie") || text.contains("miniserie") {
+
+    if text.contains("tv film") {
+        SeriesType::TvFilm
+    } else if text.contains("minisérie") || text.contains("miniserie") {
         SeriesType::MiniSeries
     } else if text.contains("série") && !text.contains("seriál") {
         SeriesType::Season
-    } else {
+    } else if text.contains("seriál") || text.contains("pořad") {
         SeriesType::Series
+    } else {
+        SeriesType::Film
     }
 }
 
-/// Detect if there are more pages of results.
-fn detect_pagination(document: &Html) -> bool {
-    // Look for "next page" link in pagination
-    let next_selectors = [
-        ".pagination .next:not(.disabled)",
-        ".paging a.next",
-        "a[rel='next']",
-        ".pagination-next:not(.disabled)",
-    ];
-    
-    for selector_str in &next_selectors {
-        if let Ok(selector) = Selector::parse(selector_str) {
-            if document.select(&selector).next().is_some() {
-                return true;
-            }
-        }
-    }
-    
-    false
-}
-
-/// Extract current page number from pagination.
-fn extract_current_page(document: &Html) -> Option<u32> {
-    let selectors = [
-        ".pagination .active",
-        ".paging .current",
-        ".pagination-current",
-    ];
-    
-    for selector_str in &selectors {
-        if let Ok(selector) = Selector::parse(selector_str) {
-            if let Some(el) = document.select(&selector).next() {
-                let text = el.text().collect::<String>();
-                if let Ok(page) = text.trim().parse::<u32>() {
-                    return Some(page);
-                }
-            }
-        }
-    }
-    
-    None
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,8 +302,14 @@ mod tests {
 
     #[test]
     fn test_extract_csfd_id_with_subpath() {
-        assert_eq!(extract_csfd_id("/film/12345-breaking-bad/prehled/"), Some(12345));
-        assert_eq!(extract_csfd_id("/film/12345-breaking-bad/456-season-1/"), Some(12345));
+        assert_eq!(
+            extract_csfd_id("/film/12345-breaking-bad/prehled/"),
+            Some(12345)
+        );
+        assert_eq!(
+            extract_csfd_id("/film/12345-breaking-bad/456-season-1/"),
+            Some(12345)
+        );
     }
 
     #[test]
@@ -282,18 +329,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_creator_id() {
+        assert_eq!(
+            extract_creator_id("/tvurce/3101-vince-gilligan/"),
+            Some(3101)
+        );
+        assert_eq!(
+            extract_creator_id("/tvurce/3101-vince-gilligan/prehled/"),
+            Some(3101)
+        );
+        assert_eq!(
+            extract_creator_id("https://www.csfd.cz/tvurce/2-bryan-cranston/"),
+            Some(2)
+        );
+        assert_eq!(extract_creator_id("/tvurce/0-nobody/"), None);
+        assert_eq!(extract_creator_id("/film/12345-breaking-bad/"), None);
+    }
+
     #[test]
     fn test_extract_year_from_text() {
         assert_eq!(extract_year_from_text("(2020)"), Some("2020".to_string()));
-        assert_eq!(extract_year_from_text("(2020-2023)"), Some("2020-2023".to_string()));
-        assert_eq!(extract_year_from_text("Some text 2020 more"), Some("2020".to_string()));
+        assert_eq!(
+            extract_year_from_text("(2020-2023)"),
+            Some("2020-2023".to_string())
+        );
+        assert_eq!(
+            extract_year_from_text("Some text 2020 more"),
+            Some("2020".to_string())
+        );
         assert_eq!(extract_year_from_text("no year here"), None);
     }
 
     #[test]
-    fn test_parse_empty_html() {
-        let result = parse_search_results("<html><body></body></html>").unwrap();
+    fn test_parse_series_and_films() {
+        let html = r#"
+            <html><body>
+                <article class="article-poster-50">
+                    <a class="film-title-name" href="/film/264494-pernikovy-tata/">Perníkový táta</a>
+                    <span class="info">(2008) (seriál)</span>
+                </article>
+                <article class="article-poster-50">
+                    <a class="film-title-name" href="/film/10135-forrest-gump/">Forrest Gump</a>
+                    <span class="info">(1994)</span>
+                </article>
+            </body></html>
+        "#;
+        let result = parse_search_results(html).unwrap();
+        assert_eq!(result.items[0].series_type, SeriesType::Series);
+        assert_eq!(result.items[1].series_type, SeriesType::Film);
+    }
+
+    #[test]
+    fn test_parse_empty_html_is_error() {
+        let result = parse_search_results("<html><body></body></html>");
+        assert!(matches!(result, Err(CsfdError::ParseError(_))));
+    }
+
+    #[test]
+    fn test_parse_no_results_message() {
+        let html = r#"
+            <html><body>
+                <div class="box-content"><p>Nebyly nalezeny
+                    žádné výsledky.</p></div>
+            </body></html>
+        "#;
+        let result = parse_search_results(html).unwrap();
         assert!(result.items.is_empty());
+        assert!(result.no_results);
         assert_eq!(result.current_page, 1);
         assert!(!result.has_next_page);
     }
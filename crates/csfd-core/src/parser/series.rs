@@ -1,13 +1,21 @@
 //! Series detail parser for ČSFD.cz
 //!
 //! Parses HTML from series detail pages to extract series information and seasons.
+//! Fields the page's JSON-LD describes are read from it first, see `structured`.
 
 use scraper::{Html, Selector};
 
 use crate::error::{CsfdError, Result};
-use crate::types::{Season, SeriesDetail};
+use crate::types::{
+    Availability, CastMember, DetailAvailability, Label, OriginLine, SearchResult, Season,
+    SeriesDetail,
+};
 
-use super::search::extract_csfd_id;
+use super::episodes::parse_rating;
+use super::origin::{extract_origin_line, parse_origin_line};
+use super::search::{extract_creator_id, extract_csfd_id, extract_genre_id, parse_search_item};
+use super::structured::extract_structured_data;
+use super::{canonical_url, element_text};
 
 /// Parse series detail from ČSFD series page HTML.
 ///
@@ -20,39 +28,335 @@ use super::search::extract_csfd_id;
 /// * `Err(CsfdError)` if parsing fails
 pub fn parse_series_detail(html: &str, csfd_id: u32) -> Result<SeriesDetail> {
     let document = Html::parse_document(html);
-    
+    parse_detail_document(&document, csfd_id)
+}
+
+/// Parse series detail, first checking that the page is the requested one.
+///
+/// Error and interstitial pages still contain enough markup to parse into
+/// a `SeriesDetail` full of garbage. This rejects pages whose canonical
+/// link points to a different ID or whose title is the generic site name.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the series detail page
+/// * `csfd_id` - The CSFD ID that was requested
+///
+/// # Returns
+/// * `Ok(SeriesDetail)` with parsed series information
+/// * `Err(CsfdError::UnexpectedPage)` if the page belongs to something else
+/// * `Err(CsfdError)` if parsing fails
+pub fn parse_series_detail_verified(html: &str, csfd_id: u32) -> Result<SeriesDetail> {
+    let document = Html::parse_document(html);
+    verify_canonical_id(&document, csfd_id)?;
+    let detail = parse_detail_document(&document, csfd_id)?;
+    verify_title_name(&detail.name, csfd_id)?;
+    Ok(detail)
+}
+
+/// Reject a page whose canonical link points to a different title ID.
+pub(super) fn verify_canonical_id(document: &Html, csfd_id: u32) -> Result<()> {
+    match canonical_url(document).and_then(extract_csfd_id) {
+        Some(page_id) if page_id != csfd_id => Err(CsfdError::UnexpectedPage(format!(
+            "requested ID {}, page is ID {}",
+            csfd_id, page_id
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Reject a title name that is the site name rather than a title.
+pub(super) fn verify_title_name(name: &str, csfd_id: u32) -> Result<()> {
+    let generic = name.trim().to_lowercase();
+    if matches!(
+        generic.as_str(),
+        "čsfd.cz" | "csfd.cz" | "čsfd" | "csfd" | "česko-slovenská filmová databáze"
+    ) {
+        return Err(CsfdError::UnexpectedPage(format!(
+            "generic title \"{}\" for ID {}",
+            name, csfd_id
+        )));
+    }
+    Ok(())
+}
+
+/// Parse series detail from an already parsed document.
+fn parse_detail_document(document: &Html, csfd_id: u32) -> Result<SeriesDetail> {
+    let structured = extract_structured_data(document).unwrap_or_default();
+
     // Extract series name
-    let name = extract_series_name(&document)
+    let name = structured
+        .name
+        .clone()
+        .or_else(|| extract_series_name(document))
         .ok_or_else(|| CsfdError::ElementNotFound("series name".to_string()))?;
-    
+
     // Extract original name (optional)
-    let original_name = extract_original_name(&document);
-    
-    // Extract year range (optional)
-    let year_range = extract_year_range(&document);
-    
+    let original_name = extract_original_name(document);
+
+    // Countries, years and episode count share one line under the name
+    let origin = extract_origin_line(document).unwrap_or_default();
+
+    // Extract year range (optional); JSON-LD only has the first year
+    let year_range =
+        extract_year_range(document, &origin).or_else(|| structured.year.map(|y| y.to_string()));
+
+    // Extract status label (optional), e.g. "ukončený"
+    let status_label = extract_status_label(document);
+
     // Extract genres
-    let genres = extract_genres(&document);
-    
+    let genres = or_selectors(structured.genres.clone(), || extract_genres(document));
+
     // Extract countries
-    let countries = extract_countries(&document);
-    
+    let countries = or_selectors(structured.countries.clone(), || {
+        extract_countries(document, &origin)
+    });
+
     // Extract seasons
-    let seasons = parse_seasons(&document);
-    
+    let seasons = parse_seasons(document);
+
+    // Extract headline creators from the "Režie / Scénář / Hrají" summary.
+    // The page's cast has character names, JSON-LD actors don't.
+    let directors = or_selectors(structured.directors.clone(), || {
+        extract_creator_group(document, &["Režie"])
+    });
+    let writers = extract_creator_group(document, &["Scénář"]);
+    let cast = or_selectors(extract_cast(document), || {
+        structured_cast(&structured.actors)
+    });
+
+    // Extract average rating (optional, missing for unrated titles)
+    let rating = structured.rating.or_else(|| {
+        document
+            .select(selector!(".film-rating-average"))
+            .next()
+            .and_then(|el| parse_rating(&element_text(&el)))
+    });
+
+    let recommendations = extract_recommendations(document, csfd_id);
+
+    let has = |selector: &Selector| document.select(selector).next().is_some();
+    let availability = DetailAvailability {
+        genres: Availability::new(
+            "genres",
+            !structured.genres.is_empty()
+                || has(selector!(
+                    ".film-header-origin .genre, .genres, [itemprop='genre'], .film-info .genre"
+                )),
+            !genres.is_empty(),
+        ),
+        countries: Availability::new(
+            "countries",
+            !structured.countries.is_empty() || !origin.countries.is_empty(),
+            !countries.is_empty(),
+        ),
+        creators: Availability::new(
+            "creators",
+            !structured.directors.is_empty() || has(selector!(".creators > div")),
+            !directors.is_empty() || !writers.is_empty() || !cast.is_empty(),
+        ),
+        rating: Availability::new(
+            "rating",
+            structured.rating.is_some()
+                || document
+                    .select(selector!(".film-rating-average"))
+                    .next()
+                    // Titles with too few ratings show "?%"
+                    .is_some_and(|el| element_text(&el).chars().any(|c| c.is_ascii_digit())),
+            rating.is_some(),
+        ),
+    };
+
     Ok(SeriesDetail {
         csfd_id,
         name,
         original_name,
         year_range,
+        status_label,
         genres,
+        genre_ids: extract_genre_ids(document),
         countries,
         seasons,
+        directors,
+        writers,
+        cast,
+        rating,
+        recommendations,
+        availability,
+        imdb_id: extract_imdb_id(document),
     })
 }
 
+/// Use values read from JSON-LD, or the selector-based ones if there are none.
+pub(super) fn or_selectors<T>(structured: Vec<T>, selectors: impl FnOnce() -> Vec<T>) -> Vec<T> {
+    if structured.is_empty() {
+        selectors()
+    } else {
+        structured
+    }
+}
+
+/// Cast from JSON-LD actors, which carry no character names.
+pub(super) fn structured_cast(actors: &[(String, u32)]) -> Vec<CastMember> {
+    actors
+        .iter()
+        .map(|(name, creator_id)| CastMember {
+            name: name.clone(),
+            creator_id: *creator_id,
+            character: None,
+        })
+        .collect()
+}
+
+/// Extract titles from recommendation boxes of a detail page.
+///
+/// Boxes are recognized by their header; the "Související" box with sequels
+/// and spin-offs is deliberately not matched. Entries use the same article
+/// markup as search results. The title itself and duplicates are skipped.
+fn extract_recommendations(document: &Html, csfd_id: u32) -> Vec<SearchResult> {
+    const LABELS: [&str; 5] = [
+        "mohlo by se vám líbit",
+        "doporučujeme",
+        "podobné",
+        "diváci také",
+        "uživatelé také",
+    ];
+
+    let mut recommendations: Vec<SearchResult> = Vec::new();
+    for section in document.select(selector!("section.box, div.box")) {
+        let Some(header) = section.select(selector!(".box-header")).next() else {
+            continue;
+        };
+        let label = element_text(&header).to_lowercase();
+        if !LABELS.iter().any(|l| label.contains(l)) {
+            continue;
+        }
+
+        for result in section
+            .select(selector!("article"))
+            .filter_map(|a| parse_search_item(&a))
+        {
+            if result.csfd_id != csfd_id
+                && !recommendations.iter().any(|r| r.csfd_id == result.csfd_id)
+            {
+                recommendations.push(result);
+            }
+        }
+    }
+    recommendations
+}
+
+/// Parse the cast from the creators summary of a title page.
+///
+/// Works for series, season and episode overview pages, which all share
+/// the same "Hrají" block.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the overview page
+///
+/// # Returns
+/// Vector of cast members in page order
+pub fn parse_cast(html: &str) -> Vec<CastMember> {
+    let document = Html::parse_document(html);
+    extract_cast(&document)
+}
+
+/// Extract cast members, including character names where ČSFD shows them.
+///
+/// Characters follow the actor link in parentheses, e.g.
+/// `<a href="/tvurce/2-bryan-cranston/">Bryan Cranston</a> (Walter White)`.
+pub(super) fn extract_cast(document: &Html) -> Vec<CastMember> {
+    extract_creator_links(document, &["Hrají", "Hraje"])
+        .into_iter()
+        .map(|(link, name, creator_id)| CastMember {
+            name,
+            creator_id,
+            character: extract_character_name(&link),
+        })
+        .collect()
+}
+
+/// Extract the character name following an actor link, if present.
+fn extract_character_name(link: &scraper::ElementRef) -> Option<String> {
+    // Collect text up to the next actor link
+    let mut trailing = String::new();
+    for sibling in link.next_siblings() {
+        if let Some(el) = scraper::ElementRef::wrap(sibling) {
+            if el.value().name() == "a" {
+                break;
+            }
+            trailing.extend(el.text());
+        } else if let Some(text) = sibling.value().as_text() {
+            trailing.push_str(text);
+        }
+    }
+
+    let re = regex!(r"^[\s,]*\(([^)]+)\)");
+    let character = re.captures(&trailing)?.get(1)?.as_str().trim().to_string();
+    if character.is_empty() {
+        None
+    } else {
+        Some(character)
+    }
+}
+
+/// Extract creators listed under one of the given labels as `(name, creator_id)` pairs.
+pub(super) fn extract_creator_group(document: &Html, labels: &[&str]) -> Vec<(String, u32)> {
+    extract_creator_links(document, labels)
+        .into_iter()
+        .map(|(_, name, creator_id)| (name, creator_id))
+        .collect()
+}
+
+/// Find creator links listed under one of the given labels in the creators summary.
+///
+/// The overview page lists creators as `<div><h4>Režie:</h4><a href="/tvurce/...">...</a></div>`
+/// inside a `.creators` block. Returns the links with their names and creator IDs
+/// in page order, without duplicates.
+fn extract_creator_links<'a>(
+    document: &'a Html,
+    labels: &[&str],
+) -> Vec<(scraper::ElementRef<'a>, String, u32)> {
+    let mut creators: Vec<(scraper::ElementRef<'a>, String, u32)> = Vec::new();
+
+    let (Ok(group_selector), Ok(label_selector), Ok(link_selector)) = (
+        Selector::parse(".creators > div"),
+        Selector::parse("h4"),
+        Selector::parse("a[href*='/tvurce/']"),
+    ) else {
+        return creators;
+    };
+
+    for group in document.select(&group_selector) {
+        let Some(label) = group.select(&label_selector).next() else {
+            continue;
+        };
+        let label_text = label.text().collect::<String>();
+        let label_text = label_text.trim().trim_end_matches(':');
+        if !labels.iter().any(|l| label_text.eq_ignore_ascii_case(l)) {
+            continue;
+        }
+
+        for link in group.select(&link_selector) {
+            // Skip the "více" expander links
+            if link.value().classes().any(|c| c == "more") {
+                continue;
+            }
+
+            let Some(creator_id) = link.value().attr("href").and_then(extract_creator_id) else {
+                continue;
+            };
+            let name = link.text().collect::<String>().trim().to_string();
+            if !name.is_empty() && !creators.iter().any(|(_, _, id)| *id == creator_id) {
+                creators.push((link, name, creator_id));
+            }
+        }
+    }
+
+    creators
+}
+
 /// Extract series name from the page.
-fn extract_series_name(document: &Html) -> Option<String> {
+pub(super) fn extract_series_name(document: &Html) -> Option<String> {
     let selectors = [
         "h1.film-header-name",
         ".film-header h1",
@@ -60,7 +364,7 @@ fn extract_series_name(document: &Html) -> Option<String> {
         ".movie-title h1",
         "h1",
     ];
-    
+
     for selector_str in &selectors {
         if let Ok(selector) = Selector::parse(selector_str) {
             if let Some(el) = document.select(&selector).next() {
@@ -71,12 +375,12 @@ fn extract_series_name(document: &Html) -> Option<String> {
             }
         }
     }
-    
+
     None
 }
 
 /// Extract original name from the page.
-fn extract_original_name(document: &Html) -> Option<String> {
+pub(super) fn extract_original_name(document: &Html) -> Option<String> {
     // First try: look for original name in film-names list (first item with USA flag)
     if let Ok(selector) = Selector::parse("ul.film-names li:first-child") {
         if let Some(li) = document.select(&selector).next() {
@@ -95,14 +399,14 @@ fn extract_original_name(document: &Html) -> Option<String> {
             }
         }
     }
-    
+
     // Fallback selectors
     let selectors = [
         ".film-header-name .film-header-origin-name",
         ".origin-name",
         "[itemprop='alternateName']",
     ];
-    
+
     for selector_str in &selectors {
         if let Ok(selector) = Selector::parse(selector_str) {
             if let Some(el) = document.select(&selector).next() {
@@ -113,62 +417,63 @@ fn extract_original_name(document: &Html) -> Option<String> {
             }
         }
     }
-    
+
     None
 }
 
-/// Extract year range from the page.
-fn extract_year_range(document: &Html) -> Option<String> {
+/// Extract year range from the origin line, or from older year markup.
+pub(super) fn extract_year_range(document: &Html, origin: &OriginLine) -> Option<String> {
+    if let Some(year) = origin.year_range() {
+        return Some(year);
+    }
+
     let selectors = [
-        ".film-header-origin .origin span",
         ".origin .year",
         "[itemprop='datePublished']",
         ".film-info .origin",
     ];
-    
+
     for selector_str in &selectors {
         if let Ok(selector) = Selector::parse(selector_str) {
             for el in document.select(&selector) {
                 let text = el.text().collect::<String>();
-                if let Some(year) = extract_year_pattern(&text) {
+                if let Some(year) = parse_origin_line(&text).year_range() {
                     return Some(year);
                 }
             }
         }
     }
-    
+
     None
 }
 
-/// Extract year pattern from text.
-fn extract_year_pattern(text: &str) -> Option<String> {
-    let re = regex_lite::Regex::new(r"(\d{4}(?:\s*[-–]\s*\d{4})?|\d{4}\s*[-–]\s*)").ok()?;
-    if let Some(caps) = re.captures(text) {
-        let year = caps.get(1)?.as_str().trim().to_string();
-        // Clean up whitespace around dash
-        let cleaned = year.replace(" - ", "-").replace(" – ", "-");
-        return Some(cleaned);
-    }
-    None
+/// Extract the production status label from the header.
+pub(super) fn extract_status_label(document: &Html) -> Option<String> {
+    document
+        .select(selector!(
+            ".film-header .film-status, .film-header-name .status"
+        ))
+        .map(|el| element_text(&el).trim_matches(['(', ')', ' ']).to_string())
+        .find(|label| !label.is_empty())
 }
 
 /// Extract genres from the page.
-fn extract_genres(document: &Html) -> Vec<String> {
+pub(super) fn extract_genres(document: &Html) -> Vec<Label> {
     let mut genres = Vec::new();
-    
+
     let selectors = [
         ".film-header-origin .genre a",
         ".genres a",
         "[itemprop='genre']",
         ".film-info .genre a",
     ];
-    
+
     for selector_str in &selectors {
         if let Ok(selector) = Selector::parse(selector_str) {
             for el in document.select(&selector) {
-                let text = el.text().collect::<String>().trim().to_string();
-                if !text.is_empty() && !genres.contains(&text) {
-                    genres.push(text);
+                let text = element_text(&el);
+                if !text.is_empty() && !genres.iter().any(|g| *g == *text) {
+                    genres.push(Label::new(&text));
                 }
             }
             if !genres.is_empty() {
@@ -176,14 +481,47 @@ fn extract_genres(document: &Html) -> Vec<String> {
             }
         }
     }
-    
+
+    genres
+}
+
+/// Extract genres linked with their advanced search filter IDs.
+///
+/// Genres whose link has no ID are left out; labels are kept as shown.
+pub(super) fn extract_genre_ids(document: &Html) -> Vec<(Label, u32)> {
+    let mut genres: Vec<(Label, u32)> = Vec::new();
+    for el in document.select(selector!(
+        ".film-header-origin .genre a, .genres a, .film-info .genre a"
+    )) {
+        let Some(id) = el.value().attr("href").and_then(extract_genre_id) else {
+            continue;
+        };
+        let name = element_text(&el);
+        if !name.is_empty() && !genres.iter().any(|(_, known)| *known == id) {
+            genres.push((Label::new(&name), id));
+        }
+    }
     genres
 }
 
+/// Extract the IMDb ID from the page's IMDb link ("https://www.imdb.com/title/tt0412142/").
+pub(super) fn extract_imdb_id(document: &Html) -> Option<String> {
+    let re = regex!(r"imdb\.com/title/(tt\d+)");
+    document
+        .select(selector!("a[href*='imdb.com/title/']"))
+        .filter_map(|el| el.value().attr("href"))
+        .find_map(|href| re.captures(href))
+        .map(|caps| caps[1].to_string())
+}
+
 /// Extract countries from the page.
-fn extract_countries(document: &Html) -> Vec<String> {
+///
+/// Country links are preferred; when the origin line names more countries
+/// than are linked, as with some co-productions, or none is linked, its
+/// countries are used instead.
+pub(super) fn extract_countries(document: &Html, origin: &OriginLine) -> Vec<Label> {
     let mut countries = Vec::new();
-    
+
     // First try to find country links
     let link_selectors = [
         ".film-header-origin .origin a",
@@ -191,36 +529,28 @@ fn extract_countries(document: &Html) -> Vec<String> {
         "[itemprop='countryOfOrigin']",
         ".film-info .origin a",
     ];
-    
+
     for selector_str in &link_selectors {
         if let Ok(selector) = Selector::parse(selector_str) {
             for el in document.select(&selector) {
-                let text = el.text().collect::<String>().trim().to_string();
-                if !text.is_empty() && !countries.contains(&text) {
-                    countries.push(text);
+                let text = element_text(&el);
+                if !text.is_empty() && !countries.iter().any(|c| *c == *text) {
+                    countries.push(Label::new(&text));
                 }
             }
             if !countries.is_empty() {
-                return countries;
+                break;
             }
         }
     }
-    
-    // Fallback: extract from div.origin text directly
-    // Format: "USA, 2007-2019, 279 epizod" or just "USA,"
-    if let Ok(selector) = Selector::parse("div.origin") {
-        if let Some(el) = document.select(&selector).next() {
-            let text = el.text().collect::<String>();
-            // Country is usually the first part before comma
-            if let Some(country_part) = text.split(',').next() {
-                let country = country_part.trim().to_string();
-                if !country.is_empty() && !country.chars().all(|c| c.is_numeric()) {
-                    countries.push(country);
-                }
-            }
-        }
+
+    if origin.countries.len() > countries.len() {
+        return origin
+            .countries
+            .iter()
+            .map(|country| country.name_cs.clone())
+            .collect();
     }
-    
     countries
 }
 
@@ -233,11 +563,11 @@ fn extract_countries(document: &Html) -> Vec<String> {
 /// Vector of Season objects found on the page
 pub fn parse_seasons(document: &Html) -> Vec<Season> {
     let mut seasons = Vec::new();
-    
+
     // Current ČSFD structure: seasons are in h3.film-title with a.film-title-name links
     // URL format: /film/{series_id}-{slug}/{season_id}-{season_slug}/prehled/
     // Text format: "Série 1" with info span "(2007) - 17 epizod"
-    
+
     // First try the current ČSFD structure
     if let Ok(selector) = Selector::parse("h3.film-title") {
         for h3 in document.select(&selector) {
@@ -248,11 +578,11 @@ pub fn parse_seasons(document: &Html) -> Vec<Season> {
             }
         }
     }
-    
+
     if !seasons.is_empty() {
         return seasons;
     }
-    
+
     // Fallback: try different selectors for seasons list
     let container_selectors = [
         ".film-episodes-list",
@@ -260,13 +590,9 @@ pub fn parse_seasons(document: &Html) -> Vec<Season> {
         ".series-seasons",
         ".box-content ul",
     ];
-    
-    let item_selectors = [
-        "li a",
-        ".season-item a",
-        "a.season-link",
-    ];
-    
+
+    let item_selectors = ["li a", ".season-item a", "a.season-link"];
+
     for container_sel in &container_selectors {
         if let Ok(container_selector) = Selector::parse(container_sel) {
             for container in document.select(&container_selector) {
@@ -287,7 +613,7 @@ pub fn parse_seasons(document: &Html) -> Vec<Season> {
             }
         }
     }
-    
+
     // Alternative: look for season links directly
     if let Ok(selector) = Selector::parse("a[href*='/film/'][href*='serie']") {
         for el in document.select(&selector) {
@@ -298,98 +624,162 @@ pub fn parse_seasons(document: &Html) -> Vec<Season> {
             }
         }
     }
-    
+
     seasons
 }
 
 /// Parse season from h3.film-title element (current ČSFD structure).
 fn parse_season_from_h3(h3: &scraper::ElementRef) -> Option<Season> {
     // Find the link inside h3
-    let link_selector = Selector::parse("a.film-title-name").ok()?;
-    let link = h3.select(&link_selector).next()?;
-    
+    let link = h3.select(selector!("a.film-title-name")).next()?;
+
     // Get URL
     let url = link.value().attr("href")?.to_string();
-    
+
     // Extract season ID from URL
     let csfd_id = extract_season_id(&url)?;
-    
+
     // Get name from link text
-    let name = link.text().collect::<String>().trim().to_string();
+    let name = element_text(&link).into_owned();
     if name.is_empty() {
         return None;
     }
-    
+
     // Get info from span.film-title-info
-    let info_selector = Selector::parse(".film-title-info").ok()?;
-    let info_text = h3.select(&info_selector)
+    let info_text = h3
+        .select(selector!(".film-title-info"))
         .next()
-        .map(|el| el.text().collect::<String>())
+        .map(|el| element_text(&el))
         .unwrap_or_default();
-    
-    // Extract year from info (e.g., "(2007)")
-    let year = extract_year_from_season_name(&info_text);
-    
-    // Extract episode count from info (e.g., "17 epizod")
-    let episode_count = extract_episode_count_from_info(&info_text).unwrap_or(0);
-    
+
+    // Info reads like a short origin line: "(2007) - 17 epizod"
+    let origin = parse_origin_line(&info_text);
+    let episode_count = origin.episode_count.unwrap_or(0);
+    let (number, label) = parse_season_label(&name);
+    let year = origin
+        .year_from
+        .map(|year| year.to_string())
+        .or_else(|| year_label(number, &label));
+
+    // Thumbnail and rating live in the enclosing article, next to the header
+    let article = h3
+        .ancestors()
+        .filter_map(scraper::ElementRef::wrap)
+        .find(|el| el.value().name() == "article");
+    let poster_url = article.as_ref().and_then(extract_poster_url);
+    let rating = extract_season_rating(article.as_ref().unwrap_or(h3));
+
     Some(Season {
         csfd_id,
         name,
+        number,
+        label,
         year,
         episode_count,
         url,
+        poster_url,
+        rating,
     })
 }
 
-/// Extract episode count from info text like "(2007) - 17 epizod".
-fn extract_episode_count_from_info(text: &str) -> Option<u32> {
-    let re = regex_lite::Regex::new(r"(\d+)\s*epizod").ok()?;
-    if let Some(caps) = re.captures(text) {
-        return caps.get(1)?.as_str().parse().ok();
+/// Extract the small average rating shown next to a season ("78%").
+fn extract_season_rating(element: &scraper::ElementRef) -> Option<f32> {
+    element
+        .select(selector!(
+            ".film-title-rating, .rating-average, .season-rating, .rating"
+        ))
+        .find_map(|el| parse_rating(&element_text(&el)))
+}
+
+/// Extract poster/thumbnail URL from an element containing an `img`.
+fn extract_poster_url(element: &scraper::ElementRef) -> Option<String> {
+    let img = element.select(selector!("img")).next()?;
+
+    // Lazy-loaded images keep the real URL in data-src
+    let src = img
+        .value()
+        .attr("data-src")
+        .or_else(|| img.value().attr("src"))?;
+
+    normalize_image_url(src)
+}
+
+/// Normalize an image URL to an absolute https URL.
+///
+/// Returns `None` for inline placeholders (data URIs) and empty values.
+pub(super) fn normalize_image_url(src: &str) -> Option<String> {
+    let src = src.trim();
+    if src.is_empty() || src.starts_with("data:") {
+        return None;
+    }
+
+    if let Some(rest) = src.strip_prefix("//") {
+        Some(format!("https://{}", rest))
+    } else if src.starts_with('/') {
+        Some(format!("https://www.csfd.cz{}", src))
+    } else {
+        Some(src.to_string())
     }
-    None
 }
 
 /// Parse a single season item from an element.
 fn parse_season_item(element: &scraper::ElementRef) -> Option<Season> {
     // Get URL
     let url = element.value().attr("href")?.to_string();
-    
+
     // Extract CSFD ID - for seasons, we need to look at the season part of the URL
     let csfd_id = extract_season_id(&url).or_else(|| extract_csfd_id(&url))?;
-    
+
     // Get name from text
-    let name = element.text().collect::<String>().trim().to_string();
+    let name = element_text(element);
     if name.is_empty() {
         return None;
     }
-    
+
+    let name_clean = clean_season_name(&name);
+    let (number, label) = parse_season_label(&name_clean);
+
     // Try to extract year from name or nearby elements
-    let year = extract_year_from_season_name(&name);
-    
+    let year = extract_year_from_season_name(&name).or_else(|| year_label(number, &label));
+
     // Try to extract episode count (often in parentheses like "(10 epizod)")
     let episode_count = extract_episode_count(&name).unwrap_or(0);
-    
+
+    // Some layouts wrap a thumbnail inside the season link itself
+    let poster_url = extract_poster_url(element);
+
+    // The rating sits next to the link, in the same list item
+    let rating = element
+        .parent()
+        .and_then(scraper::ElementRef::wrap)
+        .filter(|item| matches!(item.value().name(), "li" | "tr"))
+        .and_then(|item| extract_season_rating(&item));
+
     Some(Season {
         csfd_id,
-        name: clean_season_name(&name),
+        name: name_clean,
+        number,
+        label,
         year,
         episode_count,
         url,
+        poster_url,
+        rating,
     })
 }
 
 /// Extract season ID from URL (the second ID in the path).
 fn extract_season_id(url: &str) -> Option<u32> {
     // URL format: /film/{series_id}-{slug}/{season_id}-{season_slug}/
-    let parts: Vec<&str> = url.trim_matches('/').split('/').collect();
-    
+    let mut parts = url.trim_matches('/').split('/');
+
     // Find the film segment and look for the next segment
-    for (i, part) in parts.iter().enumerate() {
-        if *part == "film" && i + 2 < parts.len() {
-            // The season ID is in parts[i+2]
-            let season_segment = parts[i + 2];
+    while let Some(part) = parts.next() {
+        if part == "film" {
+            // The season ID is in the second segment after "film"
+            let Some(season_segment) = parts.nth(1) else {
+                break;
+            };
             if let Some(id_str) = season_segment.split('-').next() {
                 if let Ok(id) = id_str.parse::<u32>() {
                     if id > 0 {
@@ -399,13 +789,55 @@ fn extract_season_id(url: &str) -> Option<u32> {
             }
         }
     }
-    
+
     None
 }
 
+/// Split a season name into its number and short label.
+///
+/// Numbered seasons ("Série 3", "3. řada") give their number, also as the
+/// label. Reality shows often label seasons by year ("Řada 2023"); those
+/// give no number and the year as label. Other names are their own label.
+///
+/// # Returns
+/// `(number, label)`
+fn parse_season_label(name: &str) -> (Option<u8>, String) {
+    let name = name.trim();
+    let re = regex!(
+        r"(?i)^(?:s[éÉ]rie|season|[řŘ]ada|sez[óÓ]na)\s*(\d+)$|^(\d+)\.?\s*(?:s[éÉ]rie|season|[řŘ]ada|sez[óÓ]na)?$"
+    );
+    let Some(digits) = re
+        .captures(name)
+        .and_then(|caps| caps.get(1).or_else(|| caps.get(2)))
+    else {
+        return (None, name.to_string());
+    };
+    let digits = digits.as_str();
+    if is_year(digits) {
+        return (None, digits.to_string());
+    }
+    match digits.parse::<u8>() {
+        Ok(number) => (Some(number), number.to_string()),
+        Err(_) => (None, name.to_string()),
+    }
+}
+
+/// Check whether digits read as a plausible season year.
+fn is_year(digits: &str) -> bool {
+    digits.len() == 4
+        && digits
+            .parse::<u16>()
+            .is_ok_and(|year| (1900..=2100).contains(&year))
+}
+
+/// Use a year label as the season's year when ČSFD shows no other.
+fn year_label(number: Option<u8>, label: &str) -> Option<String> {
+    (number.is_none() && is_year(label)).then(|| label.to_string())
+}
+
 /// Extract year from season name.
 fn extract_year_from_season_name(name: &str) -> Option<String> {
-    let re = regex_lite::Regex::new(r"\((\d{4})\)").ok()?;
+    let re = regex!(r"\((\d{4})\)");
     if let Some(caps) = re.captures(name) {
         return Some(caps.get(1)?.as_str().to_string());
     }
@@ -415,7 +847,7 @@ fn extract_year_from_season_name(name: &str) -> Option<String> {
 /// Extract episode count from season name.
 fn extract_episode_count(name: &str) -> Option<u32> {
     // Look for patterns like "(10 epizod)" or "(8)"
-    let re = regex_lite::Regex::new(r"\((\d+)(?:\s*epizod[ay]?)?\)").ok()?;
+    let re = regex!(r"\((\d+)(?:\s*epizod[ay]?)?\)");
     if let Some(caps) = re.captures(name) {
         return caps.get(1)?.as_str().parse().ok();
     }
@@ -424,7 +856,7 @@ fn extract_episode_count(name: &str) -> Option<u32> {
 
 /// Clean season name by removing year and episode count.
 fn clean_season_name(name: &str) -> String {
-    let re = regex_lite::Regex::new(r"\s*\([^)]*\)\s*").unwrap();
+    let re = regex!(r"\s*\([^)]*\)\s*");
     re.replace_all(name, " ").trim().to_string()
 }
 
@@ -433,11 +865,58 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_extract_year_pattern() {
-        assert_eq!(extract_year_pattern("2020"), Some("2020".to_string()));
-        assert_eq!(extract_year_pattern("2020-2023"), Some("2020-2023".to_string()));
-        assert_eq!(extract_year_pattern("2020 - 2023"), Some("2020-2023".to_string()));
-        assert_eq!(extract_year_pattern("no year"), None);
+    fn test_extract_genre_ids() {
+        let document = Html::parse_document(
+            r#"<div class="film-header-origin"><div class="genre">
+                <a href="/zanr/5-krimi/">Krimi</a> / <a href="/zanr/drama/">Drama</a> /
+                <a href="/podrobne-vyhledavani/?genre%5Binclude%5D%5B0%5D=9">Thriller</a>
+            </div></div>"#,
+        );
+        assert_eq!(
+            extract_genre_ids(&document),
+            vec![(Label::new("Krimi"), 5), (Label::new("Thriller"), 9)]
+        );
+        assert_eq!(
+            extract_genres(&document),
+            vec!["Krimi", "Drama", "Thriller"]
+        );
+    }
+
+    #[test]
+    fn test_extract_genres_interned() {
+        let page = r#"<div class="genres"><a href="/zanr/drama/">Drama</a></div>"#;
+        let first = extract_genres(&Html::parse_document(page));
+        let second = extract_genres(&Html::parse_document(page));
+        assert_eq!(first[0], "Drama");
+        assert_eq!(first[0].as_ptr(), second[0].as_ptr());
+    }
+
+    #[test]
+    fn test_extract_year_range() {
+        let none = OriginLine::default();
+        let document = Html::parse_document(
+            r#"<div class="film-info"><p class="origin">2020 - 2023</p></div>"#,
+        );
+        assert_eq!(
+            extract_year_range(&document, &none),
+            Some("2020-2023".to_string())
+        );
+        assert_eq!(
+            extract_year_range(&Html::parse_document("<p>no year</p>"), &none),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_status_label() {
+        let document = Html::parse_document(
+            r#"<div class="film-header"><h1>Dr. House</h1><span class="film-status">(ukončený)</span></div>"#,
+        );
+        assert_eq!(extract_status_label(&document).as_deref(), Some("ukončený"));
+        assert_eq!(
+            extract_status_label(&Html::parse_document("<h1>Dr. House</h1>")),
+            None
+        );
     }
 
     #[test]
@@ -476,6 +955,31 @@ mod tests {
         assert_eq!(clean_season_name("Série 1"), "Série 1");
     }
 
+    #[test]
+    fn test_parse_season_label() {
+        assert_eq!(parse_season_label("Série 3"), (Some(3), "3".to_string()));
+        assert_eq!(parse_season_label("2. řada"), (Some(2), "2".to_string()));
+        assert_eq!(parse_season_label("Řada 2023"), (None, "2023".to_string()));
+        assert_eq!(parse_season_label("2023"), (None, "2023".to_string()));
+        assert_eq!(
+            parse_season_label("Speciály"),
+            (None, "Speciály".to_string())
+        );
+    }
+
+    const FULL_DETAIL_HTML: &str = r#"
+        <html>
+        <body>
+            <h1 class="film-header-name">Dr. House</h1>
+            <div class="film-header-origin">
+                <div class="genre"><a href="/zanr/drama/">Drama</a></div>
+                <div class="origin">USA, 2004-2012, 177 epizod</div>
+            </div>
+            <div class="film-rating-average">91%</div>
+        </body>
+        </html>
+    "#;
+
     #[test]
     fn test_parse_series_detail_minimal() {
         let html = r#"
@@ -485,10 +989,353 @@ mod tests {
             </body>
             </html>
         "#;
-        
+
         let result = parse_series_detail(html, 12345).unwrap();
         assert_eq!(result.name, "Breaking Bad");
         assert_eq!(result.csfd_id, 12345);
         assert!(result.seasons.is_empty());
+        assert_eq!(result.rating, None);
+        assert_eq!(result.imdb_id, None);
+    }
+
+    #[test]
+    fn test_parse_series_detail_imdb_id() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Dr. House</h1>
+                <div class="film-links">
+                    <a href="https://www.imdb.com/title/tt0412142/" class="button button-imdb">IMDb</a>
+                    <a href="https://www.imdb.com/find?q=House" class="button">Hledat</a>
+                </div>
+            </body>
+            </html>
+        "#;
+
+        let result = parse_series_detail(html, 264494).unwrap();
+        assert_eq!(result.imdb_id.as_deref(), Some("tt0412142"));
+    }
+
+    #[test]
+    fn test_parse_series_detail_recommendations() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Breaking Bad</h1>
+                <section class="box">
+                    <header class="box-header"><h3>Související</h3></header>
+                    <article><h3><a href="/film/480720-better-call-saul/" class="film-title-name">Better Call Saul</a></h3></article>
+                </section>
+                <section class="box">
+                    <header class="box-header"><h3>Mohlo by se vám líbit</h3></header>
+                    <article><h3><a href="/film/264494-pernikovy-tata/" class="film-title-name">Perníkový táta</a></h3></article>
+                    <article><h3><a href="/film/12345-breaking-bad/" class="film-title-name">Breaking Bad</a></h3></article>
+                    <article><h3><a href="/film/71924-dr-house/" class="film-title-name">Dr. House</a>
+                        <span class="film-title-info"><span class="info">(2004)</span></span></h3></article>
+                </section>
+            </body>
+            </html>
+        "#;
+        let detail = parse_series_detail(html, 12345).unwrap();
+        let ids: Vec<u32> = detail.recommendations.iter().map(|r| r.csfd_id).collect();
+        assert_eq!(ids, vec![264494, 71924]);
+        assert_eq!(detail.recommendations[1].year.as_deref(), Some("2004"));
+    }
+
+    #[test]
+    fn test_parse_series_detail_rating() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Breaking Bad</h1>
+                <div class="film-rating-average red">94%</div>
+            </body>
+            </html>
+        "#;
+        assert_eq!(parse_series_detail(html, 12345).unwrap().rating, Some(94.0));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_parse_series_detail_structured_data() {
+        // Redesigned page: the selectors find only the name, JSON-LD has the rest
+        let html = r#"
+            <html>
+            <head>
+                <script type="application/ld+json">
+                    {"@type": "TVSeries", "name": "Perníkový táta", "genre": ["Krimi", "Drama"],
+                     "countryOfOrigin": {"@type": "Country", "name": "USA"}, "dateCreated": "2008",
+                     "director": [{"@type": "Person", "name": "Vince Gilligan", "url": "/tvurce/3101-vince-gilligan/"}],
+                     "actor": [{"@type": "Person", "name": "Bryan Cranston", "url": "/tvurce/2-bryan-cranston/"}],
+                     "aggregateRating": {"ratingValue": "94.6", "bestRating": "100"}}
+                </script>
+            </head>
+            <body><h1 class="new-title">Perníkový táta</h1></body>
+            </html>
+        "#;
+        let detail = parse_series_detail(html, 264494).unwrap();
+        assert_eq!(detail.name, "Perníkový táta");
+        assert_eq!(detail.genres, vec!["Krimi", "Drama"]);
+        assert_eq!(detail.countries, vec!["USA"]);
+        assert_eq!(detail.year_range.as_deref(), Some("2008"));
+        assert_eq!(detail.directors, vec![("Vince Gilligan".to_string(), 3101)]);
+        assert_eq!(detail.cast[0].creator_id, 2);
+        assert_eq!(detail.cast[0].character, None);
+        assert_eq!(detail.rating, Some(94.6));
+        assert!(!detail.availability.genres.is_broken());
+        assert!(!detail.availability.creators.is_broken());
+    }
+
+    #[test]
+    fn test_parse_series_detail_availability() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Návštěvníci</h1>
+                <div class="film-header-origin">
+                    <div class="genre"><span>Sci-Fi</span></div>
+                    <div class="origin">1983, 15 epizod</div>
+                </div>
+                <div class="film-rating-average">?%</div>
+            </body>
+            </html>
+        "#;
+        let availability = parse_series_detail(html, 1).unwrap().availability;
+        // The genre line is there, but without genre links
+        assert!(availability.genres.is_broken());
+        assert!(availability.genres.error.is_some());
+        assert_eq!(availability.countries, Availability::default());
+        assert!(!availability.creators.present);
+        assert!(!availability.rating.present);
+
+        let availability = parse_series_detail(FULL_DETAIL_HTML, 1)
+            .unwrap()
+            .availability;
+        assert!(availability.countries.present && availability.countries.parsed);
+        assert!(availability.genres.parsed && availability.rating.parsed);
+    }
+
+    #[test]
+    fn test_parse_series_detail_verified() {
+        let html = r#"
+            <html>
+            <head><link rel="canonical" href="https://www.csfd.cz/film/12345-breaking-bad/prehled/"></head>
+            <body><h1 class="film-header-name">Breaking Bad</h1></body>
+            </html>
+        "#;
+        assert_eq!(
+            parse_series_detail_verified(html, 12345).unwrap().name,
+            "Breaking Bad"
+        );
+        assert!(matches!(
+            parse_series_detail_verified(html, 999),
+            Err(CsfdError::UnexpectedPage(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_series_detail_verified_rejects_generic_title() {
+        let html = r#"
+            <html>
+            <head><meta property="og:url" content="https://www.csfd.cz/"></head>
+            <body><h1>ČSFD.cz</h1></body>
+            </html>
+        "#;
+        // Unverified parsing accepts the page as a series named after the site
+        assert_eq!(parse_series_detail(html, 12345).unwrap().name, "ČSFD.cz");
+        assert!(matches!(
+            parse_series_detail_verified(html, 12345),
+            Err(CsfdError::UnexpectedPage(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_series_detail_creators() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Perníkový táta</h1>
+                <div class="creators">
+                    <div>
+                        <h4>Režie:</h4>
+                        <a href="/tvurce/3101-vince-gilligan/">Vince Gilligan</a>,
+                        <a href="/tvurce/4150-michelle-maclaren/">Michelle MacLaren</a>
+                    </div>
+                    <div>
+                        <h4>Scénář:</h4>
+                        <a href="/tvurce/3101-vince-gilligan/">Vince Gilligan</a>
+                    </div>
+                    <div>
+                        <h4>Hudba:</h4>
+                        <a href="/tvurce/9999-dave-porter/">Dave Porter</a>
+                    </div>
+                    <div>
+                        <h4>Hrají:</h4>
+                        <a href="/tvurce/2-bryan-cranston/">Bryan Cranston</a>,
+                        <a href="/tvurce/3-aaron-paul/">Aaron Paul</a>,
+                        <a href="/tvurce/3-aaron-paul/">Aaron Paul</a>
+                        <a class="more" href="/tvurce/3-aaron-paul/">více</a>
+                    </div>
+                </div>
+            </body>
+            </html>
+        "#;
+
+        let result = parse_series_detail(html, 264494).unwrap();
+        assert_eq!(
+            result.directors,
+            vec![
+                ("Vince Gilligan".to_string(), 3101),
+                ("Michelle MacLaren".to_string(), 4150),
+            ]
+        );
+        assert_eq!(result.writers, vec![("Vince Gilligan".to_string(), 3101)]);
+        let cast: Vec<(&str, u32)> = result
+            .cast
+            .iter()
+            .map(|m| (m.name.as_str(), m.creator_id))
+            .collect();
+        assert_eq!(cast, vec![("Bryan Cranston", 2), ("Aaron Paul", 3)]);
+    }
+
+    #[test]
+    fn test_parse_cast_episode_page() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Pilot</h1>
+                <div class="creators">
+                    <div><h4>Režie:</h4><a href="/tvurce/3101-vince-gilligan/">Vince Gilligan</a></div>
+                    <div><h4>Hrají:</h4><a href="/tvurce/2-bryan-cranston/">Bryan Cranston</a></div>
+                </div>
+            </body>
+            </html>
+        "#;
+
+        let cast = parse_cast(html);
+        assert_eq!(cast.len(), 1);
+        assert_eq!(cast[0].name, "Bryan Cranston");
+        assert_eq!(cast[0].creator_id, 2);
+        assert_eq!(cast[0].character, None);
+        assert!(parse_cast("<html><body></body></html>").is_empty());
+    }
+
+    #[test]
+    fn test_parse_cast_with_characters() {
+        let html = r#"
+            <html>
+            <body>
+                <div class="creators">
+                    <div>
+                        <h4>Hrají:</h4>
+                        <a href="/tvurce/2-bryan-cranston/">Bryan Cranston</a> (Walter White),
+                        <a href="/tvurce/3-aaron-paul/">Aaron Paul</a> <span class="role">(Jesse Pinkman)</span>,
+                        <a href="/tvurce/4-anna-gunn/">Anna Gunn</a>,
+                        <a href="/tvurce/5-dean-norris/">Dean Norris</a>
+                    </div>
+                </div>
+            </body>
+            </html>
+        "#;
+
+        let cast = parse_cast(html);
+        let characters: Vec<Option<&str>> = cast.iter().map(|m| m.character.as_deref()).collect();
+        assert_eq!(
+            characters,
+            vec![Some("Walter White"), Some("Jesse Pinkman"), None, None]
+        );
+    }
+
+    #[test]
+    fn test_normalize_image_url() {
+        assert_eq!(
+            normalize_image_url(
+                "//image.pmgstatic.com/cache/resized/w60h85/files/images/film/posters/1.jpg"
+            ),
+            Some(
+                "https://image.pmgstatic.com/cache/resized/w60h85/files/images/film/posters/1.jpg"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            normalize_image_url("/assets/images/poster-free.png"),
+            Some("https://www.csfd.cz/assets/images/poster-free.png".to_string())
+        );
+        assert_eq!(normalize_image_url("data:image/gif;base64,R0lGOD"), None);
+        assert_eq!(normalize_image_url("  "), None);
+    }
+
+    #[test]
+    fn test_parse_seasons_with_posters() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Breaking Bad</h1>
+                <article class="article article-poster-60">
+                    <figure class="article-img">
+                        <img src="//image.pmgstatic.com/files/images/film/posters/season1.jpg" alt="Série 1">
+                    </figure>
+                    <header class="article-header">
+                        <h3 class="film-title">
+                            <a class="film-title-name" href="/film/264494-breaking-bad/471398-serie-1/prehled/">Série 1</a>
+                            <span class="film-title-info">(2008) - 7 epizod</span>
+                        </h3>
+                        <span class="film-title-rating">88%</span>
+                    </header>
+                </article>
+                <article class="article article-poster-60">
+                    <header class="article-header">
+                        <h3 class="film-title">
+                            <a class="film-title-name" href="/film/264494-breaking-bad/471399-serie-2/prehled/">Série 2</a>
+                            <span class="film-title-info">(2009) - 13 epizod</span>
+                        </h3>
+                    </header>
+                </article>
+            </body>
+            </html>
+        "#;
+
+        let result = parse_series_detail(html, 264494).unwrap();
+        assert_eq!(result.seasons.len(), 2);
+
+        let first = &result.seasons[0];
+        assert_eq!(first.csfd_id, 471398);
+        assert_eq!(first.number, Some(1));
+        assert_eq!(first.year.as_deref(), Some("2008"));
+        assert_eq!(first.episode_count, 7);
+        assert_eq!(
+            first.poster_url.as_deref(),
+            Some("https://image.pmgstatic.com/files/images/film/posters/season1.jpg")
+        );
+
+        assert_eq!(first.rating, Some(88.0));
+        assert_eq!(result.seasons[1].poster_url, None);
+        assert_eq!(result.seasons[1].rating, None);
+    }
+
+    #[test]
+    fn test_parse_year_labeled_seasons() {
+        let html = r#"
+            <html>
+            <body>
+                <h1 class="film-header-name">Survivor Česko &amp; Slovensko</h1>
+                <h3 class="film-title">
+                    <a class="film-title-name" href="/film/1015437-survivor/1230001-rada-2022/prehled/">Řada 2022</a>
+                    <span class="film-title-info">- 48 epizod</span>
+                </h3>
+                <h3 class="film-title">
+                    <a class="film-title-name" href="/film/1015437-survivor/1330002-rada-2023/prehled/">Řada 2023</a>
+                    <span class="film-title-info">(2023) - 52 epizod</span>
+                </h3>
+            </body>
+            </html>
+        "#;
+
+        let result = parse_series_detail(html, 1015437).unwrap();
+        let first = &result.seasons[0];
+        assert_eq!((first.number, first.label.as_str()), (None, "2022"));
+        assert_eq!(first.year.as_deref(), Some("2022"));
+        assert_eq!(first.episode_count, 48);
+        assert_eq!(result.seasons[1].label, "2023");
     }
 }
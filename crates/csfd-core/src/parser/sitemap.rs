@@ -0,0 +1,137 @@
+//! Sitemap parser for ČSFD.cz
+//!
+//! Parses `sitemap.xml` files, which are either an index of further
+//! sitemaps or a list of page URLs.
+
+/// Contents of a sitemap file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sitemap {
+    /// Locations of nested sitemaps (for a sitemap index)
+    pub sitemaps: Vec<String>,
+    /// Locations of pages
+    pub urls: Vec<String>,
+}
+
+/// Parse a sitemap or sitemap index.
+///
+/// Sitemaps are plain, machine-generated XML, so `<loc>` entries are read
+/// directly and attributed to the enclosing `<sitemap>` or `<url>` element.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::parse_sitemap;
+///
+/// let xml = "<urlset><url><loc>https://www.csfd.cz/film/1-a/</loc></url></urlset>";
+/// assert_eq!(parse_sitemap(xml).urls, vec!["https://www.csfd.cz/film/1-a/"]);
+/// ```
+pub fn parse_sitemap(xml: &str) -> Sitemap {
+    let re = regex!(r"(?s)<(sitemap|url)>.*?<loc>\s*(.*?)\s*</loc>");
+    let mut sitemap = Sitemap::default();
+
+    for caps in re.captures_iter(xml) {
+        let loc = unescape_xml(&caps[2]);
+        if &caps[1] == "sitemap" {
+            sitemap.sitemaps.push(loc);
+        } else {
+            sitemap.urls.push(loc);
+        }
+    }
+
+    sitemap
+}
+
+/// Convert an absolute ČSFD URL to a site-relative path.
+///
+/// Returns `None` for URLs on other hosts.
+///
+/// # Examples
+/// ```
+/// use csfd_core::unstable::parser::site_path;
+///
+/// assert_eq!(site_path("https://www.csfd.cz/sitemap-1.xml"), Some("/sitemap-1.xml"));
+/// assert_eq!(site_path("https://www.example.com/"), None);
+/// ```
+pub fn site_path(url: &str) -> Option<&str> {
+    if url.starts_with('/') {
+        return Some(url);
+    }
+
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))?;
+    let rest = rest.strip_prefix("www.").unwrap_or(rest);
+    let path = rest.strip_prefix("csfd.cz")?;
+    path.starts_with('/').then_some(path)
+}
+
+/// Replace the XML entities allowed in sitemap locations.
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sitemap_index() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+                <sitemap><loc>https://www.csfd.cz/sitemap-film-1.xml</loc><lastmod>2024-01-01</lastmod></sitemap>
+                <sitemap>
+                    <loc>
+                        https://www.csfd.cz/sitemap-tvurce-1.xml
+                    </loc>
+                </sitemap>
+            </sitemapindex>"#;
+        let sitemap = parse_sitemap(xml);
+        assert_eq!(
+            sitemap.sitemaps,
+            vec![
+                "https://www.csfd.cz/sitemap-film-1.xml",
+                "https://www.csfd.cz/sitemap-tvurce-1.xml",
+            ]
+        );
+        assert!(sitemap.urls.is_empty());
+    }
+
+    #[test]
+    fn test_parse_sitemap_urls() {
+        let xml = r#"<urlset>
+                <url><loc>https://www.csfd.cz/film/264494-pernikovy-tata/</loc></url>
+                <url><loc>https://www.csfd.cz/hledat/?q=a&amp;page=2</loc></url>
+            </urlset>"#;
+        let sitemap = parse_sitemap(xml);
+        assert_eq!(
+            sitemap.urls,
+            vec![
+                "https://www.csfd.cz/film/264494-pernikovy-tata/",
+                "https://www.csfd.cz/hledat/?q=a&page=2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sitemap_garbage() {
+        assert_eq!(
+            parse_sitemap("<html><body>Not found</body></html>"),
+            Sitemap::default()
+        );
+    }
+
+    #[test]
+    fn test_site_path() {
+        assert_eq!(
+            site_path("https://www.csfd.cz/film/1-a/"),
+            Some("/film/1-a/")
+        );
+        assert_eq!(site_path("http://csfd.cz/film/1-a/"), Some("/film/1-a/"));
+        assert_eq!(site_path("/film/1-a/"), Some("/film/1-a/"));
+        assert_eq!(site_path("https://www.csfd.sk/film/1-a/"), None);
+        assert_eq!(site_path("https://www.csfd.cz.evil.com/"), None);
+    }
+}
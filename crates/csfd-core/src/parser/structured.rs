@@ -0,0 +1,222 @@
+//! Structured data parser for ČSFD.cz
+//!
+//! Detail pages embed a schema.org description of the title in a
+//! `<script type="application/ld+json">` block. Its keys don't follow the
+//! page layout, so it survives redesigns that break CSS selectors; the
+//! series and film parsers read it first and fall back to selectors for
+//! anything it lacks. Reading JSON needs `serde_json`, so without the
+//! `serde` feature nothing is found and the parsers use selectors only.
+
+use scraper::Html;
+
+use crate::types::Label;
+
+/// Title fields found in a JSON-LD block
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(super) struct StructuredData {
+    pub name: Option<String>,
+    pub year: Option<u16>,
+    pub genres: Vec<Label>,
+    pub countries: Vec<Label>,
+    /// `(name, creator_id)` pairs, like `extract_creator_group`
+    pub directors: Vec<(String, u32)>,
+    /// `(name, creator_id)` pairs, without characters
+    pub actors: Vec<(String, u32)>,
+    /// Average rating as a percentage
+    pub rating: Option<f32>,
+    pub runtime_minutes: Option<u16>,
+}
+
+/// Read the title described by the page's JSON-LD, if any.
+#[cfg(feature = "serde")]
+pub(super) fn extract_structured_data(document: &Html) -> Option<StructuredData> {
+    use serde_json::Value;
+
+    document
+        .select(selector!("script[type='application/ld+json']"))
+        .filter_map(|script| serde_json::from_str::<Value>(&script.text().collect::<String>()).ok())
+        .find_map(|value| find_title(&value).map(read_title))
+}
+
+/// Without `serde_json` there is no JSON-LD to read.
+#[cfg(not(feature = "serde"))]
+pub(super) fn extract_structured_data(_document: &Html) -> Option<StructuredData> {
+    None
+}
+
+/// Schema.org types describing a title
+#[cfg(feature = "serde")]
+const TITLE_TYPES: [&str; 5] = ["Movie", "TVSeries", "TVSeason", "TVEpisode", "CreativeWork"];
+
+/// Find the title object in a JSON-LD value, also inside arrays and `@graph`.
+#[cfg(feature = "serde")]
+fn find_title(value: &serde_json::Value) -> Option<&serde_json::Value> {
+    use serde_json::Value;
+
+    match value {
+        Value::Array(items) => items.iter().find_map(find_title),
+        Value::Object(object) => {
+            let is_title = match object.get("@type") {
+                Some(Value::String(kind)) => TITLE_TYPES.contains(&kind.as_str()),
+                Some(Value::Array(kinds)) => kinds.iter().any(|kind| {
+                    kind.as_str()
+                        .is_some_and(|kind| TITLE_TYPES.contains(&kind))
+                }),
+                _ => false,
+            };
+            if is_title {
+                Some(value)
+            } else {
+                object.get("@graph").and_then(find_title)
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(feature = "serde")]
+fn read_title(title: &serde_json::Value) -> StructuredData {
+    let year = ["dateCreated", "startDate", "datePublished"]
+        .iter()
+        .filter_map(|key| title.get(key)?.as_str())
+        .find_map(|date| date.get(..4)?.parse().ok());
+
+    StructuredData {
+        name: title
+            .get("name")
+            .and_then(|name| name.as_str())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty()),
+        year,
+        genres: names(title.get("genre")),
+        countries: names(title.get("countryOfOrigin")),
+        directors: people(title.get("director")),
+        actors: people(title.get("actor")),
+        rating: title.get("aggregateRating").and_then(read_rating),
+        runtime_minutes: title
+            .get("duration")
+            .and_then(|duration| duration.as_str())
+            .and_then(parse_duration),
+    }
+}
+
+/// Strings of a value that is a string, an object with a `name`, or an array of those.
+#[cfg(feature = "serde")]
+fn names<'a>(value: Option<&'a serde_json::Value>) -> Vec<Label> {
+    use serde_json::Value;
+
+    let name = |value: &'a Value| match value {
+        Value::String(name) => Some(name.trim()),
+        Value::Object(object) => object.get("name")?.as_str().map(str::trim),
+        _ => None,
+    };
+    let names: Vec<&str> = match value {
+        Some(Value::Array(items)) => items.iter().filter_map(name).collect(),
+        Some(value) => name(value).into_iter().collect(),
+        None => Vec::new(),
+    };
+    names
+        .into_iter()
+        .filter(|name| !name.is_empty())
+        .map(Label::new)
+        .collect()
+}
+
+/// People with a ČSFD creator link (`url` or `sameAs`), in order and without duplicates.
+#[cfg(feature = "serde")]
+fn people(value: Option<&serde_json::Value>) -> Vec<(String, u32)> {
+    use super::search::extract_creator_id;
+    use serde_json::Value;
+
+    let items = match value {
+        Some(Value::Array(items)) => items.as_slice(),
+        Some(item) => std::slice::from_ref(item),
+        None => &[],
+    };
+    let mut people: Vec<(String, u32)> = Vec::new();
+    for item in items {
+        let Some(name) = item.get("name").and_then(Value::as_str).map(str::trim) else {
+            continue;
+        };
+        let creator_id = ["url", "sameAs", "@id"]
+            .iter()
+            .filter_map(|key| item.get(key)?.as_str())
+            .find_map(extract_creator_id);
+        if let Some(creator_id) = creator_id {
+            if !name.is_empty() && !people.iter().any(|(_, id)| *id == creator_id) {
+                people.push((name.to_string(), creator_id));
+            }
+        }
+    }
+    people
+}
+
+/// Average rating as a percentage, scaled by `bestRating` (default 100).
+#[cfg(feature = "serde")]
+fn read_rating(rating: &serde_json::Value) -> Option<f32> {
+    let number = |key: &str| {
+        let value = rating.get(key)?;
+        value
+            .as_f64()
+            .or_else(|| value.as_str()?.trim().replace(',', ".").parse().ok())
+    };
+    let value = number("ratingValue")?;
+    let best = number("bestRating")
+        .filter(|best| *best > 0.0)
+        .unwrap_or(100.0);
+    let percent = value * 100.0 / best;
+    (0.0..=100.0).contains(&percent).then_some(percent as f32)
+}
+
+/// Parse an ISO 8601 duration such as "PT142M" or "PT2H22M" into minutes.
+#[cfg(feature = "serde")]
+fn parse_duration(duration: &str) -> Option<u16> {
+    let caps = regex!(r"^PT(?:(\d+)H)?(?:(\d+)M)?").captures(duration.trim())?;
+    let hours: u16 = caps.get(1).map_or(Some(0), |h| h.as_str().parse().ok())?;
+    let minutes: u16 = caps.get(2).map_or(Some(0), |m| m.as_str().parse().ok())?;
+    let total = hours * 60 + minutes;
+    (total > 0).then_some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_extract_structured_data() {
+        let html = r#"
+            <html><head>
+                <script type="application/ld+json">{"@context":"http://schema.org","@type":"WebSite","name":"ČSFD.cz"}</script>
+                <script type="application/ld+json">
+                    {"@context": "http://schema.org", "@type": "Movie",
+                     "name": "Vykoupení z věznice Shawshank",
+                     "genre": ["Drama", "Krimi"],
+                     "countryOfOrigin": [{"@type": "Country", "name": "USA"}],
+                     "dateCreated": "1994",
+                     "duration": "PT2H22M",
+                     "director": [{"@type": "Person", "name": "Frank Darabont", "sameAs": "https://www.csfd.cz/tvurce/3-frank-darabont/"}],
+                     "actor": [{"@type": "Person", "name": "Tim Robbins", "url": "/tvurce/103-tim-robbins/"},
+                               {"@type": "Person", "name": "Bez odkazu"}],
+                     "aggregateRating": {"@type": "AggregateRating", "ratingValue": 95.3, "bestRating": 100}}
+                </script>
+            </head><body></body></html>
+        "#;
+        let data = extract_structured_data(&Html::parse_document(html)).unwrap();
+        assert_eq!(data.name.as_deref(), Some("Vykoupení z věznice Shawshank"));
+        assert_eq!(data.year, Some(1994));
+        assert_eq!(data.genres, vec!["Drama", "Krimi"]);
+        assert_eq!(data.countries, vec!["USA"]);
+        assert_eq!(data.directors, vec![("Frank Darabont".to_string(), 3)]);
+        assert_eq!(data.actors, vec![("Tim Robbins".to_string(), 103)]);
+        assert_eq!(data.rating, Some(95.3));
+        assert_eq!(data.runtime_minutes, Some(142));
+    }
+
+    #[test]
+    fn test_extract_structured_data_missing() {
+        let html =
+            r#"<html><head><script type="application/ld+json">not json</script></head></html>"#;
+        assert_eq!(extract_structured_data(&Html::parse_document(html)), None);
+    }
+}
@@ -0,0 +1,85 @@
+//! Trending titles parser for ČSFD.cz
+//!
+//! The homepage has boxes of the most-visited ("nejnavštěvovanější")
+//! films and series. Boxes are told apart by their heading; entries use
+//! the same article markup as search results.
+
+use scraper::Html;
+
+use crate::types::{SearchResult, TrendingKind};
+
+use super::homepage::find_box;
+use super::search::parse_search_item;
+
+/// Parse the trending box of the given kind from the homepage.
+///
+/// # Arguments
+/// * `html` - Raw HTML content of the homepage
+/// * `kind` - Which trending box to read
+///
+/// # Returns
+/// * `Some(entries)` in the order shown
+/// * `None` if the page has no such box
+pub fn parse_trending(html: &str, kind: TrendingKind) -> Option<Vec<SearchResult>> {
+    extract_trending(&Html::parse_document(html), kind)
+}
+
+/// Find the trending box of the given kind in a parsed homepage.
+pub(super) fn extract_trending(document: &Html, kind: TrendingKind) -> Option<Vec<SearchResult>> {
+    let subject = match kind {
+        TrendingKind::Movies => "film",
+        TrendingKind::Series => "seriál",
+    };
+    let section = find_box(document, |heading| {
+        ["navštěvovan", "trend", "populárn"]
+            .iter()
+            .any(|word| heading.contains(word))
+            && heading.contains(subject)
+    })?;
+
+    Some(
+        section
+            .select(selector!("article"))
+            .filter_map(|article| parse_search_item(&article))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_trending() {
+        let html = r#"
+            <html><body>
+                <section class="box">
+                    <header class="box-header"><h2>Nejnavštěvovanější filmy</h2></header>
+                    <article class="article-poster-60">
+                        <h3 class="film-title-norating">
+                            <a href="/film/10135-forrest-gump/" class="film-title-name">Forrest Gump</a>
+                            <span class="film-title-info"><span class="info">(1994)</span></span>
+                        </h3>
+                    </article>
+                </section>
+                <section class="box">
+                    <header class="box-header"><h2>Nejnavštěvovanější seriály</h2></header>
+                    <article class="article-poster-60">
+                        <h3 class="film-title-norating">
+                            <a href="/film/264494-pernikovy-tata/" class="film-title-name">Perníkový táta</a>
+                            <span class="film-title-info"><span class="info">(2008)</span></span>
+                        </h3>
+                    </article>
+                </section>
+            </body></html>
+        "#;
+        let series = parse_trending(html, TrendingKind::Series).unwrap();
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].csfd_id, 264494);
+
+        let movies = parse_trending(html, TrendingKind::Movies).unwrap();
+        assert_eq!(movies[0].name, "Forrest Gump");
+
+        assert!(parse_trending("<html><body></body></html>", TrendingKind::Series).is_none());
+    }
+}
@@ -0,0 +1,20 @@
+//! Stable API surface
+//!
+//! Glob-import this module to get the scraper, its data types and errors.
+//! Everything re-exported here follows semver.
+
+#[cfg(feature = "client")]
+pub use crate::client::{ClientConfig, Politeness};
+pub use crate::diff::SeriesDiff;
+pub use crate::error::{CsfdError, Result, RetryAdvice, RetrySuggestion};
+#[cfg(feature = "client")]
+pub use crate::scraper::CsfdScraper;
+#[cfg(feature = "client")]
+pub use crate::session::{
+    BatchReport, Lane, LaneUsage, ScheduleWindow, ScrapeSession, ScraperMetrics, SessionMetrics,
+};
+pub use crate::types::{
+    CastMember, CsfdLink, Episode, EpisodeCode, EpisodeDetail, MovieDetail, PaginatedResult,
+    PersonDetail, PersonRole, RatingTier, Review, SearchResult, Season, SeriesDetail, SeriesType,
+    UserStarRating,
+};
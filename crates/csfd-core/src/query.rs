@@ -0,0 +1,313 @@
+//! Read-only SQL over the saved library
+//!
+//! Loads the library into an in-memory SQLite database and runs a single
+//! `SELECT` (or `WITH ... SELECT`) statement, so power users can run
+//! analytics such as average rating per season per genre without exporting.
+//! Available with the `sql` feature.
+//!
+//! # Tables
+//! - `series(csfd_id, name, original_name, year_range, rating, imdb_id)`
+//! - `genres(series_id, genre)`
+//! - `countries(series_id, country)`
+//! - `seasons(csfd_id, series_id, name, year, episode_count)`
+//! - `episodes(csfd_id, series_id, season_number, episode_number, name, rating,
+//!   rating_count, comment_count)`
+
+use rusqlite::types::ValueRef;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CsfdError, Result};
+use crate::library::Library;
+
+/// Maximum number of rows returned by a query
+const MAX_QUERY_ROWS: usize = 10_000;
+
+/// Schema of the in-memory database
+const SCHEMA: &str = "
+    CREATE TABLE series (
+        csfd_id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        original_name TEXT,
+        year_range TEXT,
+        rating REAL,
+        imdb_id TEXT
+    );
+    CREATE TABLE genres (series_id INTEGER NOT NULL, genre TEXT NOT NULL);
+    CREATE TABLE countries (series_id INTEGER NOT NULL, country TEXT NOT NULL);
+    CREATE TABLE seasons (
+        csfd_id INTEGER NOT NULL,
+        series_id INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        year TEXT,
+        episode_count INTEGER NOT NULL
+    );
+    CREATE TABLE episodes (
+        csfd_id INTEGER NOT NULL,
+        series_id INTEGER NOT NULL,
+        season_number INTEGER NOT NULL,
+        episode_number INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        rating REAL,
+        rating_count INTEGER,
+        comment_count INTEGER
+    );
+";
+
+/// Rows returned by a library query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryResult {
+    /// Column names in select order
+    pub columns: Vec<String>,
+    /// Rows of values (null, number or text)
+    pub rows: Vec<Vec<serde_json::Value>>,
+    /// Whether rows beyond the limit were left out
+    pub truncated: bool,
+}
+
+/// Run a read-only SQL query over the library.
+///
+/// Only a single `SELECT` or `WITH` statement is accepted, and the database
+/// is switched to query-only mode before it runs, so a query can neither
+/// modify data nor attach files. At most 10 000 rows are returned.
+///
+/// # Arguments
+/// * `library` - Saved series to query
+/// * `sql` - The query, e.g. `SELECT genre, AVG(rating) FROM series JOIN genres ...`
+///
+/// # Returns
+/// * `Ok(QueryResult)` with columns and rows
+/// * `Err(CsfdError::InvalidQuery)` if the statement is not allowed or fails
+///
+/// # Example
+/// ```
+/// use csfd_core::library::Library;
+/// use csfd_core::query::query_library;
+///
+/// let library = Library::new();
+/// let result = query_library(&library, "SELECT COUNT(*) AS saved FROM series").unwrap();
+/// assert_eq!(result.columns, vec!["saved"]);
+/// assert_eq!(result.rows[0][0], 0);
+/// ```
+pub fn query_library(library: &Library, sql: &str) -> Result<QueryResult> {
+    let sql = check_statement(sql)?;
+    let connection = load(library).map_err(query_error)?;
+    connection
+        .pragma_update(None, "query_only", true)
+        .map_err(query_error)?;
+
+    let mut statement = connection.prepare(sql).map_err(query_error)?;
+    if !statement.readonly() {
+        return Err(CsfdError::InvalidQuery(
+            "only read-only queries are allowed".to_string(),
+        ));
+    }
+    let columns: Vec<String> = statement
+        .column_names()
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let mut rows = Vec::new();
+    let mut truncated = false;
+    let mut cursor = statement.query([]).map_err(query_error)?;
+    while let Some(row) = cursor.next().map_err(query_error)? {
+        if rows.len() == MAX_QUERY_ROWS {
+            truncated = true;
+            break;
+        }
+        let values = (0..columns.len())
+            .map(|i| row.get_ref(i).map(json_value))
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .map_err(query_error)?;
+        rows.push(values);
+    }
+
+    Ok(QueryResult {
+        columns,
+        rows,
+        truncated,
+    })
+}
+
+/// Accept a single SELECT or WITH statement, returning it without a trailing semicolon.
+fn check_statement(sql: &str) -> Result<&str> {
+    let sql = sql.trim().trim_end_matches(';').trim_end();
+    let first_word = sql
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    if first_word != "SELECT" && first_word != "WITH" {
+        return Err(CsfdError::InvalidQuery(
+            "only SELECT and WITH statements are allowed".to_string(),
+        ));
+    }
+    // Further statements after the first are rejected when preparing
+    Ok(sql)
+}
+
+/// Create an in-memory database holding the library.
+fn load(library: &Library) -> rusqlite::Result<Connection> {
+    let mut connection = Connection::open_in_memory()?;
+    connection.execute_batch(SCHEMA)?;
+
+    let tx = connection.transaction()?;
+    for entry in library.entries() {
+        let detail = &entry.detail;
+        tx.execute(
+            "INSERT INTO series VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                detail.csfd_id,
+                detail.name,
+                detail.original_name,
+                detail.year_range,
+                detail.rating,
+                detail.imdb_id,
+            ],
+        )?;
+        for genre in &detail.genres {
+            tx.execute(
+                "INSERT INTO genres VALUES (?1, ?2)",
+                params![detail.csfd_id, genre.as_str()],
+            )?;
+        }
+        for country in &detail.countries {
+            tx.execute(
+                "INSERT INTO countries VALUES (?1, ?2)",
+                params![detail.csfd_id, country.as_str()],
+            )?;
+        }
+        for season in &detail.seasons {
+            tx.execute(
+                "INSERT INTO seasons VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![
+                    season.csfd_id,
+                    detail.csfd_id,
+                    season.name,
+                    season.year,
+                    season.episode_count,
+                ],
+            )?;
+        }
+        for episode in &entry.episodes {
+            tx.execute(
+                "INSERT INTO episodes VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    episode.csfd_id,
+                    detail.csfd_id,
+                    episode.season_number,
+                    episode.episode_number,
+                    episode.name,
+                    episode.rating,
+                    episode.rating_count,
+                    episode.comment_count,
+                ],
+            )?;
+        }
+    }
+    tx.commit()?;
+
+    Ok(connection)
+}
+
+/// Convert an SQLite value to JSON; blobs are not produced by the schema.
+fn json_value(value: ValueRef<'_>) -> serde_json::Value {
+    match value {
+        ValueRef::Null | ValueRef::Blob(_) => serde_json::Value::Null,
+        ValueRef::Integer(n) => n.into(),
+        ValueRef::Real(x) => serde_json::Number::from_f64(x)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned().into(),
+    }
+}
+
+fn query_error(error: rusqlite::Error) -> CsfdError {
+    CsfdError::InvalidQuery(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Episode, SeriesDetail};
+
+    fn library() -> Library {
+        let mut library = Library::new();
+        let detail = SeriesDetail {
+            csfd_id: 71924,
+            name: "Dr. House".to_string(),
+            original_name: Some("House M.D.".to_string()),
+            year_range: Some("2004-2012".to_string()),
+            genres: vec!["Drama".into(), "Mysteriózní".into()],
+            genre_ids: Vec::new(),
+            status_label: None,
+            countries: vec!["USA".into()],
+            seasons: Vec::new(),
+            directors: Vec::new(),
+            writers: Vec::new(),
+            cast: Vec::new(),
+            rating: Some(91.0),
+            recommendations: Vec::new(),
+            availability: Default::default(),
+            imdb_id: None,
+        };
+        let episodes = [(1, 80.0), (1, 90.0), (2, 70.0)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (season, rating))| Episode {
+                csfd_id: 100 + i as u32,
+                name: format!("Epizoda {}", i + 1),
+                season_number: season,
+                episode_number: i as u16 + 1,
+                rating: Some(rating),
+                url: String::new(),
+                comment_count: None,
+                rating_count: None,
+                absolute_number: None,
+                list_position: i as u32,
+            })
+            .collect();
+        library.save_series(detail, episodes).unwrap();
+        library
+    }
+
+    #[test]
+    fn test_query_average_per_season() {
+        let result = query_library(
+            &library(),
+            "SELECT g.genre, e.season_number, AVG(e.rating) AS avg_rating
+             FROM episodes e JOIN genres g ON g.series_id = e.series_id
+             WHERE g.genre = 'Drama'
+             GROUP BY g.genre, e.season_number ORDER BY e.season_number;",
+        )
+        .unwrap();
+        assert_eq!(result.columns, vec!["genre", "season_number", "avg_rating"]);
+        assert_eq!(
+            serde_json::to_value(&result.rows).unwrap(),
+            serde_json::json!([["Drama", 1, 85.0], ["Drama", 2, 70.0]])
+        );
+        assert!(!result.truncated);
+    }
+
+    #[test]
+    fn test_query_rejects_writes() {
+        let library = library();
+        for sql in [
+            "DELETE FROM series",
+            "SELECT 1; DROP TABLE series",
+            "ATTACH DATABASE '/tmp/x.db' AS x",
+            "PRAGMA query_only = OFF",
+            "WITH x AS (SELECT 1) DELETE FROM series",
+        ] {
+            assert!(
+                matches!(
+                    query_library(&library, sql),
+                    Err(CsfdError::InvalidQuery(_))
+                ),
+                "{} was accepted",
+                sql
+            );
+        }
+    }
+}
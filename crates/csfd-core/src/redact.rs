@@ -0,0 +1,154 @@
+//! Redaction of private data in URLs and messages
+//!
+//! Query strings can carry search terms or tokens, and profile URLs
+//! (`/uzivatel/{id}-{nick}/`) identify users. With redaction enabled (see
+//! `ClientConfig::redact`), errors and activity events pass through these
+//! helpers before they can end up in logs or bug reports.
+
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use regex_lite::{Captures, Regex};
+
+/// Placeholder for removed data
+const REDACTED: &str = "…";
+
+/// Path segments after which ČSFD puts a user identifier
+const USER_SEGMENTS: [&str; 2] = ["uzivatel", "user"];
+
+/// Strip the query string and fragment from a URL and mask user identifiers.
+///
+/// # Example
+/// ```
+/// use csfd_core::redact::redact_url;
+///
+/// assert_eq!(redact_url("/hledat/?q=dr+house"), "/hledat/?…");
+/// assert_eq!(redact_url("https://www.csfd.cz/uzivatel/12345-nick/hodnoceni/"), "https://www.csfd.cz/uzivatel/…/hodnoceni/");
+/// assert_eq!(redact_url("/film/264494-stranger-things/"), "/film/264494-stranger-things/");
+/// ```
+pub fn redact_url(url: &str) -> Cow<'_, str> {
+    let (path, had_query) = match url.find(['?', '#']) {
+        Some(index) => (&url[..index], true),
+        None => (url, false),
+    };
+
+    let mut segments: Vec<&str> = path.split('/').collect();
+    let mut masked = false;
+    for i in 1..segments.len() {
+        if USER_SEGMENTS.contains(&segments[i - 1]) && !segments[i].is_empty() {
+            segments[i] = REDACTED;
+            masked = true;
+        }
+    }
+
+    if !had_query && !masked {
+        return Cow::Borrowed(url);
+    }
+    let mut redacted = segments.join("/");
+    if had_query {
+        redacted.push('?');
+        redacted.push_str(REDACTED);
+    }
+    Cow::Owned(redacted)
+}
+
+/// Redact every URL or path in a free-form message.
+///
+/// Words starting with `/` or containing `://` are treated as URLs.
+pub fn redact_text(text: &str) -> Cow<'_, str> {
+    let is_url = |word: &str| word.starts_with('/') || word.contains("://");
+    if !text.split_whitespace().any(is_url) {
+        return Cow::Borrowed(text);
+    }
+
+    let redacted = text
+        .split(' ')
+        .map(|word| {
+            if is_url(word) {
+                redact_url(word)
+            } else {
+                Cow::Borrowed(word)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    Cow::Owned(redacted)
+}
+
+/// Redact a page before it is shared, e.g. in a diagnostics bundle.
+///
+/// Link and form targets go through `redact_url`, and values of hidden
+/// inputs (CSRF tokens, form state) and meta tags with tokens are masked.
+/// The markup itself stays intact so the page still reproduces parser bugs.
+///
+/// # Example
+/// ```
+/// use csfd_core::redact::redact_html;
+///
+/// let html = r#"<a href="/uzivatel/12345-nick/">nick</a><input type="hidden" name="_token_" value="abc">"#;
+/// assert_eq!(
+///     redact_html(html),
+///     r#"<a href="/uzivatel/…/">nick</a><input type="hidden" name="_token_" value="…">"#
+/// );
+/// ```
+pub fn redact_html(html: &str) -> Cow<'_, str> {
+    static URL_ATTR: OnceLock<Regex> = OnceLock::new();
+    static SECRET_TAG: OnceLock<Regex> = OnceLock::new();
+    static VALUE_ATTR: OnceLock<Regex> = OnceLock::new();
+    let url_attr = URL_ATTR
+        .get_or_init(|| Regex::new(r#"\b(href|action|src)="([^"]*)""#).expect("valid regex"));
+    let secret_tag = SECRET_TAG.get_or_init(|| {
+        Regex::new(r#"<input[^>]*type="hidden"[^>]*>|<meta[^>]*name="[^"]*token[^"]*"[^>]*>"#)
+            .expect("valid regex")
+    });
+    let value_attr =
+        VALUE_ATTR.get_or_init(|| Regex::new(r#"\b(value|content)="[^"]*""#).expect("valid regex"));
+
+    let html = url_attr.replace_all(html, |caps: &Captures| {
+        format!(r#"{}="{}""#, &caps[1], redact_url(&caps[2]))
+    });
+    let masked = match secret_tag.replace_all(&html, |caps: &Captures| {
+        value_attr
+            .replace_all(&caps[0], format!(r#"$1="{}""#, REDACTED).as_str())
+            .into_owned()
+    }) {
+        Cow::Owned(masked) => Some(masked),
+        Cow::Borrowed(_) => None,
+    };
+    masked.map_or(html, Cow::Owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_url() {
+        assert_eq!(redact_url("/hledat/?q=test#top"), "/hledat/?…");
+        assert_eq!(redact_url("/uzivatel/1-nick/"), "/uzivatel/…/");
+        assert_eq!(redact_url("/uzivatel/"), "/uzivatel/");
+        assert!(matches!(redact_url("/film/1-a/"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_redact_html() {
+        let html = r#"<form action="/hledat/?q=tajne"><meta name="csrf-token" content="xyz"><a href="/film/1-a/">A</a></form>"#;
+        assert_eq!(
+            redact_html(html),
+            r#"<form action="/hledat/?…"><meta name="csrf-token" content="…"><a href="/film/1-a/">A</a></form>"#
+        );
+        assert!(matches!(
+            redact_html("<p>Perníkový táta</p>"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_redact_text() {
+        assert_eq!(
+            redact_text("Series not found: https://www.csfd.cz/hledat/?q=secret"),
+            "Series not found: https://www.csfd.cz/hledat/?…"
+        );
+        assert_eq!(redact_text("Operation cancelled"), "Operation cancelled");
+    }
+}
@@ -0,0 +1,110 @@
+//! Relevance scoring of search results
+//!
+//! ČSFD orders search hits by its own popularity heuristics, which puts a
+//! well-known show above an exact title match now and then. The score
+//! combines that ordering with how closely a title matches the query, so
+//! frontends and resolvers can apply one "good enough" threshold.
+
+use crate::text::{normalize, similarity};
+use crate::types::SearchResult;
+
+/// Score from which a result is considered a confident match
+pub const STRONG_MATCH_SCORE: f32 = 0.8;
+
+/// Weight of title similarity in the score; the rest comes from ČSFD's ordering
+const SIMILARITY_WEIGHT: f32 = 0.8;
+
+/// Set `score` on search results for a query.
+///
+/// The score is a weighted mix of the title similarity to the query
+/// (ignoring case and diacritics, best of name and original name) and the
+/// position in ČSFD's ordering. Exact title matches at the top score 1.0.
+///
+/// # Arguments
+/// * `items` - Results in ČSFD's order
+/// * `query` - The query the results were returned for
+/// * `offset` - Number of results on previous pages
+pub fn score_results(items: &mut [SearchResult], query: &str, offset: usize) {
+    let query = normalize(query);
+
+    for (index, result) in items.iter_mut().enumerate() {
+        let name_similarity = similarity(&normalize(&result.name), &query);
+        let original_similarity = result
+            .original_name
+            .as_deref()
+            .map_or(0.0, |name| similarity(&normalize(name), &query));
+
+        let order = 1.0 / (1.0 + (offset + index) as f32 / 5.0);
+        let score = SIMILARITY_WEIGHT * name_similarity.max(original_similarity)
+            + (1.0 - SIMILARITY_WEIGHT) * order;
+        result.score = Some(score);
+    }
+}
+
+/// Check whether a result is a confident match for its query.
+///
+/// Results that were never scored don't count as confident.
+pub fn is_strong_match(result: &SearchResult) -> bool {
+    result
+        .score
+        .is_some_and(|score| score >= STRONG_MATCH_SCORE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SeriesType;
+
+    fn result(name: &str, original_name: Option<&str>) -> SearchResult {
+        SearchResult {
+            name: name.to_string(),
+            original_name: original_name.map(str::to_string),
+            year: None,
+            series_type: SeriesType::Series,
+            url: "/film/1-test/".to_string(),
+            csfd_id: 1,
+            local_rank: None,
+            score: None,
+        }
+    }
+
+    #[test]
+    fn test_score_exact_match_first() {
+        let mut items = vec![result("Perníkový táta", Some("Breaking Bad"))];
+        score_results(&mut items, "pernikovy tata", 0);
+        assert_eq!(items[0].score, Some(1.0));
+        assert!(is_strong_match(&items[0]));
+    }
+
+    #[test]
+    fn test_score_uses_original_name() {
+        let mut items = vec![result("Perníkový táta", Some("Breaking Bad"))];
+        score_results(&mut items, "Breaking Bad", 0);
+        assert!(is_strong_match(&items[0]));
+    }
+
+    #[test]
+    fn test_score_prefers_title_over_order() {
+        let mut items = vec![
+            result("Teorie velkého třesku", None),
+            result("Dr. House", None),
+        ];
+        score_results(&mut items, "dr house", 0);
+        assert!(items[1].score > items[0].score);
+        assert!(!is_strong_match(&items[0]));
+    }
+
+    #[test]
+    fn test_score_decreases_with_position() {
+        let mut first = vec![result("Dr. House", None)];
+        let mut later = first.clone();
+        score_results(&mut first, "house", 0);
+        score_results(&mut later, "house", 20);
+        assert!(first[0].score > later[0].score);
+    }
+
+    #[test]
+    fn test_unscored_is_not_strong() {
+        assert!(!is_strong_match(&result("Dr. House", None)));
+    }
+}
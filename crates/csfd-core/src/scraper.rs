@@ -4,10 +4,87 @@
 //! It combines the HTTP client with parsers to provide a simple interface
 //! for searching series, getting details, and fetching episodes.
 
-use crate::client::CsfdClient;
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use tokio::sync::Mutex;
+
+use crate::activity::ActivityEvent;
+use crate::auth::{validate_session, PasswordLogin};
+use crate::cache::LruMap;
+use crate::client::{CsfdClient, FetchedImage};
+#[cfg(feature = "diagnostics")]
+use crate::diagnostics::{ConfigSummary, DiagnosticsBundle};
 use crate::error::{CsfdError, Result};
-use crate::parser::{parse_episodes, parse_search_results, parse_series_detail};
-use crate::types::{Episode, PaginatedResult, SearchResult, SeriesDetail};
+use crate::freshness::EntityKind;
+use crate::parser::{
+    extract_creator_id, extract_csfd_id, has_next_page, page_path, parse_cast, parse_chart,
+    parse_episode_detail, parse_episode_detail_verified, parse_episodes, parse_hidden_inputs,
+    parse_homepage, parse_list_id, parse_movie_detail, parse_movie_detail_verified, parse_person,
+    parse_person_verified, parse_premieres, parse_profile_path, parse_reviews,
+    parse_search_results, parse_series_detail, parse_series_detail_verified, parse_sitemap,
+    parse_trending, parse_user_list, parse_user_lists, parse_user_ratings, site_path,
+};
+use crate::relevance::score_results;
+use crate::session::{Lane, Lanes, ScrapeSession, ScraperMetrics};
+use crate::types::{
+    CastMember, DateRange, DiscoveredId, DiscoveryKind, Episode, EpisodeDetail, EpisodeSort,
+    EpisodesGrouped, FullSeries, HomepageDigest, MovieDetail, PaginatedResult, PersonDetail,
+    PremiereDay, PremiereMedium, Progress, Review, SearchResult, Season, SeriesDetail,
+    SeriesFilters, SeriesType, TrendingKind, UserList, UserListEntry, UserRating, UserStarRating,
+};
+
+/// Maximum number of episode pages fetched concurrently when scanning credits
+///
+/// Requests are still spaced by the client's rate limiter; this only bounds
+/// how many are queued at once.
+const CREDITS_SCAN_CONCURRENCY: usize = 4;
+
+/// Maximum number of season pages fetched concurrently by `get_all_episodes`
+///
+/// As with credits scans, the rate limiter still spaces the requests.
+const SEASON_FETCH_CONCURRENCY: usize = 4;
+
+/// Maximum number of detail pages fetched concurrently by `get_series_bulk`
+const SERIES_BULK_CONCURRENCY: usize = 4;
+
+/// Chart of best-rated series, used as the pool for random picks
+const SERIES_CHART_PATH: &str = "/zebricky/serialy/nejlepsi/";
+
+/// Advanced search restricted to TV series
+const ADVANCED_SEARCH_PATH: &str = "/podrobne-vyhledavani/?type[]=3";
+
+/// Homepage with news, trending titles, premieres and birthdays
+const HOMEPAGE_PATH: &str = "/";
+
+/// Overviews of upcoming premieres per medium
+const PREMIERE_OVERVIEWS: [(PremiereMedium, &str); 3] = [
+    (PremiereMedium::Cinema, "/kino/prehled/?period=soon"),
+    (PremiereMedium::Tv, "/televize/premiery/"),
+    (PremiereMedium::Vod, "/vod/prehled/?period=soon"),
+];
+
+/// Form creating a custom list of the logged-in user
+const NEW_LIST_PATH: &str = "/seznamy/novy/";
+
+/// Number of downloaded images kept in memory
+const IMAGE_CACHE_CAPACITY: usize = 64;
+
+/// Number of episode casts kept in memory, enough for a long-running series
+const CAST_CACHE_CAPACITY: usize = 1024;
+
+/// Entries per page of ČSFD charts, paged with `?from=`
+const CHART_PAGE_SIZE: usize = 100;
+
+/// Entries per full page of users' lists and the watchlist
+const LIST_PAGE_SIZE: u32 = 50;
+
+/// Maximum number of detail pages fetched when looking for a random series
+const RANDOM_SERIES_ATTEMPTS: usize = 5;
 
 /// Main scraper API for ČSFD.cz
 ///
@@ -31,6 +108,55 @@ use crate::types::{Episode, PaginatedResult, SearchResult, SeriesDetail};
 /// ```
 pub struct CsfdScraper {
     client: CsfdClient,
+    /// Cast of recently scanned episode pages, keyed by episode ID
+    cast_cache: Mutex<LruMap<u32, Vec<CastMember>>>,
+    /// Episode lists of seasons, keyed by (series ID, season ID)
+    season_cache: Mutex<HashMap<(u32, u32), CachedSeason>>,
+    /// Recently downloaded images, keyed by URL
+    image_cache: Mutex<ImageCache>,
+    /// Trending rows of the homepage with when they were fetched
+    trending_cache: Mutex<HashMap<TrendingKind, (Instant, Vec<SearchResult>)>>,
+    /// Profile path of the logged-in user, read from the page header once
+    profile_path: Mutex<Option<String>>,
+    /// In-flight limits shared by all sessions
+    lanes: Lanes,
+}
+
+/// Episode list of a season with when it was fetched
+struct CachedSeason {
+    fetched: Instant,
+    /// Ended seasons stay fresh longer, see `EntityKind::season_episodes`
+    kind: EntityKind,
+    episodes: Vec<Episode>,
+}
+
+/// Images kept in memory, evicting the oldest first
+#[derive(Default)]
+struct ImageCache {
+    images: HashMap<String, Arc<FetchedImage>>,
+    order: VecDeque<String>,
+}
+
+impl ImageCache {
+    fn insert(&mut self, url: String, image: Arc<FetchedImage>) {
+        if self.images.insert(url.clone(), image).is_none() {
+            self.order.push_back(url);
+        }
+        while self.order.len() > IMAGE_CACHE_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.images.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Check whether a page belongs to a series: its detail tabs, seasons and episodes
+fn is_series_path(path: &str, series_id: u32) -> bool {
+    let Some(rest) = path.strip_prefix("/film/") else {
+        return false;
+    };
+    let id = rest.split(['/', '-', '?']).next().unwrap_or_default();
+    id.parse() == Ok(series_id)
 }
 
 impl CsfdScraper {
@@ -47,7 +173,7 @@ impl CsfdScraper {
     /// ```
     pub fn new() -> Result<Self> {
         let client = CsfdClient::new()?;
-        Ok(Self { client })
+        Ok(Self::with_client(client))
     }
 
     /// Create a new scraper with a custom client.
@@ -57,13 +183,70 @@ impl CsfdScraper {
     /// # Arguments
     /// * `client` - Pre-configured CsfdClient instance
     pub fn with_client(client: CsfdClient) -> Self {
-        Self { client }
+        let lanes = Lanes::new(
+            client.max_inflight(Lane::Interactive),
+            client.max_inflight(Lane::Background),
+        );
+        Self {
+            client,
+            lanes,
+            cast_cache: Mutex::new(LruMap::default()),
+            season_cache: Mutex::new(HashMap::new()),
+            image_cache: Mutex::new(ImageCache::default()),
+            trending_cache: Mutex::new(HashMap::new()),
+            profile_path: Mutex::new(None),
+        }
+    }
+
+    /// Get the underlying HTTP client.
+    ///
+    /// Useful to inspect client state such as write actions recorded in
+    /// dry-run mode.
+    pub fn client(&self) -> &CsfdClient {
+        &self.client
+    }
+
+    /// Start a session for a group of related operations.
+    ///
+    /// The session owns a cancellation token, a concurrency budget and metrics
+    /// for one logical batch of work, such as a library scan.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let session = scraper.session();
+    /// let results = session.run(scraper.search("Dr. House")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn session(&self) -> ScrapeSession<'_> {
+        ScrapeSession::new(self)
+    }
+
+    /// Get the current use of the interactive and background in-flight limits.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// let scraper = CsfdScraper::new().unwrap();
+    /// assert_eq!(scraper.metrics().background.in_flight, 0);
+    /// ```
+    pub fn metrics(&self) -> ScraperMetrics {
+        self.lanes.metrics()
     }
 
+    pub(crate) fn lanes(&self) -> &Lanes {
+        &self.lanes
+    }
 
     /// Search for series by name.
     ///
-    /// Returns the first page of search results. Use `search_page` for pagination.
+    /// Returns the first page of search results. Use `search_page` for pagination
+    /// or `search_all` to stream every page.
     ///
     /// # Arguments
     /// * `query` - Search query string
@@ -109,33 +292,81 @@ impl CsfdScraper {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn search_page(&self, query: &str, page: u32) -> Result<PaginatedResult<SearchResult>> {
+    pub async fn search_page(
+        &self,
+        query: &str,
+        page: u32,
+    ) -> Result<PaginatedResult<SearchResult>> {
         // Validate query is not empty or whitespace-only
         let trimmed = query.trim();
         if trimmed.is_empty() {
-            return Err(CsfdError::InvalidUrl("Search query cannot be empty".to_string()));
+            return Err(CsfdError::InvalidUrl(
+                "Search query cannot be empty".to_string(),
+            ));
         }
 
         // URL encode the query
         let encoded_query = urlencoding::encode(trimmed);
-        
+
         // Build search URL with pagination
-        let path = if page > 1 {
-            format!("/hledat/?q={}&page={}", encoded_query, page)
-        } else {
-            format!("/hledat/?q={}", encoded_query)
-        };
+        let path = page_path(&format!("/hledat/?q={}", encoded_query), page);
 
         // Fetch and parse
         let html = self.client.fetch(&path).await?;
         let mut result = parse_search_results(&html)?;
-        
+
         // Ensure current_page is set correctly
         result.current_page = page;
-        
+
+        // Rank against the query; earlier pages are assumed to be as full as this one
+        let offset = (page.saturating_sub(1) as usize) * result.items.len();
+        score_results(&mut result.items, trimmed, offset);
+
         Ok(result)
     }
 
+    /// Search for series by name, walking all result pages.
+    ///
+    /// Pages are fetched lazily as the stream is polled, so a caller that
+    /// stops early (e.g., after `take(5)`) doesn't request the remaining
+    /// pages. Results are ranked like those of `search_page`.
+    ///
+    /// # Arguments
+    /// * `query` - Search query string
+    ///
+    /// # Returns
+    /// A stream of results; an error (e.g., `CsfdError::InvalidUrl` for an
+    /// empty query) is yielded once and ends the stream.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let first: Vec<_> = scraper.search_all("Star Trek").take(30).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_all<'a>(&'a self, query: &str) -> impl Stream<Item = Result<SearchResult>> + 'a {
+        let query = query.to_string();
+        stream::try_unfold((Some(1u32), query), move |(page, query)| async move {
+            let Some(page) = page else {
+                return Ok::<_, CsfdError>(None);
+            };
+
+            let result = self.search_page(&query, page).await?;
+
+            // Stop on an empty page even if a next link is present
+            let next = (!result.items.is_empty() && result.has_next_page).then_some(page + 1);
+            Ok(Some((
+                stream::iter(result.items.into_iter().map(Ok)),
+                (next, query),
+            )))
+        })
+        .try_flatten()
+    }
 
     /// Get detailed information about a series.
     ///
@@ -146,6 +377,8 @@ impl CsfdScraper {
     /// * `Ok(SeriesDetail)` with series information and seasons
     /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
     /// * `Err(CsfdError::NotFound)` if series doesn't exist
+    /// * `Err(CsfdError::UnexpectedPage)` if the page isn't the requested series
+    ///   (checked unless `ClientConfig::verify_pages` is off)
     ///
     /// # Example
     /// ```no_run
@@ -167,15 +400,237 @@ impl CsfdScraper {
         // Fetch series detail page
         let path = format!("/film/{}/prehled/", csfd_id);
         let html = self.client.fetch(&path).await?;
-        
+
         // Parse and return
-        parse_series_detail(&html, csfd_id)
+        if self.client.verifies_pages() {
+            parse_series_detail_verified(&html, csfd_id)
+        } else {
+            parse_series_detail(&html, csfd_id)
+        }
+    }
+
+    /// Get the details of several series at once.
+    ///
+    /// Up to four detail pages are requested at once, still spaced by the
+    /// client's rate limiter. A failing series doesn't stop the others.
+    ///
+    /// # Arguments
+    /// * `ids` - ČSFD IDs of the series
+    ///
+    /// # Returns
+    /// The result of `get_series` for each ID, in the order of `ids`
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// for result in scraper.get_series_bulk(&[264494, 234260]).await {
+    ///     match result {
+    ///         Ok(series) => println!("{}", series.name),
+    ///         Err(e) => println!("failed: {}", e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_series_bulk(&self, ids: &[u32]) -> Vec<Result<SeriesDetail>> {
+        stream::iter(ids.iter().copied().map(|id| self.get_series(id)))
+            .buffered(SERIES_BULK_CONCURRENCY)
+            .collect()
+            .await
+    }
+
+    /// Fetch a series page and bundle it for a parser bug report.
+    ///
+    /// Unlike `get_series`, parse errors don't fail the call; they are
+    /// recorded in the bundle's report, next to the redacted page.
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD ID of the series
+    ///
+    /// # Returns
+    /// * `Ok(DiagnosticsBundle)` ready to be written with `write_zip`
+    /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
+    /// * `Err(CsfdError)` if the page cannot be fetched
+    #[cfg(feature = "diagnostics")]
+    pub async fn create_diagnostics_bundle(&self, csfd_id: u32) -> Result<DiagnosticsBundle> {
+        if csfd_id == 0 {
+            return Err(CsfdError::InvalidId(csfd_id));
+        }
+
+        let path = format!("/film/{}/prehled/", csfd_id);
+        let html = self.client.fetch(&path).await?;
+        Ok(DiagnosticsBundle::from_series_page(
+            csfd_id,
+            &path,
+            &html,
+            ConfigSummary::of(&self.client),
+        ))
+    }
+
+    /// Get detailed information about a film.
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD ID of the film
+    ///
+    /// # Returns
+    /// * `Ok(MovieDetail)` with film information
+    /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
+    /// * `Err(CsfdError::NotFound)` if the film doesn't exist
+    /// * `Err(CsfdError::UnexpectedPage)` if the page isn't the requested film
+    ///   (checked unless `ClientConfig::verify_pages` is off)
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let movie = scraper.get_movie(2294).await?;
+    /// println!("{} ({:?}), {:?} min", movie.name, movie.year, movie.runtime_minutes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_movie(&self, csfd_id: u32) -> Result<MovieDetail> {
+        if csfd_id == 0 {
+            return Err(CsfdError::InvalidId(csfd_id));
+        }
+
+        let html = self
+            .client
+            .fetch(&format!("/film/{}/prehled/", csfd_id))
+            .await?;
+        if self.client.verifies_pages() {
+            parse_movie_detail_verified(&html, csfd_id)
+        } else {
+            parse_movie_detail(&html, csfd_id)
+        }
+    }
+
+    /// Get the detail of a single episode.
+    ///
+    /// Unlike `Episode` from the episode lists, the detail carries the plot,
+    /// air date, runtime, directors and cast.
+    ///
+    /// # Arguments
+    /// * `series_id` - ČSFD ID of the series
+    /// * `episode_id` - ČSFD ID of the episode
+    ///
+    /// # Returns
+    /// * `Ok(EpisodeDetail)` with episode information
+    /// * `Err(CsfdError::InvalidId)` if either ID is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let episode = scraper.get_episode(71924, 233581).await?;
+    /// println!("{}: {:?}", episode.name, episode.plot);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_episode(&self, series_id: u32, episode_id: u32) -> Result<EpisodeDetail> {
+        if series_id == 0 {
+            return Err(CsfdError::InvalidId(series_id));
+        }
+        if episode_id == 0 {
+            return Err(CsfdError::InvalidId(episode_id));
+        }
+
+        let path = format!("/film/{}/{}/prehled/", series_id, episode_id);
+        let html = self.client.fetch(&path).await?;
+        if self.client.verifies_pages() {
+            parse_episode_detail_verified(&html, series_id, episode_id)
+        } else {
+            parse_episode_detail(&html, series_id, episode_id)
+        }
+    }
+
+    /// Get a person's profile and filmography.
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD creator ID of the person (from a `/tvurce/` URL)
+    ///
+    /// # Returns
+    /// * `Ok(PersonDetail)` with biography, birth date, photo and filmography by role
+    /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let person = scraper.get_person(3101).await?;
+    /// for group in &person.filmography {
+    ///     println!("{:?}: {} titles", group.role, group.titles.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_person(&self, csfd_id: u32) -> Result<PersonDetail> {
+        if csfd_id == 0 {
+            return Err(CsfdError::InvalidId(csfd_id));
+        }
+
+        let html = self
+            .client
+            .fetch(&format!("/tvurce/{}/prehled/", csfd_id))
+            .await?;
+        if self.client.verifies_pages() {
+            parse_person_verified(&html, csfd_id)
+        } else {
+            parse_person(&html, csfd_id)
+        }
+    }
+
+    /// Get a page of user reviews of a title.
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD ID of the series, film or episode
+    /// * `page` - Page number (1-based)
+    ///
+    /// # Returns
+    /// * `Ok(PaginatedResult<Review>)` with the reviews on the page
+    /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let reviews = scraper.get_reviews(264494, 1).await?;
+    /// for review in &reviews.items {
+    ///     println!("{} ({:?}): {}", review.author, review.rating, review.text);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_reviews(&self, csfd_id: u32, page: u32) -> Result<PaginatedResult<Review>> {
+        if csfd_id == 0 {
+            return Err(CsfdError::InvalidId(csfd_id));
+        }
+
+        let path = page_path(&format!("/film/{}/recenze/", csfd_id), page);
+        let html = self.client.fetch(&path).await?;
+        let mut result = parse_reviews(&html)?;
+        result.current_page = page.max(1);
+        Ok(result)
     }
 
     /// Get all episodes for a series.
     ///
+    /// Episodes come in the requested order; if ČSFD ignores the order,
+    /// they are sorted locally.
+    ///
     /// # Arguments
     /// * `csfd_id` - ČSFD ID of the series
+    /// * `sort` - Order of the listing
     ///
     /// # Returns
     /// * `Ok(Vec<Episode>)` with all episodes
@@ -183,36 +638,39 @@ impl CsfdScraper {
     ///
     /// # Example
     /// ```no_run
-    /// use csfd_core::CsfdScraper;
+    /// use csfd_core::{CsfdScraper, EpisodeSort};
     ///
     /// # async fn example() -> Result<(), csfd_core::CsfdError> {
     /// let scraper = CsfdScraper::new()?;
-    /// let episodes = scraper.get_episodes(12345).await?;
+    /// let episodes = scraper.get_episodes(12345, EpisodeSort::Aired).await?;
     /// for ep in episodes {
-    ///     println!("{}: {}", ep.episode_code, ep.name);
+    ///     println!("{}: {}", ep.code(), ep.name);
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_episodes(&self, csfd_id: u32) -> Result<Vec<Episode>> {
+    pub async fn get_episodes(&self, csfd_id: u32, sort: EpisodeSort) -> Result<Vec<Episode>> {
         // Validate ID
         if csfd_id == 0 {
             return Err(CsfdError::InvalidId(csfd_id));
         }
 
-        // Fetch episodes page
         let path = format!("/film/{}/epizody/", csfd_id);
-        let html = self.client.fetch(&path).await?;
-        
-        // Parse and return
-        parse_episodes(&html)
+        self.fetch_sorted_episodes(&path, sort).await
     }
 
     /// Get episodes for a specific season.
     ///
+    /// Episode lists in aired order are cached per season for
+    /// `FreshnessPolicy::ongoing_episodes`, or `ended_episodes` for seasons
+    /// whose year has passed when listed through the series, since those
+    /// can't gain episodes. Other orders are requested from ČSFD and sorted
+    /// locally if ČSFD ignores them.
+    ///
     /// # Arguments
     /// * `series_id` - ČSFD ID of the series
     /// * `season_id` - ČSFD ID of the season
+    /// * `sort` - Order of the listing
     ///
     /// # Returns
     /// * `Ok(Vec<Episode>)` with episodes from the specified season
@@ -220,15 +678,20 @@ impl CsfdScraper {
     ///
     /// # Example
     /// ```no_run
-    /// use csfd_core::CsfdScraper;
+    /// use csfd_core::{CsfdScraper, EpisodeSort};
     ///
     /// # async fn example() -> Result<(), csfd_core::CsfdError> {
     /// let scraper = CsfdScraper::new()?;
-    /// let episodes = scraper.get_season_episodes(12345, 67890).await?;
+    /// let best = scraper.get_season_episodes(12345, 67890, EpisodeSort::Rating).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_season_episodes(&self, series_id: u32, season_id: u32) -> Result<Vec<Episode>> {
+    pub async fn get_season_episodes(
+        &self,
+        series_id: u32,
+        season_id: u32,
+        sort: EpisodeSort,
+    ) -> Result<Vec<Episode>> {
         // Validate IDs
         if series_id == 0 {
             return Err(CsfdError::InvalidId(series_id));
@@ -237,32 +700,1410 @@ impl CsfdScraper {
             return Err(CsfdError::InvalidId(season_id));
         }
 
-        // Fetch season episodes page
+        if sort == EpisodeSort::Aired {
+            return self
+                .cached_season_episodes(series_id, season_id, None)
+                .await;
+        }
         let path = format!("/film/{}/{}/epizody/", series_id, season_id);
-        let html = self.client.fetch(&path).await?;
-        
-        // Parse and return
-        parse_episodes(&html)
+        self.fetch_sorted_episodes(&path, sort).await
     }
-}
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    #[test]
-    fn test_scraper_creation() {
-        let scraper = CsfdScraper::new();
-        assert!(scraper.is_ok());
+    /// Get all episodes of a series grouped by season.
+    ///
+    /// Seasons share the cache of `get_season_episodes`. A series without
+    /// seasons comes back as one season standing for the whole series.
+    ///
+    /// # Arguments
+    /// * `series_id` - ČSFD ID of the series
+    ///
+    /// # Returns
+    /// * `Ok(EpisodesGrouped)` with the seasons in ČSFD's order
+    /// * `Err(CsfdError::InvalidId)` if series_id is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let grouped = scraper.get_episodes_grouped(71924).await?;
+    /// for (season, episodes) in &grouped.seasons {
+    ///     println!("{}: {} episodes", season.name, episodes.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_episodes_grouped(&self, series_id: u32) -> Result<EpisodesGrouped> {
+        Ok(self.get_full_series(series_id, |_| {}).await?.episodes)
     }
 
-    #[tokio::test]
-    async fn test_search_empty_query() {
-        let scraper = CsfdScraper::new().unwrap();
-        let result = scraper.search("").await;
+    /// Get all episodes of a series, fetching its seasons concurrently.
+    ///
+    /// The season list is parsed from the series detail first, then up to
+    /// four season pages are requested at once, paced by the client's rate
+    /// limiter. Seasons share the cache of `get_season_episodes`. A series
+    /// without seasons is read from its own episodes tab.
+    ///
+    /// # Arguments
+    /// * `series_id` - ČSFD ID of the series
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Episode>)` with all episodes sorted by season and episode number
+    /// * `Err(CsfdError::InvalidId)` if series_id is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let episodes = scraper.get_all_episodes(71924).await?;
+    /// for ep in &episodes {
+    ///     println!("{}: {}", ep.code(), ep.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_episodes(&self, series_id: u32) -> Result<Vec<Episode>> {
+        if series_id == 0 {
+            return Err(CsfdError::InvalidId(series_id));
+        }
+
+        let detail = self.get_series(series_id).await?;
+        if detail.seasons.is_empty() {
+            return self.get_episodes(series_id, EpisodeSort::Aired).await;
+        }
+
+        // Seasons are moved into the futures, so the stream borrows nothing
+        // but `self` and the future stays `Send` for Tauri commands
+        let seasons: Vec<Vec<Episode>> = stream::iter(detail.seasons.into_iter().enumerate().map(
+            |(index, season)| async move {
+                let mut episodes = self
+                    .cached_season_episodes(series_id, season.csfd_id, season.year.as_deref())
+                    .await?;
+                number_unnumbered_season(&season, index, &mut episodes);
+                Ok::<_, CsfdError>(episodes)
+            },
+        ))
+        .buffered(SEASON_FETCH_CONCURRENCY)
+        .try_collect()
+        .await?;
+        Ok(merge_seasons(seasons))
+    }
+
+    /// Get a series' detail and the episodes of all its seasons, reporting progress.
+    ///
+    /// Fetching the detail is the first step and each season one more, so
+    /// `total` grows once the seasons are known. `on_progress` is called
+    /// before each step with its label (empty while the detail loads, then
+    /// the season name) and once more when all steps are done.
+    ///
+    /// # Arguments
+    /// * `series_id` - ČSFD ID of the series
+    /// * `on_progress` - Called with the progress before each step
+    ///
+    /// # Returns
+    /// * `Ok(FullSeries)` with the detail and the episodes grouped by season
+    /// * `Err(CsfdError::InvalidId)` if series_id is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let full = scraper
+    ///     .get_full_series(71924, |progress| {
+    ///         println!("{}/{} {}", progress.completed, progress.total, progress.current_label);
+    ///     })
+    ///     .await?;
+    /// println!("{} episodes", full.episodes.episode_count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_full_series(
+        &self,
+        series_id: u32,
+        mut on_progress: impl FnMut(&Progress),
+    ) -> Result<FullSeries> {
+        if series_id == 0 {
+            return Err(CsfdError::InvalidId(series_id));
+        }
+
+        let mut progress = Progress {
+            completed: 0,
+            total: 1,
+            current_label: String::new(),
+        };
+        on_progress(&progress);
+        let detail = self.get_series(series_id).await?;
+        progress.completed = 1;
+        progress.total = 1 + detail.seasons.len().max(1);
+
+        if detail.seasons.is_empty() {
+            progress.current_label = detail.name.clone();
+            on_progress(&progress);
+            let episodes = self.get_episodes(series_id, EpisodeSort::Aired).await?;
+            let season = Season {
+                csfd_id: series_id,
+                name: detail.name.clone(),
+                number: None,
+                label: detail.name.clone(),
+                year: detail.year_range.clone(),
+                episode_count: episodes.len() as u32,
+                url: format!("/film/{}/", series_id),
+                poster_url: None,
+                rating: detail.rating,
+            };
+            progress.completed += 1;
+            on_progress(&progress);
+            return Ok(FullSeries {
+                detail,
+                episodes: EpisodesGrouped {
+                    seasons: vec![(season, episodes)],
+                },
+            });
+        }
+
+        let mut seasons = Vec::with_capacity(detail.seasons.len());
+        for (index, season) in detail.seasons.iter().enumerate() {
+            progress.current_label = season.name.clone();
+            on_progress(&progress);
+            let mut episodes = self
+                .cached_season_episodes(series_id, season.csfd_id, season.year.as_deref())
+                .await?;
+            number_unnumbered_season(season, index, &mut episodes);
+            seasons.push((season.clone(), episodes));
+            progress.completed += 1;
+        }
+        on_progress(&progress);
+        Ok(FullSeries {
+            detail,
+            episodes: EpisodesGrouped { seasons },
+        })
+    }
+
+    /// Fetch an episodes tab in a given order, sorting locally if ČSFD ignored it
+    async fn fetch_sorted_episodes(&self, path: &str, sort: EpisodeSort) -> Result<Vec<Episode>> {
+        let path = match sort.query() {
+            Some(query) => format!("{}?{}", path, query),
+            None => path.to_string(),
+        };
+        let html = self.client.fetch(&path).await?;
+        let mut episodes = parse_episodes(&html)?;
+        if !sort.is_sorted(&episodes) {
+            sort.sort(&mut episodes);
+        }
+        Ok(episodes)
+    }
+
+    /// Get a season's episodes from the season cache or from ČSFD.
+    ///
+    /// A known `year` decides how long the list stays fresh; without one,
+    /// a cached list keeps the kind it was stored with.
+    async fn cached_season_episodes(
+        &self,
+        series_id: u32,
+        season_id: u32,
+        year: Option<&str>,
+    ) -> Result<Vec<Episode>> {
+        let key = (series_id, season_id);
+        if let Some(cached) = self.season_cache.lock().await.get(&key) {
+            let kind = match year {
+                Some(_) => EntityKind::season_episodes(year),
+                None => cached.kind,
+            };
+            if self
+                .client
+                .freshness()
+                .is_fresh(kind, cached.fetched.elapsed())
+            {
+                self.client.activity().emit(ActivityEvent::CacheHit {
+                    key: format!("season:{}/{}", series_id, season_id),
+                });
+                return Ok(cached.episodes.clone());
+            }
+        }
+
+        // Fetch season episodes page
+        let path = format!("/film/{}/{}/epizody/", series_id, season_id);
+        let html = self.client.fetch(&path).await?;
+        let episodes = parse_episodes(&html)?;
+
+        let cached = CachedSeason {
+            fetched: Instant::now(),
+            kind: EntityKind::season_episodes(year),
+            episodes: episodes.clone(),
+        };
+        self.season_cache.lock().await.insert(key, cached);
+        Ok(episodes)
+    }
+
+    /// Find the episodes of a series in which a given actor appears.
+    ///
+    /// Lists every episode of the series and scans each episode's credits
+    /// for the creator. Episode pages are fetched with bounded concurrency
+    /// and their cast is cached, so repeated lookups for other actors of the
+    /// same series don't hit ČSFD again.
+    ///
+    /// # Arguments
+    /// * `series_id` - ČSFD ID of the series
+    /// * `creator_id` - ČSFD ID of the actor (from a `/tvurce/` URL)
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Episode>)` with episodes crediting the actor, in listing order
+    /// * `Err(CsfdError::InvalidId)` if either ID is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let episodes = scraper.get_episode_appearances(264494, 2).await?;
+    /// println!("Appears in {} episodes", episodes.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_episode_appearances(
+        &self,
+        series_id: u32,
+        creator_id: u32,
+    ) -> Result<Vec<Episode>> {
+        // Validate IDs
+        if series_id == 0 {
+            return Err(CsfdError::InvalidId(series_id));
+        }
+        if creator_id == 0 {
+            return Err(CsfdError::InvalidId(creator_id));
+        }
+
+        let episodes = self.list_series_episodes(series_id).await?;
+
+        // Scan credits, keeping the original order of episodes; the futures
+        // own their IDs and URLs, so the scan stays `Send` for Tauri commands
+        let pages: Vec<(u32, String)> = episodes
+            .iter()
+            .map(|ep| (ep.csfd_id, ep.url.clone()))
+            .collect();
+        let casts: Vec<Vec<CastMember>> = stream::iter(
+            pages
+                .into_iter()
+                .map(|(episode_id, url)| self.get_episode_cast(episode_id, url)),
+        )
+        .buffered(CREDITS_SCAN_CONCURRENCY)
+        .try_collect()
+        .await?;
+
+        Ok(episodes
+            .into_iter()
+            .zip(casts)
+            .filter(|(_, cast)| cast.iter().any(|member| member.creator_id == creator_id))
+            .map(|(episode, _)| episode)
+            .collect())
+    }
+
+    /// Walk a paginated ČSFD listing, yielding parsed items page by page.
+    ///
+    /// Pages are fetched lazily as the stream is polled, starting at page 1
+    /// and continuing while the page links to a next one. The same pagination
+    /// markup is used by search and title tabs (reviews, gallery, trivia, ...),
+    /// so a new tab only needs a parser for the items of a single page.
+    ///
+    /// # Arguments
+    /// * `path` - Relative path of the first page (e.g., "/film/12345/recenze/")
+    /// * `parse` - Parser extracting the items of one page from its HTML
+    ///
+    /// # Returns
+    /// A stream of items; a fetch or parse error is yielded once and ends the stream.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    /// use futures::TryStreamExt;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let page_sizes: Vec<usize> = scraper
+    ///     .paginate("/film/12345/recenze/", |html| Ok(vec![html.len()]))
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn paginate<'a, T, F>(
+        &'a self,
+        path: &'a str,
+        parse: F,
+    ) -> impl Stream<Item = Result<T>> + 'a
+    where
+        T: 'a,
+        F: Fn(&str) -> Result<Vec<T>> + 'a,
+    {
+        stream::try_unfold((Some(1u32), parse), move |(page, parse)| async move {
+            let Some(page) = page else {
+                return Ok::<_, CsfdError>(None);
+            };
+
+            let html = self.client.fetch(&page_path(path, page)).await?;
+            let items = parse(&html)?;
+
+            // Stop on an empty page even if a next link is present
+            let next = (!items.is_empty() && has_next_page(&html)).then_some(page + 1);
+            Ok(Some((
+                stream::iter(items.into_iter().map(Ok)),
+                (next, parse),
+            )))
+        })
+        .try_flatten()
+    }
+
+    /// Enumerate title or creator IDs from ČSFD's sitemaps.
+    ///
+    /// Starts at `/sitemap.xml` and follows nested sitemaps, preferring
+    /// those named after the requested kind. Sitemaps are fetched one at a
+    /// time through the client's rate limiter and only until `limit` IDs
+    /// are collected. Compressed (`.xml.gz`) sitemaps are skipped.
+    ///
+    /// # Arguments
+    /// * `kind` - Whether to collect titles or creators
+    /// * `limit` - Maximum number of IDs to return
+    ///
+    /// # Returns
+    /// * `Ok(Vec<DiscoveredId>)` with unique IDs in sitemap order
+    /// * `Err(CsfdError)` if a sitemap cannot be fetched
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::types::DiscoveryKind;
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let titles = scraper.discover_ids(DiscoveryKind::Titles, 1000).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn discover_ids(
+        &self,
+        kind: DiscoveryKind,
+        limit: usize,
+    ) -> Result<Vec<DiscoveredId>> {
+        let segment = format!("/{}/", kind.path_segment());
+        let extract_id = match kind {
+            DiscoveryKind::Titles => extract_csfd_id,
+            DiscoveryKind::Creators => extract_creator_id,
+        };
+
+        let mut pending = VecDeque::from(["/sitemap.xml".to_string()]);
+        let mut visited = HashSet::new();
+        let mut seen = HashSet::new();
+        let mut ids = Vec::new();
+
+        while ids.len() < limit {
+            let Some(path) = pending.pop_front() else {
+                break;
+            };
+            if !visited.insert(path.clone()) {
+                continue;
+            }
+
+            let sitemap = parse_sitemap(&self.client.fetch(&path).await?);
+
+            // Follow nested sitemaps of the requested kind, or all if none is named so
+            let (matching, other): (Vec<&str>, Vec<&str>) = sitemap
+                .sitemaps
+                .iter()
+                .filter_map(|loc| site_path(loc))
+                .filter(|path| !path.ends_with(".gz"))
+                .partition(|path| path.contains(kind.path_segment()));
+            let nested = if matching.is_empty() { other } else { matching };
+            pending.extend(nested.into_iter().map(str::to_string));
+
+            for url in sitemap.urls {
+                if ids.len() >= limit {
+                    break;
+                }
+                if !url.contains(&segment) {
+                    continue;
+                }
+                if let Some(csfd_id) = extract_id(&url) {
+                    if seen.insert(csfd_id) {
+                        ids.push(DiscoveredId { csfd_id, url });
+                    }
+                }
+            }
+        }
+
+        Ok(ids)
+    }
+
+    /// Get the best-rated series from ČSFD's chart, in chart order.
+    ///
+    /// Fetches as many chart pages as needed for `limit` entries. Useful to
+    /// build a seed catalog (see `seed::SeedCatalog`).
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of series
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let top = scraper.get_top_series(500).await?;
+    /// println!("{} series", top.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_top_series(&self, limit: usize) -> Result<Vec<SearchResult>> {
+        let mut series = Vec::new();
+        let mut seen = HashSet::new();
+        let mut offset = 0;
+        while series.len() < limit {
+            let path = match offset {
+                0 => SERIES_CHART_PATH.to_string(),
+                from => format!("{}?from={}", SERIES_CHART_PATH, from),
+            };
+            let entries = parse_chart(&self.client.fetch(&path).await?);
+            let page_len = entries.len();
+            offset += page_len;
+            let before = series.len();
+            series.extend(
+                entries
+                    .into_iter()
+                    .filter(|r| r.series_type == SeriesType::Series && seen.insert(r.csfd_id)),
+            );
+            // A short page, or one without new entries, is the end of the chart
+            if page_len < CHART_PAGE_SIZE || series.len() == before {
+                break;
+            }
+        }
+        series.truncate(limit);
+        Ok(series)
+    }
+
+    /// Pick a random well-rated series, optionally matching filters.
+    ///
+    /// Candidates come from ČSFD's chart of best series and are filtered by
+    /// year first. The genre is only known from the detail page, so up to
+    /// `RANDOM_SERIES_ATTEMPTS` shuffled candidates are fetched until one
+    /// has the requested genre.
+    ///
+    /// # Arguments
+    /// * `filters` - Optional genre and year filters
+    ///
+    /// # Returns
+    /// * `Ok(SeriesDetail)` of the picked series
+    /// * `Err(CsfdError::NotFound)` if no candidate matches the filters
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::types::SeriesFilters;
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let filters = SeriesFilters {
+    ///     genre: Some("Krimi".to_string()),
+    ///     ..SeriesFilters::default()
+    /// };
+    /// let series = scraper.get_random_series(&filters).await?;
+    /// println!("Try {}", series.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_random_series(&self, filters: &SeriesFilters) -> Result<SeriesDetail> {
+        let html = self.client.fetch(SERIES_CHART_PATH).await?;
+        let mut candidates: Vec<SearchResult> = parse_chart(&html)
+            .into_iter()
+            .filter(|r| {
+                r.series_type == SeriesType::Series && filters.matches_year(r.year.as_deref())
+            })
+            .collect();
+        shuffle(&mut candidates, RandomState::new().build_hasher().finish());
+
+        for candidate in candidates.iter().take(RANDOM_SERIES_ATTEMPTS) {
+            let detail = self.get_series(candidate.csfd_id).await?;
+            if filters.matches_genres(&detail.genres) {
+                return Ok(detail);
+            }
+        }
+
+        Err(CsfdError::NotFound(
+            "no series matches the filters".to_string(),
+        ))
+    }
+
+    /// Walk ČSFD's advanced search for series in a year range.
+    ///
+    /// The year bounds of the filters are sent to ČSFD; results are then
+    /// filtered with `SeriesFilters::matches_year`. Genres are only known
+    /// from detail pages, so callers check `SeriesFilters::matches_genres`
+    /// on the details they fetch.
+    ///
+    /// # Arguments
+    /// * `filters` - Years to search; the genre is not applied
+    /// * `page` - Page number (1-based)
+    ///
+    /// # Returns
+    /// * `Ok(PaginatedResult<SearchResult>)` with series in ČSFD's order
+    pub async fn advanced_search(
+        &self,
+        filters: &SeriesFilters,
+        page: u32,
+    ) -> Result<PaginatedResult<SearchResult>> {
+        let mut path = ADVANCED_SEARCH_PATH.to_string();
+        if let Some(from) = filters.year_from {
+            path.push_str(&format!("&year_from={}", from));
+        }
+        if let Some(to) = filters.year_to {
+            path.push_str(&format!("&year_to={}", to));
+        }
+
+        let html = self.client.fetch(&page_path(&path, page)).await?;
+        let items = parse_chart(&html)
+            .into_iter()
+            .filter(|r| filters.matches_year(r.year.as_deref()))
+            .collect();
+        Ok(PaginatedResult::new(items, page, has_next_page(&html)))
+    }
+
+    /// Get upcoming premieres across cinema, TV and VOD, grouped by day.
+    ///
+    /// Reads ČSFD's premiere overviews for each medium, unlike the premiere
+    /// dates listed on a single title's page.
+    ///
+    /// # Arguments
+    /// * `range` - Days to include
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PremiereDay>)` sorted by date, then medium
+    /// * `Err(CsfdError)` if an overview cannot be fetched
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::types::DateRange;
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let week = DateRange::new("2024-03-04", "2024-03-10")?;
+    /// for day in scraper.get_premiere_calendar(&week).await? {
+    ///     println!("{} {:?}: {} titles", day.date, day.medium, day.titles.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_premiere_calendar(&self, range: &DateRange) -> Result<Vec<PremiereDay>> {
+        let mut days: BTreeMap<(String, PremiereMedium), Vec<SearchResult>> = BTreeMap::new();
+
+        for (medium, path) in PREMIERE_OVERVIEWS {
+            let html = self.client.fetch(path).await?;
+            for (date, title) in parse_premieres(&html) {
+                if range.contains(&date) {
+                    let titles = days.entry((date, medium)).or_default();
+                    if !titles.iter().any(|t| t.csfd_id == title.csfd_id) {
+                        titles.push(title);
+                    }
+                }
+            }
+        }
+
+        Ok(days
+            .into_iter()
+            .map(|((date, medium), titles)| PremiereDay {
+                date,
+                medium,
+                titles,
+            })
+            .collect())
+    }
+
+    /// Get the most-visited titles from the ČSFD homepage.
+    ///
+    /// Lets an app show a trending row without the user typing anything.
+    /// Rows are cached for `FreshnessPolicy::ongoing_series`, the cadence
+    /// at which the watcher refreshes running series.
+    ///
+    /// # Arguments
+    /// * `kind` - Films or series
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SearchResult>)` in the order shown on the homepage
+    /// * `Err(CsfdError::ParseError)` if the homepage has no such row
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::{CsfdScraper, TrendingKind};
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// for title in scraper.get_trending(TrendingKind::Series).await? {
+    ///     println!("{}", title.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_trending(&self, kind: TrendingKind) -> Result<Vec<SearchResult>> {
+        let freshness = EntityKind::Series { ongoing: true };
+        if let Some((fetched, titles)) = self.trending_cache.lock().await.get(&kind) {
+            if self
+                .client
+                .freshness()
+                .is_fresh(freshness, fetched.elapsed())
+            {
+                self.client.activity().emit(ActivityEvent::CacheHit {
+                    key: format!("trending:{:?}", kind),
+                });
+                return Ok(titles.clone());
+            }
+        }
+
+        let html = self.client.fetch(HOMEPAGE_PATH).await?;
+        let titles = parse_trending(&html, kind).ok_or_else(|| {
+            CsfdError::ParseError(
+                "no trending box on the homepage - markup may have changed".to_string(),
+            )
+        })?;
+        self.trending_cache
+            .lock()
+            .await
+            .insert(kind, (Instant::now(), titles.clone()));
+        Ok(titles)
+    }
+
+    /// Get a digest of the ČSFD homepage in one request.
+    ///
+    /// Collects news, the most-visited films and series, current premieres
+    /// and today's birthdays for dashboard-style start screens. The
+    /// trending rows also refresh the cache used by `get_trending`.
+    ///
+    /// # Returns
+    /// * `Ok(HomepageDigest)`; sections the homepage lacks are empty
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let digest = scraper.get_homepage().await?;
+    /// for item in &digest.news {
+    ///     println!("{}", item.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_homepage(&self) -> Result<HomepageDigest> {
+        let html = self.client.fetch(HOMEPAGE_PATH).await?;
+        let digest = parse_homepage(&html);
+
+        let mut trending = self.trending_cache.lock().await;
+        for (kind, titles) in [
+            (TrendingKind::Movies, &digest.trending_movies),
+            (TrendingKind::Series, &digest.trending_series),
+        ] {
+            if !titles.is_empty() {
+                trending.insert(kind, (Instant::now(), titles.clone()));
+            }
+        }
+        Ok(digest)
+    }
+
+    /// Get the public custom lists of a user.
+    ///
+    /// Works without login; when logged in, the user's own private lists
+    /// are included as ČSFD shows them.
+    ///
+    /// # Arguments
+    /// * `user_id` - ČSFD ID of the user
+    ///
+    /// # Returns
+    /// * `Ok(Vec<UserList>)` in the order shown on the profile
+    /// * `Err(CsfdError::InvalidId)` if user_id is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// for list in scraper.get_user_lists(2544).await? {
+    ///     println!("{} ({})", list.name, list.list_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_lists(&self, user_id: u32) -> Result<Vec<UserList>> {
+        if user_id == 0 {
+            return Err(CsfdError::InvalidId(user_id));
+        }
+
+        let html = self
+            .client
+            .fetch(&format!("/uzivatel/{}/seznamy/", user_id))
+            .await?;
+        Ok(parse_user_lists(&html))
+    }
+
+    /// Get one page of a custom list, with the author's notes.
+    ///
+    /// # Arguments
+    /// * `list_id` - ČSFD ID of the list
+    /// * `page` - Page number (1-based)
+    ///
+    /// # Returns
+    /// * `Ok(PaginatedResult<UserListEntry>)` with titles in list order
+    /// * `Err(CsfdError::InvalidId)` if list_id is 0
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let list = scraper.get_list(8123, 1).await?;
+    /// for entry in list.items {
+    ///     println!("{}. {}", entry.position, entry.title.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_list(
+        &self,
+        list_id: u32,
+        page: u32,
+    ) -> Result<PaginatedResult<UserListEntry>> {
+        if list_id == 0 {
+            return Err(CsfdError::InvalidId(list_id));
+        }
+
+        let path = page_path(&format!("/seznamy/{}/", list_id), page);
+        let html = self.client.fetch(&path).await?;
+        let mut result = parse_user_list(&html)?;
+        offset_positions(&mut result, page);
+        Ok(result)
+    }
+
+    /// Get a poster or photo, using the in-memory cache when possible.
+    ///
+    /// Meant for proxies serving ČSFD images to web frontends, see
+    /// `CsfdClient::fetch_image`.
+    ///
+    /// # Arguments
+    /// * `url` - Image URL as found in parsed data (e.g., `Season::poster_url`)
+    ///
+    /// # Returns
+    /// * `Ok(Arc<FetchedImage>)` with the content type and data
+    /// * `Err(CsfdError::InvalidUrl)` if the URL isn't a ČSFD image
+    pub async fn get_image(&self, url: &str) -> Result<Arc<FetchedImage>> {
+        if let Some(image) = self.image_cache.lock().await.images.get(url) {
+            self.client.activity().emit(ActivityEvent::CacheHit {
+                key: format!("image:{}", url),
+            });
+            return Ok(Arc::clone(image));
+        }
+
+        let image = Arc::new(self.client.fetch_image(url).await?);
+        self.image_cache
+            .lock()
+            .await
+            .insert(url.to_string(), Arc::clone(&image));
+        Ok(image)
+    }
+
+    /// Forget everything cached about a series, so it is fetched afresh.
+    ///
+    /// Clears the series' pages (detail, episode lists, episode pages) from
+    /// the memory, conditional-request and disk caches, and its season
+    /// episode lists and episode casts from the scraper's caches. The local
+    /// library keeps its snapshot.
+    ///
+    /// # Arguments
+    /// * `series_id` - ČSFD ID of the series
+    ///
+    /// # Errors
+    /// - `CsfdError::InvalidId` - series_id is 0
+    /// - `CsfdError::StorageError` - The disk cache cannot be updated
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// scraper.invalidate(71924).await?;
+    /// let fresh = scraper.get_series(71924).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn invalidate(&self, series_id: u32) -> Result<()> {
+        if series_id == 0 {
+            return Err(CsfdError::InvalidId(series_id));
+        }
+
+        let episode_ids: HashSet<u32> = {
+            let mut seasons = self.season_cache.lock().await;
+            let ids = seasons
+                .iter()
+                .filter(|((series, _), _)| *series == series_id)
+                .flat_map(|(_, cached)| cached.episodes.iter().map(|episode| episode.csfd_id))
+                .collect();
+            seasons.retain(|(series, _), _| *series != series_id);
+            ids
+        };
+        self.cast_cache
+            .lock()
+            .await
+            .remove_matching(|episode_id| episode_ids.contains(episode_id));
+
+        self.client
+            .invalidate_pages(|path| is_series_path(path, series_id))
+    }
+
+    /// Forget everything cached, in the scraper and in the client's page caches.
+    ///
+    /// The local library, search history and login session are kept.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the disk cache cannot be removed
+    pub async fn invalidate_all(&self) -> Result<()> {
+        *self.cast_cache.lock().await = LruMap::default();
+        self.season_cache.lock().await.clear();
+        *self.image_cache.lock().await = ImageCache::default();
+        self.trending_cache.lock().await.clear();
+        self.client.clear_page_caches()
+    }
+
+    /// Log in to a ČSFD account.
+    ///
+    /// The credentials are kept by the client, which logs in again by
+    /// itself when ČSFD drops the session. To restore a saved session
+    /// instead, use `CsfdClient::restore_cookies` and `is_logged_in`.
+    ///
+    /// # Arguments
+    /// * `username` - ČSFD username or e-mail
+    /// * `password` - ČSFD password
+    ///
+    /// # Returns
+    /// * `Ok(())` when logged in
+    /// * `Err(CsfdError::InvalidUrl)` if username or password is empty
+    /// * `Err(CsfdError::LoginFailed)` if ČSFD rejected the credentials
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// scraper.login("nick", "heslo").await?;
+    /// assert!(scraper.is_logged_in().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn login(&self, username: &str, password: &str) -> Result<()> {
+        let login = PasswordLogin::new(username, password);
+        login.login(&self.client).await?;
+        self.client.set_reauthenticator(Arc::new(login));
+        // The account may differ from the one logged in before
+        *self.profile_path.lock().await = None;
+        Ok(())
+    }
+
+    /// Check whether the session is logged in, e.g. after restoring cookies.
+    ///
+    /// # Returns
+    /// * `Ok(true)` if ČSFD serves pages for a logged-in user
+    /// * `Err(CsfdError)` if the check page can't be fetched
+    pub async fn is_logged_in(&self) -> Result<bool> {
+        validate_session(&self.client).await
+    }
+
+    /// Get one page of the titles the logged-in user rated.
+    ///
+    /// Ratings come newest first, as on the user's ratings tab.
+    ///
+    /// # Arguments
+    /// * `page` - Page number (1-based)
+    ///
+    /// # Returns
+    /// * `Ok(PaginatedResult<UserRating>)` with title, stars and date of each rating
+    /// * `Err(CsfdError::SessionExpired)` if not logged in
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// scraper.login("nick", "heslo").await?;
+    /// let mut page = 1;
+    /// loop {
+    ///     let ratings = scraper.get_my_ratings(page).await?;
+    ///     for rating in &ratings.items {
+    ///         println!("{}: {}*", rating.name, rating.rating.stars());
+    ///     }
+    ///     if !ratings.has_next_page {
+    ///         break;
+    ///     }
+    ///     page += 1;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_my_ratings(&self, page: u32) -> Result<PaginatedResult<UserRating>> {
+        let profile = self.my_profile_path().await?;
+        let path = page_path(&format!("{}hodnoceni/", profile), page);
+        let html = self.client.fetch_authenticated(&path).await?;
+        let mut result = parse_user_ratings(&html)?;
+        result.current_page = page.max(1);
+        Ok(result)
+    }
+
+    /// Rate a title as the logged-in user.
+    ///
+    /// Rating a title again replaces the earlier rating.
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD ID of the title
+    /// * `rating` - Stars to give, 0 for "odpad!"
+    ///
+    /// # Returns
+    /// * `Ok(())` when rated, or recorded in dry-run mode
+    /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
+    /// * `Err(CsfdError::SessionExpired)` if not logged in
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::{CsfdScraper, UserStarRating};
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// scraper.login("nick", "heslo").await?;
+    /// scraper.rate_title(264494, UserStarRating::from_percent(90.0)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rate_title(&self, csfd_id: u32, rating: UserStarRating) -> Result<()> {
+        if csfd_id == 0 {
+            return Err(CsfdError::InvalidId(csfd_id));
+        }
+
+        let path = format!("/film/{}/hodnotit/", csfd_id);
+        let stars = rating.stars().to_string();
+        self.submit_form(&path, &[("rating", &stars)])
+            .await
+            .map(|_| ())
+    }
+
+    /// Profile path of the logged-in user, e.g. "/uzivatel/42-nick/".
+    async fn my_profile_path(&self) -> Result<String> {
+        let mut profile_path = self.profile_path.lock().await;
+        if let Some(path) = profile_path.as_ref() {
+            return Ok(path.clone());
+        }
+
+        let html = self.client.fetch_authenticated("/").await?;
+        let path = parse_profile_path(&html).ok_or_else(|| {
+            CsfdError::ParseError(
+                "no profile link in the header - markup may have changed".to_string(),
+            )
+        })?;
+        *profile_path = Some(path.clone());
+        Ok(path)
+    }
+
+    /// Create a custom list on the logged-in user's profile.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the new list
+    ///
+    /// # Returns
+    /// * `Ok(Some(list_id))` with the ID of the created list
+    /// * `Ok(None)` in dry-run mode
+    /// * `Err(CsfdError::InvalidUrl)` if name is empty or whitespace-only
+    /// * `Err(CsfdError::SessionExpired)` if not logged in
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// if let Some(list_id) = scraper.create_list("K doplnění").await? {
+    ///     scraper.add_to_list(list_id, 264494, Some("Celé znovu")).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_list(&self, name: &str) -> Result<Option<u32>> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(CsfdError::InvalidUrl(
+                "List name cannot be empty".to_string(),
+            ));
+        }
+
+        let Some(html) = self.submit_form(NEW_LIST_PATH, &[("name", name)]).await? else {
+            return Ok(None);
+        };
+        parse_list_id(&html).map(Some).ok_or_else(|| {
+            CsfdError::ParseError("created list has no ID - markup may have changed".to_string())
+        })
+    }
+
+    /// Add a title to one of the logged-in user's custom lists.
+    ///
+    /// # Arguments
+    /// * `list_id` - ČSFD ID of the list
+    /// * `csfd_id` - ČSFD ID of the title
+    /// * `note` - Note shown next to the title
+    ///
+    /// # Returns
+    /// * `Ok(())` when added, or recorded in dry-run mode
+    /// * `Err(CsfdError::InvalidId)` if list_id or csfd_id is 0
+    /// * `Err(CsfdError::SessionExpired)` if not logged in
+    pub async fn add_to_list(&self, list_id: u32, csfd_id: u32, note: Option<&str>) -> Result<()> {
+        if list_id == 0 {
+            return Err(CsfdError::InvalidId(list_id));
+        }
+        if csfd_id == 0 {
+            return Err(CsfdError::InvalidId(csfd_id));
+        }
+
+        let path = format!("/seznamy/{}/pridat/", list_id);
+        let film = csfd_id.to_string();
+        let note = note.unwrap_or_default().trim();
+        self.submit_form(&path, &[("film", &film), ("note", note)])
+            .await
+            .map(|_| ())
+    }
+
+    /// Get one page of the logged-in user's "Chci vidět" (want to see) list.
+    ///
+    /// # Arguments
+    /// * `page` - Page number (1-based)
+    ///
+    /// # Returns
+    /// * `Ok(PaginatedResult<UserListEntry>)` with titles and the user's notes
+    /// * `Err(CsfdError::SessionExpired)` if not logged in
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// scraper.login("nick", "heslo").await?;
+    /// scraper.add_to_watchlist(264494).await?;
+    /// let watchlist = scraper.get_watchlist(1).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_watchlist(&self, page: u32) -> Result<PaginatedResult<UserListEntry>> {
+        let profile = self.my_profile_path().await?;
+        let path = page_path(&format!("{}chci-videt/", profile), page);
+        let html = self.client.fetch_authenticated(&path).await?;
+        let mut result = parse_user_list(&html)?;
+        offset_positions(&mut result, page);
+        Ok(result)
+    }
+
+    /// Add a title to the logged-in user's "Chci vidět" list.
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD ID of the title
+    ///
+    /// # Returns
+    /// * `Ok(())` when added, or recorded in dry-run mode
+    /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
+    /// * `Err(CsfdError::SessionExpired)` if not logged in
+    pub async fn add_to_watchlist(&self, csfd_id: u32) -> Result<()> {
+        self.update_watchlist(csfd_id, "pridat").await
+    }
+
+    /// Remove a title from the logged-in user's "Chci vidět" list.
+    ///
+    /// Removing a title that isn't on the list is not an error.
+    ///
+    /// # Arguments
+    /// * `csfd_id` - ČSFD ID of the title
+    ///
+    /// # Returns
+    /// * `Ok(())` when removed, or recorded in dry-run mode
+    /// * `Err(CsfdError::InvalidId)` if csfd_id is 0
+    /// * `Err(CsfdError::SessionExpired)` if not logged in
+    pub async fn remove_from_watchlist(&self, csfd_id: u32) -> Result<()> {
+        self.update_watchlist(csfd_id, "odebrat").await
+    }
+
+    /// Submit the watchlist form of a title ("pridat" or "odebrat").
+    async fn update_watchlist(&self, csfd_id: u32, action: &str) -> Result<()> {
+        if csfd_id == 0 {
+            return Err(CsfdError::InvalidId(csfd_id));
+        }
+
+        let path = format!("/film/{}/chci-videt/{}/", csfd_id, action);
+        self.submit_form(&path, &[]).await.map(|_| ())
+    }
+
+    /// Submit a form of the logged-in user along with its hidden fields.
+    ///
+    /// The form page is fetched first for the CSRF token ČSFD expects.
+    async fn submit_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<Option<String>> {
+        let form = self.client.fetch_authenticated(path).await?;
+        let hidden = parse_hidden_inputs(&form);
+        let params: Vec<(&str, &str)> = hidden
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .chain(fields.iter().copied())
+            .collect();
+        self.client.post_form_authenticated(path, &params).await
+    }
+
+    /// List all episodes of a series, walking seasons when the series has them.
+    pub(crate) async fn list_series_episodes(&self, series_id: u32) -> Result<Vec<Episode>> {
+        Ok(self.get_episodes_grouped(series_id).await?.into_episodes())
+    }
+
+    /// Get the cast of a single episode, using the cache when possible.
+    async fn get_episode_cast(&self, episode_id: u32, url: String) -> Result<Vec<CastMember>> {
+        {
+            let mut casts = self.cast_cache.lock().await;
+            if let Some(cast) = casts.get(&episode_id).cloned() {
+                casts.touch(&episode_id);
+                self.client.activity().emit(ActivityEvent::CacheHit {
+                    key: format!("cast:{}", episode_id),
+                });
+                return Ok(cast);
+            }
+        }
+
+        let html = self.client.fetch(&url).await?;
+        let cast = parse_cast(&html);
+
+        self.cast_cache
+            .lock()
+            .await
+            .insert(episode_id, cast.clone(), CAST_CACHE_CAPACITY);
+        Ok(cast)
+    }
+}
+
+/// Flatten the episode lists of seasons, sorted by episode code.
+///
+/// The sort is stable, so episodes sharing a code keep ČSFD's order.
+fn merge_seasons(seasons: Vec<Vec<Episode>>) -> Vec<Episode> {
+    let mut episodes: Vec<Episode> = seasons.into_iter().flatten().collect();
+    episodes.sort_by_key(Episode::code);
+    episodes
+}
+
+/// Number the episodes of a season ČSFD labels without a number.
+///
+/// Year-labeled seasons of reality shows ("Řada 2023") list episodes
+/// without codes, so they all read as season 1. The season's position in
+/// the series stands in for its number instead; episodes carrying codes of
+/// another season are left alone.
+///
+/// # Arguments
+/// * `season` - The season the episodes were listed for
+/// * `index` - Position of the season in the series (0-based)
+/// * `episodes` - Episodes of the season
+fn number_unnumbered_season(season: &Season, index: usize, episodes: &mut [Episode]) {
+    if season.number.is_some() || episodes.iter().any(|ep| ep.season_number != 1) {
+        return;
+    }
+    let number = u8::try_from(index + 1).unwrap_or(u8::MAX);
+    for episode in episodes {
+        episode.season_number = number;
+    }
+}
+
+/// Number list entries across pages of a paginated list.
+///
+/// Earlier pages are full, so a short last page continues after them.
+fn offset_positions(result: &mut PaginatedResult<UserListEntry>, page: u32) {
+    result.current_page = page;
+    let offset = page.saturating_sub(1).saturating_mul(LIST_PAGE_SIZE);
+    for entry in &mut result.items {
+        entry.position = entry.position.saturating_add(offset);
+    }
+}
+
+/// Shuffle items in place (Fisher-Yates with an xorshift generator).
+fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed | 1;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let j = (state % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::BoxFuture;
+
+    use crate::client::ClientConfig;
+    use crate::transport::{HttpRequest, HttpResponse, HttpTransport};
+
+    #[test]
+    fn test_image_cache_evicts_oldest() {
+        let mut cache = ImageCache::default();
+        let image = Arc::new(FetchedImage {
+            content_type: "image/jpeg".to_string(),
+            bytes: vec![0xff, 0xd8],
+        });
+        for i in 0..=IMAGE_CACHE_CAPACITY {
+            cache.insert(
+                format!("https://image.pmgstatic.com/{}.jpg", i),
+                Arc::clone(&image),
+            );
+        }
+        assert_eq!(cache.images.len(), IMAGE_CACHE_CAPACITY);
+        assert!(!cache
+            .images
+            .contains_key("https://image.pmgstatic.com/0.jpg"));
+        assert!(cache
+            .images
+            .contains_key("https://image.pmgstatic.com/1.jpg"));
+    }
+
+    /// Tauri runs commands on its own tasks, so the futures of scans with
+    /// bounded concurrency must be `Send` for any lifetime; compiling is the test
+    #[allow(dead_code)]
+    fn assert_scans_spawnable(scraper: Arc<CsfdScraper>) {
+        fn spawnable<F: std::future::Future + Send + 'static>(_: F) {}
+
+        let s = Arc::clone(&scraper);
+        spawnable(async move { s.get_series_bulk(&[1, 2]).await });
+        let s = Arc::clone(&scraper);
+        spawnable(async move { s.get_all_episodes(1).await });
+        let s = Arc::clone(&scraper);
+        spawnable(async move { s.get_episodes_grouped(1).await });
+        let s = Arc::clone(&scraper);
+        spawnable(async move { s.get_full_series(1, |_| {}).await });
+        spawnable(async move { scraper.get_episode_appearances(1, 2).await });
+    }
+
+    #[tokio::test]
+    async fn test_cast_cache_keeps_recently_used() {
+        let scraper = CsfdScraper::new().unwrap();
+        let mut casts = scraper.cast_cache.lock().await;
+        for episode_id in 0..CAST_CACHE_CAPACITY as u32 {
+            casts.insert(episode_id, Vec::new(), CAST_CACHE_CAPACITY);
+        }
+        casts.touch(&0);
+        casts.insert(u32::MAX, Vec::new(), CAST_CACHE_CAPACITY);
+
+        assert!(casts.get(&0).is_some());
+        assert!(casts.get(&1).is_none());
+        assert!(casts.get(&u32::MAX).is_some());
+    }
+
+    #[test]
+    fn test_offset_positions() {
+        let entry = |position| UserListEntry {
+            position,
+            title: SearchResult {
+                name: "Dr. House".to_string(),
+                original_name: None,
+                year: None,
+                series_type: SeriesType::Series,
+                url: "/film/69537-dr-house/".to_string(),
+                csfd_id: 69537,
+                local_rank: None,
+                score: None,
+            },
+            note: None,
+        };
+        let mut short_last_page = PaginatedResult::new((1..=5).map(entry).collect(), 1, false);
+        offset_positions(&mut short_last_page, 3);
+        assert_eq!(short_last_page.current_page, 3);
+        assert_eq!(short_last_page.items[0].position, 101);
+        assert_eq!(short_last_page.items[4].position, 105);
+
+        let mut far_page = PaginatedResult::new(vec![entry(1)], 1, false);
+        offset_positions(&mut far_page, u32::MAX);
+        assert_eq!(far_page.items[0].position, u32::MAX);
+    }
+
+    #[test]
+    fn test_scraper_creation() {
+        let scraper = CsfdScraper::new();
+        assert!(scraper.is_ok());
+    }
+
+    /// Serves canned pages by path and 404 for anything else
+    struct CannedTransport(HashMap<&'static str, HttpResponse>);
+
+    impl HttpTransport for CannedTransport {
+        fn send<'a>(&'a self, request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+            let path = request
+                .url
+                .as_str()
+                .trim_start_matches(crate::CSFD_BASE_URL);
+            let response =
+                self.0.get(path).cloned().unwrap_or_else(|| {
+                    HttpResponse::new(reqwest::StatusCode::NOT_FOUND, Vec::new())
+                });
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    fn canned_scraper(pages: Vec<(&'static str, HttpResponse)>) -> CsfdScraper {
+        let config = ClientConfig {
+            max_retries: 0,
+            ..ClientConfig::default()
+        };
+        let transport = CannedTransport(pages.into_iter().collect());
+        CsfdScraper::with_client(CsfdClient::with_transport(config, Arc::new(transport)))
+    }
+
+    #[tokio::test]
+    async fn test_search_canned_page() {
+        let html = r#"
+            <html><body>
+                <article class="article-poster-50">
+                    <a class="film-title-name" href="/film/69537-dr-house/">Dr. House</a>
+                    <span class="info">(2004) (seriál)</span>
+                </article>
+            </body></html>
+        "#;
+        let scraper = canned_scraper(vec![("/hledat/?q=Dr.%20House", HttpResponse::html(html))]);
+        let results = scraper.search("Dr. House").await.unwrap();
+        assert_eq!(results.items.len(), 1);
+        assert_eq!(results.items[0].csfd_id, 69537);
+        assert_eq!(results.items[0].year.as_deref(), Some("2004"));
+    }
+
+    #[tokio::test]
+    async fn test_canned_status_errors() {
+        let forbidden = HttpResponse::new(reqwest::StatusCode::FORBIDDEN, "Forbidden");
+        let scraper = canned_scraper(vec![("/hledat/?q=x", forbidden)]);
+        assert!(matches!(
+            scraper.search("x").await,
+            Err(CsfdError::HttpStatus(reqwest::StatusCode::FORBIDDEN))
+        ));
+        assert!(matches!(
+            scraper.get_series(1).await,
+            Err(CsfdError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_search_empty_query() {
+        let scraper = CsfdScraper::new().unwrap();
+        let result = scraper.search("").await;
         assert!(result.is_err());
-        
+
         match result {
             Err(CsfdError::InvalidUrl(msg)) => {
                 assert!(msg.contains("empty"));
@@ -276,7 +2117,7 @@ mod tests {
         let scraper = CsfdScraper::new().unwrap();
         let result = scraper.search("   ").await;
         assert!(result.is_err());
-        
+
         match result {
             Err(CsfdError::InvalidUrl(msg)) => {
                 assert!(msg.contains("empty"));
@@ -285,12 +2126,20 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_search_all_empty_query_ends_after_error() {
+        let scraper = CsfdScraper::new().unwrap();
+        let results: Vec<_> = scraper.search_all("  ").collect().await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(CsfdError::InvalidUrl(_))));
+    }
+
     #[tokio::test]
     async fn test_get_series_invalid_id_zero() {
         let scraper = CsfdScraper::new().unwrap();
         let result = scraper.get_series(0).await;
         assert!(result.is_err());
-        
+
         match result {
             Err(CsfdError::InvalidId(id)) => {
                 assert_eq!(id, 0);
@@ -299,12 +2148,19 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_get_person_invalid_id_zero() {
+        let scraper = CsfdScraper::new().unwrap();
+        let result = scraper.get_person(0).await;
+        assert!(matches!(result, Err(CsfdError::InvalidId(0))));
+    }
+
     #[tokio::test]
     async fn test_get_episodes_invalid_id_zero() {
         let scraper = CsfdScraper::new().unwrap();
-        let result = scraper.get_episodes(0).await;
+        let result = scraper.get_episodes(0, EpisodeSort::Aired).await;
         assert!(result.is_err());
-        
+
         match result {
             Err(CsfdError::InvalidId(id)) => {
                 assert_eq!(id, 0);
@@ -316,9 +2172,11 @@ mod tests {
     #[tokio::test]
     async fn test_get_season_episodes_invalid_series_id() {
         let scraper = CsfdScraper::new().unwrap();
-        let result = scraper.get_season_episodes(0, 123).await;
+        let result = scraper
+            .get_season_episodes(0, 123, EpisodeSort::Aired)
+            .await;
         assert!(result.is_err());
-        
+
         match result {
             Err(CsfdError::InvalidId(id)) => {
                 assert_eq!(id, 0);
@@ -327,12 +2185,28 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_get_episode_appearances_invalid_ids() {
+        let scraper = CsfdScraper::new().unwrap();
+
+        match scraper.get_episode_appearances(0, 2).await {
+            Err(CsfdError::InvalidId(id)) => assert_eq!(id, 0),
+            _ => panic!("Expected InvalidId error"),
+        }
+        match scraper.get_episode_appearances(264494, 0).await {
+            Err(CsfdError::InvalidId(id)) => assert_eq!(id, 0),
+            _ => panic!("Expected InvalidId error"),
+        }
+    }
+
     #[tokio::test]
     async fn test_get_season_episodes_invalid_season_id() {
         let scraper = CsfdScraper::new().unwrap();
-        let result = scraper.get_season_episodes(123, 0).await;
+        let result = scraper
+            .get_season_episodes(123, 0, EpisodeSort::Aired)
+            .await;
         assert!(result.is_err());
-        
+
         match result {
             Err(CsfdError::InvalidId(id)) => {
                 assert_eq!(id, 0);
@@ -340,4 +2214,199 @@ mod tests {
             _ => panic!("Expected InvalidId error"),
         }
     }
+
+    #[tokio::test]
+    async fn test_watchlist_invalid_id_zero() {
+        let scraper = CsfdScraper::new().unwrap();
+
+        match scraper.add_to_watchlist(0).await {
+            Err(CsfdError::InvalidId(id)) => assert_eq!(id, 0),
+            _ => panic!("Expected InvalidId error"),
+        }
+        match scraper.remove_from_watchlist(0).await {
+            Err(CsfdError::InvalidId(id)) => assert_eq!(id, 0),
+            _ => panic!("Expected InvalidId error"),
+        }
+    }
+
+    #[test]
+    fn test_merge_seasons_sorts_by_code() {
+        let episode = |csfd_id, season_number, episode_number| Episode {
+            csfd_id,
+            name: String::new(),
+            season_number,
+            episode_number,
+            rating: None,
+            url: String::new(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        };
+        let seasons = vec![
+            vec![episode(21, 2, 1), episode(22, 2, 2)],
+            vec![episode(12, 1, 2), episode(11, 1, 1)],
+            vec![episode(1, 0, 1)],
+        ];
+
+        let ids: Vec<u32> = merge_seasons(seasons).iter().map(|ep| ep.csfd_id).collect();
+        assert_eq!(ids, vec![1, 11, 12, 21, 22]);
+    }
+
+    #[test]
+    fn test_number_unnumbered_season() {
+        let episode = |episode_number| Episode {
+            csfd_id: u32::from(episode_number),
+            name: String::new(),
+            season_number: 1,
+            episode_number,
+            rating: None,
+            url: String::new(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        };
+        let season = |number: Option<u8>| Season {
+            csfd_id: 1,
+            name: "Řada 2023".to_string(),
+            number,
+            label: "2023".to_string(),
+            year: Some("2023".to_string()),
+            episode_count: 2,
+            url: String::new(),
+            poster_url: None,
+            rating: None,
+        };
+
+        let mut episodes = vec![episode(1), episode(2)];
+        number_unnumbered_season(&season(None), 2, &mut episodes);
+        assert_eq!(episodes[1].code().to_string(), "S03E02");
+
+        let mut episodes = vec![episode(1)];
+        number_unnumbered_season(&season(Some(1)), 2, &mut episodes);
+        assert_eq!(episodes[0].season_number, 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_series_bulk_reports_each_id() {
+        let scraper = CsfdScraper::new().unwrap();
+        let results = scraper.get_series_bulk(&[0, 0]).await;
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|r| matches!(r, Err(CsfdError::InvalidId(0)))));
+        assert!(scraper.get_series_bulk(&[]).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_all_episodes_invalid_id() {
+        let scraper = CsfdScraper::new().unwrap();
+        assert!(matches!(
+            scraper.get_all_episodes(0).await,
+            Err(CsfdError::InvalidId(0))
+        ));
+    }
+
+    #[test]
+    fn test_is_series_path() {
+        assert!(is_series_path("/film/71924/prehled/", 71924));
+        assert!(is_series_path("/film/71924-pernikovy-tata/epizody/", 71924));
+        assert!(is_series_path(
+            "/film/71924/472431/epizody/?sort=rating",
+            71924
+        ));
+        assert!(!is_series_path("/film/719240/prehled/", 71924));
+        assert!(!is_series_path("/tvurce/71924/", 71924));
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_clears_series_caches() {
+        let scraper = CsfdScraper::new().unwrap();
+        for (series_id, episode_id) in [(1, 10), (2, 20)] {
+            let episode = Episode {
+                csfd_id: episode_id,
+                name: String::new(),
+                season_number: 1,
+                episode_number: 1,
+                rating: None,
+                url: String::new(),
+                comment_count: None,
+                rating_count: None,
+                absolute_number: None,
+                list_position: 0,
+            };
+            scraper.season_cache.lock().await.insert(
+                (series_id, 5),
+                CachedSeason {
+                    fetched: Instant::now(),
+                    kind: EntityKind::Episodes { ongoing: false },
+                    episodes: vec![episode],
+                },
+            );
+            scraper
+                .cast_cache
+                .lock()
+                .await
+                .insert(episode_id, Vec::new(), CAST_CACHE_CAPACITY);
+        }
+
+        scraper.invalidate(1).await.unwrap();
+        assert!(!scraper.season_cache.lock().await.contains_key(&(1, 5)));
+        assert!(scraper.cast_cache.lock().await.get(&10).is_none());
+        assert!(scraper.season_cache.lock().await.contains_key(&(2, 5)));
+        assert!(scraper.cast_cache.lock().await.get(&20).is_some());
+
+        scraper.invalidate_all().await.unwrap();
+        assert!(scraper.season_cache.lock().await.is_empty());
+        assert!(matches!(
+            scraper.invalidate(0).await,
+            Err(CsfdError::InvalidId(0))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_season_episodes_served_from_cache() {
+        let scraper = CsfdScraper::new().unwrap();
+        let episode = Episode {
+            csfd_id: 7,
+            name: "Pilot".to_string(),
+            season_number: 1,
+            episode_number: 1,
+            rating: None,
+            url: "/film/1/2/7/".to_string(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        };
+        scraper.season_cache.lock().await.insert(
+            (1, 2),
+            CachedSeason {
+                fetched: Instant::now(),
+                kind: EntityKind::Episodes { ongoing: false },
+                episodes: vec![episode],
+            },
+        );
+
+        let episodes = scraper
+            .get_season_episodes(1, 2, EpisodeSort::Aired)
+            .await
+            .unwrap();
+        assert_eq!(episodes.len(), 1);
+        assert_eq!(episodes[0].name, "Pilot");
+    }
+
+    #[test]
+    fn test_shuffle_keeps_items() {
+        let mut items: Vec<u32> = (0..50).collect();
+        shuffle(&mut items, 42);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+
+        let mut empty: Vec<u32> = Vec::new();
+        shuffle(&mut empty, 42);
+    }
 }
@@ -0,0 +1,165 @@
+//! Bundled seed catalog for a warm start
+//!
+//! Apps can ship a small catalog of popular series (e.g. the top 500 of
+//! ČSFD's chart, see the `build_seed` example) that is copied into the local
+//! store on first run. Searching it answers instantly and offline, so a UI
+//! can show local matches while the network search is still running.
+
+use crate::error::{CsfdError, Result};
+use crate::history::matches_prefix;
+use crate::store::LocalStore;
+use crate::text::normalize;
+use crate::types::SearchResult;
+
+/// Store key of the installed seed catalog
+const SEED_KEY: &str = "seed_catalog";
+
+/// Catalog of series basic info, in chart order
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::seed::SeedCatalog;
+/// use csfd_core::store::LocalStore;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let bundled = std::fs::read("seed.json")?;
+/// let seed = SeedCatalog::install(&LocalStore::open("/tmp/csfd-data")?, &bundled)?;
+///
+/// for title in seed.search("pern", 5) {
+///     println!("{} ({:?})", title.name, title.year);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SeedCatalog {
+    titles: Vec<SearchResult>,
+}
+
+impl SeedCatalog {
+    /// Create a catalog from titles in chart order
+    pub fn new(titles: Vec<SearchResult>) -> Self {
+        Self { titles }
+    }
+
+    /// Parse a bundled catalog (a JSON array of search results)
+    ///
+    /// # Errors
+    /// Returns `CsfdError::ParseError` if the data is not a valid catalog
+    pub fn from_json(bundled: &[u8]) -> Result<Self> {
+        serde_json::from_slice(bundled)
+            .map(Self::new)
+            .map_err(|e| CsfdError::ParseError(format!("seed catalog: {}", e)))
+    }
+
+    /// Install a bundled catalog into the store on first run.
+    ///
+    /// If the store already holds a catalog, that one is returned and the
+    /// bundled data isn't parsed, so later app starts skip the work.
+    ///
+    /// # Arguments
+    /// * `store` - Local store of the app
+    /// * `bundled` - Catalog shipped with the app, as JSON
+    ///
+    /// # Errors
+    /// - `CsfdError::ParseError` - The bundled catalog is invalid
+    /// - `CsfdError::StorageError` - The store cannot be read or written
+    pub fn install(store: &LocalStore, bundled: &[u8]) -> Result<Self> {
+        if store.contains(SEED_KEY)? {
+            return Ok(Self::new(store.load(SEED_KEY)?));
+        }
+        let seed = Self::from_json(bundled)?;
+        store.save(SEED_KEY, &seed.titles)?;
+        Ok(seed)
+    }
+
+    /// Get the titles of the catalog
+    pub fn titles(&self) -> &[SearchResult] {
+        &self.titles
+    }
+
+    /// Get the number of titles
+    pub fn len(&self) -> usize {
+        self.titles.len()
+    }
+
+    /// Check whether the catalog has no titles
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty()
+    }
+
+    /// Find titles matching a query, in chart order.
+    ///
+    /// Like history suggestions, matching ignores case and diacritics and
+    /// accepts a prefix of any word of the Czech or original name.
+    ///
+    /// # Arguments
+    /// * `query` - Text typed so far
+    /// * `limit` - Maximum number of titles
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        self.titles
+            .iter()
+            .filter(|title| {
+                std::iter::once(&title.name)
+                    .chain(title.original_name.as_ref())
+                    .any(|name| matches_prefix(&normalize(name), &query))
+            })
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SeriesType;
+
+    const BUNDLED: &str = r#"[
+        {"name": "Perníkový táta", "original_name": "Breaking Bad", "year": "2008-2013",
+         "series_type": "Series", "url": "/film/264494-pernikovy-tata/", "csfd_id": 264494},
+        {"name": "Dr. House", "original_name": "House M.D.", "year": "2004-2012",
+         "series_type": "Series", "url": "/film/71924-dr-house/", "csfd_id": 71924}
+    ]"#;
+
+    #[test]
+    fn test_seed_search() {
+        let seed = SeedCatalog::from_json(BUNDLED.as_bytes()).unwrap();
+        assert_eq!(seed.len(), 2);
+        assert_eq!(seed.titles()[0].series_type, SeriesType::Series);
+
+        let ids = |query| {
+            seed.search(query, 5)
+                .iter()
+                .map(|t| t.csfd_id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids("pernik"), vec![264494]);
+        assert_eq!(ids("bad"), vec![264494]);
+        assert_eq!(ids("HOUSE"), vec![71924]);
+        assert!(ids("").is_empty());
+        assert!(ids("simpsonovi").is_empty());
+    }
+
+    #[test]
+    fn test_seed_installs_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        assert_eq!(
+            SeedCatalog::install(&store, BUNDLED.as_bytes())
+                .unwrap()
+                .len(),
+            2
+        );
+
+        // Later starts use the installed catalog without parsing the bundle
+        let seed = SeedCatalog::install(&store, b"not json").unwrap();
+        assert_eq!(seed.len(), 2);
+        assert!(SeedCatalog::from_json(b"not json").is_err());
+    }
+}
@@ -0,0 +1,1070 @@
+//! Scrape sessions for groups of related operations
+//!
+//! A `ScrapeSession` is a handle for one logical batch of work (e.g., a
+//! library scan). It owns a cancellation token, a concurrency budget, a
+//! retry budget and metrics, so batch features don't have to thread these
+//! as ad hoc parameters. Background sessions can additionally be restricted
+//! to a `ScheduleWindow`, e.g. the night hours.
+//!
+//! Every session runs in a `Lane`. All sessions of a scraper share one
+//! in-flight limit per lane, so a background refresh of many shows can't
+//! take the slots a user waiting on a search needs.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use futures::future::join_all;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+
+use crate::client::RetryBudget;
+use crate::error::{CsfdError, Result};
+use crate::scraper::CsfdScraper;
+
+/// Default number of operations a session runs concurrently
+pub const DEFAULT_SESSION_CONCURRENCY: usize = 4;
+
+/// Default number of retries all requests of a session may use in total
+pub const DEFAULT_SESSION_RETRY_BUDGET: u32 = 10;
+
+/// Default number of interactive operations in flight across all sessions
+pub const DEFAULT_INTERACTIVE_MAX_INFLIGHT: usize = 4;
+
+/// Default number of background operations in flight across all sessions
+pub const DEFAULT_BACKGROUND_MAX_INFLIGHT: usize = 2;
+
+/// Seconds in a day
+const DAY_SECS: u32 = 24 * 3600;
+
+/// Daily time window in which a session may start operations
+///
+/// The window may wrap past midnight (e.g. "22:00-06:00"). Times are in
+/// local time given by a fixed offset from UTC, which the caller keeps up
+/// to date across daylight saving changes. A window whose start equals its
+/// end is open all day.
+///
+/// # Example
+/// ```
+/// use csfd_core::session::ScheduleWindow;
+///
+/// let window: ScheduleWindow = "02:00-06:00".parse().unwrap();
+/// let window = window.with_utc_offset(60); // CET
+/// assert_eq!(window.to_string(), "02:00-06:00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduleWindow {
+    /// Start of the window in minutes after local midnight
+    start_minute: u16,
+    /// End of the window in minutes after local midnight
+    end_minute: u16,
+    /// Offset of local time from UTC in minutes
+    utc_offset_minutes: i16,
+}
+
+impl ScheduleWindow {
+    /// Create a window from start and end times as `(hour, minute)` in UTC
+    ///
+    /// Returns `None` if a time is out of range.
+    pub fn new(start: (u8, u8), end: (u8, u8)) -> Option<Self> {
+        let minute_of_day = |(hour, minute): (u8, u8)| {
+            (hour < 24 && minute < 60).then(|| u16::from(hour) * 60 + u16::from(minute))
+        };
+        Some(Self {
+            start_minute: minute_of_day(start)?,
+            end_minute: minute_of_day(end)?,
+            utc_offset_minutes: 0,
+        })
+    }
+
+    /// Interpret the window times in local time `offset_minutes` ahead of UTC
+    pub fn with_utc_offset(mut self, offset_minutes: i16) -> Self {
+        self.utc_offset_minutes = offset_minutes;
+        self
+    }
+
+    /// Check whether the window is open now
+    pub fn is_open(&self) -> bool {
+        self.time_until_open().is_zero()
+    }
+
+    /// Get the time until the window next opens, zero if it's open now
+    pub fn time_until_open(&self) -> Duration {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let local = since_epoch as i64 + i64::from(self.utc_offset_minutes) * 60;
+        let second_of_day = local.rem_euclid(i64::from(DAY_SECS)) as u32;
+        Duration::from_secs(u64::from(self.secs_until_open(second_of_day)))
+    }
+
+    /// Seconds from the given local second of the day until the window opens
+    fn secs_until_open(&self, second_of_day: u32) -> u32 {
+        let start = u32::from(self.start_minute) * 60;
+        let end = u32::from(self.end_minute) * 60;
+        let open = if start <= end {
+            start == end || (start..end).contains(&second_of_day)
+        } else {
+            second_of_day >= start || second_of_day < end
+        };
+
+        if open {
+            0
+        } else {
+            (start + DAY_SECS - second_of_day) % DAY_SECS
+        }
+    }
+
+    /// Wait until the window is open
+    ///
+    /// Re-checks at least once a minute, so clock changes while waiting
+    /// (e.g. after the machine wakes from sleep) are picked up.
+    pub async fn wait_until_open(&self) {
+        loop {
+            let wait = self.time_until_open();
+            if wait.is_zero() {
+                return;
+            }
+            tokio::time::sleep(wait.min(Duration::from_secs(60))).await;
+        }
+    }
+}
+
+impl fmt::Display for ScheduleWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}:{:02}-{:02}:{:02}",
+            self.start_minute / 60,
+            self.start_minute % 60,
+            self.end_minute / 60,
+            self.end_minute % 60
+        )
+    }
+}
+
+impl FromStr for ScheduleWindow {
+    type Err = String;
+
+    /// Parse a window like "02:00-06:00" (times in UTC until an offset is set)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || format!("Invalid schedule window: {} (expected HH:MM-HH:MM)", s);
+        let parse_time = |time: &str| -> Option<(u8, u8)> {
+            let (hour, minute) = time.trim().split_once(':')?;
+            Some((hour.parse().ok()?, minute.parse().ok()?))
+        };
+
+        let (start, end) = s.split_once('-').ok_or_else(invalid)?;
+        let start = parse_time(start).ok_or_else(invalid)?;
+        let end = parse_time(end).ok_or_else(invalid)?;
+        Self::new(start, end).ok_or_else(invalid)
+    }
+}
+
+/// Kind of work a session does, selecting its shared in-flight limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Lane {
+    /// Requests a user is waiting for, e.g. the search box
+    #[default]
+    Interactive,
+    /// Unattended work such as watcher refreshes and library scans
+    Background,
+}
+
+/// Current use of a lane's in-flight limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LaneUsage {
+    /// Operations currently running in the lane
+    pub in_flight: usize,
+    /// Maximum number of operations running in the lane at once
+    pub max_in_flight: usize,
+}
+
+/// Snapshot of a scraper's in-flight operations across all sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScraperMetrics {
+    /// Usage of the interactive lane
+    pub interactive: LaneUsage,
+    /// Usage of the background lane
+    pub background: LaneUsage,
+}
+
+/// In-flight limits shared by all sessions of a scraper
+pub(crate) struct Lanes {
+    interactive: Semaphore,
+    background: Semaphore,
+    interactive_max: usize,
+    background_max: usize,
+}
+
+impl Lanes {
+    pub(crate) fn new(interactive_max: usize, background_max: usize) -> Self {
+        let interactive_max = interactive_max.max(1);
+        let background_max = background_max.max(1);
+        Self {
+            interactive: Semaphore::new(interactive_max),
+            background: Semaphore::new(background_max),
+            interactive_max,
+            background_max,
+        }
+    }
+
+    fn semaphore(&self, lane: Lane) -> &Semaphore {
+        match lane {
+            Lane::Interactive => &self.interactive,
+            Lane::Background => &self.background,
+        }
+    }
+
+    fn usage(&self, lane: Lane) -> LaneUsage {
+        let max_in_flight = match lane {
+            Lane::Interactive => self.interactive_max,
+            Lane::Background => self.background_max,
+        };
+        LaneUsage {
+            in_flight: max_in_flight - self.semaphore(lane).available_permits(),
+            max_in_flight,
+        }
+    }
+
+    pub(crate) fn metrics(&self) -> ScraperMetrics {
+        ScraperMetrics {
+            interactive: self.usage(Lane::Interactive),
+            background: self.usage(Lane::Background),
+        }
+    }
+}
+
+/// Snapshot of a session's metrics
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionMetrics {
+    /// Number of operations started
+    pub started: u64,
+    /// Number of operations that completed successfully
+    pub succeeded: u64,
+    /// Number of operations that returned an error
+    pub failed: u64,
+    /// Number of operations aborted by cancellation
+    pub cancelled: u64,
+    /// Number of request retries taken from the session's retry budget
+    pub retries: u32,
+    /// Whether the retry budget ran out
+    pub retry_budget_exhausted: bool,
+    /// Time since the session was created
+    pub elapsed: Duration,
+}
+
+/// Outcome of a batch of operations
+///
+/// Serializes errors as their messages, so the report can be passed to a
+/// frontend as structured JSON.
+#[derive(Debug, Serialize)]
+pub struct BatchReport<I, T> {
+    /// Inputs whose operation succeeded, with the results
+    pub succeeded: Vec<(I, T)>,
+    /// Inputs whose operation failed, with the errors
+    pub failed: Vec<(I, CsfdError)>,
+    /// Inputs not attempted because the batch stopped after a failure or
+    /// the session's timeout passed
+    pub skipped: Vec<I>,
+}
+
+impl<I, T> Default for BatchReport<I, T> {
+    fn default() -> Self {
+        Self {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+            skipped: Vec::new(),
+        }
+    }
+}
+
+impl<I, T> BatchReport<I, T> {
+    /// Check whether every input succeeded
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// Running operations that can be cancelled by an ID the caller chose
+///
+/// Lets a frontend abort an operation it started, e.g. a search the user
+/// navigated away from: the operation registers under a request ID and
+/// runs in a session with the registered token, and `cancel` with the same
+/// ID aborts it. Registering an ID still in use cancels the older
+/// operation, so reusing one ID per view keeps only its latest request.
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::session::CancelRegistry;
+/// use csfd_core::CsfdScraper;
+///
+/// # async fn example(registry: &CancelRegistry) -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let request = registry.register("search-box");
+/// let session = scraper.session().with_cancellation_token(request.token().clone());
+/// // Elsewhere: registry.cancel("search-box");
+/// let results = session.run(scraper.search("Dark")).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct CancelRegistry {
+    next: AtomicU64,
+    tokens: std::sync::Mutex<HashMap<String, (u64, CancellationToken)>>,
+}
+
+impl CancelRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an operation under an ID, cancelling an older one with the same ID
+    ///
+    /// The ID is released when the returned guard is dropped.
+    pub fn register(&self, id: impl Into<String>) -> CancelGuard<'_> {
+        let id = id.into();
+        let token = CancellationToken::new();
+        let generation = self.next.fetch_add(1, Ordering::Relaxed);
+        let older = self.lock().insert(id.clone(), (generation, token.clone()));
+        if let Some((_, older)) = older {
+            older.cancel();
+        }
+        CancelGuard {
+            registry: self,
+            id,
+            generation,
+            token,
+        }
+    }
+
+    /// Cancel the operation registered under an ID
+    ///
+    /// # Returns
+    /// Whether an operation was registered under the ID
+    pub fn cancel(&self, id: &str) -> bool {
+        match self.lock().remove(id) {
+            Some((_, token)) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Cancel every registered operation
+    pub fn cancel_all(&self) {
+        for (_, (_, token)) in self.lock().drain() {
+            token.cancel();
+        }
+    }
+
+    /// Get the IDs of the registered operations
+    pub fn running(&self) -> Vec<String> {
+        self.lock().keys().cloned().collect()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, (u64, CancellationToken)>> {
+        self.tokens.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Registration of an operation in a `CancelRegistry`, released on drop
+#[derive(Debug)]
+pub struct CancelGuard<'a> {
+    registry: &'a CancelRegistry,
+    id: String,
+    generation: u64,
+    token: CancellationToken,
+}
+
+impl CancelGuard<'_> {
+    /// Get the token cancelled when the operation is cancelled
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+}
+
+impl Drop for CancelGuard<'_> {
+    fn drop(&mut self) {
+        let mut tokens = self.registry.lock();
+        // A newer operation may have taken over the ID meanwhile
+        if tokens
+            .get(&self.id)
+            .is_some_and(|(generation, _)| *generation == self.generation)
+        {
+            tokens.remove(&self.id);
+        }
+    }
+}
+
+/// Live counters behind `SessionMetrics`
+#[derive(Debug, Default)]
+struct Counters {
+    started: AtomicU64,
+    succeeded: AtomicU64,
+    failed: AtomicU64,
+    cancelled: AtomicU64,
+}
+
+/// Handle for a group of related scraping operations
+///
+/// Created via `CsfdScraper::session()`. Every operation run through the
+/// session waits for a slot in the concurrency budget and in its lane's
+/// shared in-flight limit, aborts when the session is cancelled and is
+/// counted in the session metrics. Requests of
+/// all operations share one retry budget; once it runs out, the session
+/// stops starting new operations so an outage fails the batch quickly.
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::CsfdScraper;
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let session = scraper.session().with_concurrency(2);
+///
+/// let detail = session.run(session.scraper().get_series(264494)).await?;
+/// println!("{} - {:?}", detail.name, session.metrics());
+/// # Ok(())
+/// # }
+/// ```
+pub struct ScrapeSession<'a> {
+    scraper: &'a CsfdScraper,
+    cancel: CancellationToken,
+    budget: Arc<Semaphore>,
+    concurrency: usize,
+    lane: Lane,
+    retry_budget: RetryBudget,
+    schedule: Option<ScheduleWindow>,
+    timeout: Option<Duration>,
+    counters: Counters,
+    created: Instant,
+}
+
+impl<'a> ScrapeSession<'a> {
+    /// Create a new session for the given scraper
+    ///
+    /// The concurrency budget defaults to the client's configured concurrency.
+    pub fn new(scraper: &'a CsfdScraper) -> Self {
+        let concurrency = scraper.client().concurrency();
+        Self {
+            scraper,
+            cancel: CancellationToken::new(),
+            budget: Arc::new(Semaphore::new(concurrency)),
+            concurrency,
+            lane: Lane::default(),
+            retry_budget: RetryBudget::new(DEFAULT_SESSION_RETRY_BUDGET),
+            schedule: None,
+            timeout: None,
+            counters: Counters::default(),
+            created: Instant::now(),
+        }
+    }
+
+    /// Set the maximum number of operations running at once (at least 1)
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        let concurrency = concurrency.max(1);
+        self.budget = Arc::new(Semaphore::new(concurrency));
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Run the session's operations in the given lane (default: interactive)
+    ///
+    /// Background work, e.g. a watcher refresh, should use `Lane::Background`
+    /// so it waits for background slots instead of interactive ones.
+    pub fn with_lane(mut self, lane: Lane) -> Self {
+        self.lane = lane;
+        self
+    }
+
+    /// Get the lane the session's operations run in
+    pub fn lane(&self) -> Lane {
+        self.lane
+    }
+
+    /// Set the total number of retries all requests of the session may use
+    pub fn with_retry_budget(mut self, max_retries: u32) -> Self {
+        self.retry_budget = RetryBudget::new(max_retries);
+        self
+    }
+
+    /// Only start operations within the given daily window
+    ///
+    /// Outside the window the session pauses: operations wait for it to open
+    /// before they start. Operations already running are not interrupted.
+    pub fn with_schedule_window(mut self, window: ScheduleWindow) -> Self {
+        self.schedule = Some(window);
+        self
+    }
+
+    /// Get the session's schedule window, if any
+    pub fn schedule_window(&self) -> Option<&ScheduleWindow> {
+        self.schedule.as_ref()
+    }
+
+    /// Stop the session's work once `timeout` has passed since its creation
+    ///
+    /// Operations still running then fail with `CsfdError::Timeout` and are
+    /// dropped, aborting their requests; operations not started yet are
+    /// skipped, so a batch reports the results it got so far.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Get the session's timeout, if any
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Check whether the session's timeout has passed
+    pub fn is_timed_out(&self) -> bool {
+        self.timeout
+            .is_some_and(|timeout| self.created.elapsed() >= timeout)
+    }
+
+    /// Use an existing cancellation token, e.g. one shared with the UI
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancel = token;
+        self
+    }
+
+    /// Get the scraper this session runs against
+    pub fn scraper(&self) -> &'a CsfdScraper {
+        self.scraper
+    }
+
+    /// Get the session's cancellation token
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancel
+    }
+
+    /// Cancel all running and future operations of this session
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Check whether the session has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Get the maximum number of concurrent operations
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Get the session's retry budget
+    pub fn retry_budget(&self) -> &RetryBudget {
+        &self.retry_budget
+    }
+
+    /// Run an operation within the session.
+    ///
+    /// Waits for the schedule window (if set) and a free slot in the
+    /// concurrency budget, then drives the operation until it completes or
+    /// the session is cancelled.
+    ///
+    /// # Errors
+    /// - `CsfdError::Cancelled` - The session was cancelled before the operation completed
+    /// - `CsfdError::Timeout` - The session's timeout passed before the operation completed
+    /// - `CsfdError::RetryBudgetExhausted` - The retry budget ran out before the operation started
+    /// - Any error returned by the operation itself
+    pub async fn run<T, F>(&self, operation: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        self.run_unless(|| false, operation)
+            .await
+            .unwrap_or_else(|| match self.timeout {
+                Some(timeout) if self.is_timed_out() => Err(CsfdError::Timeout(timeout)),
+                _ => Err(CsfdError::Cancelled),
+            })
+    }
+
+    /// Run operations for a batch of inputs, collecting a report.
+    ///
+    /// Operations run concurrently within the session's budget. Without
+    /// `fail_fast` every input is attempted and failures are collected; with
+    /// `fail_fast` no new operation starts after the first failure and the
+    /// remaining inputs are reported as skipped.
+    ///
+    /// # Arguments
+    /// * `inputs` - Inputs of the batch (e.g., series IDs)
+    /// * `fail_fast` - Stop starting operations after the first failure
+    /// * `operation` - Operation to run for each input
+    ///
+    /// # Example
+    /// ```no_run
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// let scraper = CsfdScraper::new()?;
+    /// let session = scraper.session();
+    /// let report = session
+    ///     .run_batch(vec![264494, 234260], false, |id| scraper.get_series(id))
+    ///     .await;
+    /// println!("{} ok, {} failed", report.succeeded.len(), report.failed.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_batch<I, T, F, Fut>(
+        &self,
+        inputs: Vec<I>,
+        fail_fast: bool,
+        operation: F,
+    ) -> BatchReport<I, T>
+    where
+        I: Clone,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let stop = AtomicBool::new(false);
+        let outcomes = join_all(inputs.iter().cloned().map(|input| {
+            let stop = &stop;
+            let operation = &operation;
+            async move {
+                let outcome = self
+                    .run_unless(|| stop.load(Ordering::SeqCst), operation(input))
+                    .await;
+                if fail_fast && matches!(outcome, Some(Err(_))) {
+                    stop.store(true, Ordering::SeqCst);
+                }
+                outcome
+            }
+        }))
+        .await;
+
+        let mut report = BatchReport::default();
+        for (input, outcome) in inputs.into_iter().zip(outcomes) {
+            match outcome {
+                Some(Ok(value)) => report.succeeded.push((input, value)),
+                Some(Err(error)) => report.failed.push((input, error)),
+                None => report.skipped.push(input),
+            }
+        }
+        report
+    }
+
+    /// Run an operation unless `skip` returns true once a budget slot is free.
+    ///
+    /// Returns `None` for a skipped operation, which is not counted as
+    /// started, including one still waiting when the timeout passes.
+    async fn run_unless<T, F>(&self, skip: impl Fn() -> bool, operation: F) -> Option<Result<T>>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        // Pause outside the schedule window, but stay responsive to cancellation
+        if let Some(window) = &self.schedule {
+            tokio::select! {
+                biased;
+                _ = self.cancel.cancelled() => {
+                    self.counters.cancelled.fetch_add(1, Ordering::Relaxed);
+                    return Some(Err(CsfdError::Cancelled));
+                }
+                _ = window.wait_until_open() => {}
+                _ = self.deadline() => return None,
+            }
+        }
+
+        // Check cancellation first so a cancelled session never starts new work
+        let _permit = tokio::select! {
+            biased;
+            _ = self.cancel.cancelled() => {
+                self.counters.cancelled.fetch_add(1, Ordering::Relaxed);
+                return Some(Err(CsfdError::Cancelled));
+            }
+            permit = self.budget.acquire() => permit.ok()?,
+            _ = self.deadline() => return None,
+        };
+        let _lane_permit = tokio::select! {
+            biased;
+            _ = self.cancel.cancelled() => {
+                self.counters.cancelled.fetch_add(1, Ordering::Relaxed);
+                return Some(Err(CsfdError::Cancelled));
+            }
+            permit = self.scraper.lanes().semaphore(self.lane).acquire() => permit.ok()?,
+            _ = self.deadline() => return None,
+        };
+
+        if skip() || self.is_timed_out() {
+            return None;
+        }
+
+        // After an outage drained the budget, fail fast instead of starting more work
+        if self.retry_budget.is_exhausted() {
+            self.counters.failed.fetch_add(1, Ordering::Relaxed);
+            return Some(Err(CsfdError::RetryBudgetExhausted));
+        }
+
+        self.counters.started.fetch_add(1, Ordering::Relaxed);
+
+        let result = tokio::select! {
+            result = self.retry_budget.scope(operation) => result,
+            _ = self.cancel.cancelled() => {
+                self.counters.cancelled.fetch_add(1, Ordering::Relaxed);
+                return Some(Err(CsfdError::Cancelled));
+            }
+            _ = self.deadline() => {
+                self.counters.failed.fetch_add(1, Ordering::Relaxed);
+                return Some(Err(CsfdError::Timeout(self.timeout.unwrap_or_default())));
+            }
+        };
+
+        let counter = if result.is_ok() {
+            &self.counters.succeeded
+        } else {
+            &self.counters.failed
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        Some(result)
+    }
+
+    /// Wait until the session's timeout passes, forever without one
+    async fn deadline(&self) {
+        match self.timeout {
+            Some(timeout) => {
+                tokio::time::sleep_until(tokio::time::Instant::from_std(self.created + timeout))
+                    .await
+            }
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Get a snapshot of the session metrics
+    pub fn metrics(&self) -> SessionMetrics {
+        SessionMetrics {
+            started: self.counters.started.load(Ordering::Relaxed),
+            succeeded: self.counters.succeeded.load(Ordering::Relaxed),
+            failed: self.counters.failed.load(Ordering::Relaxed),
+            cancelled: self.counters.cancelled.load(Ordering::Relaxed),
+            retries: self.retry_budget.used(),
+            retry_budget_exhausted: self.retry_budget.is_exhausted(),
+            elapsed: self.created.elapsed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{ClientConfig, CsfdClient};
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_session_defaults() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session();
+        assert_eq!(session.concurrency(), DEFAULT_SESSION_CONCURRENCY);
+        assert!(!session.is_cancelled());
+        assert_eq!(session.metrics().started, 0);
+    }
+
+    #[test]
+    fn test_schedule_window_parse() {
+        let window: ScheduleWindow = "02:00-06:30".parse().unwrap();
+        assert_eq!(window, ScheduleWindow::new((2, 0), (6, 30)).unwrap());
+        assert_eq!(window.to_string(), "02:00-06:30");
+
+        assert!("25:00-06:00".parse::<ScheduleWindow>().is_err());
+        assert!("02:00".parse::<ScheduleWindow>().is_err());
+        assert!("2-6".parse::<ScheduleWindow>().is_err());
+    }
+
+    #[test]
+    fn test_schedule_window_secs_until_open() {
+        let hour = 3600;
+        let night = ScheduleWindow::new((2, 0), (6, 0)).unwrap();
+        assert_eq!(night.secs_until_open(3 * hour), 0);
+        assert_eq!(night.secs_until_open(hour), hour);
+        assert_eq!(night.secs_until_open(6 * hour), 20 * hour);
+
+        let wrapping = ScheduleWindow::new((22, 0), (6, 0)).unwrap();
+        assert_eq!(wrapping.secs_until_open(23 * hour), 0);
+        assert_eq!(wrapping.secs_until_open(5 * hour), 0);
+        assert_eq!(wrapping.secs_until_open(12 * hour), 10 * hour);
+
+        let all_day = ScheduleWindow::new((0, 0), (0, 0)).unwrap();
+        assert!(all_day.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_session_schedule_window_pauses() {
+        let scraper = CsfdScraper::new().unwrap();
+        let now = ScheduleWindow::new((0, 0), (0, 0)).unwrap();
+        let session = scraper.session().with_schedule_window(now);
+        assert!(session.run(async { Ok(()) }).await.is_ok());
+
+        // A window opening in a few hours keeps the operation waiting
+        let utc_hour = (SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            / 3600
+            % 24) as u8;
+        let later =
+            ScheduleWindow::new(((utc_hour + 3) % 24, 0), ((utc_hour + 4) % 24, 0)).unwrap();
+        let session = scraper.session().with_schedule_window(later);
+        let paused = session.run(async { Ok(()) });
+        let cancel = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            session.cancel();
+        };
+        let (result, _) = tokio::join!(paused, cancel);
+
+        assert!(matches!(result, Err(CsfdError::Cancelled)));
+        assert_eq!(session.metrics().started, 0);
+    }
+
+    #[tokio::test]
+    async fn test_background_lane_limit_shared_across_sessions() {
+        let config = ClientConfig {
+            background_max_inflight: 1,
+            ..ClientConfig::default()
+        };
+        let scraper = CsfdScraper::with_client(CsfdClient::with_config(config).unwrap());
+        let first = scraper.session().with_lane(Lane::Background);
+        let second = scraper.session().with_lane(Lane::Background);
+        let interactive = scraper.session();
+
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let operation = || async {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            running.fetch_sub(1, Ordering::SeqCst);
+            Ok(())
+        };
+        let search = async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            // Interactive work doesn't queue behind the busy background lane
+            assert_eq!(scraper.metrics().background.in_flight, 1);
+            assert_eq!(scraper.metrics().interactive.in_flight, 0);
+            interactive.run(async { Ok(()) }).await
+        };
+
+        let (a, b, c) = tokio::join!(first.run(operation()), second.run(operation()), search);
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        assert_eq!(peak.load(Ordering::SeqCst), 1);
+        assert_eq!(scraper.metrics().background.max_in_flight, 1);
+        assert_eq!(scraper.metrics().background.in_flight, 0);
+    }
+
+    #[test]
+    fn test_session_with_concurrency_minimum() {
+        let scraper = CsfdScraper::new().unwrap();
+        assert_eq!(scraper.session().with_concurrency(0).concurrency(), 1);
+        assert_eq!(scraper.session().with_concurrency(8).concurrency(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_session_run_counts_outcomes() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session();
+
+        assert_eq!(session.run(async { Ok(1) }).await.unwrap(), 1);
+        let failed: Result<()> = session.run(async { Err(CsfdError::InvalidId(0)) }).await;
+        assert!(failed.is_err());
+
+        let metrics = session.metrics();
+        assert_eq!(metrics.started, 2);
+        assert_eq!(metrics.succeeded, 1);
+        assert_eq!(metrics.failed, 1);
+        assert_eq!(metrics.cancelled, 0);
+    }
+
+    #[tokio::test]
+    async fn test_session_cancel() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session();
+        session.cancel();
+
+        let result = session.run(async { Ok(()) }).await;
+        assert!(matches!(result, Err(CsfdError::Cancelled)));
+        assert_eq!(session.metrics().cancelled, 1);
+        assert_eq!(session.metrics().started, 0);
+    }
+
+    #[tokio::test]
+    async fn test_session_cancel_running_operation() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session();
+
+        let slow = session.run(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+        let cancel = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            session.cancel();
+        };
+        let (result, _) = tokio::join!(slow, cancel);
+
+        assert!(matches!(result, Err(CsfdError::Cancelled)));
+        assert_eq!(session.metrics().started, 1);
+        assert_eq!(session.metrics().cancelled, 1);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_registry() {
+        let scraper = CsfdScraper::new().unwrap();
+        let registry = CancelRegistry::new();
+        assert!(!registry.cancel("search"));
+
+        let first = registry.register("search");
+        let second = registry.register("search");
+        // Reusing an ID cancels the older operation
+        assert!(first.token().is_cancelled());
+        drop(first);
+        assert_eq!(registry.running(), vec!["search".to_string()]);
+
+        let session = scraper
+            .session()
+            .with_cancellation_token(second.token().clone());
+        let slow = session.run(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+        let cancel = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            assert!(registry.cancel("search"));
+        };
+        let (result, _) = tokio::join!(slow, cancel);
+        assert!(matches!(result, Err(CsfdError::Cancelled)));
+
+        drop(second);
+        assert!(registry.running().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_session_retry_budget_fails_fast() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session().with_retry_budget(1);
+
+        // First operation takes the only retry, then is denied a second one
+        let first: Result<()> = session
+            .run(async {
+                assert!(crate::client::try_acquire_retry());
+                assert!(!crate::client::try_acquire_retry());
+                Err(CsfdError::RateLimited(None))
+            })
+            .await;
+        assert!(matches!(first, Err(CsfdError::RateLimited(None))));
+
+        let second = session.run(async { Ok(()) }).await;
+        assert!(matches!(second, Err(CsfdError::RetryBudgetExhausted)));
+
+        let metrics = session.metrics();
+        assert_eq!(metrics.started, 1);
+        assert_eq!(metrics.failed, 2);
+        assert_eq!(metrics.retries, 1);
+        assert!(metrics.retry_budget_exhausted);
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_collects_failures() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session();
+
+        let report = session
+            .run_batch(vec![1u32, 0, 2, 0], false, |id| async move {
+                if id == 0 {
+                    Err(CsfdError::InvalidId(id))
+                } else {
+                    Ok(id * 10)
+                }
+            })
+            .await;
+
+        assert_eq!(report.succeeded, vec![(1, 10), (2, 20)]);
+        assert_eq!(report.failed.len(), 2);
+        assert!(report.skipped.is_empty());
+        assert!(!report.is_complete());
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["failed"][0][1], "Invalid CSFD ID: 0");
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_fail_fast() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session().with_concurrency(1);
+
+        let report = session
+            .run_batch(vec![1u32, 0, 2, 3], true, |id| async move {
+                if id == 0 {
+                    Err(CsfdError::InvalidId(id))
+                } else {
+                    Ok(id)
+                }
+            })
+            .await;
+
+        assert_eq!(report.succeeded, vec![(1, 1)]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.skipped, vec![2, 3]);
+        assert_eq!(session.metrics().started, 2);
+    }
+
+    #[tokio::test]
+    async fn test_session_timeout_keeps_partial_results() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper
+            .session()
+            .with_concurrency(1)
+            .with_timeout(Duration::from_millis(50));
+
+        // The second operation hangs past the timeout, the third never starts
+        let report = session
+            .run_batch(vec![1u32, 2, 3], false, |id| async move {
+                if id == 2 {
+                    std::future::pending::<()>().await;
+                }
+                Ok(id)
+            })
+            .await;
+
+        assert_eq!(report.succeeded, vec![(1, 1)]);
+        assert!(matches!(report.failed[..], [(2, CsfdError::Timeout(_))]));
+        assert_eq!(report.skipped, vec![3]);
+        assert!(session.is_timed_out());
+        assert!(matches!(
+            session.run(async { Ok(()) }).await,
+            Err(CsfdError::Timeout(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_session_concurrency_budget() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session().with_concurrency(2);
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+
+        let operations = (0..6).map(|_| {
+            session.run(async {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                Ok(())
+            })
+        });
+        join_all(operations).await;
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(session.metrics().succeeded, 6);
+    }
+}
@@ -0,0 +1,185 @@
+//! Share text for titles
+//!
+//! Formats the one-line summary used when a title is copied to the
+//! clipboard or shared, e.g. "Breaking Bad (2008–2013) — 94 % na ČSFD: <url>".
+//! Keeping the format here makes the app and command-line tools produce the
+//! same text. `format_share_text_in` writes it in another language.
+
+use std::borrow::Cow;
+
+use crate::i18n::{Locale, Message};
+use crate::types::{Episode, SearchResult, SeriesDetail};
+use crate::CSFD_BASE_URL;
+
+/// Something that can be shared as a ČSFD link with a summary
+pub trait Shareable {
+    /// Display name
+    fn share_name(&self) -> &str;
+
+    /// Year or year range (e.g., "2008-2013"), if known
+    fn share_years(&self) -> Option<&str>;
+
+    /// Rating as percentage (0.0 - 100.0), if rated
+    fn share_rating(&self) -> Option<f32>;
+
+    /// URL of the page on ČSFD, absolute or relative to the site
+    fn share_url(&self) -> Cow<'_, str>;
+}
+
+impl Shareable for SeriesDetail {
+    fn share_name(&self) -> &str {
+        &self.name
+    }
+
+    fn share_years(&self) -> Option<&str> {
+        self.year_range.as_deref()
+    }
+
+    fn share_rating(&self) -> Option<f32> {
+        self.rating
+    }
+
+    fn share_url(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("/film/{}/", self.csfd_id))
+    }
+}
+
+impl Shareable for SearchResult {
+    fn share_name(&self) -> &str {
+        &self.name
+    }
+
+    fn share_years(&self) -> Option<&str> {
+        self.year.as_deref()
+    }
+
+    fn share_rating(&self) -> Option<f32> {
+        None
+    }
+
+    fn share_url(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.url)
+    }
+}
+
+impl Shareable for Episode {
+    fn share_name(&self) -> &str {
+        &self.name
+    }
+
+    fn share_years(&self) -> Option<&str> {
+        None
+    }
+
+    fn share_rating(&self) -> Option<f32> {
+        self.rating
+    }
+
+    fn share_url(&self) -> Cow<'_, str> {
+        Cow::Borrowed(&self.url)
+    }
+}
+
+/// Format the share text of a title.
+///
+/// Year ranges use an en dash and the rating is rounded to whole percent.
+/// Parts that aren't known are left out.
+///
+/// # Example
+/// ```
+/// use csfd_core::format_share_text;
+/// # use csfd_core::SeriesDetail;
+/// # fn example(detail: &SeriesDetail) {
+/// // "Breaking Bad (2008–2013) — 94 % na ČSFD: https://www.csfd.cz/film/..."
+/// println!("{}", format_share_text(detail));
+/// # }
+/// ```
+pub fn format_share_text<S: Shareable + ?Sized>(entity: &S) -> String {
+    format_share_text_in(entity, Locale::Cs)
+}
+
+/// Format the share text of a title in the given language.
+///
+/// # Example
+/// ```
+/// use csfd_core::format_share_text_in;
+/// use csfd_core::i18n::Locale;
+/// # use csfd_core::SeriesDetail;
+/// # fn example(detail: &SeriesDetail) {
+/// // "Breaking Bad (2008–2013) — 94 % on ČSFD: https://www.csfd.cz/film/..."
+/// println!("{}", format_share_text_in(detail, Locale::En));
+/// # }
+/// ```
+pub fn format_share_text_in<S: Shareable + ?Sized>(entity: &S, locale: Locale) -> String {
+    let mut text = entity.share_name().to_string();
+
+    if let Some(years) = entity.share_years().filter(|y| !y.is_empty()) {
+        text.push_str(&format!(" ({})", years.replace('-', "–")));
+    }
+    if let Some(rating) = entity.share_rating() {
+        text.push_str(&format!(" — {} %", rating.round() as u32));
+    }
+
+    let url = entity.share_url();
+    let on_csfd = locale.text(Message::ShareOnCsfd);
+    if url.starts_with("http") {
+        text.push_str(&format!(" {}: {}", on_csfd, url));
+    } else {
+        text.push_str(&format!(" {}: {}{}", on_csfd, CSFD_BASE_URL, url));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SeriesType;
+
+    #[test]
+    fn test_format_share_text_series() {
+        let detail = SeriesDetail {
+            csfd_id: 250271,
+            name: "Breaking Bad".to_string(),
+            original_name: None,
+            year_range: Some("2008-2013".to_string()),
+            genres: Vec::new(),
+            genre_ids: Vec::new(),
+            status_label: None,
+            countries: Vec::new(),
+            seasons: Vec::new(),
+            directors: Vec::new(),
+            writers: Vec::new(),
+            cast: Vec::new(),
+            rating: Some(93.6),
+            recommendations: Vec::new(),
+            availability: Default::default(),
+            imdb_id: None,
+        };
+        assert_eq!(
+            format_share_text(&detail),
+            "Breaking Bad (2008–2013) — 94 % na ČSFD: https://www.csfd.cz/film/250271/"
+        );
+        assert_eq!(
+            format_share_text_in(&detail, Locale::En),
+            "Breaking Bad (2008–2013) — 94 % on ČSFD: https://www.csfd.cz/film/250271/"
+        );
+    }
+
+    #[test]
+    fn test_format_share_text_without_rating() {
+        let result = SearchResult {
+            name: "Perníkový táta".to_string(),
+            original_name: None,
+            year: None,
+            series_type: SeriesType::Series,
+            url: "https://www.csfd.cz/film/250271-pernikovy-tata/".to_string(),
+            csfd_id: 250271,
+            local_rank: None,
+            score: None,
+        };
+        assert_eq!(
+            format_share_text(&result),
+            "Perníkový táta na ČSFD: https://www.csfd.cz/film/250271-pernikovy-tata/"
+        );
+    }
+}
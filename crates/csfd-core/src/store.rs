@@ -0,0 +1,191 @@
+//! Local persistent store
+//!
+//! A small file-backed key-value store for data the library keeps between
+//! runs (search history, opened titles, ...). Each key is stored as a
+//! separate JSON document in the store directory.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{CsfdError, Result};
+
+/// File-backed JSON document store
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::store::LocalStore;
+///
+/// # fn example() -> Result<(), csfd_core::CsfdError> {
+/// let store = LocalStore::open("/tmp/csfd-data")?;
+/// store.save("greeting", &"ahoj".to_string())?;
+/// let greeting: String = store.load("greeting")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalStore {
+    /// Directory holding the JSON documents
+    dir: PathBuf,
+}
+
+impl LocalStore {
+    /// Open a store in the given directory, creating it if needed
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the directory cannot be created
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)
+            .map_err(|e| CsfdError::StorageError(format!("{}: {}", dir.display(), e)))?;
+        Ok(Self { dir })
+    }
+
+    /// Get the directory of the store
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Load a document, returning the default value if it doesn't exist yet
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the document cannot be read or decoded
+    pub fn load<T: DeserializeOwned + Default>(&self, key: &str) -> Result<T> {
+        let path = self.path(key)?;
+        match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| CsfdError::StorageError(format!("{}: {}", path.display(), e))),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(T::default()),
+            Err(e) => Err(CsfdError::StorageError(format!(
+                "{}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    /// Save a document, replacing any previous value atomically
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the document cannot be written
+    pub fn save<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let path = self.path(key)?;
+        let bytes = serde_json::to_vec(value)
+            .map_err(|e| CsfdError::StorageError(format!("{}: {}", key, e)))?;
+
+        // Write to a temporary file first so a crash never leaves a torn document
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, bytes)
+            .and_then(|_| fs::rename(&tmp, &path))
+            .map_err(|e| CsfdError::StorageError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Check whether a document exists
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the key is invalid
+    pub fn contains(&self, key: &str) -> Result<bool> {
+        Ok(self.path(key)?.is_file())
+    }
+
+    /// Remove a document; removing a missing document is not an error
+    ///
+    /// # Errors
+    /// Returns `CsfdError::StorageError` if the document exists but cannot be removed
+    pub fn remove(&self, key: &str) -> Result<()> {
+        let path = self.path(key)?;
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(CsfdError::StorageError(format!(
+                "{}: {}",
+                path.display(),
+                e
+            ))),
+        }
+    }
+
+    /// Resolve the file path of a key, rejecting keys that could escape the store
+    fn path(&self, key: &str) -> Result<PathBuf> {
+        let valid = !key.is_empty()
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(CsfdError::StorageError(format!(
+                "Invalid store key: {:?}",
+                key
+            )));
+        }
+        Ok(self.dir.join(format!("{}.json", key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_store_load_missing_returns_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        let value: Vec<String> = store.load("missing").unwrap();
+        assert!(value.is_empty());
+    }
+
+    #[test]
+    fn test_store_save_and_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path().join("nested")).unwrap();
+
+        let mut value = HashMap::new();
+        value.insert("breaking-bad".to_string(), 264494u32);
+        store.save("ids", &value).unwrap();
+
+        let loaded: HashMap<String, u32> = store.load("ids").unwrap();
+        assert_eq!(loaded, value);
+    }
+
+    #[test]
+    fn test_store_remove() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        store.save("doc", &1u32).unwrap();
+        assert!(store.contains("doc").unwrap());
+        store.remove("doc").unwrap();
+        store.remove("doc").unwrap();
+        assert!(!store.contains("doc").unwrap());
+
+        let value: u32 = store.load("doc").unwrap();
+        assert_eq!(value, 0);
+    }
+
+    #[test]
+    fn test_store_rejects_invalid_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        assert!(matches!(
+            store.save("../escape", &1u32),
+            Err(CsfdError::StorageError(_))
+        ));
+        assert!(matches!(
+            store.load::<u32>(""),
+            Err(CsfdError::StorageError(_))
+        ));
+    }
+
+    #[test]
+    fn test_store_corrupt_document() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = LocalStore::open(dir.path()).unwrap();
+        fs::write(dir.path().join("doc.json"), b"not json").unwrap();
+        assert!(matches!(
+            store.load::<u32>("doc"),
+            Err(CsfdError::StorageError(_))
+        ));
+    }
+}
@@ -0,0 +1,123 @@
+//! Text normalization helpers
+//!
+//! ČSFD titles are mostly Czech and Slovak. Users often type without
+//! diacritics ("pernikovy tata"), so matching is done on a folded form.
+
+/// Normalize text for matching: lowercase, strip Czech/Slovak diacritics
+/// and collapse whitespace.
+pub(crate) fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .flat_map(char::to_lowercase)
+                .map(fold_char)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse a ČSFD year or year range ("2008", "2008-2013", "2008–") into
+/// its first year and last year, `None` as last year meaning still running.
+pub(crate) fn year_span(text: &str) -> Option<(u16, Option<u16>)> {
+    let mut bounds = text.split(['-', '–']).map(|part| part.trim());
+    let start = bounds.next()?.parse().ok()?;
+    match bounds.next() {
+        None => Some((start, Some(start))),
+        Some(end) => Some((start, end.parse().ok())),
+    }
+}
+
+/// Similarity of two normalized strings (0.0 - 1.0).
+///
+/// Sørensen-Dice coefficient over character bigrams, so word order and
+/// small typos lower the score gradually instead of failing a match.
+pub(crate) fn similarity(a: &str, b: &str) -> f32 {
+    if a == b {
+        return 1.0;
+    }
+
+    let bigrams = |text: &str| {
+        let chars: Vec<char> = text.chars().collect();
+        let mut pairs: Vec<(char, char)> = chars.windows(2).map(|w| (w[0], w[1])).collect();
+        pairs.sort_unstable();
+        pairs
+    };
+    let (a, b) = (bigrams(a), bigrams(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    // Count common bigrams with multiplicity by merging the sorted lists
+    let (mut i, mut j, mut common) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                common += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    (2 * common) as f32 / (a.len() + b.len()) as f32
+}
+
+/// Map a lowercase character with diacritics to its base letter.
+fn fold_char(c: char) -> char {
+    match c {
+        'á' | 'ä' | 'à' | 'â' => 'a',
+        'č' | 'ç' => 'c',
+        'ď' => 'd',
+        'é' | 'ě' | 'ë' | 'è' | 'ê' => 'e',
+        'í' | 'ï' | 'î' => 'i',
+        'ĺ' | 'ľ' => 'l',
+        'ň' | 'ñ' => 'n',
+        'ó' | 'ô' | 'ö' | 'ò' => 'o',
+        'ŕ' | 'ř' => 'r',
+        'š' => 's',
+        'ť' => 't',
+        'ú' | 'ů' | 'ü' | 'ù' | 'û' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ž' => 'z',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Perníkový táta"), "pernikovy tata");
+        assert_eq!(
+            normalize("  Teorie   velkého\ttřesku "),
+            "teorie velkeho tresku"
+        );
+        assert_eq!(normalize("ŽLUŤOUČKÝ KŮŇ"), "zlutoucky kun");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn test_year_span() {
+        assert_eq!(year_span("2008"), Some((2008, Some(2008))));
+        assert_eq!(year_span("2008 - 2013"), Some((2008, Some(2013))));
+        assert_eq!(year_span("2019–"), Some((2019, None)));
+        assert_eq!(year_span("unknown"), None);
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("dr house", "dr house"), 1.0);
+        assert_eq!(similarity("abc", "xyz"), 0.0);
+        assert_eq!(similarity("", "house"), 0.0);
+
+        let close = similarity("pernikovy tata", "pernikovy taata");
+        let far = similarity("pernikovy tata", "teorie velkeho tresku");
+        assert!(close > 0.8, "{}", close);
+        assert!(far < 0.3, "{}", far);
+    }
+}
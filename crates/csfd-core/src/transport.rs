@@ -0,0 +1,234 @@
+//! HTTP transport of the client
+//!
+//! `CsfdClient` sends every request through an [`HttpTransport`] and keeps
+//! rate limiting, retries, caching and status handling to itself. The
+//! default [`ReqwestTransport`] talks to ČSFD over the network; tests and
+//! downstream users can supply their own, e.g. one serving canned pages, to
+//! exercise `CsfdScraper` without hitting the live site.
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//! use futures::future::BoxFuture;
+//! use csfd_core::{ClientConfig, CsfdClient, CsfdScraper, Result};
+//! use csfd_core::{HttpRequest, HttpResponse, HttpTransport};
+//!
+//! /// Answers every request with the same page
+//! struct Canned(&'static str);
+//!
+//! impl HttpTransport for Canned {
+//!     fn send<'a>(&'a self, _request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+//!         Box::pin(async move { Ok(HttpResponse::html(self.0)) })
+//!     }
+//! }
+//!
+//! let transport = Arc::new(Canned("<html></html>"));
+//! let client = CsfdClient::with_transport(ClientConfig::default(), transport);
+//! let scraper = CsfdScraper::with_client(client);
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode, Url};
+
+use crate::client::ClientConfig;
+use crate::error::Result;
+
+/// Default User-Agent mimicking a modern browser
+const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+/// Default Accept-Language header for Czech content
+const DEFAULT_ACCEPT_LANGUAGE: &str = "cs-CZ,cs;q=0.9,en;q=0.8";
+
+/// Request sent by the client
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+    /// GET for pages and images, POST for forms
+    pub method: Method,
+    /// Absolute URL of the page, form or image
+    pub url: Url,
+    /// Request headers, e.g. validators of a conditional request
+    pub headers: HeaderMap,
+    /// Fields of the URL-encoded form body, empty for GET
+    pub form: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// GET request without headers
+    pub fn get(url: Url) -> Self {
+        Self {
+            method: Method::GET,
+            url,
+            headers: HeaderMap::new(),
+            form: Vec::new(),
+        }
+    }
+
+    /// POST request submitting a form
+    pub fn post_form(url: Url, form: &[(&str, &str)]) -> Self {
+        Self {
+            method: Method::POST,
+            url,
+            headers: HeaderMap::new(),
+            form: form
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+}
+
+/// Response received by the client, with the whole body
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    /// Status code
+    pub status: StatusCode,
+    /// Response headers, e.g. `ETag` or `Retry-After`
+    pub headers: HeaderMap,
+    /// Raw body
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Response with the given status and body, without headers
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// 200 OK response with an HTML page
+    pub fn html(body: impl Into<String>) -> Self {
+        Self::new(StatusCode::OK, body.into()).with_header(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("text/html; charset=utf-8"),
+        )
+    }
+
+    /// Add a header
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Get a header as text, if present and readable
+    pub fn header(&self, name: HeaderName) -> Option<&str> {
+        self.headers.get(name)?.to_str().ok()
+    }
+
+    /// Get the body as text, replacing invalid UTF-8
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// Sends the requests of a `CsfdClient`
+///
+/// Any status is a response: the client handles 304, 404, 429 and 5xx
+/// itself. Only failures to get a response, such as network errors or
+/// timeouts, are errors.
+pub trait HttpTransport: Send + Sync {
+    /// Send a request and receive the whole response
+    fn send<'a>(&'a self, request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>>;
+}
+
+/// Transport backed by reqwest, used by default
+///
+/// Sends a browser User-Agent and Czech `Accept-Language`, honours the
+/// proxy and timeout of the client configuration, and keeps cookies in the
+/// given jar, following redirects like a browser.
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport for the given configuration
+    ///
+    /// # Arguments
+    /// * `config` - Client configuration, for the proxy and timeout
+    /// * `cookies` - Jar the cookies are kept in, shared with `CsfdClient`
+    ///
+    /// # Errors
+    /// Returns an error if the HTTP client cannot be created, or
+    /// `CsfdError::InvalidUrl` if the proxy URL is invalid
+    pub fn new(config: &ClientConfig, cookies: Arc<reqwest::cookie::Jar>) -> Result<Self> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(proxy.to_reqwest()?);
+        }
+        let client = builder
+            .user_agent(DEFAULT_USER_AGENT)
+            .default_headers({
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    reqwest::header::ACCEPT_LANGUAGE,
+                    HeaderValue::from_static(DEFAULT_ACCEPT_LANGUAGE),
+                );
+                headers
+            })
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .cookie_provider(cookies)
+            .build()?;
+        Ok(Self { client })
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send<'a>(&'a self, request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if !request.form.is_empty() {
+                builder = builder.form(&request.form);
+            }
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_helpers() {
+        let response = HttpResponse::html("<p>Dr. House</p>")
+            .with_header(reqwest::header::ETAG, HeaderValue::from_static("\"abc\""));
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.header(reqwest::header::ETAG), Some("\"abc\""));
+        assert_eq!(response.header(reqwest::header::LAST_MODIFIED), None);
+        assert_eq!(response.text(), "<p>Dr. House</p>");
+    }
+
+    #[test]
+    fn test_post_form() {
+        let url = Url::parse("https://www.csfd.cz/prihlaseni/").unwrap();
+        let request = HttpRequest::post_form(url, &[("username", "house")]);
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(
+            request.form,
+            vec![("username".to_string(), "house".to_string())]
+        );
+    }
+}
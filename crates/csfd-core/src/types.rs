@@ -1,12 +1,21 @@
 //! Data types for ČSFD Scraper
 //!
 //! This module contains all the core data structures used throughout the library.
-//! All types implement Serialize and Deserialize for JSON compatibility with Tauri.
+//! With the `serde` feature, all types implement Serialize and Deserialize
+//! for JSON compatibility with Tauri.
 
-use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::hash::ContentHasher;
+
+#[cfg(feature = "serde")]
+use serde::{ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Type of series/show on ČSFD
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SeriesType {
     /// Regular TV series (seriál)
     Series,
@@ -14,10 +23,15 @@ pub enum SeriesType {
     Season,
     /// Mini-series (minisérie)
     MiniSeries,
+    /// TV film, possibly in several parts (TV film)
+    TvFilm,
+    /// Film listed without a TV type, e.g. a cinema film in search results
+    Film,
 }
 
 /// Search result item from ČSFD search
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SearchResult {
     /// Display name of the series
     pub name: String,
@@ -31,10 +45,17 @@ pub struct SearchResult {
     pub url: String,
     /// Unique ČSFD identifier
     pub csfd_id: u32,
+    /// Rank among titles the user opened before (1 = most frecent), if any
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub local_rank: Option<u32>,
+    /// Relevance to the query (0.0 - 1.0) from title similarity and ČSFD's ordering
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub score: Option<f32>,
 }
 
 /// Detailed information about a series
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SeriesDetail {
     /// Unique ČSFD identifier
     pub csfd_id: u32,
@@ -44,51 +65,1422 @@ pub struct SeriesDetail {
     pub original_name: Option<String>,
     /// Year range (e.g., "2020-2023" or "2020")
     pub year_range: Option<String>,
+    /// Production status shown in the header (e.g., "ukončený"), if any
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status_label: Option<String>,
     /// List of genres
-    pub genres: Vec<String>,
+    pub genres: Vec<Label>,
+    /// Genres linked with their advanced search filter IDs as (label, ID) pairs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub genre_ids: Vec<(Label, u32)>,
     /// List of countries of origin
-    pub countries: Vec<String>,
+    pub countries: Vec<Label>,
     /// List of seasons
     pub seasons: Vec<Season>,
+    /// Directors from the overview summary as (name, creator ID) pairs
+    pub directors: Vec<(String, u32)>,
+    /// Screenwriters from the overview summary as (name, creator ID) pairs
+    pub writers: Vec<(String, u32)>,
+    /// Headline cast from the overview summary
+    pub cast: Vec<CastMember>,
+    /// Average rating as percentage (0.0 - 100.0)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rating: Option<f32>,
+    /// Titles from the page's recommendation widgets ("Mohlo by se vám líbit"),
+    /// not including related titles such as sequels or spin-offs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recommendations: Vec<SearchResult>,
+    /// Which optional sections the page had and whether they could be parsed
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub availability: DetailAvailability,
+    /// IMDb ID from the page's IMDb link (e.g., "tt0412142"), if linked
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub imdb_id: Option<String>,
+}
+
+/// Whether a section of a detail page was found and parsed
+///
+/// Lets a UI tell "no genres listed" (`present == false`) apart from
+/// "failed to parse genres" (`present && !parsed`, with an `error`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Availability {
+    /// The page has the section
+    pub present: bool,
+    /// Values were extracted from the section
+    pub parsed: bool,
+    /// Why a present section yielded no values
+    pub error: Option<String>,
+}
+
+impl Availability {
+    /// Availability of a section from whether it was found and yielded values
+    pub fn new(section: &str, present: bool, parsed: bool) -> Self {
+        let error = (present && !parsed)
+            .then(|| format!("{} section found, but nothing could be parsed", section));
+        Self {
+            present: present || parsed,
+            parsed,
+            error,
+        }
+    }
+
+    /// Check whether the section was found but couldn't be parsed
+    pub fn is_broken(&self) -> bool {
+        self.present && !self.parsed
+    }
+}
+
+/// Availability of the optional sections of a series detail page
+///
+/// Details saved before availability was tracked deserialize with every
+/// section marked as not present.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DetailAvailability {
+    /// Genre line
+    pub genres: Availability,
+    /// Countries of origin
+    pub countries: Availability,
+    /// Directors, writers and cast summary
+    pub creators: Availability,
+    /// Average rating
+    pub rating: Availability,
+}
+
+impl SeriesDetail {
+    /// Check whether the series is probably still running.
+    ///
+    /// A status label decides when ČSFD shows one. Otherwise an open year
+    /// range ("2008–") or a season from this year or the last one means
+    /// running, and a year range that ended before this year means ended.
+    /// Without any of these the series counts as running, so it is
+    /// refreshed rather than kept stale.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # #[cfg(feature = "client")]
+    /// # async fn example() -> Result<(), csfd_core::CsfdError> {
+    /// use csfd_core::CsfdScraper;
+    ///
+    /// let scraper = CsfdScraper::new()?;
+    /// let detail = scraper.get_series(264494).await?;
+    /// println!("{} is running: {}", detail.name, detail.is_ongoing());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_ongoing(&self) -> bool {
+        if let Some(ongoing) = self.status_label.as_deref().and_then(status_is_ongoing) {
+            return ongoing;
+        }
+
+        let this_year = crate::freshness::current_year();
+        let span = self.year_range.as_deref().and_then(crate::text::year_span);
+        if matches!(span, Some((_, None))) {
+            return true;
+        }
+        let latest_season = self
+            .seasons
+            .iter()
+            .filter_map(|season| crate::text::year_span(season.year.as_deref()?))
+            .map(|(start, end)| end.unwrap_or(this_year).max(start))
+            .max();
+        if latest_season.is_some_and(|year| year + 1 >= this_year) {
+            return true;
+        }
+        match span {
+            Some((_, Some(end))) => end >= this_year,
+            _ => latest_season.is_none(),
+        }
+    }
+
+    /// Get the countries of origin with their ISO codes, in ČSFD's order
+    pub fn origin_countries(&self) -> Vec<Country> {
+        self.countries
+            .iter()
+            .map(|name| Country::from_name_cs(name))
+            .collect()
+    }
+
+    /// Stable hash of the series' content for cheap change detection.
+    ///
+    /// Covers all parsed fields with text trimmed and the rating rounded to
+    /// one decimal place. Equal hashes mean nothing the scraper reads has
+    /// changed; the value is stable across releases and platforms.
+    pub fn content_hash(&self) -> ContentHash {
+        let mut hasher = ContentHasher::new();
+        hasher
+            .u32(self.csfd_id)
+            .str(&self.name)
+            .opt_str(self.original_name.as_deref())
+            .opt_str(self.year_range.as_deref())
+            .opt_percent(self.rating);
+        for list in [&self.genres, &self.countries] {
+            hasher.u32(list.len() as u32);
+            for value in list {
+                hasher.str(value);
+            }
+        }
+        hasher.u32(self.seasons.len() as u32);
+        for season in &self.seasons {
+            hasher
+                .u32(season.csfd_id)
+                .str(&season.name)
+                .opt_str(season.year.as_deref())
+                .u32(season.episode_count)
+                .str(&season.url)
+                .opt_str(season.poster_url.as_deref());
+        }
+        for creators in [&self.directors, &self.writers] {
+            hasher.u32(creators.len() as u32);
+            for (name, creator_id) in creators {
+                hasher.str(name).u32(*creator_id);
+            }
+        }
+        hasher.u32(self.cast.len() as u32);
+        for member in &self.cast {
+            hasher
+                .str(&member.name)
+                .u32(member.creator_id)
+                .opt_str(member.character.as_deref());
+        }
+        hasher.u32(self.recommendations.len() as u32);
+        for result in &self.recommendations {
+            hasher.u32(result.csfd_id).str(&result.name);
+        }
+        hasher.finish()
+    }
+}
+
+/// Whether a ČSFD status label means a running series, `None` if unknown
+fn status_is_ongoing(label: &str) -> Option<bool> {
+    let label = label.to_lowercase();
+    if ["ukončen", "zrušen", "ended", "cancelled", "canceled"]
+        .iter()
+        .any(|word| label.contains(word))
+    {
+        Some(false)
+    } else if ["vysílá", "pokračuje", "v produkci", "running", "returning"]
+        .iter()
+        .any(|word| label.contains(word))
+    {
+        Some(true)
+    } else {
+        None
+    }
+}
+
+/// Detailed information about a film
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MovieDetail {
+    /// Unique ČSFD identifier
+    pub csfd_id: u32,
+    /// Display name of the film
+    pub name: String,
+    /// Original name (if different from Czech name)
+    pub original_name: Option<String>,
+    /// Release year
+    pub year: Option<u16>,
+    /// List of genres
+    pub genres: Vec<Label>,
+    /// Genres linked with their advanced search filter IDs as (label, ID) pairs
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub genre_ids: Vec<(Label, u32)>,
+    /// List of countries of origin
+    pub countries: Vec<Label>,
+    /// Runtime in minutes
+    pub runtime_minutes: Option<u16>,
+    /// Average rating as percentage (0.0 - 100.0)
+    pub rating: Option<f32>,
+    /// Directors as (name, creator ID) pairs
+    pub directors: Vec<(String, u32)>,
+    /// Headline cast
+    pub cast: Vec<CastMember>,
+    /// Whether this is a cinema or TV film
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub film_type: FilmType,
+    /// Parts of a multi-part TV film, in order; empty for single films
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub parts: Vec<Part>,
+    /// IMDb ID from the page's IMDb link (e.g., "tt0944947"), if linked
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub imdb_id: Option<String>,
+}
+
+impl MovieDetail {
+    /// Get the countries of origin with their ISO codes, in ČSFD's order
+    pub fn origin_countries(&self) -> Vec<Country> {
+        self.countries
+            .iter()
+            .map(|name| Country::from_name_cs(name))
+            .collect()
+    }
+
+    /// Get a part of a multi-part film by its number (1-based)
+    pub fn part(&self, number: u16) -> Option<&Part> {
+        self.parts.iter().find(|part| part.number == number)
+    }
+}
+
+/// Kind of film on ČSFD
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FilmType {
+    /// Cinema film
+    #[default]
+    Film,
+    /// Film made for television (TV film)
+    TvFilm,
+}
+
+/// One part of a multi-part TV film (e.g., "1. díl" of a two-part drama)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Part {
+    /// Unique ČSFD identifier of the part
+    pub csfd_id: u32,
+    /// Display name of the part
+    pub name: String,
+    /// Part number (1-based)
+    pub number: u16,
+    /// Average rating as percentage (0.0 - 100.0), if shown
+    pub rating: Option<f32>,
+    /// Relative URL on ČSFD
+    pub url: String,
+}
+
+/// Detailed information about a single episode
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EpisodeDetail {
+    /// Unique ČSFD identifier of the episode
+    pub csfd_id: u32,
+    /// ČSFD identifier of the series
+    pub series_id: u32,
+    /// Episode name
+    pub name: String,
+    /// Season and episode number, if shown in the header
+    pub code: Option<EpisodeCode>,
+    /// Plot summary
+    pub plot: Option<String>,
+    /// First air date (YYYY-MM-DD)
+    pub air_date: Option<String>,
+    /// Runtime in minutes
+    pub runtime_minutes: Option<u16>,
+    /// Average rating as percentage (0.0 - 100.0)
+    pub rating: Option<f32>,
+    /// Directors as (name, creator ID) pairs
+    pub directors: Vec<(String, u32)>,
+    /// Credited cast
+    pub cast: Vec<CastMember>,
+}
+
+/// Actor credited in a cast listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CastMember {
+    /// Display name of the actor
+    pub name: String,
+    /// ČSFD creator identifier (from the `/tvurce/` URL)
+    pub creator_id: u32,
+    /// Character played, if ČSFD lists it
+    pub character: Option<String>,
+}
+
+/// Role of a person in a filmography
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PersonRole {
+    /// Herec / Herečka
+    Actor,
+    /// Režie
+    Director,
+    /// Scénář
+    Writer,
+    /// Any other role, with its heading as shown on ČSFD (e.g., "Hudba")
+    Other(Label),
+}
+
+impl PersonRole {
+    /// Map a filmography heading to a role
+    pub fn from_heading(heading: &str) -> Self {
+        let heading = heading.trim().trim_end_matches(':');
+        match heading.to_lowercase().as_str() {
+            "herec" | "herečka" | "hraje" => Self::Actor,
+            "režie" | "režisér" | "režisérka" => Self::Director,
+            "scénář" | "scenárista" | "scenáristka" => Self::Writer,
+            _ => Self::Other(Label::new(heading)),
+        }
+    }
+}
+
+/// Title in a person's filmography
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FilmographyEntry {
+    /// ČSFD identifier of the title
+    pub csfd_id: u32,
+    /// Display name of the title
+    pub name: String,
+    /// Release year
+    pub year: Option<u16>,
+    /// Relative URL on ČSFD
+    pub url: String,
+}
+
+/// Titles of a person in one role, newest first as listed on ČSFD
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FilmographyGroup {
+    /// Role of the person in these titles
+    pub role: PersonRole,
+    /// Titles in ČSFD's order
+    pub titles: Vec<FilmographyEntry>,
+}
+
+/// Detailed information about a person (`/tvurce/` page)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PersonDetail {
+    /// ČSFD creator identifier
+    pub csfd_id: u32,
+    /// Name of the person
+    pub name: String,
+    /// Biography text
+    pub biography: Option<String>,
+    /// Birth date (YYYY-MM-DD)
+    pub birth_date: Option<String>,
+    /// Birth place (e.g., "Oxford, Velká Británie")
+    pub birth_place: Option<String>,
+    /// Absolute URL of the profile photo
+    pub photo_url: Option<String>,
+    /// Filmography grouped by role, in ČSFD's order
+    pub filmography: Vec<FilmographyGroup>,
+}
+
+/// Country of origin of a title
+///
+/// # Example
+/// ```
+/// use csfd_core::Country;
+///
+/// let country = Country::from_name_cs("Velká Británie");
+/// assert_eq!(country.iso2.as_deref(), Some("GB"));
+/// assert_eq!(Country::from_name_cs("Československo").iso2, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Country {
+    /// Name as ČSFD shows it (e.g., "Velká Británie")
+    pub name_cs: Label,
+    /// ISO 3166-1 alpha-2 code, `None` for unknown or former countries
+    pub iso2: Option<Label>,
+}
+
+impl Country {
+    /// Create a country from its Czech name, looking up its ISO code
+    pub fn from_name_cs(name_cs: &str) -> Self {
+        let name_cs = name_cs.trim();
+        Self {
+            name_cs: Label::new(name_cs),
+            iso2: crate::country::iso2(name_cs).map(Label::new),
+        }
+    }
+}
+
+/// Facts from the origin line under a title's name
+///
+/// ČSFD summarizes a title in one line such as
+/// "USA / Kanada, 2008–2013, 62 epizod, 45 min"; seasons use a shorter
+/// "(2008) - 7 epizod". Parts missing from the line are `None` or empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OriginLine {
+    /// Countries of origin, all co-producers in ČSFD's order
+    pub countries: Vec<Country>,
+    /// First year
+    pub year_from: Option<u16>,
+    /// Last year of a finished span ("2008–2013")
+    pub year_to: Option<u16>,
+    /// The span has no end yet ("2019–")
+    pub open_ended: bool,
+    /// Number of episodes
+    pub episode_count: Option<u32>,
+    /// Runtime in minutes, of one episode for series
+    pub runtime_minutes: Option<u16>,
+}
+
+impl OriginLine {
+    /// Get the year span as "2008", "2008-2013" or "2019-"
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::OriginLine;
+    ///
+    /// let origin = OriginLine {
+    ///     year_from: Some(2008),
+    ///     year_to: Some(2013),
+    ///     ..OriginLine::default()
+    /// };
+    /// assert_eq!(origin.year_range().as_deref(), Some("2008-2013"));
+    /// ```
+    pub fn year_range(&self) -> Option<String> {
+        let from = self.year_from?;
+        Some(match (self.year_to, self.open_ended) {
+            (Some(to), _) => format!("{}-{}", from, to),
+            (None, true) => format!("{}-", from),
+            (None, false) => from.to_string(),
+        })
+    }
 }
 
 /// Season information within a series
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Season {
     /// Unique ČSFD identifier for this season
     pub csfd_id: u32,
     /// Display name of the season
     pub name: String,
+    /// Season number, if ČSFD numbers the season ("Série 3")
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub number: Option<u8>,
+    /// Short label of the season: its number ("3"), the year of a reality
+    /// show season ("Řada 2023"), or the whole name if it has neither
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub label: String,
     /// Year of the season
     pub year: Option<String>,
     /// Number of episodes in this season
     pub episode_count: u32,
     /// Relative URL on ČSFD
     pub url: String,
+    /// Absolute URL of the season thumbnail, if ČSFD shows one
+    pub poster_url: Option<String>,
+    /// Average rating as percentage (0.0 - 100.0), if shown next to the season
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rating: Option<f32>,
 }
 
-
 /// Episode information
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Serializes with an `episode_code` string field (e.g., "S01E01") derived
+/// from the season and episode numbers, for frontend compatibility.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
 pub struct Episode {
     /// Unique ČSFD identifier for this episode
     pub csfd_id: u32,
     /// Display name of the episode
     pub name: String,
-    /// Episode code in format SxxExx (e.g., S01E01)
-    pub episode_code: String,
     /// Season number (1-based)
     pub season_number: u8,
     /// Episode number within the season (1-based)
-    pub episode_number: u8,
+    pub episode_number: u16,
     /// Rating as percentage (0.0 - 100.0), None if not rated
     pub rating: Option<f32>,
     /// Relative URL on ČSFD
     pub url: String,
+    /// Number of user comments, if ČSFD shows it
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub comment_count: Option<u32>,
+    /// Number of user ratings, if ČSFD shows it
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rating_count: Option<u32>,
+    /// Episode number counted across all seasons (1-based), if assigned
+    /// by `Episode::assign_absolute_numbers`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub absolute_number: Option<u32>,
+    /// Index of the episode in ČSFD's listing (0-based), which keeps
+    /// broadcast order when it differs from the production order of the codes
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub list_position: u32,
+}
+
+impl Episode {
+    /// Episode code of this episode, displayed as SxxExx (e.g., S01E01)
+    pub fn code(&self) -> EpisodeCode {
+        EpisodeCode {
+            season: self.season_number,
+            episode: self.episode_number,
+        }
+    }
+
+    /// Color tier ČSFD uses to display this episode's rating
+    pub fn rating_tier(&self) -> RatingTier {
+        RatingTier::from_percentage(self.rating)
+    }
+
+    /// Stable hash of the episode's content for cheap change detection.
+    ///
+    /// Covers the parsed fields with text trimmed and the rating rounded to
+    /// one decimal place; `absolute_number`, which is assigned locally, is
+    /// left out. Serialized as `content_hash` so frontends can skip
+    /// re-rendering unchanged episodes.
+    pub fn content_hash(&self) -> ContentHash {
+        ContentHasher::new()
+            .u32(self.csfd_id)
+            .str(&self.name)
+            .u32(u32::from(self.season_number))
+            .u32(u32::from(self.episode_number))
+            .opt_percent(self.rating)
+            .str(&self.url)
+            .opt_u32(self.comment_count)
+            .opt_u32(self.rating_count)
+            .u32(self.list_position)
+            .finish()
+    }
+
+    /// Number episodes across seasons, as anime libraries do (e.g., One Piece 1000).
+    ///
+    /// Episodes are counted in season and episode order; the order of the
+    /// slice is left as is. Episodes sharing a code are counted once each,
+    /// in slice order.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::Episode;
+    ///
+    /// # fn example(mut episodes: Vec<Episode>) {
+    /// Episode::assign_absolute_numbers(&mut episodes);
+    /// for episode in &episodes {
+    ///     println!("{} ({})", episode.absolute_number.unwrap(), episode.code());
+    /// }
+    /// # }
+    /// ```
+    pub fn assign_absolute_numbers(episodes: &mut [Episode]) {
+        let mut order: Vec<usize> = (0..episodes.len()).collect();
+        order.sort_by_key(|&i| episodes[i].code());
+        for (number, index) in (1..).zip(order) {
+            episodes[index].absolute_number = Some(number);
+        }
+    }
+}
+
+/// Episodes of a series grouped by season, for views rendering one section
+/// per season
+///
+/// Serializes each season as a `[season, episodes]` pair.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EpisodesGrouped {
+    /// Seasons in ČSFD's order, each with its episodes in listing order
+    pub seasons: Vec<(Season, Vec<Episode>)>,
+}
+
+impl EpisodesGrouped {
+    /// Total number of episodes across seasons
+    pub fn episode_count(&self) -> usize {
+        self.seasons
+            .iter()
+            .map(|(_, episodes)| episodes.len())
+            .sum()
+    }
+
+    /// Flatten into one list in season order
+    pub fn into_episodes(self) -> Vec<Episode> {
+        self.seasons
+            .into_iter()
+            .flat_map(|(_, episodes)| episodes)
+            .collect()
+    }
+}
+
+/// A series' detail with the episodes of all its seasons
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FullSeries {
+    /// Detail of the series
+    pub detail: SeriesDetail,
+    /// Episodes grouped by season
+    pub episodes: EpisodesGrouped,
+}
+
+/// Progress of an operation fetching several pages
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Progress {
+    /// Steps finished so far
+    pub completed: usize,
+    /// Steps known so far; may grow as the operation discovers more work
+    pub total: usize,
+    /// What the operation works on now (e.g., a season name)
+    pub current_label: String,
+}
+
+impl Progress {
+    /// Check whether every known step is finished
+    pub fn is_done(&self) -> bool {
+        self.completed >= self.total
+    }
+}
+
+/// Order of ČSFD's episode listing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum EpisodeSort {
+    /// Broadcast order, as ČSFD lists episodes by default
+    #[default]
+    Aired,
+    /// Best rated first, unrated episodes last
+    Rating,
+}
+
+impl EpisodeSort {
+    /// Query string of the episodes tab for this order, `None` for the default
+    pub fn query(self) -> Option<&'static str> {
+        match self {
+            Self::Aired => None,
+            Self::Rating => Some("sort=rating"),
+        }
+    }
+
+    /// Check whether episodes are listed in this order
+    ///
+    /// Broadcast order can't be told from the episodes themselves, so any
+    /// list counts as aired.
+    ///
+    /// # Example
+    /// ```
+    /// use csfd_core::{Episode, EpisodeSort};
+    ///
+    /// # fn example(episodes: Vec<Episode>) {
+    /// if !EpisodeSort::Rating.is_sorted(&episodes) {
+    ///     println!("ČSFD ignored the sort order");
+    /// }
+    /// # }
+    /// ```
+    pub fn is_sorted(self, episodes: &[Episode]) -> bool {
+        match self {
+            Self::Aired => true,
+            Self::Rating => episodes
+                .windows(2)
+                .all(|pair| pair[0].rating >= pair[1].rating),
+        }
+    }
+
+    /// Sort episodes into this order
+    ///
+    /// Aired order follows `Episode::list_position`. The sort is stable, so
+    /// equally rated episodes keep their listing order.
+    pub fn sort(self, episodes: &mut [Episode]) {
+        match self {
+            Self::Aired => episodes.sort_by_key(|episode| episode.list_position),
+            Self::Rating => episodes.sort_by(|a, b| {
+                b.rating
+                    .partial_cmp(&a.rating)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }),
+        }
+    }
+}
+
+/// A user's rating in ČSFD stars (0-5, where 0 is "odpad")
+///
+/// Aggregate ratings are percentages; this type keeps individual user
+/// ratings apart from them, both those parsed from reviews and those
+/// submitted with `CsfdScraper::rate_title`. Serializes as the number of stars.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "u8", into = "u8"))]
+pub struct UserStarRating(u8);
+
+impl UserStarRating {
+    /// Highest number of stars
+    pub const MAX_STARS: u8 = 5;
+
+    /// Create a rating from a number of stars, `None` if above 5
+    pub fn new(stars: u8) -> Option<Self> {
+        (stars <= Self::MAX_STARS).then_some(Self(stars))
+    }
+
+    /// Number of stars (0-5)
+    pub fn stars(self) -> u8 {
+        self.0
+    }
+
+    /// Rating as percentage (0.0 - 100.0), 20 % per star
+    pub fn to_percent(self) -> f32 {
+        f32::from(self.0) * 20.0
+    }
+
+    /// Nearest star rating for a percentage (0.0 - 100.0, clamped)
+    pub fn from_percent(percent: f32) -> Self {
+        Self((percent.clamp(0.0, 100.0) / 20.0).round() as u8)
+    }
+
+    /// Nearest star rating for a 1-10 rating (as used by IMDb or Trakt, clamped)
+    pub fn from_ten_point(rating: u8) -> Self {
+        Self((f32::from(rating.min(10)) / 2.0).round() as u8)
+    }
+}
+
+impl TryFrom<u8> for UserStarRating {
+    type Error = String;
+
+    fn try_from(stars: u8) -> std::result::Result<Self, Self::Error> {
+        Self::new(stars).ok_or_else(|| format!("Invalid star rating: {} (expected 0-5)", stars))
+    }
+}
+
+impl From<UserStarRating> for u8 {
+    fn from(rating: UserStarRating) -> Self {
+        rating.0
+    }
+}
+
+impl fmt::Display for UserStarRating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 == 0 {
+            write!(f, "odpad!")
+        } else {
+            write!(f, "{}", "*".repeat(usize::from(self.0)))
+        }
+    }
+}
+
+/// Color tier ČSFD uses to display a rating
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RatingTier {
+    /// 70 % and more
+    Red,
+    /// 30 % to 69 %
+    Blue,
+    /// Below 30 %
+    Black,
+    /// Not rated yet or too few ratings
+    Grey,
+}
+
+impl RatingTier {
+    /// Lowest percentage shown in red
+    pub const RED_THRESHOLD: f32 = 70.0;
+    /// Lowest percentage shown in blue
+    pub const BLUE_THRESHOLD: f32 = 30.0;
+
+    /// Tier of a rating percentage; `None` (unrated) is grey
+    pub fn from_percentage(rating: Option<f32>) -> Self {
+        match rating {
+            Some(rating) if rating >= Self::RED_THRESHOLD => RatingTier::Red,
+            Some(rating) if rating >= Self::BLUE_THRESHOLD => RatingTier::Blue,
+            Some(_) => RatingTier::Black,
+            None => RatingTier::Grey,
+        }
+    }
+
+    /// Tier from the `class` attribute of a ČSFD rating element
+    /// (e.g., "film-rating-average red" or "icon rating-blue").
+    pub fn from_css_class(classes: &str) -> Option<Self> {
+        classes.split_whitespace().find_map(|class| {
+            let color = class.rsplit('-').next().unwrap_or(class);
+            match color {
+                "red" => Some(RatingTier::Red),
+                "blue" => Some(RatingTier::Blue),
+                "black" => Some(RatingTier::Black),
+                "grey" | "gray" | "lightgrey" => Some(RatingTier::Grey),
+                _ => None,
+            }
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Episode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Episode", 12)?;
+        state.serialize_field("csfd_id", &self.csfd_id)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("episode_code", &self.code())?;
+        state.serialize_field("season_number", &self.season_number)?;
+        state.serialize_field("episode_number", &self.episode_number)?;
+        state.serialize_field("rating", &self.rating)?;
+        state.serialize_field("url", &self.url)?;
+        state.serialize_field("comment_count", &self.comment_count)?;
+        state.serialize_field("rating_count", &self.rating_count)?;
+        state.serialize_field("absolute_number", &self.absolute_number)?;
+        state.serialize_field("list_position", &self.list_position)?;
+        state.serialize_field("content_hash", &self.content_hash())?;
+        state.end()
+    }
+}
+
+/// Stable 64-bit content hash of a parsed entity
+///
+/// Displays and serializes as 16 hex digits, since JavaScript numbers
+/// can't hold 64-bit integers exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContentHash(pub u64);
+
+impl fmt::Display for ContentHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ContentHash {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ContentHash {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        u64::from_str_radix(&hex, 16)
+            .map(Self)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Interned text of a genre, country or role
+///
+/// These values repeat across titles, so parsed and deserialized labels
+/// share one allocation per distinct string. Derefs to `str` and compares
+/// with string slices; serializes as a plain string.
+///
+/// # Example
+/// ```
+/// use csfd_core::Label;
+///
+/// let genre = Label::new("Drama");
+/// assert_eq!(genre, "Drama");
+/// assert_eq!(genre.to_lowercase(), "drama");
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Label(Arc<str>);
+
+impl Label {
+    /// Intern `value`, reusing the allocation of an equal label
+    pub fn new(value: &str) -> Self {
+        Self(crate::intern::intern(value))
+    }
+
+    /// The label as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for Label {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Label {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for Label {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for Label {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<String> for Label {
+    fn from(value: String) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl PartialEq<str> for Label {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Label {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for Label {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Self::new(&value))
+    }
+}
+
+/// Season and episode number pair, displayed as SxxExx (e.g., S01E05)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EpisodeCode {
+    /// Season number (1-based)
+    pub season: u8,
+    /// Episode number within the season (1-based)
+    pub episode: u16,
+}
+
+impl fmt::Display for EpisodeCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "S{:02}E{:02}", self.season, self.episode)
+    }
+}
+
+impl FromStr for EpisodeCode {
+    type Err = String;
+
+    /// Parse a code in format SxxExx (case-insensitive)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || format!("Invalid episode code: {}", s);
+        let rest = s.trim().strip_prefix(['S', 's']).ok_or_else(invalid)?;
+        let (season, episode) = rest.split_once(['E', 'e']).ok_or_else(invalid)?;
+        Ok(Self {
+            season: season.parse().map_err(|_| invalid())?,
+            episode: episode.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for EpisodeCode {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for EpisodeCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Optional genre and year filters for picking series
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SeriesFilters {
+    /// Genre the series must have (e.g., "Krimi"), ignoring case and diacritics
+    pub genre: Option<String>,
+    /// Earliest year the series must have run in
+    pub year_from: Option<u16>,
+    /// Latest year the series must have run in
+    pub year_to: Option<u16>,
+}
+
+impl SeriesFilters {
+    /// Check whether a year or year range (e.g., "2008-2013") overlaps the
+    /// requested years. Unknown years pass only when no years are requested.
+    pub fn matches_year(&self, year: Option<&str>) -> bool {
+        if self.year_from.is_none() && self.year_to.is_none() {
+            return true;
+        }
+        let Some((start, end)) = year.and_then(crate::text::year_span) else {
+            return false;
+        };
+        self.year_to.is_none_or(|to| start <= to)
+            && self
+                .year_from
+                .is_none_or(|from| end.is_none_or(|end| end >= from))
+    }
+
+    /// Check whether the requested genre is among the given genres.
+    pub fn matches_genres(&self, genres: &[Label]) -> bool {
+        let Some(genre) = &self.genre else {
+            return true;
+        };
+        let genre = crate::text::normalize(genre);
+        genres.iter().any(|g| crate::text::normalize(g) == genre)
+    }
+}
+
+/// Kind of page to discover from the sitemap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DiscoveryKind {
+    /// Films and series (`/film/` pages)
+    Titles,
+    /// People (`/tvurce/` pages)
+    Creators,
+}
+
+impl DiscoveryKind {
+    /// Path segment of pages of this kind
+    pub fn path_segment(self) -> &'static str {
+        match self {
+            DiscoveryKind::Titles => "film",
+            DiscoveryKind::Creators => "tvurce",
+        }
+    }
+}
+
+/// ID discovered from the sitemap, with the page it came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DiscoveredId {
+    /// ČSFD identifier of the title or creator
+    pub csfd_id: u32,
+    /// Absolute URL of the page on ČSFD
+    pub url: String,
+}
+
+/// Where a title premieres
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PremiereMedium {
+    /// Cinema release (kino)
+    Cinema,
+    /// TV broadcast (televize)
+    Tv,
+    /// Streaming or video on demand (VOD)
+    Vod,
+}
+
+/// Row of the homepage's most-visited ("nejnavštěvovanější") widgets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum TrendingKind {
+    /// Most-visited films
+    Movies,
+    /// Most-visited TV series
+    Series,
+}
+
+/// News article teaser from the homepage
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NewsItem {
+    /// Headline
+    pub title: String,
+    /// Relative URL of the article on ČSFD
+    pub url: String,
+    /// Publication date (YYYY-MM-DD)
+    pub date: Option<String>,
+    /// Short summary below the headline
+    pub perex: Option<String>,
+}
+
+/// Creator celebrating a birthday, from the homepage
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Birthday {
+    /// Name of the creator
+    pub name: String,
+    /// ČSFD creator ID
+    pub creator_id: u32,
+    /// Age reached today, if shown
+    pub age: Option<u16>,
+}
+
+/// Sections of the ČSFD homepage, for dashboard-style start screens
+///
+/// Sections the page doesn't have are empty.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HomepageDigest {
+    /// Latest news articles
+    pub news: Vec<NewsItem>,
+    /// Most-visited films
+    pub trending_movies: Vec<SearchResult>,
+    /// Most-visited TV series
+    pub trending_series: Vec<SearchResult>,
+    /// Titles premiering now, in cinemas or on TV
+    pub premieres: Vec<SearchResult>,
+    /// Creators born on this day
+    pub birthdays: Vec<Birthday>,
+}
+
+/// Inclusive range of ISO dates (YYYY-MM-DD)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DateRange {
+    /// First day of the range
+    pub from: String,
+    /// Last day of the range
+    pub to: String,
+}
+
+impl DateRange {
+    /// Create a range from two ISO dates (YYYY-MM-DD).
+    ///
+    /// # Errors
+    /// Returns `CsfdError::ParseError` if a date is malformed or `from` is after `to`
+    pub fn new(from: &str, to: &str) -> crate::error::Result<Self> {
+        let is_iso_date = |date: &str| {
+            let bytes = date.as_bytes();
+            bytes.len() == 10
+                && bytes[4] == b'-'
+                && bytes[7] == b'-'
+                && bytes
+                    .iter()
+                    .enumerate()
+                    .all(|(i, b)| i == 4 || i == 7 || b.is_ascii_digit())
+        };
+        if !is_iso_date(from) || !is_iso_date(to) {
+            return Err(crate::error::CsfdError::ParseError(format!(
+                "Invalid date range {}..{}, expected YYYY-MM-DD",
+                from, to
+            )));
+        }
+        if from > to {
+            return Err(crate::error::CsfdError::ParseError(format!(
+                "Date range starts after it ends: {}..{}",
+                from, to
+            )));
+        }
+
+        Ok(Self {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+
+    /// Check whether an ISO date lies within the range
+    pub fn contains(&self, date: &str) -> bool {
+        self.from.as_str() <= date && date <= self.to.as_str()
+    }
+}
+
+/// ČSFD page a link points to
+///
+/// Parsed from links pasted or dropped by the user, or passed in by the OS
+/// as a deep link. Sub-pages such as `/prehled/` or `/epizody/` resolve to
+/// the entity they belong to. Serializes with a `kind` tag (e.g.,
+/// `{"kind": "season", "series_id": 1, "season_id": 2}`).
+///
+/// # Example
+/// ```
+/// use csfd_core::CsfdLink;
+///
+/// let link = CsfdLink::parse("https://www.csfd.cz/film/264494-stranger-things/prehled/").unwrap();
+/// assert_eq!(link, CsfdLink::Title { csfd_id: 264494 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "kind", rename_all = "snake_case"))]
+pub enum CsfdLink {
+    /// A series or film
+    Title {
+        /// ČSFD ID of the title
+        csfd_id: u32,
+    },
+    /// A season, or an episode of a series without seasons
+    Season {
+        /// ČSFD ID of the series
+        series_id: u32,
+        /// ČSFD ID of the season (or episode)
+        season_id: u32,
+    },
+    /// An episode within a season
+    Episode {
+        /// ČSFD ID of the series
+        series_id: u32,
+        /// ČSFD ID of the season
+        season_id: u32,
+        /// ČSFD ID of the episode
+        episode_id: u32,
+    },
+    /// A creator (actor, director, ...)
+    Creator {
+        /// ČSFD creator ID
+        creator_id: u32,
+    },
+}
+
+impl CsfdLink {
+    /// Parse a ČSFD link.
+    ///
+    /// Accepts absolute URLs on csfd.cz or csfd.sk (with or without `www.`),
+    /// `csfd://` deep links and site-relative paths. Query strings and
+    /// fragments are ignored.
+    ///
+    /// # Errors
+    /// Returns `CsfdError::InvalidUrl` if the link is on another site or
+    /// doesn't point to a title or creator page
+    pub fn parse(url: &str) -> crate::error::Result<Self> {
+        let invalid = || crate::error::CsfdError::InvalidUrl(url.to_string());
+
+        let trimmed = url.trim();
+        let path = if let Some((scheme, rest)) = trimmed.split_once("://") {
+            let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+            let host = host.to_lowercase();
+            let host = host.strip_prefix("www.").unwrap_or(&host);
+            match scheme.to_lowercase().as_str() {
+                "http" | "https" if host == "csfd.cz" || host == "csfd.sk" => path,
+                // csfd://film/123-slug/ has the first segment in the host position
+                "csfd" => rest,
+                _ => return Err(invalid()),
+            }
+        } else {
+            trimmed
+        };
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+
+        let mut segments = path.split('/').filter(|s| !s.is_empty());
+        let kind = segments.next().ok_or_else(invalid)?;
+        // IDs are the numeric prefixes of the leading "{id}-{slug}" segments
+        let ids: Vec<u32> = segments
+            .map_while(|segment| segment.split('-').next()?.parse().ok())
+            .filter(|&id| id > 0)
+            .collect();
+
+        match (kind, ids.as_slice()) {
+            ("film", [csfd_id]) => Ok(Self::Title { csfd_id: *csfd_id }),
+            ("film", [series_id, season_id]) => Ok(Self::Season {
+                series_id: *series_id,
+                season_id: *season_id,
+            }),
+            ("film", [series_id, season_id, episode_id, ..]) => Ok(Self::Episode {
+                series_id: *series_id,
+                season_id: *season_id,
+                episode_id: *episode_id,
+            }),
+            ("tvurce", [creator_id, ..]) => Ok(Self::Creator {
+                creator_id: *creator_id,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Get the ČSFD ID of the series or film the link belongs to, if any
+    pub fn title_id(&self) -> Option<u32> {
+        match self {
+            Self::Title { csfd_id } => Some(*csfd_id),
+            Self::Season { series_id, .. } | Self::Episode { series_id, .. } => Some(*series_id),
+            Self::Creator { .. } => None,
+        }
+    }
+}
+
+/// Titles premiering on one day in one medium
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PremiereDay {
+    /// Premiere date (YYYY-MM-DD)
+    pub date: String,
+    /// Where the titles premiere
+    pub medium: PremiereMedium,
+    /// Premiering titles in ČSFD's order
+    pub titles: Vec<SearchResult>,
+}
+
+/// Custom list of a user (seznam uživatele)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UserList {
+    /// ČSFD identifier of the list
+    pub list_id: u32,
+    /// Name of the list (e.g., "Nejlepší české seriály")
+    pub name: String,
+    /// Number of titles in the list, if shown
+    pub item_count: Option<u32>,
+    /// Relative URL on ČSFD
+    pub url: String,
+}
+
+/// Title in a user's custom list
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UserListEntry {
+    /// Position in the list (1-based)
+    pub position: u32,
+    /// The listed title
+    pub title: SearchResult,
+    /// Note the list author added to the title
+    pub note: Option<String>,
+}
+
+/// User review from a title's reviews tab (`/recenze/`)
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Review {
+    /// Username of the reviewer
+    pub author: String,
+    /// Stars the reviewer gave; 0 stars is ČSFD's "odpad" (trash)
+    pub rating: Option<UserStarRating>,
+    /// Date of the review (YYYY-MM-DD)
+    pub date: Option<String>,
+    /// Review text
+    pub text: String,
+}
+
+/// A title rated by the logged-in user, from their ratings tab (`/hodnoceni/`)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UserRating {
+    /// ČSFD ID of the rated title
+    pub csfd_id: u32,
+    /// Display name of the title
+    pub name: String,
+    /// Release year of the title
+    pub year: Option<u16>,
+    /// Relative URL on ČSFD
+    pub url: String,
+    /// Stars the user gave; 0 stars is ČSFD's "odpad" (trash)
+    pub rating: UserStarRating,
+    /// Date of the rating (YYYY-MM-DD)
+    pub date: Option<String>,
 }
 
 /// Paginated result wrapper for search results
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PaginatedResult<T> {
     /// Items on the current page
     pub items: Vec<T>,
@@ -96,6 +1488,9 @@ pub struct PaginatedResult<T> {
     pub current_page: u32,
     /// Whether there are more pages available
     pub has_next_page: bool,
+    /// Whether ČSFD explicitly reported that nothing matched
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub no_results: bool,
 }
 
 impl<T> PaginatedResult<T> {
@@ -105,15 +1500,17 @@ impl<T> PaginatedResult<T> {
             items,
             current_page,
             has_next_page,
+            no_results: false,
         }
     }
 
-    /// Create an empty result for the first page
+    /// Create an empty result for the first page, with nothing matching
     pub fn empty() -> Self {
         Self {
             items: Vec::new(),
             current_page: 1,
             has_next_page: false,
+            no_results: true,
         }
     }
 }
@@ -122,6 +1519,7 @@ impl<T> PaginatedResult<T> {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_series_type_serialization() {
         let series = SeriesType::Series;
@@ -133,6 +1531,7 @@ mod tests {
         assert_eq!(json, "\"MiniSeries\"");
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_search_result_serialization() {
         let result = SearchResult {
@@ -142,6 +1541,8 @@ mod tests {
             series_type: SeriesType::Series,
             url: "/film/123-test/".to_string(),
             csfd_id: 123,
+            local_rank: None,
+            score: None,
         };
 
         let json = serde_json::to_string(&result).unwrap();
@@ -151,27 +1552,454 @@ mod tests {
         assert_eq!(deserialized.csfd_id, 123);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_search_result_deserialize_without_local_rank() {
+        let json = r#"{"name":"Test","original_name":null,"year":null,"series_type":"Series","url":"/film/1-test/","csfd_id":1}"#;
+        let result: SearchResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.local_rank, None);
+        assert_eq!(result.score, None);
+    }
+
     #[test]
     fn test_episode_rating_range() {
         let episode = Episode {
             csfd_id: 1,
             name: "Pilot".to_string(),
-            episode_code: "S01E01".to_string(),
             season_number: 1,
             episode_number: 1,
             rating: Some(85.5),
             url: "/film/1-test/".to_string(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
         };
 
         assert!(episode.rating.unwrap() >= 0.0);
         assert!(episode.rating.unwrap() <= 100.0);
     }
 
+    #[test]
+    fn test_episode_code_display_and_parse() {
+        let code = EpisodeCode {
+            season: 2,
+            episode: 10,
+        };
+        assert_eq!(code.to_string(), "S02E10");
+        assert_eq!("s2e10".parse::<EpisodeCode>(), Ok(code));
+        assert!("2x10".parse::<EpisodeCode>().is_err());
+        assert!(
+            EpisodeCode {
+                season: 1,
+                episode: 9
+            } < code
+        );
+    }
+
+    #[test]
+    fn test_assign_absolute_numbers_long_seasons() {
+        // One Piece-style: a short first season, then seasons running past 100 episodes
+        let season_lengths: [(u8, u16); 3] = [(1, 61), (2, 16), (21, 1085)];
+        let mut episodes: Vec<Episode> = season_lengths
+            .iter()
+            .rev()
+            .flat_map(|&(season, count)| (1..=count).map(move |episode| (season, episode)))
+            .map(|(season_number, episode_number)| Episode {
+                csfd_id: u32::from(season_number) * 10_000 + u32::from(episode_number),
+                name: String::new(),
+                season_number,
+                episode_number,
+                rating: None,
+                url: String::new(),
+                comment_count: None,
+                rating_count: None,
+                absolute_number: None,
+                list_position: 0,
+            })
+            .collect();
+
+        Episode::assign_absolute_numbers(&mut episodes);
+
+        // Slice order is kept, numbers follow the codes
+        assert_eq!(
+            episodes[0].code(),
+            EpisodeCode {
+                season: 21,
+                episode: 1
+            }
+        );
+        assert_eq!(episodes[0].absolute_number, Some(78));
+        let last = episodes
+            .iter()
+            .find(|e| e.code().to_string() == "S21E1085")
+            .unwrap();
+        assert_eq!(last.absolute_number, Some(61 + 16 + 1085));
+        let first = episodes
+            .iter()
+            .find(|e| e.code().to_string() == "S01E01")
+            .unwrap();
+        assert_eq!(first.absolute_number, Some(1));
+    }
+
+    #[test]
+    fn test_episode_sort() {
+        let mut episodes: Vec<Episode> = [Some(80.0), None, Some(91.5), Some(80.0)]
+            .into_iter()
+            .zip(0..)
+            .map(|(rating, list_position)| Episode {
+                csfd_id: list_position + 1,
+                name: String::new(),
+                season_number: 1,
+                episode_number: list_position as u16 + 1,
+                rating,
+                url: String::new(),
+                comment_count: None,
+                rating_count: None,
+                absolute_number: None,
+                list_position,
+            })
+            .collect();
+        assert!(EpisodeSort::Aired.is_sorted(&episodes));
+        assert!(!EpisodeSort::Rating.is_sorted(&episodes));
+
+        EpisodeSort::Rating.sort(&mut episodes);
+        assert!(EpisodeSort::Rating.is_sorted(&episodes));
+        let ids: Vec<u32> = episodes.iter().map(|e| e.csfd_id).collect();
+        assert_eq!(ids, vec![3, 1, 4, 2]);
+
+        EpisodeSort::Aired.sort(&mut episodes);
+        assert_eq!(episodes[1].csfd_id, 2);
+        assert_eq!(EpisodeSort::Aired.query(), None);
+    }
+
+    #[test]
+    fn test_episode_content_hash() {
+        let episode = Episode {
+            csfd_id: 1,
+            name: "Pilot".to_string(),
+            season_number: 1,
+            episode_number: 1,
+            rating: Some(85.0),
+            url: "/film/1-test/".to_string(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        };
+        let mut renumbered = episode.clone();
+        renumbered.absolute_number = Some(1);
+        renumbered.name = " Pilot ".to_string();
+        assert_eq!(episode.content_hash(), renumbered.content_hash());
+
+        let mut rerated = episode.clone();
+        rerated.rating = Some(86.0);
+        assert_ne!(episode.content_hash(), rerated.content_hash());
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&episode).unwrap();
+            assert_eq!(json["content_hash"], episode.content_hash().to_string());
+            let hash: ContentHash = serde_json::from_value(json["content_hash"].clone()).unwrap();
+            assert_eq!(hash, episode.content_hash());
+        }
+    }
+
+    #[test]
+    fn test_episode_code_long_season() {
+        let code: EpisodeCode = "S21E1085".parse().unwrap();
+        assert_eq!(
+            code,
+            EpisodeCode {
+                season: 21,
+                episode: 1085
+            }
+        );
+        assert_eq!(code.to_string(), "S21E1085");
+        assert!(
+            EpisodeCode {
+                season: 21,
+                episode: 999
+            } < code
+        );
+    }
+
+    #[test]
+    fn test_csfd_link_parse() {
+        assert_eq!(
+            CsfdLink::parse("https://www.csfd.cz/film/264494-stranger-things/").unwrap(),
+            CsfdLink::Title { csfd_id: 264494 }
+        );
+        assert_eq!(
+            CsfdLink::parse("https://csfd.sk/film/264494-stranger-things/recenze/?page=2").unwrap(),
+            CsfdLink::Title { csfd_id: 264494 }
+        );
+        assert_eq!(
+            CsfdLink::parse("/film/264494-stranger-things/411457-serie-1/epizody/").unwrap(),
+            CsfdLink::Season {
+                series_id: 264494,
+                season_id: 411457
+            }
+        );
+        assert_eq!(
+            CsfdLink::parse("csfd://film/1/2-serie/3-pilot/").unwrap(),
+            CsfdLink::Episode {
+                series_id: 1,
+                season_id: 2,
+                episode_id: 3
+            }
+        );
+        assert_eq!(
+            CsfdLink::parse("https://www.csfd.cz/tvurce/3101-vince-gilligan/#biografie").unwrap(),
+            CsfdLink::Creator { creator_id: 3101 }
+        );
+        assert_eq!(CsfdLink::Creator { creator_id: 1 }.title_id(), None);
+        assert_eq!(
+            CsfdLink::Season {
+                series_id: 1,
+                season_id: 2
+            }
+            .title_id(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_csfd_link_parse_invalid() {
+        for url in [
+            "",
+            "https://www.imdb.com/title/tt4574334/",
+            "https://www.csfd.cz/zebricky/",
+            "/film/abc/",
+            "/film/0-nic/",
+        ] {
+            assert!(
+                matches!(
+                    CsfdLink::parse(url),
+                    Err(crate::error::CsfdError::InvalidUrl(_))
+                ),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_csfd_link_serde() {
+        let link = CsfdLink::Season {
+            series_id: 1,
+            season_id: 2,
+        };
+        let json = serde_json::to_value(link).unwrap();
+        assert_eq!(json["kind"], "season");
+        assert_eq!(json["season_id"], 2);
+    }
+
+    #[test]
+    fn test_user_star_rating_conversions() {
+        let four = UserStarRating::new(4).unwrap();
+        assert_eq!(four.stars(), 4);
+        assert_eq!(four.to_percent(), 80.0);
+        assert_eq!(UserStarRating::from_percent(80.0), four);
+        assert_eq!(UserStarRating::from_percent(89.0), four);
+        assert_eq!(UserStarRating::from_percent(150.0).stars(), 5);
+        assert_eq!(UserStarRating::from_ten_point(7), four);
+        assert_eq!(UserStarRating::from_ten_point(1).stars(), 1);
+        assert!(UserStarRating::new(6).is_none());
+        assert_eq!(four.to_string(), "****");
+        assert_eq!(UserStarRating::new(0).unwrap().to_string(), "odpad!");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_user_star_rating_serde() {
+        let rating: UserStarRating = serde_json::from_str("3").unwrap();
+        assert_eq!(serde_json::to_string(&rating).unwrap(), "3");
+        assert!(serde_json::from_str::<UserStarRating>("6").is_err());
+    }
+
+    #[test]
+    fn test_rating_tier_from_percentage() {
+        assert_eq!(RatingTier::from_percentage(Some(70.0)), RatingTier::Red);
+        assert_eq!(RatingTier::from_percentage(Some(69.9)), RatingTier::Blue);
+        assert_eq!(RatingTier::from_percentage(Some(30.0)), RatingTier::Blue);
+        assert_eq!(RatingTier::from_percentage(Some(29.0)), RatingTier::Black);
+        assert_eq!(RatingTier::from_percentage(None), RatingTier::Grey);
+    }
+
+    #[test]
+    fn test_rating_tier_from_css_class() {
+        assert_eq!(
+            RatingTier::from_css_class("film-rating-average red"),
+            Some(RatingTier::Red)
+        );
+        assert_eq!(
+            RatingTier::from_css_class("icon rating-blue"),
+            Some(RatingTier::Blue)
+        );
+        assert_eq!(
+            RatingTier::from_css_class("rating-average lightgrey"),
+            Some(RatingTier::Grey)
+        );
+        assert_eq!(RatingTier::from_css_class("rating-average"), None);
+    }
+
+    #[test]
+    fn test_episode_serializes_code() {
+        let episode = Episode {
+            csfd_id: 1,
+            name: "Pilot".to_string(),
+            season_number: 1,
+            episode_number: 5,
+            rating: None,
+            url: "/film/1-test/".to_string(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        };
+        assert_eq!(episode.code().to_string(), "S01E05");
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&episode).unwrap();
+            assert_eq!(json["episode_code"], "S01E05");
+
+            // Older payloads carrying the code still deserialize
+            let roundtrip: Episode = serde_json::from_value(json).unwrap();
+            assert_eq!(roundtrip.code(), episode.code());
+        }
+    }
+
+    #[test]
+    fn test_episodes_grouped() {
+        let season = Season {
+            csfd_id: 2,
+            name: "Série 1".to_string(),
+            number: Some(1),
+            label: "1".to_string(),
+            year: Some("2008".to_string()),
+            episode_count: 2,
+            url: "/film/1/2/".to_string(),
+            poster_url: None,
+            rating: None,
+        };
+        let episode = |csfd_id| Episode {
+            csfd_id,
+            name: String::new(),
+            season_number: 1,
+            episode_number: csfd_id as u16,
+            rating: None,
+            url: String::new(),
+            comment_count: None,
+            rating_count: None,
+            absolute_number: None,
+            list_position: 0,
+        };
+        let grouped = EpisodesGrouped {
+            seasons: vec![(season, vec![episode(1), episode(2)])],
+        };
+        assert_eq!(grouped.episode_count(), 2);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_value(&grouped).unwrap();
+            assert_eq!(json["seasons"][0][0]["name"], "Série 1");
+            assert_eq!(json["seasons"][0][1][1]["episode_code"], "S01E02");
+        }
+
+        let ids: Vec<u32> = grouped.into_episodes().iter().map(|e| e.csfd_id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_series_filters() {
+        let any = SeriesFilters::default();
+        assert!(any.matches_year(None));
+        assert!(any.matches_genres(&[]));
+
+        let filters = SeriesFilters {
+            genre: Some("krimi".to_string()),
+            year_from: Some(2010),
+            year_to: Some(2015),
+        };
+        assert!(filters.matches_year(Some("2008-2013")));
+        assert!(filters.matches_year(Some("2014–")));
+        assert!(!filters.matches_year(Some("2001-2009")));
+        assert!(!filters.matches_year(Some("2016")));
+        assert!(!filters.matches_year(None));
+        assert!(filters.matches_genres(&["Drama".into(), "Krimi".into()]));
+        assert!(!filters.matches_genres(&["Komedie".into()]));
+    }
+
+    #[test]
+    fn test_date_range() {
+        let range = DateRange::new("2024-03-01", "2024-03-07").unwrap();
+        assert!(range.contains("2024-03-01"));
+        assert!(range.contains("2024-03-07"));
+        assert!(!range.contains("2024-03-08"));
+
+        assert!(DateRange::new("2024-3-1", "2024-03-07").is_err());
+        assert!(DateRange::new("2024-03-08", "2024-03-07").is_err());
+    }
+
     #[test]
     fn test_paginated_result_empty() {
         let result: PaginatedResult<SearchResult> = PaginatedResult::empty();
         assert!(result.items.is_empty());
         assert_eq!(result.current_page, 1);
         assert!(!result.has_next_page);
+        assert!(result.no_results);
+    }
+
+    #[test]
+    fn test_series_is_ongoing() {
+        let this_year = crate::freshness::current_year();
+        let season = |year: String| Season {
+            csfd_id: 1,
+            name: "Série 1".to_string(),
+            number: Some(1),
+            label: "1".to_string(),
+            year: Some(year),
+            episode_count: 8,
+            url: String::new(),
+            poster_url: None,
+            rating: None,
+        };
+        let mut detail = SeriesDetail {
+            csfd_id: 1,
+            name: "Seriál".to_string(),
+            original_name: None,
+            year_range: Some("2008-2013".to_string()),
+            status_label: None,
+            genres: Vec::new(),
+            genre_ids: Vec::new(),
+            countries: Vec::new(),
+            seasons: vec![season("2013".to_string())],
+            directors: Vec::new(),
+            writers: Vec::new(),
+            cast: Vec::new(),
+            rating: None,
+            recommendations: Vec::new(),
+            availability: DetailAvailability::default(),
+            imdb_id: None,
+        };
+        assert!(!detail.is_ongoing());
+
+        detail.year_range = Some("2008–".to_string());
+        assert!(detail.is_ongoing());
+        detail.status_label = Some("Ukončený".to_string());
+        assert!(!detail.is_ongoing());
+
+        // A season aired last year outweighs a stale closed year range
+        detail.status_label = None;
+        detail.year_range = Some("2008-2013".to_string());
+        detail.seasons.push(season((this_year - 1).to_string()));
+        assert!(detail.is_ongoing());
+
+        detail.year_range = None;
+        detail.seasons.clear();
+        assert!(detail.is_ongoing());
     }
 }
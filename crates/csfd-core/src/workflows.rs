@@ -0,0 +1,359 @@
+//! High-level workflows composing scraper calls
+//!
+//! Integrators tend to reimplement the same orchestration: search, pick a
+//! series, load its detail and episodes, or refresh a list of followed
+//! series. This module provides those flows with the right ordering,
+//! concurrency and error handling.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{CsfdError, Result};
+use crate::scraper::CsfdScraper;
+use crate::session::{BatchReport, ScrapeSession};
+use crate::text::normalize;
+use crate::types::{CsfdLink, Episode, EpisodeSort, SearchResult, SeriesDetail, SeriesType};
+
+/// Everything needed to show a series page after a search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesBrowse {
+    /// The search hit that was picked
+    pub result: SearchResult,
+    /// Detail of the picked series
+    pub detail: SeriesDetail,
+    /// Episodes of the first season (or of the whole series if it has no seasons)
+    pub episodes: Vec<Episode>,
+}
+
+/// Search for a series and load its detail and first-season episodes.
+///
+/// Picks the best hit of the first search page (see `pick_series`), then
+/// fetches the detail page and the episode list of the first season.
+///
+/// # Arguments
+/// * `scraper` - Scraper to use
+/// * `query` - Search query string
+///
+/// # Returns
+/// * `Ok(SeriesBrowse)` with the picked result, its detail and episodes
+/// * `Err(CsfdError::NotFound)` if the search has no results
+/// * `Err(CsfdError::InvalidUrl)` if query is empty or whitespace-only
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::{workflows, CsfdScraper};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let browse = workflows::browse_series(&scraper, "Perníkový táta").await?;
+/// println!("{}: {} episodes", browse.detail.name, browse.episodes.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn browse_series(scraper: &CsfdScraper, query: &str) -> Result<SeriesBrowse> {
+    let results = scraper.search(query).await?;
+    let result = pick_series(&results.items, query)
+        .cloned()
+        .ok_or_else(|| CsfdError::NotFound(query.trim().to_string()))?;
+
+    let detail = scraper.get_series(result.csfd_id).await?;
+    let episodes = match detail.seasons.first() {
+        Some(season) => {
+            scraper
+                .get_season_episodes(result.csfd_id, season.csfd_id, EpisodeSort::Aired)
+                .await?
+        }
+        None => {
+            scraper
+                .get_episodes(result.csfd_id, EpisodeSort::Aired)
+                .await?
+        }
+    };
+
+    Ok(SeriesBrowse {
+        result,
+        detail,
+        episodes,
+    })
+}
+
+/// Refresh the detail of tracked series.
+///
+/// All series are fetched within the session, so its concurrency budget
+/// and cancellation apply. Unless `fail_fast` is set, one failing series
+/// doesn't stop the others.
+///
+/// # Arguments
+/// * `session` - Session to run the requests in
+/// * `series_ids` - ČSFD IDs of the tracked series
+/// * `fail_fast` - Stop after the first failing series
+///
+/// # Returns
+/// A `BatchReport` of refreshed, failed and skipped series IDs
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::{workflows, CsfdScraper};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let session = scraper.session();
+/// let report = workflows::refresh_tracked(&session, &[264494, 234260], false).await;
+/// for (id, error) in &report.failed {
+///     println!("{}: {}", id, error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn refresh_tracked(
+    session: &ScrapeSession<'_>,
+    series_ids: &[u32],
+    fail_fast: bool,
+) -> BatchReport<u32, SeriesDetail> {
+    let scraper = session.scraper();
+    session
+        .run_batch(series_ids.to_vec(), fail_fast, |id| scraper.get_series(id))
+        .await
+}
+
+/// A resolved ČSFD link with the detail needed to open it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolvedLink {
+    /// The page the link points to
+    pub link: CsfdLink,
+    /// Detail of the series or film the link belongs to (`None` for creators)
+    pub detail: Option<SeriesDetail>,
+}
+
+/// Resolve a pasted, dropped or deep link and prefetch its detail.
+///
+/// For title, season and episode links the detail of the series is fetched,
+/// so the app can open the right screen right away.
+///
+/// # Arguments
+/// * `scraper` - Scraper to use
+/// * `url` - Link to a ČSFD page (see `CsfdLink::parse`)
+///
+/// # Returns
+/// * `Ok(ResolvedLink)` with the parsed link and prefetched detail
+/// * `Err(CsfdError::InvalidUrl)` if the link isn't a ČSFD title or creator link
+///
+/// # Example
+/// ```no_run
+/// use csfd_core::{workflows, CsfdScraper};
+///
+/// # async fn example() -> Result<(), csfd_core::CsfdError> {
+/// let scraper = CsfdScraper::new()?;
+/// let resolved = workflows::resolve_link(&scraper, "https://www.csfd.cz/film/264494-stranger-things/").await?;
+/// println!("{:?}", resolved.link);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn resolve_link(scraper: &CsfdScraper, url: &str) -> Result<ResolvedLink> {
+    let link = CsfdLink::parse(url)?;
+    let detail = match link.title_id() {
+        Some(csfd_id) => Some(scraper.get_series(csfd_id).await?),
+        None => None,
+    };
+    Ok(ResolvedLink { link, detail })
+}
+
+/// Pick the most likely intended series from search results.
+///
+/// Prefers a whole series whose name or original name equals the query
+/// (ignoring case and diacritics), then any whole series, then the first hit.
+/// A query naming a part, like a file name ending in "part1" (see
+/// `detect_part`), is matched without it and prefers TV films, whose parts
+/// are listed in `MovieDetail::parts`.
+pub fn pick_series<'a>(items: &'a [SearchResult], query: &str) -> Option<&'a SearchResult> {
+    match detect_part(query) {
+        Some((title, _)) => pick_title(items, title, &[SeriesType::TvFilm, SeriesType::Series]),
+        None => pick_title(items, query, &[SeriesType::Series]),
+    }
+}
+
+/// Pick the most likely intended title of the given types from search results.
+///
+/// Tries the types in order of preference: a result of the type whose name
+/// or original name equals the query (ignoring case and diacritics), then
+/// any result of the type. Falls back to the first hit.
+pub fn pick_title<'a>(
+    items: &'a [SearchResult],
+    query: &str,
+    preferred: &[SeriesType],
+) -> Option<&'a SearchResult> {
+    let query = normalize(query);
+    let is_exact = |r: &&SearchResult| {
+        normalize(&r.name) == query
+            || r.original_name.as_deref().map(normalize).as_deref() == Some(query.as_str())
+    };
+
+    preferred
+        .iter()
+        .find_map(|series_type| {
+            let is_type = |r: &&SearchResult| r.series_type == *series_type;
+            items
+                .iter()
+                .filter(is_type)
+                .find(is_exact)
+                .or_else(|| items.iter().find(is_type))
+        })
+        .or_else(|| items.first())
+}
+
+/// Detect a part number in a title or file name ("Marie Terezie part2",
+/// "Marie.Terezie.pt.1", "Marie Terezie - 2. díl").
+///
+/// # Returns
+/// The title before the part marker, trimmed of separators, with the part
+/// number (1-based); `None` if no part is named.
+///
+/// # Example
+/// ```
+/// use csfd_core::workflows::detect_part;
+///
+/// assert_eq!(detect_part("Marie.Terezie.part2"), Some(("Marie.Terezie", 2)));
+/// assert_eq!(detect_part("Marie Terezie"), None);
+/// ```
+pub fn detect_part(name: &str) -> Option<(&str, u16)> {
+    static PART: std::sync::OnceLock<regex_lite::Regex> = std::sync::OnceLock::new();
+    let re = PART.get_or_init(|| {
+        regex_lite::Regex::new(
+            r"(?i)[\s._-]+(?:(?:part|pt|cd|díl|[čČ]ást)[\s._]*(\d{1,2})|(\d{1,2})\.\s*(?:díl|[čČ]ást))\b",
+        )
+        .expect("valid regex")
+    });
+    let caps = re.captures(name)?;
+    let number = caps.get(1).or_else(|| caps.get(2))?.as_str().parse().ok()?;
+    let title = name[..caps.get(0)?.start()].trim_end_matches([' ', '.', '_', '-']);
+    (number > 0 && !title.is_empty()).then_some((title, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, series_type: SeriesType, csfd_id: u32) -> SearchResult {
+        SearchResult {
+            name: name.to_string(),
+            original_name: None,
+            year: None,
+            series_type,
+            url: format!("/film/{}-test/", csfd_id),
+            csfd_id,
+            local_rank: None,
+            score: None,
+        }
+    }
+
+    #[test]
+    fn test_pick_series_prefers_exact_series() {
+        let items = vec![
+            result("Teorie velkého třesku", SeriesType::Season, 1),
+            result("Teorie velkého třesku: Zákulisí", SeriesType::Series, 2),
+            result("Teorie velkého třesku", SeriesType::Series, 3),
+        ];
+        assert_eq!(
+            pick_series(&items, "teorie velkeho tresku")
+                .unwrap()
+                .csfd_id,
+            3
+        );
+    }
+
+    #[test]
+    fn test_pick_series_prefers_tv_films_for_parts() {
+        let items = vec![
+            result("Marie Terezie", SeriesType::Series, 1),
+            result("Marie Terezie", SeriesType::TvFilm, 2),
+        ];
+        assert_eq!(pick_series(&items, "Marie Terezie").unwrap().csfd_id, 1);
+        assert_eq!(
+            pick_series(&items, "marie terezie part1").unwrap().csfd_id,
+            2
+        );
+        assert_eq!(
+            pick_series(&items[..1], "marie terezie part1")
+                .unwrap()
+                .csfd_id,
+            1
+        );
+    }
+
+    #[test]
+    fn test_detect_part() {
+        assert_eq!(
+            detect_part("Marie Terezie part1"),
+            Some(("Marie Terezie", 1))
+        );
+        assert_eq!(
+            detect_part("Marie.Terezie.pt.2.1080p"),
+            Some(("Marie.Terezie", 2))
+        );
+        assert_eq!(
+            detect_part("Marie Terezie - 2. díl"),
+            Some(("Marie Terezie", 2))
+        );
+        assert_eq!(detect_part("Bratři CD2"), Some(("Bratři", 2)));
+        assert_eq!(detect_part("Apartmán 2"), None);
+        assert_eq!(detect_part("part1"), None);
+    }
+
+    #[test]
+    fn test_pick_series_falls_back() {
+        let items = vec![
+            result("Série 1", SeriesType::Season, 1),
+            result("Dr. House", SeriesType::Series, 2),
+        ];
+        assert_eq!(pick_series(&items, "house").unwrap().csfd_id, 2);
+
+        let items = vec![result("Série 1", SeriesType::Season, 1)];
+        assert_eq!(pick_series(&items, "house").unwrap().csfd_id, 1);
+
+        assert!(pick_series(&[], "house").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_browse_series_empty_query() {
+        let scraper = CsfdScraper::new().unwrap();
+        let result = browse_series(&scraper, "  ").await;
+        assert!(matches!(result, Err(CsfdError::InvalidUrl(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_link_creator_skips_fetch() {
+        let scraper = CsfdScraper::new().unwrap();
+        let resolved = resolve_link(&scraper, "/tvurce/3101-vince-gilligan/")
+            .await
+            .unwrap();
+        assert_eq!(resolved.link, CsfdLink::Creator { creator_id: 3101 });
+        assert!(resolved.detail.is_none());
+
+        let result = resolve_link(&scraper, "https://example.com/film/1-x/").await;
+        assert!(matches!(result, Err(CsfdError::InvalidUrl(_))));
+    }
+
+    #[tokio::test]
+    async fn test_refresh_tracked_reports_failures() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session();
+        let report = refresh_tracked(&session, &[0, 0], false).await;
+
+        assert!(report.succeeded.is_empty());
+        assert_eq!(report.failed.len(), 2);
+        assert!(report
+            .failed
+            .iter()
+            .all(|(id, e)| *id == 0 && matches!(e, CsfdError::InvalidId(0))));
+        assert_eq!(session.metrics().failed, 2);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_tracked_fail_fast() {
+        let scraper = CsfdScraper::new().unwrap();
+        let session = scraper.session().with_concurrency(1);
+        let report = refresh_tracked(&session, &[0, 0, 0], true).await;
+
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.skipped, vec![0, 0]);
+    }
+}
@@ -0,0 +1,58 @@
+// Commands of all groups; permissions are generated for each, whether its
+// group's feature is enabled or not, so capabilities stay valid either way
+const COMMANDS: &[&str] = &[
+    "get_scraper_metrics",
+    "get_cache_stats",
+    "invalidate",
+    "invalidate_all",
+    "cancel_request",
+    "get_parser_capabilities",
+    "create_diagnostics_bundle",
+    "search_series",
+    "search_series_page",
+    "get_search_suggestions",
+    "search_local",
+    "record_search_choice",
+    "browse_series",
+    "get_random_series",
+    "get_series_detail",
+    "get_movie_detail",
+    "get_episode_detail",
+    "get_person_detail",
+    "get_reviews",
+    "share_series",
+    "get_episodes",
+    "get_season_episodes",
+    "get_all_episodes",
+    "get_episodes_grouped",
+    "get_full_series",
+    "get_episode_appearances",
+    "resolve_deeplink",
+    "get_premiere_calendar",
+    "get_homepage",
+    "get_trending",
+    "get_user_lists",
+    "get_list",
+    "get_image",
+    "get_image_queue_metrics",
+    "refresh_tracked",
+    "save_to_library",
+    "query_library",
+    "set_watch_options",
+    "get_watch_options",
+    "check_watched",
+    "create_list",
+    "add_to_list",
+    "get_watchlist",
+    "add_to_watchlist",
+    "remove_from_watchlist",
+    "get_my_ratings",
+    "take_planned_actions",
+    "login",
+    "save_credentials",
+    "clear_credentials",
+];
+
+fn main() {
+    tauri_plugin::Builder::new(COMMANDS).build();
+}
@@ -0,0 +1,63 @@
+//! Forwarding of scraper activity to the frontend
+//!
+//! Events are batched and emitted at most a few times per second as
+//! `csfd://activity`, so a busy batch job doesn't flood the webview with
+//! one IPC message per request.
+
+use std::time::Duration;
+
+use csfd_core::{ActivityEvent, ActivityLog};
+use tauri::{AppHandle, Emitter, Runtime};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Name of the event carrying batches of activity events
+pub const ACTIVITY_EVENT: &str = "csfd://activity";
+
+/// How often collected events are emitted
+const ACTIVITY_BATCH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Forward scraper activity to the frontend until the app exits.
+///
+/// The payload of each `csfd://activity` event is an array of activity
+/// events (tagged by `type`, e.g. `request_started`, `retry`,
+/// `rate_limit_wait`, `rate_adjusted`, `cache_hit`) collected since the
+/// previous emit.
+///
+/// # Example
+/// ```rust,ignore
+/// use csfd_tauri::ScraperState;
+/// use tauri::Manager;
+///
+/// tauri::Builder::default()
+///     .setup(|app| {
+///         let state = ScraperState::new()?;
+///         csfd_tauri::activity::forward_activity(app.handle(), state.activity());
+///         app.manage(state);
+///         Ok(())
+///     })
+/// ```
+pub fn forward_activity<R: Runtime>(app: &AppHandle<R>, log: &ActivityLog) {
+    let app = app.clone();
+    let mut receiver = log.subscribe();
+
+    tauri::async_runtime::spawn(async move {
+        let mut batch: Vec<ActivityEvent> = Vec::new();
+        let mut ticker = tokio::time::interval(ACTIVITY_BATCH_INTERVAL);
+
+        loop {
+            tokio::select! {
+                event = receiver.recv() => match event {
+                    Ok(event) => batch.push(event),
+                    // Missed events only thin out the activity panel
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                },
+                _ = ticker.tick() => {
+                    if !batch.is_empty() {
+                        let _ = app.emit(ACTIVITY_EVENT, std::mem::take(&mut batch));
+                    }
+                }
+            }
+        }
+    });
+}
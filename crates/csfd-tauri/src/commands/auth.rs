@@ -0,0 +1,229 @@
+//! Account commands (`auth` feature)
+//!
+//! Logging in with credentials kept in the OS keychain, and reading and
+//! writing the logged-in user's ratings, watchlist and lists.
+
+use tauri::State;
+
+use crate::credentials::{self, Credentials};
+use crate::error::CommandError;
+use crate::ScraperState;
+use csfd_core::{PaginatedResult, PlannedAction, UserListEntry, UserRating};
+
+/// Create a custom list on the logged-in user's ČSFD profile.
+///
+/// # Arguments
+/// * `name` - Name of the new list
+///
+/// # Returns
+/// * `Ok(Some(list_id))` with the ID of the created list
+/// * `Ok(None)` in dry-run mode
+/// * `Err(CommandError)` with error message if not logged in or the request fails
+#[tauri::command]
+pub async fn create_list(
+    state: State<'_, ScraperState>,
+    name: String,
+) -> Result<Option<u32>, CommandError> {
+    let scraper = state.scraper();
+    scraper
+        .create_list(&name)
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Add a title to one of the logged-in user's ČSFD lists.
+///
+/// # Arguments
+/// * `list_id` - ČSFD ID of the list
+/// * `csfd_id` - ČSFD ID of the title
+/// * `note` - Note shown next to the title
+///
+/// # Returns
+/// * `Ok(())` when added, or recorded in dry-run mode
+/// * `Err(CommandError)` with error message if not logged in or the request fails
+#[tauri::command]
+pub async fn add_to_list(
+    state: State<'_, ScraperState>,
+    list_id: u32,
+    csfd_id: u32,
+    note: Option<String>,
+) -> Result<(), CommandError> {
+    let scraper = state.scraper();
+    scraper
+        .add_to_list(list_id, csfd_id, note.as_deref())
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get one page of the logged-in user's "Chci vidět" (want to see) list.
+///
+/// # Arguments
+/// * `page` - Page number (default: 1)
+///
+/// # Returns
+/// * `Ok(PaginatedResult<UserListEntry>)` with titles and the user's notes
+/// * `Err(CommandError)` with error message if not logged in or the request fails
+#[tauri::command]
+pub async fn get_watchlist(
+    state: State<'_, ScraperState>,
+    page: Option<u32>,
+) -> Result<PaginatedResult<UserListEntry>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_watchlist(page.unwrap_or(1)))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Add a title to the logged-in user's "Chci vidět" list.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the title
+///
+/// # Returns
+/// * `Ok(())` when added, or recorded in dry-run mode
+/// * `Err(CommandError)` with error message if not logged in or the request fails
+#[tauri::command]
+pub async fn add_to_watchlist(
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<(), CommandError> {
+    let scraper = state.scraper();
+    scraper
+        .add_to_watchlist(csfd_id)
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Remove a title from the logged-in user's "Chci vidět" list.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the title
+///
+/// # Returns
+/// * `Ok(())` when removed, or recorded in dry-run mode
+/// * `Err(CommandError)` with error message if not logged in or the request fails
+#[tauri::command]
+pub async fn remove_from_watchlist(
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<(), CommandError> {
+    let scraper = state.scraper();
+    scraper
+        .remove_from_watchlist(csfd_id)
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get one page of the titles the logged-in user rated, newest first.
+///
+/// # Arguments
+/// * `page` - Page number (default: 1)
+///
+/// # Returns
+/// * `Ok(PaginatedResult<UserRating>)` with title, ČSFD ID, stars and date of each rating
+/// * `Err(CommandError)` with error message if not logged in or the request fails
+#[tauri::command]
+pub async fn get_my_ratings(
+    state: State<'_, ScraperState>,
+    page: Option<u32>,
+) -> Result<PaginatedResult<UserRating>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_my_ratings(page.unwrap_or(1)))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Take the write actions recorded in dry-run mode.
+///
+/// Lets the frontend preview what a bulk operation would do before running
+/// it for real. The recorded list is cleared.
+///
+/// # Returns
+/// * `Ok(Vec<PlannedAction>)` with actions in the order they were planned
+#[tauri::command]
+pub async fn take_planned_actions(
+    state: State<'_, ScraperState>,
+) -> Result<Vec<PlannedAction>, CommandError> {
+    let scraper = state.scraper();
+    Ok(scraper.client().take_planned_actions())
+}
+
+/// Log in to ČSFD and store the credentials in the OS keychain.
+///
+/// The credentials are only stored when ČSFD accepts them; afterwards the
+/// scraper logs in again by itself when the session expires.
+///
+/// # Arguments
+/// * `username` - ČSFD username or e-mail
+/// * `password` - ČSFD password
+///
+/// # Returns
+/// * `Ok(())` when logged in
+/// * `Err(CommandError)` with error message if the login was rejected or the keychain is unavailable
+#[tauri::command]
+pub async fn login(
+    state: State<'_, ScraperState>,
+    username: String,
+    password: String,
+) -> Result<(), CommandError> {
+    state
+        .scraper()
+        .login(&username, &password)
+        .await
+        .map_err(|e| state.command_error(&e))?;
+    let saved = Credentials {
+        username: Some(username),
+        password: Some(password),
+        session_cookie: None,
+    };
+    credentials::store(&saved)?;
+    state.set_credentials(Some(saved)).await;
+    Ok(())
+}
+
+/// Store ČSFD credentials in the OS keychain and use them right away.
+///
+/// Pass a username and password, a session cookie copied from a logged-in
+/// browser, or both. Nothing is written to the plaintext config.
+///
+/// # Arguments
+/// * `username` - ČSFD username or e-mail
+/// * `password` - ČSFD password
+/// * `session_cookie` - Session cookie in `name=value` form
+///
+/// # Returns
+/// * `Ok(())` when stored
+/// * `Err(CommandError)` with error message if nothing was given or the keychain is unavailable
+#[tauri::command]
+pub async fn save_credentials(
+    state: State<'_, ScraperState>,
+    username: Option<String>,
+    password: Option<String>,
+    session_cookie: Option<String>,
+) -> Result<(), CommandError> {
+    let saved = Credentials {
+        username,
+        password,
+        session_cookie,
+    };
+    if saved.is_empty() {
+        return Err("No credentials given".into());
+    }
+    credentials::store(&saved)?;
+    state.set_credentials(Some(saved)).await;
+    Ok(())
+}
+
+/// Remove ČSFD credentials from the OS keychain.
+///
+/// # Returns
+/// * `Ok(())` when removed or nothing was stored
+/// * `Err(CommandError)` with error message if the keychain is unavailable
+#[tauri::command]
+pub async fn clear_credentials(state: State<'_, ScraperState>) -> Result<(), CommandError> {
+    credentials::clear()?;
+    state.set_credentials(None).await;
+    Ok(())
+}
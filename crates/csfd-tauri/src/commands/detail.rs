@@ -0,0 +1,559 @@
+//! Detail commands (`detail` feature)
+//!
+//! Reading titles, episodes, people, reviews, public lists, the homepage
+//! and images from ČSFD, and diagnostics bundles of titles that render wrong.
+
+use std::sync::Arc;
+
+use tauri::ipc::{Channel, Response};
+use tauri::{AppHandle, Runtime, State};
+
+use crate::error::CommandError;
+use crate::images::ImageQueueMetrics;
+use crate::updates;
+use crate::ScraperState;
+use csfd_core::workflows::{self, ResolvedLink};
+use csfd_core::{
+    format_share_text_in, CsfdScraper, DateRange, Episode, EpisodeDetail, EpisodeSort,
+    EpisodesGrouped, FullSeries, HomepageDigest, MovieDetail, PaginatedResult, PersonDetail,
+    PremiereDay, Progress, Review, SearchResult, SeriesDetail, TrendingKind, UserList,
+    UserListEntry,
+};
+
+/// Get detailed information about a series.
+///
+/// A detail served from stale cache is refreshed in the background and
+/// sent again as `csfd://updated` if it changed.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the series
+///
+/// # Returns
+/// * `Ok(SeriesDetail)` with series information and seasons
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_series_detail<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<SeriesDetail, CommandError> {
+    let scraper = state.scraper();
+    let (detail, stale) = scraper
+        .client()
+        .track_stale(state.run_timed(scraper.get_series(csfd_id)))
+        .await;
+    let detail = detail.map_err(|e| state.command_error(&e))?;
+    let key = format!("series:{}", csfd_id);
+    updates::refresh_stale(
+        &app,
+        scraper,
+        "get_series_detail",
+        key,
+        &detail,
+        stale,
+        move |scraper| async move { scraper.get_series(csfd_id).await },
+    );
+    Ok(detail)
+}
+
+/// Get detailed information about a film.
+///
+/// A detail served from stale cache is refreshed in the background and
+/// sent again as `csfd://updated` if it changed.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the film
+///
+/// # Returns
+/// * `Ok(MovieDetail)` with film information
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_movie_detail<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<MovieDetail, CommandError> {
+    let scraper = state.scraper();
+    let (detail, stale) = scraper
+        .client()
+        .track_stale(state.run_timed(scraper.get_movie(csfd_id)))
+        .await;
+    let detail = detail.map_err(|e| state.command_error(&e))?;
+    let key = format!("movie:{}", csfd_id);
+    updates::refresh_stale(
+        &app,
+        scraper,
+        "get_movie_detail",
+        key,
+        &detail,
+        stale,
+        move |scraper| async move { scraper.get_movie(csfd_id).await },
+    );
+    Ok(detail)
+}
+
+/// Get detailed information about a single episode.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+/// * `episode_id` - ČSFD ID of the episode
+///
+/// # Returns
+/// * `Ok(EpisodeDetail)` with plot, air date, runtime, directors and cast
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_episode_detail(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+    episode_id: u32,
+) -> Result<EpisodeDetail, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_episode(series_id, episode_id))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get a person's profile and filmography.
+///
+/// A profile served from stale cache is refreshed in the background and
+/// sent again as `csfd://updated` if it changed.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD creator ID of the person
+///
+/// # Returns
+/// * `Ok(PersonDetail)` with biography, birth date, photo and filmography by role
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_person_detail<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<PersonDetail, CommandError> {
+    let scraper = state.scraper();
+    let (person, stale) = scraper
+        .client()
+        .track_stale(state.run_timed(scraper.get_person(csfd_id)))
+        .await;
+    let person = person.map_err(|e| state.command_error(&e))?;
+    let key = format!("person:{}", csfd_id);
+    updates::refresh_stale(
+        &app,
+        scraper,
+        "get_person_detail",
+        key,
+        &person,
+        stale,
+        move |scraper| async move { scraper.get_person(csfd_id).await },
+    );
+    Ok(person)
+}
+
+/// Get one page of user reviews of a title.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the series, film or episode
+/// * `page` - Page number (default: 1)
+///
+/// # Returns
+/// * `Ok(PaginatedResult<Review>)` with author, stars, date and text of each review
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_reviews(
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+    page: Option<u32>,
+) -> Result<PaginatedResult<Review>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_reviews(csfd_id, page.unwrap_or(1)))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get the share text of a series for the clipboard.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the series
+///
+/// # Returns
+/// * `Ok(String)` like "Breaking Bad (2008–2013) — 94 % na ČSFD: <url>"
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn share_series(
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<String, CommandError> {
+    let scraper = state.scraper();
+    let detail = state
+        .run_timed(scraper.get_series(csfd_id))
+        .await
+        .map_err(|e| state.command_error(&e))?;
+    Ok(format_share_text_in(&detail, state.locale()))
+}
+
+/// Get all episodes for a series.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the series
+/// * `absolute` - Fill `absolute_number` across seasons, e.g. for anime (default: false)
+/// * `sort` - Order of the listing (default: aired)
+///
+/// # Returns
+/// * `Ok(Vec<Episode>)` with all episodes
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_episodes<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+    absolute: Option<bool>,
+    sort: Option<EpisodeSort>,
+) -> Result<Vec<Episode>, CommandError> {
+    let scraper = state.scraper();
+    let (absolute, sort) = (absolute.unwrap_or(false), sort.unwrap_or_default());
+    let list = move |scraper: Arc<CsfdScraper>| async move {
+        scraper
+            .get_episodes(csfd_id, sort)
+            .await
+            .map(|mut episodes| {
+                if absolute {
+                    Episode::assign_absolute_numbers(&mut episodes);
+                }
+                episodes
+            })
+    };
+    let (episodes, stale) = scraper
+        .client()
+        .track_stale(state.run_timed(list(Arc::clone(scraper))))
+        .await;
+    let episodes = episodes.map_err(|e| state.command_error(&e))?;
+    let key = format!("episodes:{}", csfd_id);
+    updates::refresh_stale(&app, scraper, "get_episodes", key, &episodes, stale, list);
+    Ok(episodes)
+}
+
+/// Get episodes for a specific season.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+/// * `season_id` - ČSFD ID of the season
+/// * `sort` - Order of the listing (default: aired)
+///
+/// # Returns
+/// * `Ok(Vec<Episode>)` with episodes from the specified season
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_season_episodes(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+    season_id: u32,
+    sort: Option<EpisodeSort>,
+) -> Result<Vec<Episode>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_season_episodes(series_id, season_id, sort.unwrap_or_default()))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get all episodes of a series, fetching its seasons concurrently.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+/// * `request_id` - ID to abort the retrieval with `cancel_request`
+///
+/// # Returns
+/// * `Ok(Vec<Episode>)` with all episodes sorted by season and episode number
+/// * `Err(CommandError)` with error message if retrieval fails or is cancelled
+#[tauri::command]
+pub async fn get_all_episodes(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+    request_id: Option<String>,
+) -> Result<Vec<Episode>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_cancellable(request_id, scraper.get_all_episodes(series_id))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get all episodes of a series grouped by season.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+/// * `request_id` - ID to abort the retrieval with `cancel_request`
+///
+/// # Returns
+/// * `Ok(EpisodesGrouped)` with `[season, episodes]` pairs in ČSFD's order
+/// * `Err(CommandError)` with error message if retrieval fails or is cancelled
+#[tauri::command]
+pub async fn get_episodes_grouped(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+    request_id: Option<String>,
+) -> Result<EpisodesGrouped, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_cancellable(request_id, scraper.get_episodes_grouped(series_id))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get a series with the episodes of all its seasons, streaming progress.
+///
+/// Long series take one request per season; `on_progress` receives
+/// `{ completed, total, current_label }` before each of them, so the
+/// frontend can show a progress bar instead of waiting silently.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+/// * `on_progress` - Channel receiving `Progress` updates
+/// * `request_id` - ID to abort the retrieval with `cancel_request`
+///
+/// # Returns
+/// * `Ok(FullSeries)` with the detail and the episodes grouped by season
+/// * `Err(CommandError)` with error message if retrieval fails or is cancelled
+#[tauri::command]
+pub async fn get_full_series(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+    on_progress: Channel<Progress>,
+    request_id: Option<String>,
+) -> Result<FullSeries, CommandError> {
+    let scraper = state.scraper();
+    // A closed channel only means the view stopped listening
+    let full = scraper.get_full_series(series_id, |progress| {
+        let _ = on_progress.send(progress.clone());
+    });
+    state
+        .run_cancellable(request_id, full)
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get the episodes of a series in which a given actor appears.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+/// * `creator_id` - ČSFD ID of the actor
+/// * `request_id` - ID to abort the lookup with `cancel_request`
+///
+/// # Returns
+/// * `Ok(Vec<Episode>)` with episodes crediting the actor
+/// * `Err(CommandError)` with error message if retrieval fails or is cancelled
+#[tauri::command]
+pub async fn get_episode_appearances(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+    creator_id: u32,
+    request_id: Option<String>,
+) -> Result<Vec<Episode>, CommandError> {
+    let scraper = state.scraper();
+    let lookup = scraper.get_episode_appearances(series_id, creator_id);
+    state
+        .run_cancellable(request_id, lookup)
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Resolve a ČSFD link from the OS deep-link plugin, paste or drag-and-drop.
+///
+/// # Arguments
+/// * `url` - Link to a ČSFD title, season, episode or creator page
+///
+/// # Returns
+/// * `Ok(ResolvedLink)` with the entity kind, IDs and prefetched series detail
+/// * `Err(CommandError)` with error message if the link is invalid or retrieval fails
+#[tauri::command]
+pub async fn resolve_deeplink(
+    state: State<'_, ScraperState>,
+    url: String,
+) -> Result<ResolvedLink, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(workflows::resolve_link(scraper, &url))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get upcoming cinema, TV and VOD premieres grouped by day.
+///
+/// # Arguments
+/// * `from` - First day (YYYY-MM-DD)
+/// * `to` - Last day (YYYY-MM-DD)
+///
+/// # Returns
+/// * `Ok(Vec<PremiereDay>)` sorted by date, then medium
+/// * `Err(CommandError)` with error message if the range is invalid or retrieval fails
+#[tauri::command]
+pub async fn get_premiere_calendar(
+    state: State<'_, ScraperState>,
+    from: String,
+    to: String,
+) -> Result<Vec<PremiereDay>, CommandError> {
+    let range = DateRange::new(&from, &to).map_err(|e| state.command_error(&e))?;
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_premiere_calendar(&range))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get a digest of the ČSFD homepage for the start screen.
+///
+/// # Returns
+/// * `Ok(HomepageDigest)` with news, trending films and series, premieres and birthdays
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_homepage(state: State<'_, ScraperState>) -> Result<HomepageDigest, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_homepage())
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get the most-visited films or series from the ČSFD homepage.
+///
+/// Rows are cached for the client's `ongoing_series` lifetime, so calling
+/// this on every app start or watcher check is cheap.
+///
+/// # Arguments
+/// * `kind` - `"movies"` or `"series"`
+///
+/// # Returns
+/// * `Ok(Vec<SearchResult>)` in the order shown on the homepage
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_trending(
+    state: State<'_, ScraperState>,
+    kind: TrendingKind,
+) -> Result<Vec<SearchResult>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_trending(kind))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get the public custom lists of a user.
+///
+/// # Arguments
+/// * `user_id` - ČSFD ID of the user
+///
+/// # Returns
+/// * `Ok(Vec<UserList>)` in the order shown on the profile
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_user_lists(
+    state: State<'_, ScraperState>,
+    user_id: u32,
+) -> Result<Vec<UserList>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_user_lists(user_id))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Get one page of a custom list for importing it.
+///
+/// # Arguments
+/// * `list_id` - ČSFD ID of the list
+/// * `page` - Page number (default: 1)
+///
+/// # Returns
+/// * `Ok(PaginatedResult<UserListEntry>)` with titles, positions and notes
+/// * `Err(CommandError)` with error message if retrieval fails
+#[tauri::command]
+pub async fn get_list(
+    state: State<'_, ScraperState>,
+    list_id: u32,
+    page: Option<u32>,
+) -> Result<PaginatedResult<UserListEntry>, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_list(list_id, page.unwrap_or(1)))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Load a poster or photo from ČSFD's image servers.
+///
+/// Only a few images load at once (see `ScraperState::with_max_image_loads`);
+/// the rest wait in order, so scrolling through a long list of posters
+/// doesn't start hundreds of downloads. Recently loaded images come from
+/// memory.
+///
+/// # Arguments
+/// * `url` - Image URL as found in parsed data (e.g., `Season::poster_url`)
+///
+/// # Returns
+/// * `Ok(Response)` with the raw image data, an `ArrayBuffer` in the
+///   frontend, e.g. for `URL.createObjectURL(new Blob([data]))`
+/// * `Err(CommandError)` with error message if the URL isn't a ČSFD image or
+///   loading fails
+#[tauri::command]
+pub async fn get_image(
+    state: State<'_, ScraperState>,
+    url: String,
+) -> Result<Response, CommandError> {
+    let image = state
+        .images()
+        .load(state.scraper(), &url)
+        .await
+        .map_err(|e| state.command_error(&e))?;
+    Ok(Response::new(image.bytes.clone()))
+}
+
+/// Get how many images are loading and waiting in `get_image`.
+///
+/// A long queue while scrolling points to the list requesting posters
+/// that are no longer on screen.
+///
+/// # Returns
+/// * `Ok(ImageQueueMetrics)` with `loading`, `queued` and `max_loading`
+#[tauri::command]
+pub async fn get_image_queue_metrics(
+    state: State<'_, ScraperState>,
+) -> Result<ImageQueueMetrics, CommandError> {
+    Ok(state.images().metrics())
+}
+
+/// Create a diagnostics bundle for reporting a title that renders wrong.
+///
+/// Fetches the series page and writes a zip with the redacted page, the
+/// parse report, the parser capabilities and the client settings into the
+/// `diagnostics` folder of the app data dir, ready to attach to an issue.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the series
+///
+/// # Returns
+/// * `Ok(String)` with the path of the written zip file
+/// * `Err(CommandError)` if the app has no data dir or the page cannot be fetched
+#[tauri::command]
+pub async fn create_diagnostics_bundle(
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<String, CommandError> {
+    let dir = state
+        .data_dir()
+        .ok_or("No data directory for diagnostics bundles")?
+        .join("diagnostics");
+    let bundle = state
+        .scraper()
+        .create_diagnostics_bundle(csfd_id)
+        .await
+        .map_err(|e| state.command_error(&e))?;
+
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    let path = dir.join(bundle.file_name());
+    bundle
+        .write_zip(&path)
+        .map_err(|e| state.command_error(&e))?;
+    Ok(path.display().to_string())
+}
@@ -0,0 +1,130 @@
+//! Library commands (`library` feature)
+//!
+//! Saving series to the local library, SQL analytics over it and batch
+//! refreshes of tracked series.
+
+use tauri::State;
+
+use crate::error::CommandError;
+use crate::updates;
+use crate::ScraperState;
+use csfd_core::query::{self, QueryResult};
+use csfd_core::workflows;
+use csfd_core::{BatchReport, CsfdScraper, Episode, EpisodeSort, Lane, SeriesDetail};
+
+/// Refresh the detail of tracked series in one batch.
+///
+/// Failures are reported per series instead of failing the whole command.
+/// The refresh runs in the background lane, so it doesn't hold up searches
+/// and other interactive commands. Once the command timeout passes, series
+/// still loading fail with a timeout and the rest are skipped, so the
+/// report keeps the series refreshed so far.
+///
+/// # Arguments
+/// * `series_ids` - ČSFD IDs of the tracked series
+/// * `fail_fast` - Stop after the first failing series (default: false)
+///
+/// # Returns
+/// * `Ok(BatchReport)` with `succeeded`, `failed` (error messages) and `skipped` entries
+#[tauri::command]
+pub async fn refresh_tracked(
+    state: State<'_, ScraperState>,
+    series_ids: Vec<u32>,
+    fail_fast: Option<bool>,
+) -> Result<BatchReport<u32, SeriesDetail>, CommandError> {
+    let scraper = state.scraper();
+    let session = scraper
+        .session()
+        .with_lane(Lane::Background)
+        .with_timeout(state.command_timeout());
+    let fail_fast = fail_fast.unwrap_or(false);
+    let client = scraper.client();
+    let (mut report, stale) = client
+        .track_stale(workflows::refresh_tracked(&session, &series_ids, fail_fast))
+        .await;
+    // A refresh must not answer with pages past their TTL
+    if !stale.is_empty() && session.run(client.revalidate_pages(&stale)).await.is_ok() {
+        let fresh = workflows::refresh_tracked(&session, &series_ids, fail_fast).await;
+        // A rerun cut short by the timeout would drop results already fetched
+        if !session.is_timed_out() {
+            report = fresh;
+        }
+    }
+    Ok(report)
+}
+
+/// Save a series with the episodes of all its seasons to the local library.
+///
+/// Saving a series again replaces the stored snapshot.
+///
+/// # Arguments
+/// * `csfd_id` - ČSFD ID of the series
+///
+/// # Returns
+/// * `Ok(SeriesDetail)` of the saved series
+/// * `Err(CommandError)` with error message if retrieval or saving fails
+#[tauri::command]
+pub async fn save_to_library(
+    state: State<'_, ScraperState>,
+    csfd_id: u32,
+) -> Result<SeriesDetail, CommandError> {
+    let (detail, episodes) = state
+        .run_timed(fetch_snapshot(state.scraper(), csfd_id))
+        .await
+        .map_err(|e| state.command_error(&e))?;
+
+    let mut library = state.library().lock().await;
+    library
+        .save_series(detail.clone(), episodes)
+        .map_err(|e| state.command_error(&e))?;
+    Ok(detail)
+}
+
+/// Run a read-only SQL query over the saved library.
+///
+/// Only a single `SELECT` or `WITH` statement is accepted. Tables are
+/// `series`, `genres`, `countries`, `seasons` and `episodes`.
+///
+/// # Arguments
+/// * `sql` - The query, e.g. average rating per season per genre
+///
+/// # Returns
+/// * `Ok(QueryResult)` with column names and rows
+/// * `Err(CommandError)` with error message if the query is not allowed or fails
+#[tauri::command]
+pub async fn query_library(
+    state: State<'_, ScraperState>,
+    sql: String,
+) -> Result<QueryResult, CommandError> {
+    let library = state.library().lock().await;
+    query::query_library(&library, &sql).map_err(|e| state.command_error(&e))
+}
+
+/// Fetch a series with the episodes of all its seasons, none of them stale.
+pub(super) async fn fetch_snapshot(
+    scraper: &CsfdScraper,
+    csfd_id: u32,
+) -> csfd_core::Result<(SeriesDetail, Vec<Episode>)> {
+    updates::without_stale(scraper, || read_snapshot(scraper, csfd_id)).await
+}
+
+/// Read a series with the episodes of all its seasons, from cache or ČSFD.
+async fn read_snapshot(
+    scraper: &CsfdScraper,
+    csfd_id: u32,
+) -> csfd_core::Result<(SeriesDetail, Vec<Episode>)> {
+    let detail = scraper.get_series(csfd_id).await?;
+    let mut episodes = Vec::new();
+    if detail.seasons.is_empty() {
+        episodes = scraper.get_episodes(csfd_id, EpisodeSort::Aired).await?;
+    }
+    for season in &detail.seasons {
+        let sort = EpisodeSort::Aired;
+        episodes.extend(
+            scraper
+                .get_season_episodes(csfd_id, season.csfd_id, sort)
+                .await?,
+        );
+    }
+    Ok((detail, episodes))
+}
@@ -0,0 +1,140 @@
+//! Tauri commands for ČSFD Scraper
+//!
+//! This module contains all Tauri commands that can be invoked from the frontend.
+//! Commands are grouped by Cargo feature, all enabled by default, so an app
+//! that only searches doesn't compile the library, watcher or keychain:
+//! - `search` - Searching, suggestions and the seed catalog
+//! - `detail` - Titles, episodes, people, reviews, lists, images and
+//!   diagnostics bundles
+//! - `library` - Local library and refreshes of tracked series
+//! - `watcher` - Watch options and checks of watched series (needs `library`)
+//! - `auth` - Login, keychain credentials and the user's account
+//!
+//! Cache, metrics and cancellation commands below are always available,
+//! except `get_cache_stats`, which needs `search`, `detail` or `library` to
+//! cache pages on disk. Commands are re-exported here, so their paths don't
+//! depend on the group, e.g. `csfd_tauri::commands::search_series`.
+
+use tauri::State;
+
+use crate::error::CommandError;
+use crate::ScraperState;
+#[cfg(any(feature = "search", feature = "detail", feature = "library"))]
+use csfd_core::cache::CacheStats;
+use csfd_core::{Capabilities, ScraperMetrics};
+
+#[cfg(feature = "auth")]
+mod auth;
+#[cfg(feature = "detail")]
+mod detail;
+#[cfg(feature = "library")]
+mod library;
+#[cfg(feature = "search")]
+mod search;
+#[cfg(feature = "watcher")]
+mod watcher;
+
+#[cfg(feature = "auth")]
+pub use auth::*;
+#[cfg(feature = "detail")]
+pub use detail::*;
+#[cfg(feature = "library")]
+pub use library::*;
+#[cfg(feature = "search")]
+pub use search::*;
+#[cfg(feature = "watcher")]
+pub use watcher::*;
+
+/// Get the current use of the interactive and background in-flight limits.
+///
+/// Lets the frontend show how busy the scraper is, e.g. while a watcher
+/// refresh runs.
+///
+/// # Returns
+/// * `Ok(ScraperMetrics)` with `in_flight` and `max_in_flight` per lane
+#[tauri::command]
+pub async fn get_scraper_metrics(
+    state: State<'_, ScraperState>,
+) -> Result<ScraperMetrics, CommandError> {
+    Ok(state.scraper().metrics())
+}
+
+/// Get the hits, misses and writes of the page cache on disk.
+///
+/// # Returns
+/// * `Ok(Some(CacheStats))` since the app started
+/// * `Ok(None)` if the state has no data dir and so no disk cache
+#[cfg(any(feature = "search", feature = "detail", feature = "library"))]
+#[tauri::command]
+pub async fn get_cache_stats(
+    state: State<'_, ScraperState>,
+) -> Result<Option<CacheStats>, CommandError> {
+    Ok(state.scraper().client().disk_cache_stats())
+}
+
+/// Forget everything cached about a series, e.g. for a "refresh" button.
+///
+/// The next `get_series` or `get_episodes` call fetches it afresh; the
+/// library keeps its saved snapshot.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+///
+/// # Returns
+/// * `Ok(())` once every cache layer dropped the series
+/// * `Err(CommandError)` if the ID is invalid or the disk cache cannot be updated
+#[tauri::command]
+pub async fn invalidate(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+) -> Result<(), CommandError> {
+    state
+        .scraper()
+        .invalidate(series_id)
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Forget everything cached, in memory and on disk.
+///
+/// # Returns
+/// * `Ok(())` once every cache layer is empty
+/// * `Err(CommandError)` if the disk cache cannot be removed
+#[tauri::command]
+pub async fn invalidate_all(state: State<'_, ScraperState>) -> Result<(), CommandError> {
+    state
+        .scraper()
+        .invalidate_all()
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Abort an operation started with a `request_id`.
+///
+/// The aborted command fails with a "cancelled" error.
+///
+/// # Arguments
+/// * `request_id` - ID the operation was started with
+///
+/// # Returns
+/// * `Ok(true)` if the operation was still running
+/// * `Ok(false)` if it had already finished or never started
+#[tauri::command]
+pub async fn cancel_request(
+    state: State<'_, ScraperState>,
+    request_id: String,
+) -> Result<bool, CommandError> {
+    Ok(state.requests().cancel(&request_id))
+}
+
+/// Get the parser version and the fields each parser currently extracts.
+///
+/// Lets the frontend hide features whose extractor is known to be broken
+/// after a ČSFD markup change, e.g. episode air dates.
+///
+/// # Returns
+/// * `Ok(Capabilities)` with `version` and `parsers` (name and fields)
+#[tauri::command]
+pub async fn get_parser_capabilities() -> Result<Capabilities, CommandError> {
+    Ok(csfd_core::parser_capabilities())
+}
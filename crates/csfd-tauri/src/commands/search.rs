@@ -0,0 +1,192 @@
+//! Search commands (`search` feature)
+//!
+//! Searching ČSFD, type-ahead suggestions from the search history and
+//! instant matches from the bundled seed catalog.
+
+use tauri::State;
+
+use crate::error::CommandError;
+use crate::ScraperState;
+use csfd_core::workflows::{self, SeriesBrowse};
+use csfd_core::{PaginatedResult, SearchResult, SeriesDetail, SeriesFilters, Suggestion};
+
+/// Default number of type-ahead suggestions
+const DEFAULT_SUGGESTION_LIMIT: usize = 8;
+
+/// Search for series by name.
+///
+/// Returns the first page of search results. Successful queries are
+/// recorded in the search history, and titles opened before get a
+/// `local_rank`.
+///
+/// # Arguments
+/// * `query` - Search query string
+/// * `pin_local` - Move previously opened titles to the top (default: false)
+/// * `request_id` - ID to abort the search with `cancel_request`
+///
+/// # Returns
+/// * `Ok(PaginatedResult<SearchResult>)` with matching series
+/// * `Err(CommandError)` with error message if search fails or is cancelled
+#[tauri::command]
+pub async fn search_series(
+    state: State<'_, ScraperState>,
+    query: String,
+    pin_local: Option<bool>,
+    request_id: Option<String>,
+) -> Result<PaginatedResult<SearchResult>, CommandError> {
+    let mut results = {
+        let scraper = state.scraper();
+        state
+            .run_cancellable(request_id, scraper.search(&query))
+            .await
+            .map_err(|e| state.command_error(&e))?
+    };
+
+    let mut history = state.history().lock().await;
+    history.rank_results(&mut results.items, pin_local.unwrap_or(false));
+    // History is best-effort; a storage failure must not fail the search
+    let _ = history.record_query(&query);
+
+    Ok(results)
+}
+
+/// Search for series by name with pagination.
+///
+/// # Arguments
+/// * `query` - Search query string
+/// * `page` - Page number (1-based)
+/// * `pin_local` - Move previously opened titles to the top (default: false)
+/// * `request_id` - ID to abort the search with `cancel_request`
+///
+/// # Returns
+/// * `Ok(PaginatedResult<SearchResult>)` with matching series
+/// * `Err(CommandError)` with error message if search fails or is cancelled
+#[tauri::command]
+pub async fn search_series_page(
+    state: State<'_, ScraperState>,
+    query: String,
+    page: u32,
+    pin_local: Option<bool>,
+    request_id: Option<String>,
+) -> Result<PaginatedResult<SearchResult>, CommandError> {
+    let mut results = {
+        let scraper = state.scraper();
+        state
+            .run_cancellable(request_id, scraper.search_page(&query, page))
+            .await
+            .map_err(|e| state.command_error(&e))?
+    };
+
+    let history = state.history().lock().await;
+    history.rank_results(&mut results.items, pin_local.unwrap_or(false));
+
+    Ok(results)
+}
+
+/// Get type-ahead suggestions from previous searches and opened titles.
+///
+/// # Arguments
+/// * `prefix` - Text typed so far
+/// * `limit` - Maximum number of suggestions (default: 8)
+///
+/// # Returns
+/// * `Ok(Vec<Suggestion>)` ranked by how often and how recently they were used
+#[tauri::command]
+pub async fn get_search_suggestions(
+    state: State<'_, ScraperState>,
+    prefix: String,
+    limit: Option<usize>,
+) -> Result<Vec<Suggestion>, CommandError> {
+    let history = state.history().lock().await;
+    Ok(history.suggest(&prefix, limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT)))
+}
+
+/// Search the bundled seed catalog without network access.
+///
+/// Meant to be called alongside `search_series`, so the first results
+/// render instantly while ČSFD is still being asked. Titles opened before
+/// get a `local_rank` and are moved to the top.
+///
+/// # Arguments
+/// * `query` - Search query string
+/// * `limit` - Maximum number of results (default: 8)
+///
+/// # Returns
+/// * `Ok(Vec<SearchResult>)` with matching seed titles, empty without a seed
+#[tauri::command]
+pub async fn search_local(
+    state: State<'_, ScraperState>,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<SearchResult>, CommandError> {
+    let mut results = state
+        .seed()
+        .search(&query, limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT));
+    let history = state.history().lock().await;
+    history.rank_results(&mut results, true);
+    Ok(results)
+}
+
+/// Remember a search result the user opened.
+///
+/// # Arguments
+/// * `query` - Query that produced the result
+/// * `result` - The chosen search result
+///
+/// # Returns
+/// * `Ok(())` when recorded
+/// * `Err(CommandError)` with error message if the history cannot be saved
+#[tauri::command]
+pub async fn record_search_choice(
+    state: State<'_, ScraperState>,
+    query: String,
+    result: SearchResult,
+) -> Result<(), CommandError> {
+    let mut history = state.history().lock().await;
+    history
+        .record_choice(&query, &result)
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Search for a series and load its detail and first-season episodes.
+///
+/// # Arguments
+/// * `query` - Search query string
+/// * `request_id` - ID to abort the lookup with `cancel_request`
+///
+/// # Returns
+/// * `Ok(SeriesBrowse)` with the picked result, its detail and episodes
+/// * `Err(CommandError)` with error message if nothing was found, retrieval fails
+///   or the lookup is cancelled
+#[tauri::command]
+pub async fn browse_series(
+    state: State<'_, ScraperState>,
+    query: String,
+    request_id: Option<String>,
+) -> Result<SeriesBrowse, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_cancellable(request_id, workflows::browse_series(scraper, &query))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
+
+/// Pick a random well-rated series for a "náhodný seriál" button.
+///
+/// # Arguments
+/// * `filters` - Optional genre and year filters
+///
+/// # Returns
+/// * `Ok(SeriesDetail)` of the picked series
+/// * `Err(CommandError)` with error message if nothing matches or retrieval fails
+#[tauri::command]
+pub async fn get_random_series(
+    state: State<'_, ScraperState>,
+    filters: Option<SeriesFilters>,
+) -> Result<SeriesDetail, CommandError> {
+    let scraper = state.scraper();
+    state
+        .run_timed(scraper.get_random_series(&filters.unwrap_or_default()))
+        .await
+        .map_err(|e| state.command_error(&e))
+}
@@ -0,0 +1,131 @@
+//! Watcher commands (`watcher` feature)
+//!
+//! Watch options of tracked series and checks for new episodes and rating
+//! changes, compared against the snapshots in the library.
+
+use tauri::{AppHandle, Runtime, State};
+
+use super::library::fetch_snapshot;
+use crate::error::CommandError;
+use crate::watch::{self, WatchOptions, WatchReport};
+use crate::ScraperState;
+use csfd_core::{Lane, SeriesDiff};
+
+/// Set how changes of a tracked series are reported.
+///
+/// # Arguments
+/// * `series_id` - ČSFD ID of the series
+/// * `options` - Actions to run when `check_watched` finds changes, e.g.
+///   `{ "actions": [{ "type": "notification" }, { "type": "webhook", "url": "..." }] }`;
+///   `null` stops watching the series
+///
+/// # Returns
+/// * `Ok(())` once the options are saved
+/// * `Err(CommandError)` with error message if a webhook URL isn't http(s)
+///   or saving fails
+#[tauri::command]
+pub async fn set_watch_options(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+    options: Option<WatchOptions>,
+) -> Result<(), CommandError> {
+    Ok(state.watch().lock().await.set(series_id, options)?)
+}
+
+/// Get the watch options of a series.
+///
+/// # Returns
+/// * `Ok(Some(WatchOptions))` if the series is watched, `Ok(None)` otherwise
+#[tauri::command]
+pub async fn get_watch_options(
+    state: State<'_, ScraperState>,
+    series_id: u32,
+) -> Result<Option<WatchOptions>, CommandError> {
+    Ok(state.watch().lock().await.get(series_id).cloned())
+}
+
+/// Check watched series for new episodes and rating changes.
+///
+/// Each watched series is fetched in the background lane and compared with
+/// its snapshot in the library; the changes are reported through the
+/// series' watch actions and the library snapshot is updated. Series not in
+/// the library yet are saved without reporting anything.
+///
+/// Snapshots that are still fresh are skipped: running series are checked
+/// after the client's `ongoing_series` lifetime, ended ones (see
+/// `SeriesDetail::is_ongoing`) after `ended_series`.
+///
+/// # Arguments
+/// * `force` - Check all watched series, fresh or not (default: false)
+///
+/// Failures of single series don't fail the check: the changes found for
+/// the other series are returned together with the errors, since their
+/// snapshots are already updated and the changes would be lost otherwise.
+///
+/// # Returns
+/// * `Ok(WatchReport)` with the changes of all checked series in `events`
+///   and the errors of failed fetches, saves and actions in `errors`
+#[tauri::command]
+pub async fn check_watched<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, ScraperState>,
+    force: Option<bool>,
+) -> Result<WatchReport, CommandError> {
+    let watched: Vec<(u32, WatchOptions)> = {
+        let registry = state.watch().lock().await;
+        registry
+            .series_ids()
+            .into_iter()
+            .filter_map(|id| Some((id, registry.get(id)?.clone())))
+            .collect()
+    };
+    let watched = if force.unwrap_or(false) {
+        watched
+    } else {
+        let library = state.library().lock().await;
+        let freshness = state.scraper().client().freshness();
+        watched
+            .into_iter()
+            .filter(|(id, _)| library.get(*id).is_none_or(|entry| entry.is_due(freshness)))
+            .collect()
+    };
+
+    let scraper = state.scraper();
+    let session = scraper
+        .session()
+        .with_lane(Lane::Background)
+        .with_timeout(state.command_timeout());
+    let mut report = WatchReport::default();
+    for (series_id, options) in watched {
+        let (detail, episodes) = match session.run(fetch_snapshot(scraper, series_id)).await {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                let message = state.error_message(&error);
+                report.errors.push(format!("{}: {}", series_id, message));
+                continue;
+            }
+        };
+
+        let events = {
+            let mut library = state.library().lock().await;
+            let events = library
+                .get(series_id)
+                .map(|stored| {
+                    SeriesDiff::between(&stored.detail, &stored.episodes, &detail, &episodes)
+                        .events()
+                })
+                .unwrap_or_default();
+            if let Err(error) = library.save_series(detail.clone(), episodes) {
+                let message = state.error_message(&error);
+                report.errors.push(format!("{}: {}", series_id, message));
+            }
+            events
+        };
+
+        report
+            .errors
+            .extend(watch::notify(&app, &detail.name, &options, &events).await);
+        report.events.extend(events);
+    }
+    Ok(report)
+}
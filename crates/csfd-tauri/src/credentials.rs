@@ -0,0 +1,95 @@
+//! ČSFD credentials kept in the OS keychain
+//!
+//! Credentials are stored as one JSON entry in the platform secret store
+//! (Keychain on macOS, Credential Manager on Windows, Secret Service on
+//! Linux), never in the app's plaintext config.
+
+use std::fmt;
+
+use csfd_core::PasswordLogin;
+use serde::{Deserialize, Serialize};
+
+/// Keychain service name of the stored credentials
+pub const KEYCHAIN_SERVICE: &str = "csfd-scraper";
+
+/// Keychain account holding the credentials entry
+const KEYCHAIN_ACCOUNT: &str = "csfd";
+
+/// ČSFD login or session cookie of the user
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Credentials {
+    /// ČSFD username or e-mail
+    #[serde(default)]
+    pub username: Option<String>,
+    /// ČSFD password
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Session cookie in `name=value` form
+    #[serde(default)]
+    pub session_cookie: Option<String>,
+}
+
+impl Credentials {
+    /// Check whether nothing would be stored
+    pub fn is_empty(&self) -> bool {
+        self.username.is_none() && self.password.is_none() && self.session_cookie.is_none()
+    }
+
+    /// Get a login for logging in again, if both username and password are stored
+    pub fn password_login(&self) -> Option<PasswordLogin> {
+        match (&self.username, &self.password) {
+            (Some(username), Some(password)) => Some(PasswordLogin::new(username, password)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Secrets must not end up in logs
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "…"))
+            .field("session_cookie", &self.session_cookie.as_ref().map(|_| "…"))
+            .finish()
+    }
+}
+
+fn entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(KEYCHAIN_SERVICE, KEYCHAIN_ACCOUNT).map_err(|e| e.to_string())
+}
+
+/// Read the stored credentials.
+///
+/// # Returns
+/// * `Ok(None)` if nothing is stored
+/// * `Err(String)` if the keychain is unavailable or the entry is corrupt
+pub fn load() -> Result<Option<Credentials>, String> {
+    match entry()?.get_password() {
+        Ok(json) => serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| e.to_string()),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Store credentials, replacing any stored before.
+///
+/// # Errors
+/// Returns an error string if the keychain is unavailable.
+pub fn store(credentials: &Credentials) -> Result<(), String> {
+    let json = serde_json::to_string(credentials).map_err(|e| e.to_string())?;
+    entry()?.set_password(&json).map_err(|e| e.to_string())
+}
+
+/// Remove the stored credentials; succeeds if nothing was stored.
+///
+/// # Errors
+/// Returns an error string if the keychain is unavailable.
+pub fn clear() -> Result<(), String> {
+    match entry()?.delete_credential() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
@@ -0,0 +1,55 @@
+//! Errors returned by commands
+//!
+//! Commands reject with `{ message, retry }` instead of a bare string:
+//! `message` is shown to the user in the state's language, and `retry`
+//! tells the frontend whether to offer a retry button and when, e.g.
+//! `{ "retryable": true, "after_ms": 30000, "suggestion": "retry_later" }`
+//! after a rate limit.
+
+use std::fmt;
+
+use csfd_core::{CsfdError, Locale, RetryAdvice};
+use serde::Serialize;
+
+/// Error of a failed command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommandError {
+    /// Message for the user
+    pub message: String,
+    /// Whether and when retrying may help
+    pub retry: RetryAdvice,
+}
+
+impl CommandError {
+    /// Error of a failed scraper operation, with its message in the given language
+    pub fn from_csfd(error: &CsfdError, locale: Locale) -> Self {
+        Self {
+            message: error.localized(locale),
+            retry: error.retry_advice(),
+        }
+    }
+}
+
+/// Errors without a `CsfdError`, e.g. from the keychain, aren't retryable
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            retry: RetryAdvice::default(),
+        }
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> Self {
+        message.to_string().into()
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CommandError {}
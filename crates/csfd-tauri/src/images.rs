@@ -0,0 +1,94 @@
+//! Bounded loading of posters and photos for the frontend
+//!
+//! A virtualized list scrolling through hundreds of posters asks for all of
+//! them within a moment. `ImageQueue` lets a few downloads run at a time and
+//! keeps the rest waiting in order, so posters on screen aren't stuck behind
+//! a burst of requests; its metrics show how long the queue gets.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use csfd_core::{CsfdError, CsfdScraper, FetchedImage};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+
+/// Images loaded at once unless set otherwise
+pub const DEFAULT_MAX_IMAGE_LOADS: usize = 6;
+
+/// Current use of the image queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageQueueMetrics {
+    /// Images being loaded, from cache or ČSFD
+    pub loading: usize,
+    /// Images waiting for a free slot
+    pub queued: usize,
+    /// Maximum number of images loaded at once
+    pub max_loading: usize,
+}
+
+/// Queue limiting how many images are loaded at once
+pub struct ImageQueue {
+    slots: Semaphore,
+    queued: AtomicUsize,
+    max_loading: usize,
+}
+
+/// Counts a load as queued until it gets a slot or is dropped
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl ImageQueue {
+    /// Create a queue loading up to `max_loading` images at once (at least one)
+    pub fn new(max_loading: usize) -> Self {
+        let max_loading = max_loading.max(1);
+        Self {
+            slots: Semaphore::new(max_loading),
+            queued: AtomicUsize::new(0),
+            max_loading,
+        }
+    }
+
+    /// Load an image once a slot is free.
+    ///
+    /// Waiting loads get slots in the order they were queued. Cached images
+    /// take a slot only briefly, since `CsfdScraper::get_image` answers them
+    /// from memory.
+    ///
+    /// # Errors
+    /// Any error of `CsfdScraper::get_image`
+    pub async fn load(
+        &self,
+        scraper: &CsfdScraper,
+        url: &str,
+    ) -> csfd_core::Result<Arc<FetchedImage>> {
+        let _slot = {
+            self.queued.fetch_add(1, Ordering::Relaxed);
+            let _queued = QueuedGuard(&self.queued);
+            self.slots
+                .acquire()
+                .await
+                .map_err(|_| CsfdError::Cancelled)?
+        };
+        scraper.get_image(url).await
+    }
+
+    /// Get how many images are loading and waiting
+    pub fn metrics(&self) -> ImageQueueMetrics {
+        ImageQueueMetrics {
+            loading: self.max_loading - self.slots.available_permits(),
+            queued: self.queued.load(Ordering::Relaxed),
+            max_loading: self.max_loading,
+        }
+    }
+}
+
+impl Default for ImageQueue {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_IMAGE_LOADS)
+    }
+}
@@ -5,46 +5,325 @@
 //!
 //! # Usage
 //!
+//! Register the plugin and manage the scraper state:
+//!
 //! ```rust,ignore
 //! use csfd_tauri::ScraperState;
 //! use tauri::Manager;
 //!
 //! fn main() {
 //!     tauri::Builder::default()
+//!         .plugin(tauri_plugin_notification::init())
+//!         .plugin(csfd_tauri::init())
 //!         .setup(|app| {
 //!             app.manage(ScraperState::new()?);
 //!             Ok(())
 //!         })
-//!         .invoke_handler(tauri::generate_handler![
-//!             csfd_tauri::commands::search_series,
-//!             csfd_tauri::commands::search_series_page,
-//!             csfd_tauri::commands::get_series_detail,
-//!             csfd_tauri::commands::get_episodes,
-//!             csfd_tauri::commands::get_season_episodes,
-//!         ])
 //!         .run(tauri::generate_context!())
 //!         .expect("error while running tauri application");
 //! }
 //! ```
 //!
+//! The frontend invokes commands through the plugin, e.g.
+//! `invoke('plugin:csfd|search_series', { query: 'Dr. House' })`.
+//!
+//! # Permissions
+//! Commands are allowed through the app's capabilities. `csfd:default`
+//! allows reading public data only; the other sets are granted per window:
+//! - `csfd:read` - Search and read titles, episodes, people, lists and images
+//! - `csfd:library` - Local library and watched series
+//! - `csfd:read-account` - The logged-in user's ratings and watchlist
+//! - `csfd:write-account` - Login, keychain credentials and account changes
+//! - `csfd:filesystem-export` - Diagnostics bundles in the app data dir
+//!
+//! ```json
+//! {
+//!   "identifier": "main-capability",
+//!   "windows": ["main"],
+//!   "permissions": ["csfd:default", "csfd:library", "csfd:write-account"]
+//! }
+//! ```
+//!
+//! Each command also has its own `csfd:allow-<command>` permission, e.g.
+//! `csfd:allow-get-series-detail`.
+//!
+//! # Cargo features
+//! Commands come in groups, all enabled by default (see [`commands`]):
+//! `search`, `detail`, `library`, `watcher` (needs `library`) and `auth`.
+//! An app that only searches can leave out the local library, the watcher
+//! with its notification plugin and the OS keychain:
+//!
+//! ```toml
+//! csfd-tauri = { version = "0.1", default-features = false, features = ["search"] }
+//! ```
+//!
+//! The plugin then registers only the commands of the enabled groups.
+//!
 //! # Commands
 //! - `search_series` - Search for series by name
 //! - `search_series_page` - Search with pagination
 //! - `get_series_detail` - Get series details
+//! - `get_movie_detail` - Get film details
+//! - `get_person_detail` - Get a person's biography and filmography
+//! - `get_reviews` - User reviews of a title, page by page
+//! - `share_series` - Clipboard-friendly share text of a series
 //! - `get_episodes` - Get episode list
 //! - `get_season_episodes` - Get episodes for a specific season
+//! - `get_all_episodes` - Get all episodes sorted by season, fetching seasons concurrently
+//! - `get_episodes_grouped` - Get all episodes grouped by season
+//! - `get_full_series` - Get a series with all its episodes, streaming progress over a channel
+//! - `get_episode_detail` - Get plot, air date and credits of an episode
+//! - `get_episode_appearances` - Get episodes in which an actor appears
+//! - `get_random_series` - Pick a random well-rated series matching filters
+//! - `get_premiere_calendar` - Upcoming cinema, TV and VOD premieres by day
+//! - `get_trending` - Most-visited films or series from the homepage
+//! - `get_homepage` - News, trending titles, premieres and birthdays in one call
+//! - `get_user_lists` - Custom lists of a user
+//! - `get_list` - Titles and notes of a custom list, page by page
+//! - `create_list` - Create a list on the user's ČSFD profile
+//! - `add_to_list` - Add a title with a note to one of the user's lists
+//! - `get_my_ratings` - Titles the logged-in user rated, page by page
+//! - `get_watchlist` - The user's "Chci vidět" list, page by page
+//! - `add_to_watchlist` - Add a title to the user's "Chci vidět" list
+//! - `remove_from_watchlist` - Remove a title from the user's "Chci vidět" list
+//! - `get_search_suggestions` - Type-ahead suggestions from search history
+//! - `search_local` - Instant matches from the bundled seed catalog
+//! - `record_search_choice` - Remember a search result the user opened
+//! - `browse_series` - Search and load detail with first-season episodes
+//! - `resolve_deeplink` - Parse a ČSFD link and prefetch the series detail
+//! - `refresh_tracked` - Refresh tracked series with a per-series report
+//! - `set_watch_options` - Choose how changes of a tracked series are reported
+//! - `get_watch_options` - Current watch options of a series
+//! - `check_watched` - Check watched series for new episodes and rating changes
+//! - `get_scraper_metrics` - Interactive and background requests in flight
+//! - `get_image` - Load a poster or photo, a few at a time
+//! - `get_image_queue_metrics` - Images loading and waiting in `get_image`
+//! - `get_cache_stats` - Hits, misses and writes of the page cache on disk
+//! - `invalidate` - Drop a series from every cache, e.g. to refresh it
+//! - `invalidate_all` - Drop everything cached
+//! - `cancel_request` - Abort a search or other long operation started with a `request_id`
+//! - `get_parser_capabilities` - Parser version and the fields parsers extract
+//! - `create_diagnostics_bundle` - Zip the redacted page and parse report of a title for a bug report
+//! - `take_planned_actions` - Write actions recorded in dry-run mode
+//! - `save_to_library` - Save a series with its episodes to the local library
+//! - `query_library` - Read-only SQL analytics over the saved library
+//! - `login` - Log in to ČSFD and keep the credentials in the OS keychain
+//! - `save_credentials` - Store ČSFD login or session cookie in the OS keychain
+//! - `clear_credentials` - Remove stored credentials from the OS keychain
+//!
+//! # Errors
+//! Failed commands reject with a [`error::CommandError`], `{ message, retry }`,
+//! whose `retry` advice says whether and when to offer a retry button.
+//!
+//! # Events
+//! - `csfd://activity` - Batched request, retry, rate-limit wait and cache
+//!   hit events, see [`activity::forward_activity`]
+//! - `csfd://watch` - New episodes and rating changes of a watched series,
+//!   see [`watch::notify`]
+//! - `csfd://updated` - Fresh result of a detail or episodes command that
+//!   answered from stale cache, see [`updates::refresh_stale`]
 
+pub mod activity;
 pub mod commands;
+#[cfg(feature = "auth")]
+pub mod credentials;
+pub mod error;
+pub mod images;
+pub mod updates;
+#[cfg(feature = "watcher")]
+pub mod watch;
 
+use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
+#[cfg(any(
+    feature = "search",
+    feature = "library",
+    feature = "watcher",
+    feature = "auth"
+))]
 use tokio::sync::Mutex;
 
-use csfd_core::CsfdScraper;
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::Runtime;
+
+use csfd_core::cache::CacheStrategy;
+#[cfg(any(feature = "search", feature = "detail", feature = "library"))]
+use csfd_core::cache::DiskCacheConfig;
+#[cfg(feature = "library")]
+use csfd_core::Library;
+use csfd_core::{ActivityLog, CancelRegistry, CsfdError, CsfdScraper, LocalStore, Locale};
+use csfd_core::{ClientConfig, CsfdClient};
+#[cfg(feature = "search")]
+use csfd_core::{SearchHistory, SeedCatalog};
+
+#[cfg(feature = "auth")]
+use credentials::Credentials;
+use error::CommandError;
+use images::ImageQueue;
+#[cfg(feature = "watcher")]
+use watch::WatchRegistry;
+
+/// Pages kept in memory, enough for the titles open in one session
+const MEMORY_CACHE_PAGES: usize = 64;
+
+/// Time a command may take before it's aborted, unless set otherwise
+pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(45);
+
+/// Name of the plugin, the prefix of its commands and permissions
+pub const PLUGIN_NAME: &str = "csfd";
+
+/// Create the plugin registering the commands of the enabled groups.
+///
+/// Commands need a managed `ScraperState` and are allowed through the
+/// `csfd:*` permissions of the app's capabilities.
+///
+/// # Example
+/// ```rust,ignore
+/// tauri::Builder::default()
+///     .plugin(csfd_tauri::init())
+///     .setup(|app| {
+///         app.manage(ScraperState::new()?);
+///         Ok(())
+///     })
+/// ```
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+    Builder::new(PLUGIN_NAME)
+        .invoke_handler(tauri::generate_handler![
+            commands::get_scraper_metrics,
+            #[cfg(any(feature = "search", feature = "detail", feature = "library"))]
+            commands::get_cache_stats,
+            commands::invalidate,
+            commands::invalidate_all,
+            commands::cancel_request,
+            commands::get_parser_capabilities,
+            #[cfg(feature = "search")]
+            commands::search_series,
+            #[cfg(feature = "search")]
+            commands::search_series_page,
+            #[cfg(feature = "search")]
+            commands::get_search_suggestions,
+            #[cfg(feature = "search")]
+            commands::search_local,
+            #[cfg(feature = "search")]
+            commands::record_search_choice,
+            #[cfg(feature = "search")]
+            commands::browse_series,
+            #[cfg(feature = "search")]
+            commands::get_random_series,
+            #[cfg(feature = "detail")]
+            commands::get_series_detail,
+            #[cfg(feature = "detail")]
+            commands::get_movie_detail,
+            #[cfg(feature = "detail")]
+            commands::get_episode_detail,
+            #[cfg(feature = "detail")]
+            commands::get_person_detail,
+            #[cfg(feature = "detail")]
+            commands::get_reviews,
+            #[cfg(feature = "detail")]
+            commands::share_series,
+            #[cfg(feature = "detail")]
+            commands::get_episodes,
+            #[cfg(feature = "detail")]
+            commands::get_season_episodes,
+            #[cfg(feature = "detail")]
+            commands::get_all_episodes,
+            #[cfg(feature = "detail")]
+            commands::get_episodes_grouped,
+            #[cfg(feature = "detail")]
+            commands::get_full_series,
+            #[cfg(feature = "detail")]
+            commands::get_episode_appearances,
+            #[cfg(feature = "detail")]
+            commands::resolve_deeplink,
+            #[cfg(feature = "detail")]
+            commands::get_premiere_calendar,
+            #[cfg(feature = "detail")]
+            commands::get_homepage,
+            #[cfg(feature = "detail")]
+            commands::get_trending,
+            #[cfg(feature = "detail")]
+            commands::get_user_lists,
+            #[cfg(feature = "detail")]
+            commands::get_list,
+            #[cfg(feature = "detail")]
+            commands::get_image,
+            #[cfg(feature = "detail")]
+            commands::get_image_queue_metrics,
+            #[cfg(feature = "detail")]
+            commands::create_diagnostics_bundle,
+            #[cfg(feature = "library")]
+            commands::refresh_tracked,
+            #[cfg(feature = "library")]
+            commands::save_to_library,
+            #[cfg(feature = "library")]
+            commands::query_library,
+            #[cfg(feature = "watcher")]
+            commands::set_watch_options,
+            #[cfg(feature = "watcher")]
+            commands::get_watch_options,
+            #[cfg(feature = "watcher")]
+            commands::check_watched,
+            #[cfg(feature = "auth")]
+            commands::create_list,
+            #[cfg(feature = "auth")]
+            commands::add_to_list,
+            #[cfg(feature = "auth")]
+            commands::get_watchlist,
+            #[cfg(feature = "auth")]
+            commands::add_to_watchlist,
+            #[cfg(feature = "auth")]
+            commands::remove_from_watchlist,
+            #[cfg(feature = "auth")]
+            commands::get_my_ratings,
+            #[cfg(feature = "auth")]
+            commands::take_planned_actions,
+            #[cfg(feature = "auth")]
+            commands::login,
+            #[cfg(feature = "auth")]
+            commands::save_credentials,
+            #[cfg(feature = "auth")]
+            commands::clear_credentials,
+        ])
+        .build()
+}
+
+/// Client settings of the app: defaults with a memory cache, since the
+/// frontend reopens the same titles often, serving expired pages at once
+/// and refreshing them in the background
+fn app_config() -> ClientConfig {
+    ClientConfig {
+        memory_cache_capacity: MEMORY_CACHE_PAGES,
+        cache_strategy: CacheStrategy::StaleWhileRevalidate,
+        ..ClientConfig::default()
+    }
+}
+
+/// Restore the session cookie of stored credentials and log in again with
+/// the stored password when the session expires
+#[cfg(feature = "auth")]
+fn use_credentials(client: &CsfdClient, credentials: &Credentials) {
+    if let Some(cookie) = credentials.session_cookie.as_deref() {
+        client.set_session_cookie(cookie);
+    }
+    match credentials.password_login() {
+        Some(login) => client.set_reauthenticator(Arc::new(login)),
+        None => client.clear_reauthenticator(),
+    }
+}
 
 /// Thread-safe wrapper for CsfdScraper.
 ///
-/// This state is managed by Tauri and provides safe concurrent access
-/// to the scraper from multiple commands.
+/// This state is managed by Tauri and shares the scraper between commands
+/// without locking it, so a long background refresh doesn't block other
+/// commands; the scraper's lanes limit how much runs at once. Credentials
+/// stored in the OS keychain are read on creation and a saved session
+/// cookie is restored.
+///
+/// A seed catalog bundled with the app can be installed with `with_seed`,
+/// so `search_local` has matches before the first network search.
 ///
 /// # Example
 /// ```rust,ignore
@@ -57,8 +336,48 @@ use csfd_core::CsfdScraper;
 ///         Ok(())
 ///     })
 /// ```
+///
+/// With local data and a bundled seed catalog:
+/// ```rust,ignore
+/// const SEED: &[u8] = include_bytes!("../seed.json");
+///
+/// let dir = app.path().app_data_dir()?.join("csfd");
+/// app.manage(ScraperState::with_data_dir(dir)?.with_seed(SEED)?);
+/// ```
+///
+/// Error messages and share text are English unless set otherwise:
+/// ```rust,ignore
+/// use csfd_core::Locale;
+///
+/// app.manage(ScraperState::new()?.with_locale(Locale::Cs));
+/// ```
+///
+/// Commands reading from ČSFD give up after `DEFAULT_COMMAND_TIMEOUT`
+/// unless set otherwise, so a wedged request chain can't keep the
+/// frontend waiting:
+/// ```rust,ignore
+/// use std::time::Duration;
+///
+/// app.manage(ScraperState::new()?.with_command_timeout(Duration::from_secs(20)));
+/// ```
 pub struct ScraperState {
-    scraper: Arc<Mutex<CsfdScraper>>,
+    scraper: Arc<CsfdScraper>,
+    #[cfg(feature = "search")]
+    history: Arc<Mutex<SearchHistory>>,
+    #[cfg(feature = "library")]
+    library: Arc<Mutex<Library>>,
+    activity: ActivityLog,
+    #[cfg(feature = "auth")]
+    credentials: Arc<Mutex<Option<Credentials>>>,
+    #[cfg(feature = "search")]
+    seed: Arc<SeedCatalog>,
+    #[cfg(feature = "watcher")]
+    watch: Arc<Mutex<WatchRegistry>>,
+    requests: Arc<CancelRegistry>,
+    images: Arc<ImageQueue>,
+    store: Option<LocalStore>,
+    locale: Locale,
+    command_timeout: Duration,
 }
 
 impl ScraperState {
@@ -67,14 +386,241 @@ impl ScraperState {
     /// # Errors
     /// Returns an error string if the scraper cannot be created.
     pub fn new() -> Result<Self, String> {
-        let scraper = CsfdScraper::new().map_err(|e| e.to_string())?;
+        let client = CsfdClient::with_config(app_config()).map_err(|e| e.to_string())?;
+        let scraper = CsfdScraper::with_client(client);
+        Self::from_parts(scraper, None)
+    }
+
+    /// Create a new ScraperState persisting local data in the given directory.
+    ///
+    /// Typically called with the app data dir, e.g.
+    /// `app.path().app_data_dir()?.join("csfd")`. Fetched pages are cached
+    /// in its `cache` folder if a group fetching pages is enabled.
+    ///
+    /// # Errors
+    /// Returns an error string if the scraper cannot be created or the
+    /// local store cannot be opened.
+    pub fn with_data_dir(dir: impl Into<PathBuf>) -> Result<Self, String> {
+        let dir = dir.into();
+        let defaults = app_config();
+        let config = ClientConfig {
+            #[cfg(any(feature = "search", feature = "detail", feature = "library"))]
+            disk_cache: Some(DiskCacheConfig::new(dir.join("cache"), &defaults.freshness)),
+            ..defaults
+        };
+        let client = CsfdClient::with_config(config).map_err(|e| e.to_string())?;
+        let scraper = CsfdScraper::with_client(client);
+        let store = LocalStore::open(dir).map_err(|e| e.to_string())?;
+        Self::from_parts(scraper, Some(store))
+    }
+
+    /// Show error messages and share text in the given language.
+    ///
+    /// Defaults to the scraper's `ClientConfig::locale`.
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Abort commands still running after `timeout`.
+    ///
+    /// Defaults to `DEFAULT_COMMAND_TIMEOUT`. A command that runs out of
+    /// time fails with `CsfdError::Timeout`; batch commands report the
+    /// results they got so far.
+    pub fn with_command_timeout(mut self, timeout: Duration) -> Self {
+        self.command_timeout = timeout;
+        self
+    }
+
+    /// Load up to `max_loading` images at once in `get_image`.
+    ///
+    /// Defaults to `images::DEFAULT_MAX_IMAGE_LOADS`.
+    pub fn with_max_image_loads(mut self, max_loading: usize) -> Self {
+        self.images = Arc::new(ImageQueue::new(max_loading));
+        self
+    }
+
+    /// Use a seed catalog bundled with the app.
+    ///
+    /// With a data directory the catalog is copied into the local store on
+    /// first run and read from there afterwards; otherwise it is kept in
+    /// memory only.
+    ///
+    /// # Errors
+    /// Returns an error string if the catalog is invalid or cannot be stored.
+    #[cfg(feature = "search")]
+    pub fn with_seed(mut self, bundled: &[u8]) -> Result<Self, String> {
+        let seed = match &self.store {
+            Some(store) => SeedCatalog::install(store, bundled),
+            None => SeedCatalog::from_json(bundled),
+        };
+        self.seed = Arc::new(seed.map_err(|e| e.to_string())?);
+        Ok(self)
+    }
+
+    /// Open the local data of the enabled command groups from the store,
+    /// or keep it in memory without one
+    fn from_parts(scraper: CsfdScraper, store: Option<LocalStore>) -> Result<Self, String> {
+        #[cfg(feature = "search")]
+        let history = match &store {
+            Some(store) => SearchHistory::open(store.clone()).map_err(|e| e.to_string())?,
+            None => SearchHistory::new(),
+        };
+        #[cfg(feature = "library")]
+        let library = match &store {
+            Some(store) => Library::open(store.clone()).map_err(|e| e.to_string())?,
+            None => Library::new(),
+        };
+        #[cfg(feature = "watcher")]
+        let watch = match &store {
+            Some(store) => WatchRegistry::open(store.clone())?,
+            None => WatchRegistry::new(),
+        };
+        // A missing or locked keychain must not prevent the app from starting
+        #[cfg(feature = "auth")]
+        let credentials = credentials::load().ok().flatten();
+        #[cfg(feature = "auth")]
+        if let Some(credentials) = &credentials {
+            use_credentials(scraper.client(), credentials);
+        }
         Ok(Self {
-            scraper: Arc::new(Mutex::new(scraper)),
+            activity: scraper.client().activity().clone(),
+            locale: scraper.client().locale(),
+            scraper: Arc::new(scraper),
+            #[cfg(feature = "search")]
+            history: Arc::new(Mutex::new(history)),
+            #[cfg(feature = "library")]
+            library: Arc::new(Mutex::new(library)),
+            #[cfg(feature = "auth")]
+            credentials: Arc::new(Mutex::new(credentials)),
+            #[cfg(feature = "search")]
+            seed: Arc::new(SeedCatalog::default()),
+            #[cfg(feature = "watcher")]
+            watch: Arc::new(Mutex::new(watch)),
+            requests: Arc::new(CancelRegistry::new()),
+            images: Arc::new(ImageQueue::default()),
+            store,
+            command_timeout: DEFAULT_COMMAND_TIMEOUT,
         })
     }
 
     /// Get a reference to the inner scraper.
-    pub fn scraper(&self) -> &Arc<Mutex<CsfdScraper>> {
+    pub fn scraper(&self) -> &Arc<CsfdScraper> {
         &self.scraper
     }
+
+    /// Get a reference to the search history.
+    #[cfg(feature = "search")]
+    pub fn history(&self) -> &Arc<Mutex<SearchHistory>> {
+        &self.history
+    }
+
+    /// Get the library of saved series.
+    #[cfg(feature = "library")]
+    pub fn library(&self) -> &Arc<Mutex<Library>> {
+        &self.library
+    }
+
+    /// Get the seed catalog (empty unless set with `with_seed`).
+    #[cfg(feature = "search")]
+    pub fn seed(&self) -> &Arc<SeedCatalog> {
+        &self.seed
+    }
+
+    /// Get the watch options of tracked series.
+    #[cfg(feature = "watcher")]
+    pub fn watch(&self) -> &Arc<Mutex<WatchRegistry>> {
+        &self.watch
+    }
+
+    /// Get the language of error messages and share text.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Get the running operations the frontend can cancel by request ID.
+    pub fn requests(&self) -> &Arc<CancelRegistry> {
+        &self.requests
+    }
+
+    /// Get the queue of image loads.
+    pub fn images(&self) -> &Arc<ImageQueue> {
+        &self.images
+    }
+
+    /// Get the time a command may take before it's aborted.
+    pub fn command_timeout(&self) -> Duration {
+        self.command_timeout
+    }
+
+    /// Run an operation the frontend can cancel with `cancel_request`.
+    ///
+    /// The operation runs in a session with the command timeout and fails
+    /// with `CsfdError::Timeout` once it passes. With a request ID the
+    /// session holds the ID's token, and the operation fails with
+    /// `CsfdError::Cancelled` once cancelled or replaced by a newer
+    /// operation with the same ID.
+    pub async fn run_cancellable<T>(
+        &self,
+        request_id: Option<String>,
+        operation: impl Future<Output = csfd_core::Result<T>>,
+    ) -> csfd_core::Result<T> {
+        let session = self.scraper.session().with_timeout(self.command_timeout);
+        let Some(request_id) = request_id else {
+            return session.run(operation).await;
+        };
+        let request = self.requests.register(request_id);
+        let session = session.with_cancellation_token(request.token().clone());
+        session.run(operation).await
+    }
+
+    /// Run an operation with the command timeout.
+    ///
+    /// Aborting drops the operation, so its requests stop too. Commands
+    /// writing to the user's ČSFD account run without a timeout, since an
+    /// aborted write may have reached ČSFD anyway.
+    pub async fn run_timed<T>(
+        &self,
+        operation: impl Future<Output = csfd_core::Result<T>>,
+    ) -> csfd_core::Result<T> {
+        self.run_cancellable(None, operation).await
+    }
+
+    /// Message of an error for the frontend, in the state's language.
+    pub fn error_message(&self, error: &CsfdError) -> String {
+        error.localized(self.locale)
+    }
+
+    /// Error of a command for the frontend, with its message in the
+    /// state's language and retry advice.
+    pub fn command_error(&self, error: &CsfdError) -> CommandError {
+        CommandError::from_csfd(error, self.locale)
+    }
+
+    /// Get the directory of local data, if created with `with_data_dir`.
+    pub fn data_dir(&self) -> Option<&Path> {
+        self.store.as_ref().map(LocalStore::dir)
+    }
+
+    /// Get the credentials read from the OS keychain.
+    #[cfg(feature = "auth")]
+    pub fn credentials(&self) -> &Arc<Mutex<Option<Credentials>>> {
+        &self.credentials
+    }
+
+    /// Replace the credentials in use, restoring a given session cookie
+    /// and logging in again with a given password when the session expires.
+    #[cfg(feature = "auth")]
+    pub async fn set_credentials(&self, credentials: Option<Credentials>) {
+        match &credentials {
+            Some(credentials) => use_credentials(self.scraper.client(), credentials),
+            None => self.scraper.client().clear_reauthenticator(),
+        }
+        *self.credentials.lock().await = credentials;
+    }
+
+    /// Get the scraper's activity channel, e.g. for `activity::forward_activity`.
+    pub fn activity(&self) -> &ActivityLog {
+        &self.activity
+    }
 }
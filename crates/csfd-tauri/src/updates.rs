@@ -0,0 +1,126 @@
+//! Background refresh of stale results
+//!
+//! The app's client serves cached pages past their TTL at once (see
+//! `CacheStrategy::StaleWhileRevalidate`). Commands collect the expired
+//! pages they read with `CsfdClient::track_stale`; when a command answered
+//! from such pages, they are refetched in the background and the command's
+//! result is built again; if it changed, `csfd://updated` carries the
+//! fresh result so the frontend can patch the view it already rendered.
+//! Jobs that compare against ČSFD's current data, like watch checks, use
+//! `without_stale` instead.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use csfd_core::{CsfdScraper, Lane};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Runtime};
+
+/// Name of the event carrying a refreshed command result
+pub const UPDATED_EVENT: &str = "csfd://updated";
+
+/// Payload of `csfd://updated`
+#[derive(Debug, Clone, Serialize)]
+pub struct Updated<T> {
+    /// Command whose result changed (e.g., "get_series_detail")
+    pub command: &'static str,
+    /// What the result is about (e.g., "series:264494")
+    pub key: String,
+    /// The refreshed result, shaped as the command returns it
+    pub payload: T,
+}
+
+/// Refresh a command's result in the background if it was served stale.
+///
+/// Does nothing when the command read no stale page. Otherwise those
+/// pages are refetched in the background lane, `rebuild` runs
+/// again on the fresh pages, and `csfd://updated` is emitted if its result
+/// differs from `served`. Failures are dropped: the frontend keeps the
+/// stale result it already shows.
+///
+/// # Arguments
+/// * `app` - App handle used to emit the event
+/// * `scraper` - Scraper that served the result
+/// * `command` - Name of the command, passed to the frontend
+/// * `key` - What the result is about, passed to the frontend
+/// * `served` - Result the command returned
+/// * `stale` - Expired pages the command read, from `CsfdClient::track_stale`
+/// * `rebuild` - Builds the result again from the scraper
+///
+/// # Example
+/// ```rust,ignore
+/// let key = format!("series:{}", csfd_id);
+/// let (detail, stale) = scraper.client().track_stale(scraper.get_series(csfd_id)).await;
+/// let detail = detail?;
+/// updates::refresh_stale(&app, scraper, "get_series_detail", key, &detail, stale, move |scraper| async move {
+///     scraper.get_series(csfd_id).await
+/// });
+/// ```
+pub fn refresh_stale<R, T, F, Fut>(
+    app: &AppHandle<R>,
+    scraper: &Arc<CsfdScraper>,
+    command: &'static str,
+    key: String,
+    served: &T,
+    stale: Vec<String>,
+    rebuild: F,
+) where
+    R: Runtime,
+    T: Serialize + Clone + Send + 'static,
+    F: FnOnce(Arc<CsfdScraper>) -> Fut + Send + 'static,
+    Fut: Future<Output = csfd_core::Result<T>> + Send + 'static,
+{
+    if stale.is_empty() {
+        return;
+    }
+
+    let app = app.clone();
+    let scraper = Arc::clone(scraper);
+    let served = serde_json::to_value(served).ok();
+    tauri::async_runtime::spawn(async move {
+        let session = scraper.session().with_lane(Lane::Background);
+        if session
+            .run(scraper.client().revalidate_pages(&stale))
+            .await
+            .is_err()
+        {
+            return;
+        }
+        let Ok(fresh) = session.run(rebuild(Arc::clone(&scraper))).await else {
+            return;
+        };
+        if serde_json::to_value(&fresh).ok() != served {
+            let _ = app.emit(
+                UPDATED_EVENT,
+                Updated {
+                    command,
+                    key,
+                    payload: fresh,
+                },
+            );
+        }
+    });
+}
+
+/// Run an operation, running it again on refetched pages if it read stale ones.
+///
+/// Only the pages this operation read are refetched, not those other
+/// commands read meanwhile. The second run reads the revalidated pages
+/// from memory, so it costs no more requests than fetching them again.
+///
+/// # Errors
+/// Any error of the operation or of refetching the stale pages
+pub async fn without_stale<T, F, Fut>(scraper: &CsfdScraper, operation: F) -> csfd_core::Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = csfd_core::Result<T>>,
+{
+    let client = scraper.client();
+    let (result, stale) = client.track_stale(operation()).await;
+    let result = result?;
+    if stale.is_empty() {
+        return Ok(result);
+    }
+    client.revalidate_pages(&stale).await?;
+    operation().await
+}
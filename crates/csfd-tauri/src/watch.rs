@@ -0,0 +1,213 @@
+//! Notifications about changes of watched series
+//!
+//! Each tracked series has `WatchOptions` listing what to do when a check
+//! finds new episodes or rating changes: emit `csfd://watch` to the
+//! frontend, show an OS notification, or POST the events to a webhook.
+//! Options are kept in the local store, so they survive restarts.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use csfd_core::{LocalStore, WatchEvent};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Runtime};
+use tauri_plugin_notification::NotificationExt;
+
+/// Name of the event carrying the changes of one watched series
+pub const WATCH_EVENT: &str = "csfd://watch";
+
+/// Store key of the watch options
+const WATCH_OPTIONS_KEY: &str = "watch_options";
+
+/// Longest a webhook may take to answer before the post is given up
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What to do when a watched series changes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WatchAction {
+    /// Emit `csfd://watch` with the events to the frontend
+    Event,
+    /// Show an OS notification through the notification plugin
+    Notification,
+    /// POST the events as JSON to a user-provided URL
+    Webhook {
+        /// URL to post to
+        url: String,
+    },
+}
+
+/// Actions for one tracked series
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchOptions {
+    /// Actions run for each check that finds changes
+    #[serde(default)]
+    pub actions: Vec<WatchAction>,
+}
+
+impl WatchOptions {
+    /// Check that webhook URLs are absolute http(s) URLs
+    ///
+    /// # Errors
+    /// Returns an error string naming the first invalid URL.
+    pub fn validate(&self) -> Result<(), String> {
+        for action in &self.actions {
+            if let WatchAction::Webhook { url } = action {
+                let valid = reqwest::Url::parse(url)
+                    .is_ok_and(|parsed| matches!(parsed.scheme(), "http" | "https"));
+                if !valid {
+                    return Err(format!("Invalid webhook URL: {}", url));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            actions: vec![WatchAction::Event],
+        }
+    }
+}
+
+/// Watch options of all tracked series, keyed by ČSFD ID
+#[derive(Debug, Default)]
+pub struct WatchRegistry {
+    options: HashMap<u32, WatchOptions>,
+    store: Option<LocalStore>,
+}
+
+impl WatchRegistry {
+    /// Create a registry kept in memory only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the registry saved in a local store
+    ///
+    /// # Errors
+    /// Returns an error string if the saved options cannot be read.
+    pub fn open(store: LocalStore) -> Result<Self, String> {
+        let options = store.load(WATCH_OPTIONS_KEY).map_err(|e| e.to_string())?;
+        Ok(Self {
+            options,
+            store: Some(store),
+        })
+    }
+
+    /// Get the options of a series, `None` if it isn't watched
+    pub fn get(&self, series_id: u32) -> Option<&WatchOptions> {
+        self.options.get(&series_id)
+    }
+
+    /// Get the IDs of all watched series
+    pub fn series_ids(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.options.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Set the options of a series; `None` stops watching it
+    ///
+    /// # Errors
+    /// Returns an error string if a webhook URL isn't http(s) (see
+    /// `WatchOptions::validate`) or the options cannot be saved.
+    pub fn set(&mut self, series_id: u32, options: Option<WatchOptions>) -> Result<(), String> {
+        if let Some(options) = &options {
+            options.validate()?;
+        }
+        match options {
+            Some(options) => self.options.insert(series_id, options),
+            None => self.options.remove(&series_id),
+        };
+        match &self.store {
+            Some(store) => store
+                .save(WATCH_OPTIONS_KEY, &self.options)
+                .map_err(|e| e.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Result of checking watched series
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WatchReport {
+    /// Changes of all checked series; their snapshots are already updated,
+    /// so these are the only record of them
+    pub events: Vec<WatchEvent>,
+    /// Errors of failed fetches, saves and actions, each naming its series
+    pub errors: Vec<String>,
+}
+
+/// Run the actions of a watched series for the changes found by a check.
+///
+/// Failing actions don't stop the others; their errors are returned so
+/// the caller can report them, e.g. an unreachable webhook.
+///
+/// # Arguments
+/// * `app` - App handle used to emit events and show notifications
+/// * `name` - Name of the series, shown in notifications
+/// * `options` - Actions of the series
+/// * `events` - Changes of the series, see `SeriesDiff::events`
+pub async fn notify<R: Runtime>(
+    app: &AppHandle<R>,
+    name: &str,
+    options: &WatchOptions,
+    events: &[WatchEvent],
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    if events.is_empty() {
+        return errors;
+    }
+
+    for action in &options.actions {
+        let result = match action {
+            WatchAction::Event => app.emit(WATCH_EVENT, events).map_err(|e| e.to_string()),
+            WatchAction::Notification => app
+                .notification()
+                .builder()
+                .title(name)
+                .body(summary(events))
+                .show()
+                .map_err(|e| e.to_string()),
+            WatchAction::Webhook { url } => post_webhook(url, events).await,
+        };
+        if let Err(error) = result {
+            errors.push(format!("{}: {}", name, error));
+        }
+    }
+    errors
+}
+
+/// One-line summary of the changes, e.g. "Nové díly: 2, Změny hodnocení: 1".
+fn summary(events: &[WatchEvent]) -> String {
+    let new_episodes = events
+        .iter()
+        .filter(|e| matches!(e, WatchEvent::NewEpisode { .. }))
+        .count();
+    let rating_changes = events.len() - new_episodes;
+    let mut parts = Vec::new();
+    if new_episodes > 0 {
+        parts.push(format!("Nové díly: {}", new_episodes));
+    }
+    if rating_changes > 0 {
+        parts.push(format!("Změny hodnocení: {}", rating_changes));
+    }
+    parts.join(", ")
+}
+
+async fn post_webhook(url: &str, events: &[WatchEvent]) -> Result<(), String> {
+    reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?
+        .post(url)
+        .json(events)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}